@@ -1,3 +1,4 @@
+use anyhow::Context;
 use brotli::enc::BrotliEncoderParams;
 use std::fs;
 use std::path::Path;
@@ -9,6 +10,8 @@ use std::{fs::File, io::Write};
 use crate::Result;
 use dioxus_cli_config::CrateConfig;
 use dioxus_cli_config::Platform;
+use dioxus_html::HtmlCtx;
+use dioxus_rsx::hot_reload::{FileMap, FileMapBuildResult};
 use manganis_cli_support::{AssetManifest, AssetManifestExt};
 
 /// The temp file name for passing manganis json from linker to current exec.
@@ -68,6 +71,65 @@ impl Drop for AssetConfigDropGuard {
     }
 }
 
+/// Scope a CSS module the same way the `css_module!` macro does. Returns the id of the module and the scoped stylesheet.
+pub fn scope_css_module(config: &CrateConfig, path: &Path) -> anyhow::Result<(String, String)> {
+    let package = config
+        .manifest
+        .package
+        .as_ref()
+        .context("CSS modules must be in a package")?;
+    let relative_path = path
+        .strip_prefix(&config.crate_dir)
+        .context("CSS modules must be inside the crate")?
+        .to_string_lossy()
+        .replace('\\', "/");
+    let id = dioxus_css_module::module_id(&package.name, &relative_path);
+    let css = fs::read_to_string(path)?;
+    let scoped = dioxus_css_module::scope_css(&css, &id)
+        .with_context(|| format!("Failed to scope {}", path.display()))?;
+    Ok((id, scoped.css))
+}
+
+pub const TEMPLATE_CACHE_FILE: &str = "templates.json";
+
+/// Extract the templates of every `rsx!` call in the crate into a template cache in the output directory that
+/// renderers can preload
+pub(crate) fn bundle_template_cache(
+    config: &CrateConfig,
+    platform: Platform,
+) -> anyhow::Result<()> {
+    let FileMapBuildResult { mut map, errors } =
+        FileMap::<HtmlCtx>::create(config.crate_dir.clone())?;
+    for err in errors {
+        tracing::warn!("Failed to read a file while extracting templates: {err}");
+    }
+    let cache = map.template_cache(&config.crate_dir)?;
+    if cache.is_empty() {
+        return Ok(());
+    }
+
+    tracing::info!("Extracting {} templates...", cache.len());
+    let output_dir = match platform {
+        Platform::Web => config.out_dir().join(
+            config
+                .dioxus_config
+                .web
+                .app
+                .base_path
+                .clone()
+                .unwrap_or_default(),
+        ),
+        _ => config.out_dir(),
+    };
+    fs::create_dir_all(&output_dir)?;
+    fs::write(
+        output_dir.join(TEMPLATE_CACHE_FILE),
+        serde_json::to_string(&cache)?,
+    )?;
+
+    Ok(())
+}
+
 pub fn copy_assets_dir(config: &CrateConfig, platform: Platform) -> anyhow::Result<()> {
     tracing::info!("Copying public assets to the output directory...");
     let out_dir = config.out_dir();
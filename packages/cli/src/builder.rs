@@ -1,7 +1,7 @@
 use crate::{
     assets::{
-        asset_manifest, copy_assets_dir, create_assets_head, pre_compress_folder, process_assets,
-        AssetConfigDropGuard,
+        asset_manifest, bundle_template_cache, copy_assets_dir, create_assets_head,
+        pre_compress_folder, process_assets, AssetConfigDropGuard,
     },
     error::{Error, Result},
     link::LinkCommand,
@@ -79,7 +79,8 @@ pub fn build_web(
     // [3] Wasm-bindgen the .wasm file, and move it into the {builddir}/modules/xxxx/xxxx_bg.wasm
     // [4] Wasm-opt the .wasm file with whatever optimizations need to be done
     // [5][OPTIONAL] Builds the Tailwind CSS file using the Tailwind standalone binary
-    // [6] Link up the html page to the wasm module
+    // [6] Extract the templates in the crate into a template cache
+    // [7] Link up the html page to the wasm module
 
     let CrateConfig {
         crate_dir,
@@ -294,6 +295,11 @@ pub fn build_web(
         }
     }
 
+    // [6] Extract the templates in the crate so the renderer can preload them
+    if let Err(err) = bundle_template_cache(config, dioxus_cli_config::Platform::Web) {
+        tracing::warn!("Failed to extract the templates in the crate: {err}");
+    }
+
     // this code will copy all public file to the output dir
     copy_assets_dir(config, dioxus_cli_config::Platform::Web)?;
 
@@ -442,6 +448,9 @@ pub fn build_desktop(
     }
 
     copy_assets_dir(config, dioxus_cli_config::Platform::Desktop)?;
+    if let Err(err) = bundle_template_cache(config, dioxus_cli_config::Platform::Desktop) {
+        tracing::warn!("Failed to extract the templates in the crate: {err}");
+    }
 
     let assets = if !skip_assets {
         tracing::info!("Processing assets");
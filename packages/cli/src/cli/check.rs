@@ -1,3 +1,6 @@
+use crate::server::fullstack;
+use dioxus_cli_config::Platform;
+use dioxus_ssr::a11y::Auditor;
 use futures_util::{stream::FuturesUnordered, StreamExt};
 use std::{path::Path, process::exit};
 
@@ -12,11 +15,29 @@ pub struct Check {
     /// Input file
     #[clap(short, long)]
     pub file: Option<PathBuf>,
+
+    /// Audit the pages of the app for accessibility issues instead of checking the RSX.
+    ///
+    /// Without any paths, the app is built for static generation and every static route is rendered with
+    /// `dioxus_ssr` before the pages are audited. Pass HTML files or folders to audit pages that were already rendered.
+    #[clap(long, num_args = 0..)]
+    pub a11y: Option<Vec<PathBuf>>,
+
+    /// The example to render when auditing the routes of an app
+    #[clap(long)]
+    pub example: Option<String>,
 }
 
 impl Check {
     // Todo: check the entire crate
     pub fn check(self) -> Result<()> {
+        if let Some(mut pages) = self.a11y {
+            if pages.is_empty() {
+                pages.push(render_routes(self.example)?);
+            }
+            return audit_pages_and_report(pages);
+        }
+
         let rt = tokio::runtime::Runtime::new().unwrap();
 
         rt.block_on(async move {
@@ -109,7 +130,97 @@ async fn check_files_and_report(files_to_check: Vec<PathBuf>) -> Result<()> {
     }
 }
 
+/// Render every static route of the app with static generation and return the folder the pages were written to
+fn render_routes(example: Option<String>) -> Result<PathBuf> {
+    let mut crate_config = dioxus_cli_config::CrateConfig::new(None)?;
+    if let Some(example) = example {
+        crate_config.as_example(example);
+    }
+    crate_config.extend_with_platform(Platform::StaticGeneration);
+
+    // Static generation renders the pages in the server binary, so the client doesn't need to be built
+    let build = ConfigOptsBuild {
+        platform: Some(Platform::StaticGeneration),
+        ..Default::default()
+    };
+    crate_config.set_features(vec!["server".to_string()]);
+    let build_result = crate::builder::build_desktop(
+        &crate_config,
+        false,
+        true,
+        Some(fullstack::server_rust_flags(&build)),
+    )?;
+    let executable = build_result
+        .executable
+        .ok_or_else(|| Error::BuildFailed("the server binary was not found".to_string()))?;
+
+    // Outside of cargo and the dev server, the app exits once the pages are written instead of serving them
+    let status = Command::new(executable)
+        .current_dir(&crate_config.crate_dir)
+        .env_remove("CARGO")
+        .env_remove("DIOXUS_ACTIVE")
+        .status()?;
+    if !status.success() {
+        return Err(Error::RuntimeError(format!(
+            "rendering the routes of the app failed with {status}"
+        )));
+    }
+
+    Ok(crate_config.crate_dir.join("static"))
+}
+
+/// Audit the accessibility of rendered HTML pages and report the issues
+fn audit_pages_and_report(paths: Vec<PathBuf>) -> Result<()> {
+    let mut pages = vec![];
+    for path in &paths {
+        if path.is_dir() {
+            collect_files_with_extension(path, "html", &mut pages);
+        } else {
+            pages.push(path.clone());
+        }
+    }
+
+    if pages.is_empty() {
+        return Err(format!(
+            "no HTML pages found in {}. Render the pages of the app before auditing them",
+            paths
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+        .into());
+    }
+
+    let auditor = Auditor::new();
+    let mut total_issues = 0;
+    for page in pages {
+        let contents = std::fs::read_to_string(&page)?;
+        let report = auditor.audit_html(&contents);
+        if !report.is_empty() {
+            println!("{}:", page.display());
+            for issue in report.issues() {
+                println!("{}", issue);
+            }
+        }
+        total_issues += report.issues().len();
+    }
+
+    match total_issues {
+        0 => {
+            println!("No accessibility issues found.");
+            Ok(())
+        }
+        1 => Err("1 accessibility issue found.".into()),
+        _ => Err(format!("{} accessibility issues found.", total_issues).into()),
+    }
+}
+
 fn collect_rs_files(folder: &Path, files: &mut Vec<PathBuf>) {
+    collect_files_with_extension(folder, "rs", files)
+}
+
+fn collect_files_with_extension(folder: &Path, extension: &str, files: &mut Vec<PathBuf>) {
     let Ok(folder) = folder.read_dir() else {
         return;
     };
@@ -124,11 +235,11 @@ fn collect_rs_files(folder: &Path, files: &mut Vec<PathBuf>) {
         let path = entry.path();
 
         if path.is_dir() {
-            collect_rs_files(&path, files);
+            collect_files_with_extension(&path, extension, files);
         }
 
         if let Some(ext) = path.extension() {
-            if ext == "rs" {
+            if ext == extension {
                 files.push(path);
             }
         }
@@ -0,0 +1,34 @@
+use super::*;
+
+/// Generate typed rsx bindings for the web components in a custom elements manifest
+#[derive(Clone, Debug, Parser)]
+#[clap(name = "custom-elements")]
+pub struct CustomElements {
+    /// The `custom-elements.json` manifest to generate bindings for
+    pub manifest: PathBuf,
+
+    /// Output file, stdout if not present
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// The path the generated code uses to refer to the dioxus crate
+    #[arg(long, default_value = "dioxus")]
+    pub dioxus_path: String,
+}
+
+impl CustomElements {
+    pub fn generate(self) -> Result<()> {
+        let manifest = std::fs::read_to_string(&self.manifest)?;
+
+        let out = dioxus_custom_elements::Generator::new()
+            .dioxus_path(self.dioxus_path)
+            .generate(&manifest)?;
+
+        match self.output {
+            Some(output) => std::fs::write(output, out)?,
+            None => print!("{}", out),
+        }
+
+        Ok(())
+    }
+}
@@ -6,6 +6,7 @@ pub mod check;
 pub mod clean;
 pub mod config;
 pub mod create;
+pub mod custom_elements;
 pub mod init;
 pub mod link;
 pub mod plugin;
@@ -54,6 +55,10 @@ pub enum Commands {
     /// Translate a source file into Dioxus code.
     Translate(translate::Translate),
 
+    /// Generate typed rsx bindings for web components from a custom elements manifest.
+    #[clap(name = "custom-elements")]
+    CustomElements(custom_elements::CustomElements),
+
     /// Build, watch & serve the Dioxus project and all of its assets.
     Serve(serve::Serve),
 
@@ -97,6 +102,7 @@ impl Display for Commands {
         match self {
             Commands::Build(_) => write!(f, "build"),
             Commands::Translate(_) => write!(f, "translate"),
+            Commands::CustomElements(_) => write!(f, "custom-elements"),
             Commands::Serve(_) => write!(f, "serve"),
             Commands::New(_) => write!(f, "create"),
             Commands::Init(_) => write!(f, "init"),
@@ -66,6 +66,12 @@ impl From<html_parser::Error> for Error {
     }
 }
 
+impl From<dioxus_custom_elements::Error> for Error {
+    fn from(e: dioxus_custom_elements::Error) -> Self {
+        Self::ParseError(e.to_string())
+    }
+}
+
 impl From<hyper::Error> for Error {
     fn from(e: hyper::Error) -> Self {
         Self::RuntimeError(e.to_string())
@@ -26,6 +26,10 @@ fn main() -> anyhow::Result<()> {
             .translate()
             .context(error_wrapper("Translation of HTML into RSX failed")),
 
+        CustomElements(opts) => opts.generate().context(error_wrapper(
+            "Generating bindings for custom elements failed",
+        )),
+
         New(opts) => opts
             .create()
             .context(error_wrapper("Creating new project failed")),
@@ -1,4 +1,4 @@
-use crate::{cfg::ConfigOptsServe, BuildResult, Result};
+use crate::{assets::scope_css_module, cfg::ConfigOptsServe, BuildResult, Result};
 use dioxus_cli_config::CrateConfig;
 
 use cargo_metadata::diagnostic::Diagnostic;
@@ -245,6 +245,20 @@ fn hotreload_file(
         return None;
     }
 
+    // CSS modules are scoped and sent to the renderer directly. Class names only depend on where the module is, so
+    // changing the stylesheet never changes the rsx that uses it
+    if path
+        .to_string_lossy()
+        .ends_with(dioxus_css_module::MODULE_EXTENSION)
+    {
+        match scope_css_module(config, path) {
+            Ok((id, css)) => messages.push(HotReloadMsg::UpdateCssModule { id, css }),
+            Err(err) => tracing::error!("{err:#}"),
+        }
+
+        return None;
+    }
+
     // Attempt to hotreload css in the asset directory
     // Currently no other assets are hotreloaded, but in theory we could hotreload pngs/jpegs, etc
     //
@@ -0,0 +1,124 @@
+use std::{collections::BTreeMap, path::Path};
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Ident, LitStr, Result, Token, Visibility,
+};
+
+/// The input to `css_module!`: `pub Styles = "src/styles.module.css"`
+pub struct CssModule {
+    vis: Visibility,
+    name: Ident,
+    path: LitStr,
+    classes: Vec<(Ident, String, String)>,
+    id: String,
+    css: String,
+    full_path: String,
+}
+
+impl Parse for CssModule {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let path: LitStr = input.parse()?;
+        if !input.is_empty() {
+            input.parse::<Token![;]>()?;
+        }
+
+        let relative_path = path.value();
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+        let package = std::env::var("CARGO_PKG_NAME").unwrap_or_default();
+        let full_path = Path::new(&manifest_dir).join(&relative_path);
+
+        let source = std::fs::read_to_string(&full_path).map_err(|err| {
+            syn::Error::new(
+                path.span(),
+                format!("Failed to read `{}`: {err}", full_path.display()),
+            )
+        })?;
+        let id = dioxus_css_module::module_id(&package, &relative_path);
+        let scoped = dioxus_css_module::scope_css(&source, &id).map_err(|err| {
+            syn::Error::new(
+                path.span(),
+                format!("Failed to parse `{relative_path}`: {err}"),
+            )
+        })?;
+
+        // Every class becomes a constant with the dashes replaced by underscores
+        let mut constants: BTreeMap<String, &str> = BTreeMap::new();
+        let mut classes = Vec::new();
+        for (class, scoped_class) in &scoped.classes {
+            let constant = class.replace('-', "_");
+            if let Some(other) = constants.insert(constant.clone(), class) {
+                return Err(syn::Error::new(
+                    path.span(),
+                    format!(
+                        "The classes `.{other}` and `.{class}` would both be named `{constant}`"
+                    ),
+                ));
+            }
+            let ident = syn::parse_str::<Ident>(&constant)
+                .or_else(|_| syn::parse_str::<Ident>(&format!("r#{constant}")))
+                .map_err(|_| {
+                    syn::Error::new(
+                        path.span(),
+                        format!("The class `.{class}` can't be used as the name of a constant"),
+                    )
+                })?;
+            classes.push((ident, class.clone(), scoped_class.clone()));
+        }
+
+        Ok(Self {
+            vis,
+            name,
+            path,
+            classes,
+            id,
+            css: scoped.css,
+            full_path: full_path.to_string_lossy().to_string(),
+        })
+    }
+}
+
+impl ToTokens for CssModule {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let Self {
+            vis,
+            name,
+            path,
+            id,
+            css,
+            full_path,
+            ..
+        } = self;
+
+        let struct_doc = format!("The scoped classes in `{}`", path.value());
+        let classes = self.classes.iter().map(|(ident, class, scoped)| {
+            let doc = format!("The scoped name of `.{class}`");
+            quote! {
+                #[doc = #doc]
+                #vis const #ident: &'static str = #scoped;
+            }
+        });
+
+        tokens.extend(quote! {
+            #[doc = #struct_doc]
+            #vis struct #name;
+
+            #[allow(non_upper_case_globals)]
+            impl #name {
+                #(#classes)*
+
+                /// The scoped stylesheet. Pass it to `use_css_module` to render it.
+                #vis const MODULE: dioxus_elements::css_module::CssModule =
+                    dioxus_elements::css_module::CssModule::new(#id, #path, #css);
+            }
+
+            // Recompile when the stylesheet changes
+            const _: &str = include_str!(#full_path);
+        });
+    }
+}
@@ -8,6 +8,7 @@ use quote::ToTokens;
 use syn::parse_macro_input;
 
 mod component;
+mod css_module;
 mod props;
 mod utils;
 
@@ -39,3 +40,11 @@ pub fn component(_args: TokenStream, input: TokenStream) -> TokenStream {
         .into_token_stream()
         .into()
 }
+
+#[doc = include_str!("../docs/css_module.md")]
+#[proc_macro]
+pub fn css_module(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as css_module::CssModule)
+        .into_token_stream()
+        .into()
+}
@@ -84,7 +84,7 @@ impl VirtualDom {
             context.height
         };
 
-        self.dirty_scopes.remove(&ScopeOrder::new(height, id));
+        self.remove_dirty_scope(&ScopeOrder::new(height, id));
     }
 }
 
@@ -6,8 +6,8 @@ use std::{
 use crate::{
     any_props::AnyProps,
     innerlude::{
-        ElementRef, MountId, ScopeOrder, SuspenseBoundaryProps, SuspenseBoundaryPropsWithOwner,
-        VComponent, WriteMutations,
+        ElementRef, KeepAliveProps, MountId, PortalProps, ScopeOrder, SuspenseBoundaryProps,
+        SuspenseBoundaryPropsWithOwner, VComponent, WriteMutations,
     },
     nodes::VNode,
     scopes::ScopeId,
@@ -18,15 +18,24 @@ use crate::{
 impl VirtualDom {
     pub(crate) fn run_and_diff_scope<M: WriteMutations>(
         &mut self,
-        to: Option<&mut M>,
+        mut to: Option<&mut M>,
         scope_id: ScopeId,
     ) {
         let scope = &mut self.scopes[scope_id.0];
         if SuspenseBoundaryProps::downcast_mut_from_props(&mut *scope.props).is_some() {
-            SuspenseBoundaryProps::diff(scope_id, self, to)
+            SuspenseBoundaryProps::diff(scope_id, self, to.as_deref_mut())
+        } else if PortalProps::downcast_mut_from_props(&mut *scope.props).is_some() {
+            PortalProps::diff(scope_id, self, to.as_deref_mut())
+        } else if KeepAliveProps::downcast_mut_from_props(&mut *scope.props).is_some() {
+            KeepAliveProps::diff(scope_id, self, to.as_deref_mut())
         } else {
             let new_nodes = self.run_scope(scope_id);
-            self.diff_scope(to, scope_id, new_nodes);
+            self.diff_scope(to.as_deref_mut(), scope_id, new_nodes);
+        }
+
+        // Diffing may have removed portals along with their ancestors. Their children live somewhere else in the renderer, so remove them now
+        if let Some(to) = to {
+            self.flush_portal_removals(to);
         }
     }
 
@@ -43,6 +52,9 @@ impl VirtualDom {
         };
 
         self.runtime.push_scope(scope);
+        if let Some(profiler) = &mut self.profiler {
+            profiler.start_diff(scope);
+        }
         let scope_state = &mut self.scopes[scope.0];
         // Load the old and new rendered nodes
         let old = scope_state.last_rendered_node.take().unwrap();
@@ -59,6 +71,10 @@ impl VirtualDom {
             self.runtime.get_state(scope).unwrap().mount(&self.runtime);
         }
 
+        if let Some(profiler) = &mut self.profiler {
+            profiler.finish_diff();
+        }
+
         self.runtime.pop_scope();
     }
 
@@ -74,6 +90,9 @@ impl VirtualDom {
         parent: Option<ElementRef>,
     ) -> usize {
         self.runtime.push_scope(scope);
+        if let Some(profiler) = &mut self.profiler {
+            profiler.start_diff(scope);
+        }
 
         // If there are suspended scopes, we need to check if the scope is suspended before we diff it
         // If it is suspended, we need to diff it but write the mutations nothing
@@ -90,13 +109,17 @@ impl VirtualDom {
             self.runtime.get_state(scope).unwrap().mount(&self.runtime);
         }
 
+        if let Some(profiler) = &mut self.profiler {
+            profiler.finish_diff();
+        }
+
         self.runtime.pop_scope();
         nodes
     }
 
     pub(crate) fn remove_component_node<M: WriteMutations>(
         &mut self,
-        to: Option<&mut M>,
+        mut to: Option<&mut M>,
         destroy_component_state: bool,
         scope_id: ScopeId,
         replace_with: Option<usize>,
@@ -109,6 +132,21 @@ impl VirtualDom {
             suspense.remove_suspended_nodes::<M>(self, destroy_component_state);
         }
 
+        // If this is a portal, remove the children from the portal target
+        if PortalProps::downcast_mut_from_props(&mut *self.scopes[scope_id.0].props).is_some() {
+            PortalProps::remove_children(
+                scope_id,
+                self,
+                to.as_deref_mut(),
+                destroy_component_state,
+            );
+        }
+
+        // If this is a keep alive component, drop the subtrees it cached as well
+        if KeepAliveProps::downcast_mut_from_props(&mut *self.scopes[scope_id.0].props).is_some() {
+            KeepAliveProps::remove_cached::<M>(scope_id, self, destroy_component_state);
+        }
+
         // Remove the component from the dom
         if let Some(node) = self.scopes[scope_id.0].last_rendered_node.as_ref() {
             node.clone_mounted()
@@ -156,7 +194,7 @@ impl VNode {
         dom.run_and_diff_scope(to, scope_id);
 
         let height = dom.runtime.get_state(scope_id).unwrap().height;
-        dom.dirty_scopes.remove(&ScopeOrder::new(height, scope_id));
+        dom.remove_dirty_scope(&ScopeOrder::new(height, scope_id));
     }
 
     fn replace_vcomponent(
@@ -195,6 +233,16 @@ impl VNode {
             return SuspenseBoundaryProps::create(mount, idx, component, parent, dom, to);
         }
 
+        // Portals create their children in the portal target instead of in place
+        if component.props.props().type_id() == TypeId::of::<PortalProps>() {
+            return PortalProps::create(mount, idx, component, parent, dom, to);
+        }
+
+        // Keep alive components render their children in place, but they are never run
+        if component.props.props().type_id() == TypeId::of::<KeepAliveProps>() {
+            return KeepAliveProps::create(mount, idx, component, parent, dom, to);
+        }
+
         let mut scope_id = ScopeId(dom.mounts[mount.0].mounted_dynamic_nodes[idx]);
 
         // If the scopeid is a placeholder, we need to load up a new scope for this vcomponent. If it's already mounted, then we can just use that
@@ -127,6 +127,11 @@ impl VirtualDom {
             return;
         }
 
+        // If the renderer already loaded the template, we don't need to register it again
+        if self.take_preloaded_template(&template) {
+            return;
+        }
+
         // If it's all dynamic nodes, then we don't need to register it
         if !template.is_completely_dynamic() {
             to.register_template(template)
@@ -147,6 +152,8 @@ impl VirtualDom {
         {
             // the byte index of the hot reloaded template could be different
             template.name = old_template.name;
+            // the renderer needs to load the new template instead of the preloaded one
+            self.template_aliases.remove(template.name);
             *old_template = template;
         } else {
             // This is a template without any current instances
@@ -840,7 +840,8 @@ impl VNode {
         let this_id = dom.next_element();
         dom.mounts[mount.0].root_ids[root_idx] = this_id;
 
-        to.load_template(self.template.get().name, root_idx, this_id);
+        let name = dom.renderer_template_name(self.template.get().name);
+        to.load_template(name, root_idx, this_id);
 
         this_id
     }
@@ -1,11 +1,13 @@
 use crate::{
-    global_context::current_scope_id, innerlude::provide_context, use_hook, Element, IntoDynNode,
-    Properties, ScopeId, Template, TemplateAttribute, TemplateNode, VNode,
+    global_context::current_scope_id,
+    innerlude::{provide_context, RenderError},
+    use_hook, AnyValue, Element, IntoDynNode, Properties, ScopeId, Template, TemplateAttribute,
+    TemplateNode, VNode,
 };
 use std::{
     any::{Any, TypeId},
     backtrace::Backtrace,
-    cell::{Ref, RefCell},
+    cell::{Cell, Ref, RefCell},
     error::Error,
     fmt::{Debug, Display},
     rc::Rc,
@@ -266,6 +268,23 @@ impl ErrorContext {
     pub fn clear_errors(&self) {
         self.errors.borrow_mut().clear();
     }
+
+    /// Clear all errors from this Error Boundary and try to render its children again
+    pub fn reset(&self) {
+        self.clear_errors();
+        self.id.needs_update();
+    }
+
+    /// Pass all errors in this Error Boundary to the closest Error Boundary above it
+    pub fn rethrow(&self) {
+        let errors = std::mem::take(&mut *self.errors.borrow_mut());
+        let Some(parent) = self.id.parent_scope() else {
+            return;
+        };
+        for error in errors {
+            throw_into(error, parent);
+        }
+    }
 }
 
 /// Errors can have additional context added as they bubble up the render tree
@@ -522,6 +541,15 @@ impl<F: Fn(ErrorContext) -> Element + 'static> From<F> for ErrorHandler {
     }
 }
 
+/// Values that reset an [`ErrorBoundary`] when they change
+#[derive(Clone)]
+pub struct ResetKeys(Rc<dyn AnyValue>);
+
+/// A callback that is called with every error an [`ErrorBoundary`] catches
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+pub struct ErrorCallback(Rc<dyn Fn(&CapturedError)>);
+
 fn default_handler(errors: ErrorContext) -> Element {
     static TEMPLATE: Template = Template {
         name: "error_handle.rs:42:5:884",
@@ -572,16 +600,20 @@ fn default_handler(errors: ErrorContext) -> Element {
 pub struct ErrorBoundaryProps {
     children: Element,
     handle_error: ErrorHandler,
+    reset_keys: Option<ResetKeys>,
+    on_error: Option<ErrorCallback>,
 }
 impl ErrorBoundaryProps {
     /**
     Create a builder for building `ErrorBoundaryProps`.
-    On the builder, call `.children(...)`(optional), `.handle_error(...)`(optional) to set the values of the fields.
+    On the builder, call `.children(...)`(optional), `.handle_error(...)`(optional), `.reset_keys(...)`(optional), `.on_error(...)`(optional) to set the values of the fields.
     Finally, call `.build()` to create the instance of `ErrorBoundaryProps`.
                         */
     #[allow(dead_code)]
-    pub fn builder() -> ErrorBoundaryPropsBuilder<((), ())> {
-        ErrorBoundaryPropsBuilder { fields: ((), ()) }
+    pub fn builder() -> ErrorBoundaryPropsBuilder<((), (), (), ())> {
+        ErrorBoundaryPropsBuilder {
+            fields: ((), (), (), ()),
+        }
     }
 }
 #[must_use]
@@ -601,7 +633,7 @@ where
     }
 }
 impl Properties for ErrorBoundaryProps {
-    type Builder = ErrorBoundaryPropsBuilder<((), ())>;
+    type Builder = ErrorBoundaryPropsBuilder<((), (), (), ())>;
     fn builder() -> Self::Builder {
         ErrorBoundaryProps::builder()
     }
@@ -626,15 +658,17 @@ impl<T> ErrorBoundaryPropsBuilder_Optional<T> for (T,) {
     }
 }
 #[allow(dead_code, non_camel_case_types, missing_docs)]
-impl<__handle_error> ErrorBoundaryPropsBuilder<((), __handle_error)> {
+impl<__handle_error, __reset_keys, __on_error>
+    ErrorBoundaryPropsBuilder<((), __handle_error, __reset_keys, __on_error)>
+{
     pub fn children(
         self,
         children: Element,
-    ) -> ErrorBoundaryPropsBuilder<((Element,), __handle_error)> {
+    ) -> ErrorBoundaryPropsBuilder<((Element,), __handle_error, __reset_keys, __on_error)> {
         let children = (children,);
-        let (_, handle_error) = self.fields;
+        let (_, handle_error, reset_keys, on_error) = self.fields;
         ErrorBoundaryPropsBuilder {
-            fields: (children, handle_error),
+            fields: (children, handle_error, reset_keys, on_error),
         }
     }
 }
@@ -643,25 +677,29 @@ impl<__handle_error> ErrorBoundaryPropsBuilder<((), __handle_error)> {
 pub enum ErrorBoundaryPropsBuilder_Error_Repeated_field_children {}
 #[doc(hidden)]
 #[allow(dead_code, non_camel_case_types, missing_docs)]
-impl<__handle_error> ErrorBoundaryPropsBuilder<((Element,), __handle_error)> {
+impl<__handle_error, __reset_keys, __on_error>
+    ErrorBoundaryPropsBuilder<((Element,), __handle_error, __reset_keys, __on_error)>
+{
     #[deprecated(note = "Repeated field children")]
     pub fn children(
         self,
         _: ErrorBoundaryPropsBuilder_Error_Repeated_field_children,
-    ) -> ErrorBoundaryPropsBuilder<((Element,), __handle_error)> {
+    ) -> ErrorBoundaryPropsBuilder<((Element,), __handle_error, __reset_keys, __on_error)> {
         self
     }
 }
 #[allow(dead_code, non_camel_case_types, missing_docs)]
-impl<__children> ErrorBoundaryPropsBuilder<(__children, ())> {
+impl<__children, __reset_keys, __on_error>
+    ErrorBoundaryPropsBuilder<(__children, (), __reset_keys, __on_error)>
+{
     pub fn handle_error(
         self,
         handle_error: impl ::core::convert::Into<ErrorHandler>,
-    ) -> ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,))> {
+    ) -> ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,), __reset_keys, __on_error)> {
         let handle_error = (handle_error.into(),);
-        let (children, _) = self.fields;
+        let (children, _, reset_keys, on_error) = self.fields;
         ErrorBoundaryPropsBuilder {
-            fields: (children, handle_error),
+            fields: (children, handle_error, reset_keys, on_error),
         }
     }
 }
@@ -670,30 +708,117 @@ impl<__children> ErrorBoundaryPropsBuilder<(__children, ())> {
 pub enum ErrorBoundaryPropsBuilder_Error_Repeated_field_handle_error {}
 #[doc(hidden)]
 #[allow(dead_code, non_camel_case_types, missing_docs)]
-impl<__children> ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,))> {
+impl<__children, __reset_keys, __on_error>
+    ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,), __reset_keys, __on_error)>
+{
     #[deprecated(note = "Repeated field handle_error")]
     pub fn handle_error(
         self,
         _: ErrorBoundaryPropsBuilder_Error_Repeated_field_handle_error,
-    ) -> ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,))> {
+    ) -> ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,), __reset_keys, __on_error)> {
+        self
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__children, __handle_error, __on_error>
+    ErrorBoundaryPropsBuilder<(__children, __handle_error, (), __on_error)>
+{
+    pub fn reset_keys(
+        self,
+        reset_keys: impl PartialEq + 'static,
+    ) -> ErrorBoundaryPropsBuilder<(__children, __handle_error, (Option<ResetKeys>,), __on_error)>
+    {
+        let reset_keys = (Some(ResetKeys(Rc::new(reset_keys))),);
+        let (children, handle_error, _, on_error) = self.fields;
+        ErrorBoundaryPropsBuilder {
+            fields: (children, handle_error, reset_keys, on_error),
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub enum ErrorBoundaryPropsBuilder_Error_Repeated_field_reset_keys {}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__children, __handle_error, __on_error>
+    ErrorBoundaryPropsBuilder<(__children, __handle_error, (Option<ResetKeys>,), __on_error)>
+{
+    #[deprecated(note = "Repeated field reset_keys")]
+    pub fn reset_keys(
+        self,
+        _: ErrorBoundaryPropsBuilder_Error_Repeated_field_reset_keys,
+    ) -> ErrorBoundaryPropsBuilder<(__children, __handle_error, (Option<ResetKeys>,), __on_error)>
+    {
+        self
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__children, __handle_error, __reset_keys>
+    ErrorBoundaryPropsBuilder<(__children, __handle_error, __reset_keys, ())>
+{
+    pub fn on_error(
+        self,
+        on_error: impl Fn(&CapturedError) + 'static,
+    ) -> ErrorBoundaryPropsBuilder<(
+        __children,
+        __handle_error,
+        __reset_keys,
+        (Option<ErrorCallback>,),
+    )> {
+        let on_error = (Some(ErrorCallback(Rc::new(on_error))),);
+        let (children, handle_error, reset_keys, _) = self.fields;
+        ErrorBoundaryPropsBuilder {
+            fields: (children, handle_error, reset_keys, on_error),
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub enum ErrorBoundaryPropsBuilder_Error_Repeated_field_on_error {}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__children, __handle_error, __reset_keys>
+    ErrorBoundaryPropsBuilder<(
+        __children,
+        __handle_error,
+        __reset_keys,
+        (Option<ErrorCallback>,),
+    )>
+{
+    #[deprecated(note = "Repeated field on_error")]
+    pub fn on_error(
+        self,
+        _: ErrorBoundaryPropsBuilder_Error_Repeated_field_on_error,
+    ) -> ErrorBoundaryPropsBuilder<(
+        __children,
+        __handle_error,
+        __reset_keys,
+        (Option<ErrorCallback>,),
+    )> {
         self
     }
 }
 #[allow(dead_code, non_camel_case_types, missing_docs)]
 impl<
-        __handle_error: ErrorBoundaryPropsBuilder_Optional<ErrorHandler>,
         __children: ErrorBoundaryPropsBuilder_Optional<Element>,
-    > ErrorBoundaryPropsBuilder<(__children, __handle_error)>
+        __handle_error: ErrorBoundaryPropsBuilder_Optional<ErrorHandler>,
+        __reset_keys: ErrorBoundaryPropsBuilder_Optional<Option<ResetKeys>>,
+        __on_error: ErrorBoundaryPropsBuilder_Optional<Option<ErrorCallback>>,
+    > ErrorBoundaryPropsBuilder<(__children, __handle_error, __reset_keys, __on_error)>
 {
     pub fn build(self) -> ErrorBoundaryProps {
-        let (children, handle_error) = self.fields;
+        let (children, handle_error, reset_keys, on_error) = self.fields;
         let children = ErrorBoundaryPropsBuilder_Optional::into_value(children, VNode::empty);
         let handle_error = ErrorBoundaryPropsBuilder_Optional::into_value(handle_error, || {
             ErrorHandler(Rc::new(default_handler))
         });
+        let reset_keys = ErrorBoundaryPropsBuilder_Optional::into_value(reset_keys, || None);
+        let on_error = ErrorBoundaryPropsBuilder_Optional::into_value(on_error, || None);
         ErrorBoundaryProps {
             children,
             handle_error,
+            reset_keys,
+            on_error,
         }
     }
 }
@@ -743,10 +868,68 @@ impl<
 /// Error boundaries are an easy way to handle errors in your application.
 /// They are similar to `try/catch` in JavaScript, but they only catch errors in the tree below them.
 /// Error boundaries are quick to implement, but it can be useful to individually handle errors in your components to provide a better user experience when you know that an error is likely to occur.
+///
+/// ## Recovering from errors
+///
+/// The error handler can call [`ErrorContext::reset`] to clear the errors and try to render the children again. The
+/// boundary also resets itself when the value passed to `reset_keys` changes, which is useful when the error was caused
+/// by a value that the user can change, like the current route or a selected item:
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[component]
+/// fn UserPage(id: u32) -> Element {
+///     rsx! {
+///         ErrorBoundary {
+///             // Try to render the profile again when the user changes
+///             reset_keys: id,
+///             // Report errors to the console or an error tracking service
+///             on_error: |error: &CapturedError| tracing::error!("{error}"),
+///             handle_error: |errors: ErrorContext| rsx! {
+///                 "Failed to load the profile"
+///                 button { onclick: move |_| errors.reset(), "Try again" }
+///             },
+///             Profile { id }
+///         }
+///     }
+/// }
+/// # #[component]
+/// # fn Profile(id: u32) -> Element { VNode::empty() }
+/// ```
+///
+/// ## Nested error boundaries
+///
+/// Errors are caught by the closest error boundary. An error handler can pass the errors to the next error boundary up
+/// the tree with [`ErrorContext::rethrow`]. If the error handler itself returns an error, that error is also passed to
+/// the next error boundary.
 #[allow(non_upper_case_globals, non_snake_case)]
 pub fn ErrorBoundary(props: ErrorBoundaryProps) -> Element {
     let error_boundary = use_error_boundary();
-    let errors = error_boundary.errors();
+    let state = use_hook(|| Rc::new(ErrorBoundaryState::default()));
+
+    // If the reset keys changed, try to render the children again
+    let reset_keys_changed = {
+        let mut last_reset_keys = state.reset_keys.borrow_mut();
+        let changed = match (&*last_reset_keys, &props.reset_keys) {
+            (Some(old), Some(new)) => !old.0.any_cmp(new.0.as_ref()),
+            _ => false,
+        };
+        *last_reset_keys = props.reset_keys.clone();
+        changed
+    };
+    if reset_keys_changed {
+        error_boundary.clear_errors();
+    }
+
+    // Report any errors we haven't seen yet
+    let errors = error_boundary.errors().to_vec();
+    let reported = state.reported.replace(errors.len()).min(errors.len());
+    if let Some(on_error) = &props.on_error {
+        for error in &errors[reported..] {
+            (on_error.0)(error);
+        }
+    }
+
     if errors.is_empty() {
         std::result::Result::Ok({
             static TEMPLATE: Template = Template {
@@ -763,6 +946,22 @@ pub fn ErrorBoundary(props: ErrorBoundaryProps) -> Element {
             )
         })
     } else {
-        (props.handle_error.0)(error_boundary.clone())
+        match (props.handle_error.0)(error_boundary.clone()) {
+            // If the error handler fails, the error is passed to the closest error boundary above this one
+            Err(RenderError::Aborted(error)) => {
+                if let Some(parent) = error_boundary.id.parent_scope() {
+                    throw_into(error, parent);
+                }
+                VNode::empty()
+            }
+            element => element,
+        }
     }
 }
+
+/// The state an [`ErrorBoundary`] keeps between renders
+#[derive(Default)]
+struct ErrorBoundaryState {
+    reset_keys: RefCell<Option<ResetKeys>>,
+    reported: Cell<usize>,
+}
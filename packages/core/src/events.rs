@@ -36,6 +36,21 @@ impl<T: ?Sized + 'static> Event<T> {
             propagates: Rc::new(Cell::new(bubbles)),
         }
     }
+
+    /// Create an event for a listener that is not attached to an element in the tree, like a listener on the window.
+    /// The event never bubbles.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// # use std::rc::Rc;
+    /// let event = Event::detached(Rc::new("resized"));
+    /// assert_eq!(*event.data(), "resized");
+    /// ```
+    pub fn detached(data: Rc<T>) -> Self {
+        Self::new(data, false)
+    }
 }
 
 impl<T> Event<T> {
@@ -1,5 +1,10 @@
-use crate::{innerlude::SuspendedFuture, runtime::Runtime, CapturedError, Element, ScopeId, Task};
+use crate::{
+    innerlude::{with_fallback_update_location, SuspendedFuture},
+    runtime::Runtime,
+    CapturedError, Element, ScopeId, Task,
+};
 use std::future::Future;
+use std::panic::Location;
 use std::sync::Arc;
 
 /// Get the current scope id
@@ -265,13 +270,19 @@ pub fn parent_scope() -> Option<ScopeId> {
 }
 
 /// Mark the current scope as dirty, causing it to re-render
+#[track_caller]
 pub fn needs_update() {
-    Runtime::with_current_scope(|cx| cx.needs_update());
+    let location = Location::caller();
+    Runtime::with_current_scope(|cx| with_fallback_update_location(location, || cx.needs_update()));
 }
 
 /// Mark the current scope as dirty, causing it to re-render
+#[track_caller]
 pub fn needs_update_any(id: ScopeId) {
-    Runtime::with_current_scope(|cx| cx.needs_update_any(id));
+    let location = Location::caller();
+    Runtime::with_current_scope(|cx| {
+        with_fallback_update_location(location, || cx.needs_update_any(id))
+    });
 }
 
 /// Schedule an update for the current component
@@ -0,0 +1,481 @@
+//! Keep alive components cache subtrees that are switched away from instead of dropping them
+//!
+//! The keep alive scope renders its children in place. When the `active` key changes, the old children are removed from
+//! the renderer without destroying their component state and stored in a least recently used cache. The scopes in a
+//! cached subtree are marked as offscreen: they keep rendering in the background like suspended children, and the tasks
+//! they spawned are paused until the subtree is shown again. Switching back to a cached key diffs the cached subtree with
+//! the new children in the background and then creates it in place of the current children.
+
+use crate::innerlude::*;
+use rustc_hash::FxHashMap;
+use std::rc::Rc;
+
+/// Properties for the [`KeepAlive()`] component.
+#[derive(Clone)]
+pub struct KeepAliveProps {
+    /// The key of the children that should be shown
+    active: String,
+    /// The maximum number of inactive subtrees that are kept alive
+    max: usize,
+    /// The children of the keep alive component
+    children: Element,
+    /// The key of the children that are currently rendered
+    rendered_key: String,
+    /// Inactive subtrees ordered from the least to the most recently used
+    cached: Vec<(String, RenderReturn)>,
+}
+
+impl KeepAliveProps {
+    /**
+    Create a builder for building `KeepAliveProps`.
+    On the builder, call `.active(...)`, `.max(...)`(optional), `.children(...)`(optional) to set the values of the fields.
+    Finally, call `.build()` to create the instance of `KeepAliveProps`.
+                        */
+    #[allow(dead_code)]
+    pub fn builder() -> KeepAlivePropsBuilder<((), (), ())> {
+        KeepAlivePropsBuilder {
+            fields: ((), (), ()),
+        }
+    }
+}
+#[must_use]
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub struct KeepAlivePropsBuilder<TypedBuilderFields> {
+    fields: TypedBuilderFields,
+}
+impl Properties for KeepAliveProps {
+    type Builder = KeepAlivePropsBuilder<((), (), ())>;
+    fn builder() -> Self::Builder {
+        KeepAliveProps::builder()
+    }
+    fn memoize(&mut self, new: &Self) -> bool {
+        let equal = self == new;
+        if !equal {
+            self.active = new.active.clone();
+            self.max = new.max;
+            self.children = new.children.clone();
+        }
+        equal
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub trait KeepAlivePropsBuilder_Optional<T> {
+    fn into_value<F: FnOnce() -> T>(self, default: F) -> T;
+}
+impl<T> KeepAlivePropsBuilder_Optional<T> for () {
+    fn into_value<F: FnOnce() -> T>(self, default: F) -> T {
+        default()
+    }
+}
+impl<T> KeepAlivePropsBuilder_Optional<T> for (T,) {
+    fn into_value<F: FnOnce() -> T>(self, _: F) -> T {
+        self.0
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__max, __children> KeepAlivePropsBuilder<((), __max, __children)> {
+    pub fn active(
+        self,
+        active: impl ::core::fmt::Display,
+    ) -> KeepAlivePropsBuilder<((String,), __max, __children)> {
+        let active = (active.to_string(),);
+        let (_, max, children) = self.fields;
+        KeepAlivePropsBuilder {
+            fields: (active, max, children),
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub enum KeepAlivePropsBuilder_Error_Repeated_field_active {}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__max, __children> KeepAlivePropsBuilder<((String,), __max, __children)> {
+    #[deprecated(note = "Repeated field active")]
+    pub fn active(
+        self,
+        _: KeepAlivePropsBuilder_Error_Repeated_field_active,
+    ) -> KeepAlivePropsBuilder<((String,), __max, __children)> {
+        self
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__active, __children> KeepAlivePropsBuilder<(__active, (), __children)> {
+    pub fn max(self, max: usize) -> KeepAlivePropsBuilder<(__active, (usize,), __children)> {
+        let max = (max,);
+        let (active, _, children) = self.fields;
+        KeepAlivePropsBuilder {
+            fields: (active, max, children),
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub enum KeepAlivePropsBuilder_Error_Repeated_field_max {}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__active, __children> KeepAlivePropsBuilder<(__active, (usize,), __children)> {
+    #[deprecated(note = "Repeated field max")]
+    pub fn max(
+        self,
+        _: KeepAlivePropsBuilder_Error_Repeated_field_max,
+    ) -> KeepAlivePropsBuilder<(__active, (usize,), __children)> {
+        self
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__active, __max> KeepAlivePropsBuilder<(__active, __max, ())> {
+    pub fn children(
+        self,
+        children: Element,
+    ) -> KeepAlivePropsBuilder<(__active, __max, (Element,))> {
+        let children = (children,);
+        let (active, max, _) = self.fields;
+        KeepAlivePropsBuilder {
+            fields: (active, max, children),
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub enum KeepAlivePropsBuilder_Error_Repeated_field_children {}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__active, __max> KeepAlivePropsBuilder<(__active, __max, (Element,))> {
+    #[deprecated(note = "Repeated field children")]
+    pub fn children(
+        self,
+        _: KeepAlivePropsBuilder_Error_Repeated_field_children,
+    ) -> KeepAlivePropsBuilder<(__active, __max, (Element,))> {
+        self
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub enum KeepAlivePropsBuilder_Error_Missing_required_field_active {}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, missing_docs, clippy::panic)]
+impl<__max, __children> KeepAlivePropsBuilder<((), __max, __children)> {
+    #[deprecated(note = "Missing required field active")]
+    pub fn build(
+        self,
+        _: KeepAlivePropsBuilder_Error_Missing_required_field_active,
+    ) -> KeepAliveProps {
+        panic!()
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<
+        __max: KeepAlivePropsBuilder_Optional<usize>,
+        __children: KeepAlivePropsBuilder_Optional<Element>,
+    > KeepAlivePropsBuilder<((String,), __max, __children)>
+{
+    pub fn build(self) -> KeepAliveProps {
+        let (active, max, children) = self.fields;
+        let active = active.0;
+        let max = KeepAlivePropsBuilder_Optional::into_value(max, || 10);
+        let children = KeepAlivePropsBuilder_Optional::into_value(children, VNode::empty);
+        KeepAliveProps {
+            active,
+            max,
+            children,
+            rendered_key: String::new(),
+            cached: Vec::new(),
+        }
+    }
+}
+impl PartialEq for KeepAliveProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.active == other.active && self.max == other.max && self.children == other.children
+    }
+}
+
+/// Keep alive components keep the state of their children when the children are switched out.
+///
+/// The children are identified by the `active` key. When the key changes, the current children are removed from the
+/// renderer, but their hooks, signals and context are kept and the tasks they spawned are paused. If the key changes
+/// back, the cached children are reattached with their state intact and their tasks resume. Children that are cached
+/// keep rerendering in the background when their state changes, so they are up to date when they are shown again.
+///
+/// At most `max` (10 by default) inactive subtrees are cached. When the cache is full, the least recently used subtree
+/// is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let mut tab = use_signal(|| 0);
+///     rsx! {
+///         button { onclick: move |_| tab.set(0), "Inbox" }
+///         button { onclick: move |_| tab.set(1), "Settings" }
+///         // Switching tabs keeps the scroll position and the draft in the inbox
+///         KeepAlive { active: "{tab}", max: 5,
+///             if tab() == 0 {
+///                 Inbox {}
+///             } else {
+///                 Settings {}
+///             }
+///         }
+///     }
+/// }
+/// # fn Inbox() -> Element { rsx! { textarea {} } }
+/// # fn Settings() -> Element { rsx! { "settings" } }
+/// ```
+#[allow(non_snake_case)]
+pub fn KeepAlive(mut __props: KeepAliveProps) -> Element {
+    unreachable!("KeepAlive should not be called directly")
+}
+#[allow(non_snake_case)]
+#[doc(hidden)]
+mod KeepAlive_completions {
+    #[doc(hidden)]
+    #[allow(non_camel_case_types)]
+    /// This enum is generated to help autocomplete the braces after the component. It does nothing
+    pub enum Component {
+        KeepAlive {},
+    }
+}
+#[allow(unused)]
+pub use KeepAlive_completions::Component::KeepAlive;
+
+/// Keep alive components have a custom diffing algorithm that moves their children to the cache instead of removing them
+impl KeepAliveProps {
+    /// Try to downcast [`AnyProps`] to [`KeepAliveProps`]
+    pub(crate) fn downcast_mut_from_props(props: &mut dyn AnyProps) -> Option<&mut Self> {
+        props.props_mut().downcast_mut()
+    }
+
+    /// Try to extract [`KeepAliveProps`] from [`ScopeState`]
+    pub fn downcast_from_scope(scope_state: &ScopeState) -> Option<&Self> {
+        scope_state.props.props().downcast_ref()
+    }
+
+    /// Get the key of the children that should be shown
+    pub fn active(&self) -> &str {
+        &self.active
+    }
+
+    /// Get the keys of the inactive subtrees that are cached, ordered from the least to the most recently used
+    pub fn cached_keys(&self) -> impl Iterator<Item = &str> {
+        self.cached.iter().map(|(key, _)| key.as_str())
+    }
+
+    pub(crate) fn create<M: WriteMutations>(
+        mount: MountId,
+        idx: usize,
+        component: &VComponent,
+        parent: Option<ElementRef>,
+        dom: &mut VirtualDom,
+        to: Option<&mut M>,
+    ) -> usize {
+        let mut scope_id = ScopeId(dom.mounts[mount.0].mounted_dynamic_nodes[idx]);
+
+        // If the ScopeId is a placeholder, we need to load up a new scope for this vcomponent. If it's already mounted, then we can just use that
+        if scope_id.is_placeholder() {
+            scope_id = dom
+                .new_scope(component.props.duplicate(), component.name)
+                .state()
+                .id;
+
+            // Store the scope id for the next render
+            dom.mounts[mount.0].mounted_dynamic_nodes[idx] = scope_id.0;
+
+            let props = Self::downcast_mut_from_props(&mut *dom.scopes[scope_id.0].props).unwrap();
+            props.rendered_key = props.active.clone();
+            let children = RenderReturn {
+                node: props.children.clone(),
+            };
+            dom.scopes[scope_id.0].last_rendered_node = Some(children);
+        }
+
+        let children = dom.scopes[scope_id.0]
+            .last_rendered_node
+            .clone()
+            .expect("KeepAlive to be mounted");
+        dom.create_scope(to, scope_id, children, parent)
+    }
+
+    pub(crate) fn diff<M: WriteMutations>(
+        scope_id: ScopeId,
+        dom: &mut VirtualDom,
+        to: Option<&mut M>,
+    ) {
+        let props = Self::downcast_mut_from_props(&mut *dom.scopes[scope_id.0].props).unwrap();
+        let new_children = RenderReturn {
+            node: props.children.clone(),
+        };
+        let active = props.active.clone();
+        let old_key = std::mem::replace(&mut props.rendered_key, active.clone());
+        let cached = match old_key == active {
+            true => None,
+            false => props
+                .cached
+                .iter()
+                .position(|(key, _)| *key == active)
+                .map(|index| props.cached.remove(index).1),
+        };
+        let old_children = dom.scopes[scope_id.0]
+            .last_rendered_node
+            .take()
+            .expect("KeepAlive to be mounted");
+
+        dom.runtime.push_scope(scope_id);
+        let render_to = to.filter(|_| dom.runtime.scope_should_render(scope_id));
+
+        // If the key didn't change, this is a normal diff
+        if old_key == active {
+            old_children.diff_node(&new_children, dom, render_to);
+            dom.scopes[scope_id.0].last_rendered_node = Some(new_children);
+            dom.runtime.pop_scope();
+            return;
+        }
+
+        // If the children were cached, bring them up to date in the background and then show them again
+        if let Some(cached) = cached {
+            cached.diff_node(&new_children, dom, None::<&mut M>);
+            let scopes = dom.scopes_in_subtree(&new_children);
+            dom.runtime.set_offscreen(&scopes, false);
+        }
+
+        // Replace the old children with the new children without dropping the state of the old children
+        let parent = dom.mounts[old_children.mount.get().0].parent;
+        old_children.move_node_to_background(
+            std::slice::from_ref(&*new_children),
+            parent,
+            dom,
+            render_to,
+        );
+        let scopes = dom.scopes_in_subtree(&old_children);
+        dom.runtime.set_offscreen(&scopes, true);
+        dom.runtime.pop_scope();
+
+        dom.scopes[scope_id.0].last_rendered_node = Some(new_children);
+
+        // Cache the old children, dropping the least recently used subtrees that don't fit
+        let props = Self::downcast_mut_from_props(&mut *dom.scopes[scope_id.0].props).unwrap();
+        props.cached.push((old_key, old_children));
+        let evict = props.cached.len().saturating_sub(props.max);
+        let evicted: Vec<_> = props.cached.drain(..evict).collect();
+        for (_, children) in evicted {
+            children.remove_node_inner(dom, None::<&mut M>, true, None);
+        }
+    }
+
+    pub(crate) fn remove_cached<M: WriteMutations>(
+        scope_id: ScopeId,
+        dom: &mut VirtualDom,
+        destroy_component_state: bool,
+    ) {
+        // If we are keeping the component state, the cached subtrees stay in the cache
+        if !destroy_component_state {
+            return;
+        }
+
+        let props = Self::downcast_mut_from_props(&mut *dom.scopes[scope_id.0].props).unwrap();
+        // The cached subtrees are not in the renderer, so we only need to clean up their state
+        for (_, children) in std::mem::take(&mut props.cached) {
+            children.remove_node_inner(dom, None::<&mut M>, true, None);
+        }
+    }
+}
+
+impl VirtualDom {
+    /// Collect every scope that was created for a subtree, including scopes that are not mounted like the children of a
+    /// suspended boundary
+    fn scopes_in_subtree(&self, node: &VNode) -> Vec<ScopeId> {
+        fn component_roots(node: &VNode, dom: &VirtualDom, roots: &mut Vec<ScopeId>) {
+            let mount = node.mount.get();
+            let Some(mount) = mount.as_usize().and_then(|mount| dom.mounts.get(mount)) else {
+                return;
+            };
+            for (idx, dynamic_node) in node.dynamic_nodes.iter().enumerate() {
+                match dynamic_node {
+                    DynamicNode::Component(_) => {
+                        let scope = ScopeId(mount.mounted_dynamic_nodes[idx]);
+                        if !scope.is_placeholder() {
+                            roots.push(scope);
+                        }
+                    }
+                    DynamicNode::Fragment(nodes) => {
+                        for node in nodes {
+                            component_roots(node, dom, roots);
+                        }
+                    }
+                    DynamicNode::Text(_) | DynamicNode::Placeholder(_) => {}
+                }
+            }
+        }
+
+        let mut scopes = Vec::new();
+        component_roots(node, self, &mut scopes);
+
+        // Every scope created under one of the components is part of the subtree
+        let mut children: FxHashMap<ScopeId, Vec<ScopeId>> = FxHashMap::default();
+        for (id, _) in self.scopes.iter() {
+            let id = ScopeId(id);
+            if let Some(parent) = self.runtime.get_state(id).and_then(|scope| scope.parent_id) {
+                children.entry(parent).or_default().push(id);
+            }
+        }
+        let mut index = 0;
+        while let Some(scope) = scopes.get(index) {
+            if let Some(children) = children.get(scope) {
+                scopes.extend_from_slice(children);
+            }
+            index += 1;
+        }
+
+        scopes
+    }
+}
+
+impl Runtime {
+    /// Move scopes out of the rendered tree or back into it. Offscreen scopes render in the background and the tasks
+    /// they spawned are paused until they are shown again.
+    pub(crate) fn set_offscreen(self: &Rc<Self>, scopes: &[ScopeId], offscreen: bool) {
+        let mut tasks = Vec::new();
+        {
+            let states = self.scope_states.borrow();
+            let local_tasks = self.tasks.borrow();
+            for scope in scopes
+                .iter()
+                .filter_map(|id| states.get(id.0).and_then(|scope| scope.as_ref()))
+            {
+                if scope.offscreen.replace(offscreen) == offscreen {
+                    continue;
+                }
+                let count = self.offscreen_scopes.get();
+                self.offscreen_scopes.set(match offscreen {
+                    true => count + 1,
+                    false => count - 1,
+                });
+
+                for task in scope.spawned_tasks.borrow().iter() {
+                    let Some(local_task) = local_tasks.get(task.id) else {
+                        continue;
+                    };
+                    // Only resume the tasks that were paused because they went offscreen
+                    let toggle = match offscreen {
+                        true => local_task.active.get(),
+                        false => local_task.offscreen.get(),
+                    };
+                    if toggle {
+                        local_task.offscreen.set(offscreen);
+                        tasks.push(*task);
+                    }
+                }
+            }
+        }
+
+        let _runtime = RuntimeGuard::new(self.clone());
+        for task in tasks {
+            match offscreen {
+                true => task.pause(),
+                false => task.resume(),
+            }
+        }
+    }
+}
@@ -12,8 +12,11 @@ mod events;
 mod fragment;
 mod generational_box;
 mod global_context;
+mod keep_alive;
 mod mutations;
 mod nodes;
+mod portal;
+mod profiler;
 mod properties;
 mod reactive_context;
 mod render_error;
@@ -25,6 +28,8 @@ mod scope_context;
 mod scopes;
 mod suspense;
 mod tasks;
+mod template_cache;
+mod transition;
 mod virtual_dom;
 
 /// Items exported from this module are used in macros and should not be used directly.
@@ -42,8 +47,11 @@ pub(crate) mod innerlude {
     pub use crate::fragment::*;
     pub use crate::generational_box::*;
     pub use crate::global_context::*;
+    pub use crate::keep_alive::*;
     pub use crate::mutations::*;
     pub use crate::nodes::*;
+    pub use crate::portal::*;
+    pub use crate::profiler::*;
     pub use crate::properties::*;
     pub use crate::reactive_context::*;
     pub use crate::render_error::*;
@@ -52,6 +60,8 @@ pub(crate) mod innerlude {
     pub use crate::scopes::*;
     pub use crate::suspense::*;
     pub use crate::tasks::*;
+    pub use crate::template_cache::*;
+    pub use crate::transition::*;
     pub use crate::virtual_dom::*;
 
     /// An [`Element`] is a possibly-none [`VNode`] created by calling `render` on [`ScopeId`] or [`ScopeState`].
@@ -67,8 +77,9 @@ pub use crate::innerlude::{
     fc_to_builder, generation, schedule_update, schedule_update_any, use_hook, vdom_is_rendering,
     AnyValue, Attribute, AttributeValue, CapturedError, Component, ComponentFunction, DynamicNode,
     Element, ElementId, Event, Fragment, HasAttributes, IntoDynNode, MarkerWrapper, Mutation,
-    Mutations, NoOpMutations, Ok, Properties, RenderReturn, Result, Runtime, ScopeId, ScopeState,
-    SpawnIfAsync, Task, Template, TemplateAttribute, TemplateNode, VComponent, VNode, VNodeInner,
+    Mutations, NoOpMutations, Ok, Priority, Profiler, Properties, RenderReturn, RenderStatus,
+    RenderTrigger, Result, Runtime, ScopeId, ScopeRender, ScopeState, SpawnIfAsync, Task, Template,
+    TemplateAttribute, TemplateCache, TemplateNode, UpdateSource, VComponent, VNode, VNodeInner,
     VPlaceholder, VText, VirtualDom, WriteMutations,
 };
 
@@ -77,17 +88,20 @@ pub use crate::innerlude::{
 /// This includes types like [`Element`], and [`Component`].
 pub mod prelude {
     pub use crate::innerlude::{
-        consume_context, consume_context_from_scope, current_owner, current_scope_id,
-        fc_to_builder, generation, has_context, needs_update, needs_update_any, parent_scope,
-        provide_context, provide_root_context, queue_effect, remove_future, schedule_update,
-        schedule_update_any, spawn, spawn_forever, spawn_isomorphic, suspend, throw_error,
+        after_transitions, consume_context, consume_context_from_scope, current_owner,
+        current_priority, current_scope_id, fc_to_builder, generation, has_context, needs_update,
+        needs_update_any, parent_scope, provide_context, provide_root_context, queue_effect,
+        remove_future, schedule_update, schedule_update_any, spawn, spawn_forever,
+        spawn_isomorphic, start_transition, start_transition_then, suspend, throw_error,
         try_consume_context, use_after_render, use_before_render, use_drop, use_error_boundary,
-        use_hook, use_hook_with_cleanup, with_owner, AnyValue, Attribute, Callback, CapturedError,
-        Component, ComponentFunction, Context, Element, ErrorBoundary, ErrorContext, Event,
-        EventHandler, Fragment, HasAttributes, IntoAttributeValue, IntoDynNode,
-        OptionStringFromMarker, Properties, ReactiveContext, RenderError, Runtime, RuntimeGuard,
-        ScopeId, ScopeState, SuperFrom, SuperInto, SuspendedFuture, SuspenseBoundary,
-        SuspenseBoundaryProps, SuspenseContext, SuspenseExtension, Task, Template,
+        use_hook, use_hook_with_cleanup, with_owner, with_priority, with_update_location, AnyValue,
+        Attribute, Callback, CapturedError, Component, ComponentFunction, Context, Element,
+        ErrorBoundary, ErrorContext, Event, EventHandler, Fragment, HasAttributes,
+        IntoAttributeValue, IntoDynNode, KeepAlive, KeepAliveProps, OptionStringFromMarker, Portal,
+        PortalProps, Priority, Properties, ReactiveContext, RenderError, RevealOrder, Runtime,
+        RuntimeGuard, ScopeId, ScopeState, SuperFrom, SuperInto, SuspendedFuture, SuspenseBoundary,
+        SuspenseBoundaryProps, SuspenseContext, SuspenseExtension, SuspenseList,
+        SuspenseListContext, SuspenseListProps, SuspenseListTail, Task, Template,
         TemplateAttribute, TemplateNode, VNode, VNodeInner, VirtualDom,
     };
 }
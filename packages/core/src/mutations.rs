@@ -130,6 +130,16 @@ pub trait WriteMutations {
     /// Id: The ID of the root node to push.
     fn push_root(&mut self, id: ElementId);
 
+    /// Append the top m nodes on the stack to the portal target with the given name.
+    ///
+    /// Target: The name of the element the portal renders into. Renderers that can't find the target should create it.
+    /// M: The number of nodes on the stack to append to the portal target
+    ///
+    /// By default the nodes are appended to the root element for renderers that don't support portal targets.
+    fn append_to_portal(&mut self, _target: &str, m: usize) {
+        self.append_children(ElementId(0), m);
+    }
+
     /// Swap to a new subtree
     fn swap_subtree(&mut self, _subtree_index: usize) {}
 
@@ -323,6 +333,15 @@ pub enum Mutation {
         /// The ID of the root node to push.
         id: ElementId,
     },
+
+    /// Append the top m nodes on the stack to the portal target with the given name.
+    AppendToPortal {
+        /// The name of the element the portal renders into.
+        target: String,
+
+        /// The number of nodes on the stack to append to the portal target.
+        m: usize,
+    },
 }
 
 /// A static list of mutations that can be applied to the DOM. Note: this list does not contain any `Any` attribute values
@@ -458,6 +477,13 @@ impl WriteMutations for Mutations {
         self.edits.push(Mutation::PushRoot { id })
     }
 
+    fn append_to_portal(&mut self, target: &str, m: usize) {
+        self.edits.push(Mutation::AppendToPortal {
+            target: target.into(),
+            m,
+        })
+    }
+
     fn swap_subtree(&mut self, _subtree_index: usize) {}
 
     fn mark_scope_dirty(&mut self, scope_id: ScopeId) {
@@ -509,4 +535,6 @@ impl WriteMutations for NoOpMutations {
     fn remove_node(&mut self, _: ElementId) {}
 
     fn push_root(&mut self, _: ElementId) {}
+
+    fn append_to_portal(&mut self, _: &str, _: usize) {}
 }
@@ -0,0 +1,431 @@
+//! Portals render their children into a different part of the renderer's tree while keeping their place in the component tree
+//!
+//! The portal scope itself only leaves a placeholder at its location. The children of the portal are created with the
+//! portal's parent, so they can still read context from the portal's ancestors and events bubble through the logical
+//! parents of the portal. Once the children are created, they are moved into the named target with
+//! [`WriteMutations::append_to_portal`].
+
+use crate::innerlude::*;
+
+/// Properties for the [`Portal()`] component.
+#[derive(Clone)]
+pub struct PortalProps {
+    /// The name of the element the children are rendered into
+    target: String,
+    /// The children of the portal
+    children: Element,
+    /// The children that are currently mounted under the target
+    rendered_children: Option<RenderReturn>,
+    /// The target the rendered children were written to. This is `None` if the children were only created in the background
+    mounted_target: Option<String>,
+}
+
+impl PortalProps {
+    /**
+    Create a builder for building `PortalProps`.
+    On the builder, call `.target(...)`, `.children(...)`(optional) to set the values of the fields.
+    Finally, call `.build()` to create the instance of `PortalProps`.
+                        */
+    #[allow(dead_code)]
+    pub fn builder() -> PortalPropsBuilder<((), ())> {
+        PortalPropsBuilder { fields: ((), ()) }
+    }
+}
+#[must_use]
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub struct PortalPropsBuilder<TypedBuilderFields> {
+    fields: TypedBuilderFields,
+}
+impl Properties for PortalProps {
+    type Builder = PortalPropsBuilder<((), ())>;
+    fn builder() -> Self::Builder {
+        PortalProps::builder()
+    }
+    fn memoize(&mut self, new: &Self) -> bool {
+        let equal = self == new;
+        if !equal {
+            self.target = new.target.clone();
+            self.children = new.children.clone();
+        }
+        equal
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub trait PortalPropsBuilder_Optional<T> {
+    fn into_value<F: FnOnce() -> T>(self, default: F) -> T;
+}
+impl<T> PortalPropsBuilder_Optional<T> for () {
+    fn into_value<F: FnOnce() -> T>(self, default: F) -> T {
+        default()
+    }
+}
+impl<T> PortalPropsBuilder_Optional<T> for (T,) {
+    fn into_value<F: FnOnce() -> T>(self, _: F) -> T {
+        self.0
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__children> PortalPropsBuilder<((), __children)> {
+    pub fn target(
+        self,
+        target: impl ::core::fmt::Display,
+    ) -> PortalPropsBuilder<((String,), __children)> {
+        let target = (target.to_string(),);
+        let (_, children) = self.fields;
+        PortalPropsBuilder {
+            fields: (target, children),
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub enum PortalPropsBuilder_Error_Repeated_field_target {}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__children> PortalPropsBuilder<((String,), __children)> {
+    #[deprecated(note = "Repeated field target")]
+    pub fn target(
+        self,
+        _: PortalPropsBuilder_Error_Repeated_field_target,
+    ) -> PortalPropsBuilder<((String,), __children)> {
+        self
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__target> PortalPropsBuilder<(__target, ())> {
+    pub fn children(self, children: Element) -> PortalPropsBuilder<(__target, (Element,))> {
+        let children = (children,);
+        let (target, _) = self.fields;
+        PortalPropsBuilder {
+            fields: (target, children),
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub enum PortalPropsBuilder_Error_Repeated_field_children {}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__target> PortalPropsBuilder<(__target, (Element,))> {
+    #[deprecated(note = "Repeated field children")]
+    pub fn children(
+        self,
+        _: PortalPropsBuilder_Error_Repeated_field_children,
+    ) -> PortalPropsBuilder<(__target, (Element,))> {
+        self
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub enum PortalPropsBuilder_Error_Missing_required_field_target {}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, missing_docs, clippy::panic)]
+impl<__children> PortalPropsBuilder<((), __children)> {
+    #[deprecated(note = "Missing required field target")]
+    pub fn build(self, _: PortalPropsBuilder_Error_Missing_required_field_target) -> PortalProps {
+        panic!()
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__children: PortalPropsBuilder_Optional<Element>> PortalPropsBuilder<((String,), __children)> {
+    pub fn build(self) -> PortalProps {
+        let (target, children) = self.fields;
+        let target = target.0;
+        let children = PortalPropsBuilder_Optional::into_value(children, VNode::empty);
+        PortalProps {
+            target,
+            children,
+            rendered_children: None,
+            mounted_target: None,
+        }
+    }
+}
+impl PartialEq for PortalProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.target == other.target && self.children == other.children
+    }
+}
+
+/// Portals render their children into a different element than the element the portal is placed in.
+///
+/// The children are mounted into the element with the id `target`. If no element with that id exists, the renderer
+/// creates one. The children keep their place in the component tree: they can read context from the components the
+/// portal is placed in and events handled in the children bubble up to the parents of the portal. When the portal is
+/// removed, the children are removed from the target.
+///
+/// # Example
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let mut open = use_signal(|| true);
+///     rsx! {
+///         div {
+///             // Clicks on the close button bubble up to this div even though the button is rendered into #modal-root
+///             onclick: move |_| open.set(false),
+///             if open() {
+///                 Portal {
+///                     target: "modal-root",
+///                     button { onclick: move |_| println!("closing the modal"), "Close" }
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn Portal(mut __props: PortalProps) -> Element {
+    unreachable!("Portal should not be called directly")
+}
+#[allow(non_snake_case)]
+#[doc(hidden)]
+mod Portal_completions {
+    #[doc(hidden)]
+    #[allow(non_camel_case_types)]
+    /// This enum is generated to help autocomplete the braces after the component. It does nothing
+    pub enum Component {
+        Portal {},
+    }
+}
+#[allow(unused)]
+pub use Portal_completions::Component::Portal;
+
+/// Portals have a custom diffing algorithm that creates their children in the target instead of in place
+impl PortalProps {
+    /// Try to downcast [`AnyProps`] to [`PortalProps`]
+    pub(crate) fn downcast_mut_from_props(props: &mut dyn AnyProps) -> Option<&mut Self> {
+        props.props_mut().downcast_mut()
+    }
+
+    /// Try to extract [`PortalProps`] from [`ScopeState`]
+    pub fn downcast_from_scope(scope_state: &ScopeState) -> Option<&Self> {
+        scope_state.props.props().downcast_ref()
+    }
+
+    /// Get the name of the element the children of this portal are rendered into
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Get the children that are currently mounted under the target
+    pub fn rendered_children(&self) -> Option<&VNode> {
+        self.rendered_children.as_deref()
+    }
+
+    pub(crate) fn create<M: WriteMutations>(
+        mount: MountId,
+        idx: usize,
+        component: &VComponent,
+        parent: Option<ElementRef>,
+        dom: &mut VirtualDom,
+        mut to: Option<&mut M>,
+    ) -> usize {
+        let mut scope_id = ScopeId(dom.mounts[mount.0].mounted_dynamic_nodes[idx]);
+
+        // If the ScopeId is a placeholder, we need to load up a new scope for this vcomponent. If it's already mounted, then we can just use that
+        if scope_id.is_placeholder() {
+            scope_id = dom
+                .new_scope(component.props.duplicate(), component.name)
+                .state()
+                .id;
+
+            // Store the scope id for the next render
+            dom.mounts[mount.0].mounted_dynamic_nodes[idx] = scope_id.0;
+
+            // The portal only leaves a placeholder where it is placed
+            dom.scopes[scope_id.0].last_rendered_node = Some(RenderReturn::default());
+        }
+
+        let props = Self::downcast_mut_from_props(&mut *dom.scopes[scope_id.0].props).unwrap();
+        let children = props
+            .rendered_children
+            .take()
+            .unwrap_or_else(|| RenderReturn {
+                node: props.children.clone(),
+            });
+        let target = props.target.clone();
+
+        // Create the children with the parent of the portal so events bubble through the portal's parents, then move them into the target
+        dom.runtime.push_scope(scope_id);
+        let mut render_to = to
+            .as_deref_mut()
+            .filter(|_| dom.runtime.scope_should_render(scope_id));
+        let nodes_created = children.create(dom, parent, render_to.as_deref_mut());
+        let mounted_target = render_to.map(|to| {
+            to.append_to_portal(&target, nodes_created);
+            target
+        });
+        dom.runtime.pop_scope();
+
+        let props = Self::downcast_mut_from_props(&mut *dom.scopes[scope_id.0].props).unwrap();
+        props.rendered_children = Some(children);
+        props.mounted_target = mounted_target;
+
+        let placeholder = dom.scopes[scope_id.0]
+            .last_rendered_node
+            .clone()
+            .expect("Portal to be mounted");
+        dom.create_scope(to, scope_id, placeholder, parent)
+    }
+
+    fn remount<M: WriteMutations>(scope_id: ScopeId, dom: &mut VirtualDom, to: &mut M) {
+        let props = Self::downcast_mut_from_props(&mut *dom.scopes[scope_id.0].props).unwrap();
+        // Children that were only created in the background will be mounted when they are rendered
+        let Some(target) = props.mounted_target.clone() else {
+            return;
+        };
+        let Some(children) = props.rendered_children.take() else {
+            return;
+        };
+        let parent = dom.scopes[scope_id.0]
+            .last_rendered_node
+            .as_ref()
+            .and_then(|node| node.mount.get().as_usize())
+            .and_then(|mount| dom.mounts.get(mount))
+            .and_then(|mount| mount.parent);
+
+        // Forget about the old nodes and reclaim their ids without touching the renderer
+        dom.runtime.push_scope(scope_id);
+        children.remove_node_inner(dom, Some(&mut NoOpMutations), false, None);
+        let nodes_created = children.create(dom, parent, Some(&mut *to));
+        to.append_to_portal(&target, nodes_created);
+        dom.runtime.pop_scope();
+
+        let props = Self::downcast_mut_from_props(&mut *dom.scopes[scope_id.0].props).unwrap();
+        props.rendered_children = Some(children);
+    }
+
+    pub(crate) fn diff<M: WriteMutations>(
+        scope_id: ScopeId,
+        dom: &mut VirtualDom,
+        to: Option<&mut M>,
+    ) {
+        let props = Self::downcast_mut_from_props(&mut *dom.scopes[scope_id.0].props).unwrap();
+        let new_children = RenderReturn {
+            node: props.children.clone(),
+        };
+        let target = props.target.clone();
+        let old_children = props
+            .rendered_children
+            .take()
+            .expect("Portal to be mounted");
+        let mounted_target = props.mounted_target.take();
+
+        // If the children were only created in the background, keep diffing them in the background
+        let mut render_to =
+            to.filter(|_| mounted_target.is_some() && dom.runtime.scope_should_render(scope_id));
+
+        dom.runtime.push_scope(scope_id);
+        old_children.diff_node(&new_children, dom, render_to.as_deref_mut());
+
+        // If the target changed, move the children into the new target
+        if let Some(to) = render_to {
+            if mounted_target.as_deref() != Some(target.as_str()) {
+                let m = new_children.push_all_root_nodes(dom, to);
+                to.append_to_portal(&target, m);
+            }
+            dom.flush_portal_removals(to);
+        }
+        dom.runtime.pop_scope();
+
+        let props = Self::downcast_mut_from_props(&mut *dom.scopes[scope_id.0].props).unwrap();
+        props.mounted_target = mounted_target.map(|_| target);
+        props.rendered_children = Some(new_children);
+    }
+
+    pub(crate) fn remove_children<M: WriteMutations>(
+        scope_id: ScopeId,
+        dom: &mut VirtualDom,
+        to: Option<&mut M>,
+        destroy_component_state: bool,
+    ) {
+        let props = Self::downcast_mut_from_props(&mut *dom.scopes[scope_id.0].props).unwrap();
+        let Some(children) = props.rendered_children.take() else {
+            return;
+        };
+        let mounted_target = props.mounted_target.take();
+
+        match (to, mounted_target) {
+            (Some(to), Some(_)) => {
+                children.remove_node_inner(dom, Some(to), destroy_component_state, None)
+            }
+            // The portal is being removed along with one of its ancestors, which doesn't remove the children from the target.
+            // Queue the roots of the children to be removed the next time we have a mutation writer
+            (None, Some(_)) => {
+                let mut roots = Mutations::default();
+                children.push_all_root_nodes(dom, &mut roots);
+                dom.queued_portal_removals
+                    .extend(roots.edits.into_iter().filter_map(|edit| match edit {
+                        Mutation::PushRoot { id } => Some(id),
+                        _ => None,
+                    }));
+                children.remove_node_inner(dom, None::<&mut M>, destroy_component_state, None)
+            }
+            // The children were never written to the renderer, so we only need to clean up their state
+            (_, None) => {
+                children.remove_node_inner(dom, None::<&mut M>, destroy_component_state, None)
+            }
+        }
+
+        // If we are keeping the component state, we need to keep the children around to create them again later
+        if !destroy_component_state {
+            let props = Self::downcast_mut_from_props(&mut *dom.scopes[scope_id.0].props).unwrap();
+            props.rendered_children = Some(children);
+        }
+    }
+}
+
+impl VirtualDom {
+    /// Create the children of the mounted portals under a scope again and move them into their targets.
+    ///
+    /// Portal children are not part of the tree they are placed in, so renderers that hydrate existing nodes never
+    /// attach them. Renderers call this after hydrating a scope to mount the children of the portals in it. Like
+    /// [`VirtualDom::remount_scope`], no mutations are written to remove the old nodes and the state of the children is kept.
+    pub fn remount_portals<M: WriteMutations>(&mut self, scope: ScopeId, to: &mut M) {
+        self.flush_templates(to);
+        let _runtime = RuntimeGuard::new(self.runtime.clone());
+
+        // Only remount the outermost portals. Remounting a portal creates any portals nested in its children
+        let is_portal = |dom: &Self, id: ScopeId| {
+            dom.scopes
+                .get(id.0)
+                .and_then(PortalProps::downcast_from_scope)
+                .is_some()
+        };
+        let portals: Vec<_> = self
+            .scopes
+            .iter()
+            .map(|(id, _)| ScopeId(id))
+            .filter(|&id| {
+                if id == scope || !is_portal(self, id) {
+                    return false;
+                }
+                let mut parent = self.scopes[id.0].state().parent_id();
+                while let Some(id) = parent {
+                    if id == scope {
+                        return true;
+                    }
+                    if is_portal(self, id) {
+                        return false;
+                    }
+                    parent = self.scopes[id.0].state().parent_id();
+                }
+                false
+            })
+            .collect();
+
+        for portal in portals {
+            PortalProps::remount(portal, self, to);
+        }
+    }
+
+    /// Remove the roots of any portal children that were removed without a mutation writer
+    pub(crate) fn flush_portal_removals(&mut self, to: &mut impl WriteMutations) {
+        for id in std::mem::take(&mut self.queued_portal_removals) {
+            to.remove_node(id);
+            self.reclaim(id);
+        }
+    }
+}
@@ -0,0 +1,475 @@
+//! An opt-in profiler that records how long each component takes to render and diff.
+//!
+//! Start recording with [`VirtualDom::start_profiling`] and stop with [`VirtualDom::stop_profiling`]. The recorded renders can be
+//! exported as [Chrome trace events](https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU) with
+//! [`Profiler::to_chrome_trace`] and opened in `chrome://tracing`, [Perfetto](https://ui.perfetto.dev) or any other trace viewer.
+
+use crate::innerlude::*;
+use rustc_hash::FxHashMap;
+use std::{
+    cell::Cell,
+    fmt::Write,
+    panic::Location,
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+// The number of profilers that are currently alive. We only track the location of updates while a profiler exists
+static ACTIVE_PROFILERS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static CURRENT_UPDATE_LOCATION: Cell<Option<&'static Location<'static>>> = const { Cell::new(None) };
+}
+
+/// Run a closure and attribute any components marked dirty inside of it to an update at a location. The location shows up as the
+/// trigger of the rerender in the [`Profiler`].
+///
+/// Signals call this automatically when they are written to in debug builds.
+pub fn with_update_location<O>(location: &'static Location<'static>, f: impl FnOnce() -> O) -> O {
+    if ACTIVE_PROFILERS.load(Ordering::Relaxed) == 0 {
+        return f();
+    }
+
+    struct ResetLocation(Option<&'static Location<'static>>);
+
+    impl Drop for ResetLocation {
+        fn drop(&mut self) {
+            CURRENT_UPDATE_LOCATION.with(|location| location.set(self.0));
+        }
+    }
+
+    let _reset =
+        ResetLocation(CURRENT_UPDATE_LOCATION.with(|current| current.replace(Some(location))));
+    f()
+}
+
+/// Attribute any components marked dirty inside of the closure to a location unless an outer update, like a signal write, is
+/// already running. Manual updates like [`needs_update`] use this so the outermost caller shows up in the [`Profiler`].
+pub(crate) fn with_fallback_update_location<O>(
+    location: &'static Location<'static>,
+    f: impl FnOnce() -> O,
+) -> O {
+    if ACTIVE_PROFILERS.load(Ordering::Relaxed) == 0
+        || CURRENT_UPDATE_LOCATION
+            .with(|current| current.get())
+            .is_some()
+    {
+        return f();
+    }
+    with_update_location(location, f)
+}
+
+/// Get the update that is currently running if a profiler is recording
+pub(crate) fn current_update_source() -> Option<UpdateSource> {
+    if ACTIVE_PROFILERS.load(Ordering::Relaxed) == 0 {
+        return None;
+    }
+    let location = CURRENT_UPDATE_LOCATION.with(|location| location.get())?;
+    // Event handlers, tasks and effects run with the scope that owns them on the scope stack
+    let scope = Runtime::with(|rt| rt.current_scope_id()).flatten();
+    let component = scope.and_then(|scope| Runtime::with_scope(scope, |scope| scope.name));
+    Some(UpdateSource {
+        location,
+        scope,
+        component,
+    })
+}
+
+/// An update that marked a component dirty
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UpdateSource {
+    /// Where the update happened. For signals, this is the location of the write
+    pub location: &'static Location<'static>,
+    /// The scope that was running when the update happened. This is the component that owns the event handler, task, effect or
+    /// memo that made the update. This is `None` if the update happened outside of the virtual dom.
+    pub scope: Option<ScopeId>,
+    /// The name of the component that was running when the update happened
+    pub component: Option<&'static str>,
+}
+
+/// The reason a component rendered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenderTrigger {
+    /// The component rendered for the first time
+    Mount,
+    /// The parent of the component rendered and passed new props
+    Props,
+    /// The component was marked dirty. This contains the signal writes and manual updates (like [`needs_update`] calls from
+    /// context providers such as error and suspense boundaries) that marked the component dirty. Updates from
+    /// [`schedule_update`] callbacks are not attributed and leave this empty.
+    Update(Vec<UpdateSource>),
+}
+
+/// A single render of a component recorded by the [`Profiler`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScopeRender {
+    /// The scope that rendered
+    pub scope: ScopeId,
+    /// The name of the component that rendered
+    pub name: &'static str,
+    /// Why the component rendered
+    pub trigger: RenderTrigger,
+    /// When the component started rendering, relative to when the profiler was created
+    pub render_start: Duration,
+    /// How long running the component took
+    pub render_duration: Duration,
+    /// When diffing the output of the component started, relative to when the profiler was created
+    pub diff_start: Option<Duration>,
+    /// How long diffing the output of the component took. This includes the time spent rendering and diffing any child components
+    pub diff_duration: Option<Duration>,
+    /// The number of mutations the component emitted. This does not include mutations emitted by child components
+    pub mutations: usize,
+}
+
+struct DiffFrame {
+    scope: ScopeId,
+    start: Duration,
+    mutations_at_start: usize,
+    child_mutations: usize,
+}
+
+/// Records the render and diff time of every component that renders in a [`VirtualDom`].
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_core::{NoOpMutations, Profiler};
+/// # fn app() -> Element { rsx! { div {} } }
+/// let mut dom = VirtualDom::new(app);
+/// dom.start_profiling(Profiler::new());
+/// dom.rebuild(&mut NoOpMutations);
+///
+/// let profiler = dom.stop_profiling().unwrap();
+/// for render in profiler.renders() {
+///     println!("{} rendered in {:?}", render.name, render.render_duration);
+/// }
+/// std::fs::write("trace.json", profiler.to_chrome_trace()).unwrap();
+/// ```
+pub struct Profiler {
+    clock: Box<dyn Fn() -> Duration>,
+    renders: Vec<ScopeRender>,
+    // Scopes that were marked dirty since they last rendered with the updates that marked them dirty
+    pending_updates: FxHashMap<ScopeId, Vec<UpdateSource>>,
+    diff_stack: Vec<DiffFrame>,
+    mutations: Rc<Cell<usize>>,
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profiler {
+    /// Create a new profiler that measures time with [`std::time::Instant`].
+    ///
+    /// `Instant` is not available on `wasm32-unknown-unknown`. Use [`Profiler::with_clock`] with a clock based on `performance.now()` instead.
+    pub fn new() -> Self {
+        let start = std::time::Instant::now();
+        Self::with_clock(move || start.elapsed())
+    }
+
+    /// Create a new profiler with a custom clock. The clock should return the time elapsed since some fixed point in time.
+    pub fn with_clock(clock: impl Fn() -> Duration + 'static) -> Self {
+        ACTIVE_PROFILERS.fetch_add(1, Ordering::Relaxed);
+        Self {
+            clock: Box::new(clock),
+            renders: Vec::new(),
+            pending_updates: FxHashMap::default(),
+            diff_stack: Vec::new(),
+            mutations: Default::default(),
+        }
+    }
+
+    /// Get all renders that have been recorded so far
+    pub fn renders(&self) -> &[ScopeRender] {
+        &self.renders
+    }
+
+    /// Clear all recorded renders
+    pub fn clear(&mut self) {
+        self.renders.clear();
+    }
+
+    /// Export the recorded renders as [Chrome trace event](https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU) JSON.
+    ///
+    /// Each render and diff is exported as a complete event. Diffs of child components are nested inside the diff of their parent.
+    pub fn to_chrome_trace(&self) -> String {
+        let mut events = Vec::new();
+        for render in &self.renders {
+            let mut args = format!(
+                r#""scope":{},"trigger":"{}""#,
+                render.scope.0,
+                match &render.trigger {
+                    RenderTrigger::Mount => "mount",
+                    RenderTrigger::Props => "props",
+                    RenderTrigger::Update(_) => "update",
+                }
+            );
+            if let RenderTrigger::Update(sources) = &render.trigger {
+                args.push_str(r#","updated_at":["#);
+                for (i, source) in sources.iter().enumerate() {
+                    if i > 0 {
+                        args.push(',');
+                    }
+                    _ = write!(args, r#""{}""#, escape_json(&source.location.to_string()));
+                }
+                args.push_str(r#"],"updated_by":["#);
+                for (i, source) in sources.iter().enumerate() {
+                    if i > 0 {
+                        args.push(',');
+                    }
+                    match (source.component, source.scope) {
+                        (Some(component), Some(scope)) => {
+                            _ = write!(args, r#""{} ({})""#, escape_json(component), scope.0)
+                        }
+                        _ => args.push_str("null"),
+                    }
+                }
+                args.push(']');
+            }
+            events.push(trace_event(
+                &format!("Render {}", render.name),
+                "render",
+                render.render_start,
+                render.render_duration,
+                &args,
+            ));
+
+            if let (Some(start), Some(duration)) = (render.diff_start, render.diff_duration) {
+                events.push(trace_event(
+                    &format!("Diff {}", render.name),
+                    "diff",
+                    start,
+                    duration,
+                    &format!(
+                        r#""scope":{},"mutations":{}"#,
+                        render.scope.0, render.mutations
+                    ),
+                ));
+            }
+        }
+
+        format!(
+            r#"{{"traceEvents":[{}],"displayTimeUnit":"ms"}}"#,
+            events.join(",")
+        )
+    }
+
+    fn now(&self) -> Duration {
+        (self.clock)()
+    }
+
+    pub(crate) fn mutation_counter(&self) -> Rc<Cell<usize>> {
+        self.mutations.clone()
+    }
+
+    pub(crate) fn mark_dirty(&mut self, scope: ScopeId) {
+        self.pending_updates.entry(scope).or_default();
+    }
+
+    pub(crate) fn update_triggered(&mut self, scope: ScopeId, source: UpdateSource) {
+        let sources = self.pending_updates.entry(scope).or_default();
+        if !sources.contains(&source) {
+            sources.push(source);
+        }
+    }
+
+    pub(crate) fn start_render(&self) -> Duration {
+        self.now()
+    }
+
+    pub(crate) fn finish_render(
+        &mut self,
+        scope: ScopeId,
+        name: &'static str,
+        first_render: bool,
+        start: Duration,
+    ) {
+        let trigger = match self.pending_updates.remove(&scope) {
+            _ if first_render => RenderTrigger::Mount,
+            Some(sources) => RenderTrigger::Update(sources),
+            None => RenderTrigger::Props,
+        };
+        self.renders.push(ScopeRender {
+            scope,
+            name,
+            trigger,
+            render_start: start,
+            render_duration: self.now().saturating_sub(start),
+            diff_start: None,
+            diff_duration: None,
+            mutations: 0,
+        });
+    }
+
+    pub(crate) fn start_diff(&mut self, scope: ScopeId) {
+        self.diff_stack.push(DiffFrame {
+            scope,
+            start: self.now(),
+            mutations_at_start: self.mutations.get(),
+            child_mutations: 0,
+        });
+    }
+
+    pub(crate) fn finish_diff(&mut self) {
+        let Some(frame) = self.diff_stack.pop() else {
+            return;
+        };
+        let total_mutations = self.mutations.get() - frame.mutations_at_start;
+        if let Some(parent) = self.diff_stack.last_mut() {
+            parent.child_mutations += total_mutations;
+        }
+
+        let now = self.now();
+        if let Some(render) = self
+            .renders
+            .iter_mut()
+            .rev()
+            .find(|render| render.scope == frame.scope)
+        {
+            render.diff_start = Some(frame.start);
+            render.diff_duration = Some(now.saturating_sub(frame.start));
+            render.mutations = total_mutations - frame.child_mutations;
+        }
+    }
+}
+
+impl Drop for Profiler {
+    fn drop(&mut self) {
+        ACTIVE_PROFILERS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+fn trace_event(
+    name: &str,
+    category: &str,
+    start: Duration,
+    duration: Duration,
+    args: &str,
+) -> String {
+    format!(
+        r#"{{"name":"{}","cat":"{category}","ph":"X","ts":{},"dur":{},"pid":1,"tid":1,"args":{{{args}}}}}"#,
+        escape_json(name),
+        start.as_secs_f64() * 1_000_000.0,
+        duration.as_secs_f64() * 1_000_000.0,
+    )
+}
+
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => _ = write!(escaped, "\\u{:04x}", c as u32),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A wrapper around a mutation writer that counts the number of mutations written for the profiler
+pub(crate) struct CountMutations<'a, M> {
+    inner: &'a mut M,
+    count: Rc<Cell<usize>>,
+}
+
+impl<'a, M> CountMutations<'a, M> {
+    pub(crate) fn new(inner: &'a mut M, count: Rc<Cell<usize>>) -> Self {
+        Self { inner, count }
+    }
+
+    fn count(&mut self) -> &mut M {
+        self.count.set(self.count.get() + 1);
+        self.inner
+    }
+}
+
+impl<M: WriteMutations> WriteMutations for CountMutations<'_, M> {
+    fn register_template(&mut self, template: Template) {
+        self.inner.register_template(template)
+    }
+
+    fn append_children(&mut self, id: ElementId, m: usize) {
+        self.count().append_children(id, m)
+    }
+
+    fn assign_node_id(&mut self, path: &'static [u8], id: ElementId) {
+        self.count().assign_node_id(path, id)
+    }
+
+    fn create_placeholder(&mut self, id: ElementId) {
+        self.count().create_placeholder(id)
+    }
+
+    fn create_text_node(&mut self, value: &str, id: ElementId) {
+        self.count().create_text_node(value, id)
+    }
+
+    fn hydrate_text_node(&mut self, path: &'static [u8], value: &str, id: ElementId) {
+        self.count().hydrate_text_node(path, value, id)
+    }
+
+    fn load_template(&mut self, name: &'static str, index: usize, id: ElementId) {
+        self.count().load_template(name, index, id)
+    }
+
+    fn replace_node_with(&mut self, id: ElementId, m: usize) {
+        self.count().replace_node_with(id, m)
+    }
+
+    fn replace_placeholder_with_nodes(&mut self, path: &'static [u8], m: usize) {
+        self.count().replace_placeholder_with_nodes(path, m)
+    }
+
+    fn insert_nodes_after(&mut self, id: ElementId, m: usize) {
+        self.count().insert_nodes_after(id, m)
+    }
+
+    fn insert_nodes_before(&mut self, id: ElementId, m: usize) {
+        self.count().insert_nodes_before(id, m)
+    }
+
+    fn set_attribute(
+        &mut self,
+        name: &'static str,
+        ns: Option<&'static str>,
+        value: &AttributeValue,
+        id: ElementId,
+    ) {
+        self.count().set_attribute(name, ns, value, id)
+    }
+
+    fn set_node_text(&mut self, value: &str, id: ElementId) {
+        self.count().set_node_text(value, id)
+    }
+
+    fn create_event_listener(&mut self, name: &'static str, id: ElementId) {
+        self.count().create_event_listener(name, id)
+    }
+
+    fn remove_event_listener(&mut self, name: &'static str, id: ElementId) {
+        self.count().remove_event_listener(name, id)
+    }
+
+    fn remove_node(&mut self, id: ElementId) {
+        self.count().remove_node(id)
+    }
+
+    fn push_root(&mut self, id: ElementId) {
+        self.count().push_root(id)
+    }
+
+    fn append_to_portal(&mut self, target: &str, m: usize) {
+        self.count().append_to_portal(target, m)
+    }
+
+    fn swap_subtree(&mut self, subtree_index: usize) {
+        self.inner.swap_subtree(subtree_index)
+    }
+
+    fn mark_scope_dirty(&mut self, scope_id: ScopeId) {
+        self.inner.mark_scope_dirty(scope_id)
+    }
+}
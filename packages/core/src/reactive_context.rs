@@ -110,7 +110,7 @@ impl ReactiveContext {
         let sender = runtime.sender.clone();
         let update_scope = move || {
             tracing::trace!("Marking scope {:?} as dirty", id);
-            sender.unbounded_send(SchedulerMsg::Immediate(id)).unwrap();
+            SchedulerMsg::send_mark_dirty(&sender, id).unwrap();
         };
 
         // Otherwise, create a new context at the current scope
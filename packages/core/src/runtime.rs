@@ -1,4 +1,4 @@
-use crate::innerlude::{DirtyTasks, Effect};
+use crate::innerlude::{DirtyTasks, Effect, SuspenseListContext, TransitionId, TransitionState};
 use crate::scope_context::SuspenseLocation;
 use crate::{
     innerlude::{LocalTask, SchedulerMsg},
@@ -6,6 +6,7 @@ use crate::{
     scopes::ScopeId,
     Task,
 };
+use rustc_hash::FxHashMap;
 use slotmap::DefaultKey;
 use std::collections::BTreeSet;
 use std::{
@@ -45,6 +46,30 @@ pub struct Runtime {
 
     // Tasks that are waiting to be polled
     pub(crate) dirty_tasks: RefCell<BTreeSet<DirtyTasks>>,
+
+    // If we are currently rerunning a scope that was marked dirty in a transition
+    pub(crate) rendering_transition: Cell<bool>,
+
+    // The transitions that were waiting for the scope that is currently rerunning
+    pub(crate) rendering_transitions: RefCell<Vec<TransitionId>>,
+
+    // Tasks that suspended while rendering a transition. The transition isn't finished until these tasks resolve
+    pub(crate) transition_suspensions: Cell<usize>,
+
+    // The work each pending transition is waiting on
+    pub(crate) transitions: RefCell<FxHashMap<TransitionId, TransitionState>>,
+
+    // Callbacks that run once all pending transitions have finished
+    pub(crate) transition_callbacks: RefCell<Vec<Box<dyn FnOnce()>>>,
+
+    // Suspense lists that are waiting for a render to reveal the boundaries they created
+    pub(crate) mounting_suspense_lists: Cell<usize>,
+
+    // Suspense lists that need to order the boundaries that were added to them once the current diff is finished
+    pub(crate) unordered_suspense_lists: RefCell<Vec<SuspenseListContext>>,
+
+    // Scopes that are cached outside of the rendered tree by a keep alive component
+    pub(crate) offscreen_scopes: Cell<usize>,
 }
 
 impl Runtime {
@@ -60,6 +85,14 @@ impl Runtime {
             suspended_tasks: Default::default(),
             pending_effects: Default::default(),
             dirty_tasks: Default::default(),
+            rendering_transition: Default::default(),
+            rendering_transitions: Default::default(),
+            transition_suspensions: Default::default(),
+            transitions: Default::default(),
+            transition_callbacks: Default::default(),
+            mounting_suspense_lists: Default::default(),
+            unordered_suspense_lists: Default::default(),
+            offscreen_scopes: Default::default(),
         })
     }
 
@@ -75,6 +108,15 @@ impl Runtime {
         if scopes.len() <= id.0 {
             scopes.resize_with(id.0 + 1, Default::default);
         }
+        // Scopes created while rendering an offscreen subtree are offscreen as well
+        let parent_offscreen = context
+            .parent_id
+            .and_then(|parent| scopes.get(parent.0)?.as_ref())
+            .is_some_and(|parent| parent.offscreen.get());
+        if parent_offscreen {
+            context.offscreen.set(true);
+            self.offscreen_scopes.set(self.offscreen_scopes.get() + 1);
+        }
         scopes[id.0] = Some(context);
     }
 
@@ -82,6 +124,9 @@ impl Runtime {
         {
             let borrow = self.scope_states.borrow();
             if let Some(scope) = &borrow[id.0] {
+                if scope.offscreen.get() {
+                    self.offscreen_scopes.set(self.offscreen_scopes.get() - 1);
+                }
                 let _runtime_guard = RuntimeGuard::new(self.clone());
                 // Manually drop tasks, hooks, and contexts inside of the runtime
                 self.on_scope(id, || {
@@ -194,13 +239,18 @@ impl Runtime {
     /// Check if we should render a scope
     pub(crate) fn scope_should_render(&self, scope_id: ScopeId) -> bool {
         // If there are no suspended futures, we know the scope is not  and we can skip context checks
-        if self.suspended_tasks.get() == 0 {
+        if self.suspended_tasks.get() == 0
+            && self.mounting_suspense_lists.get() == 0
+            && self.offscreen_scopes.get() == 0
+        {
             return true;
         }
         // If this is not a suspended scope, and we are under a frozen context, then we should
         let scopes = self.scope_states.borrow();
         let scope = &scopes[scope_id.0].as_ref().unwrap();
-        !matches!(scope.suspense_boundary(), SuspenseLocation::UnderSuspense(suspense) if suspense.suspended())
+        // Offscreen scopes are kept up to date in the background until they are shown again
+        !scope.offscreen.get()
+            && !matches!(scope.suspense_boundary(), SuspenseLocation::UnderSuspense(suspense) if suspense.showing_fallback())
     }
 }
 
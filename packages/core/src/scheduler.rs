@@ -75,16 +75,107 @@
 //! 3. Effects:
 //!    Description: Effects should always run after all changes to the DOM have been applied.
 //!    Priority: These are the lowest priority tasks in the scheduler. They are run after all other dirty scopes and futures have been resolved. Other tasks may cause components to rerun, which would update the DOM. These effects should only run after the DOM has been updated.
+//!
+//! ## Update priorities
+//!
+//! Dirty scopes are queued with a [`Priority`]. Updates are [`Priority::Immediate`] by default, which is the right choice for anything that responds directly
+//! to user input. Updates made inside [`with_priority`] with [`Priority::Background`] are queued separately and only rerun once all immediate scopes and tasks
+//! have been resolved. If a scope is marked dirty with both priorities, it is rerun once with the immediate priority.
+//!
+//! ## Time slicing
+//!
+//! [`VirtualDom::render_with_deadline`] runs the same work loop as [`VirtualDom::render_immediate`], but checks the deadline between each unit of work. If the
+//! deadline passes before the queue is empty, it returns [`RenderStatus::Interrupted`] and the remaining work is picked up by the next render. A scope is never
+//! interrupted in the middle of running or diffing, so the mutations for each component are always complete.
 
 use crate::innerlude::Effect;
 use crate::ScopeId;
 use crate::Task;
 use crate::VirtualDom;
 use std::borrow::Borrow;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::VecDeque;
 use std::hash::Hash;
 
+/// The priority of an update to a component.
+///
+/// Updates are queued with the priority returned by [`current_priority`] when the scope is marked dirty. Immediate updates are always rendered before
+/// background updates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    /// An update that should be rendered as soon as possible, like a response to user input. This is the default priority.
+    #[default]
+    Immediate,
+    /// An update that can be deferred until all immediate work is done. Background updates are rendered in slices with
+    /// [`VirtualDom::render_with_deadline`] so they don't block immediate updates.
+    Background,
+}
+
+thread_local! {
+    static CURRENT_PRIORITY: Cell<Priority> = const { Cell::new(Priority::Immediate) };
+}
+
+/// Get the priority that components marked dirty on this thread will be queued with
+pub fn current_priority() -> Priority {
+    CURRENT_PRIORITY.with(|priority| priority.get())
+}
+
+/// Run a closure with a different update priority. Any components marked dirty inside of the closure (for example, by writing to a signal)
+/// will be queued with this priority.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_core::prelude::*;
+/// fn app() -> Element {
+///     let mut query = use_signal(String::new);
+///     let mut results_query = use_signal(String::new);
+///
+///     rsx! {
+///         input {
+///             value: "{query}",
+///             oninput: move |event| {
+///                 // Update the input immediately so typing stays responsive
+///                 query.set(event.value());
+///                 // And render the (expensive) results in the background
+///                 with_priority(Priority::Background, || results_query.set(event.value()));
+///             }
+///         }
+///         SearchResults { query: results_query }
+///     }
+/// }
+/// # #[component]
+/// # fn SearchResults(query: ReadOnlySignal<String>) -> Element { VNode::empty() }
+/// ```
+pub fn with_priority<O>(priority: Priority, f: impl FnOnce() -> O) -> O {
+    struct ResetPriority(Priority);
+
+    impl Drop for ResetPriority {
+        fn drop(&mut self) {
+            CURRENT_PRIORITY.with(|priority| priority.set(self.0));
+        }
+    }
+
+    let _reset = ResetPriority(CURRENT_PRIORITY.with(|current| current.replace(priority)));
+    f()
+}
+
+/// The result of [`VirtualDom::render_with_deadline`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[must_use = "An interrupted render should be resumed later"]
+pub enum RenderStatus {
+    /// All queued work was rendered
+    Complete,
+    /// The deadline passed before all queued work was rendered. Render again to continue the work.
+    Interrupted,
+}
+
+impl RenderStatus {
+    /// Check if all queued work was rendered
+    pub fn is_complete(&self) -> bool {
+        matches!(self, RenderStatus::Complete)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq)]
 pub struct ScopeOrder {
     pub(crate) height: u32,
@@ -136,13 +227,31 @@ impl VirtualDom {
     }
 
     /// Queue a scope to be rerendered
-    pub(crate) fn queue_scope(&mut self, order: ScopeOrder) {
-        self.dirty_scopes.insert(order);
+    pub(crate) fn queue_scope(&mut self, order: ScopeOrder, priority: Priority) {
+        match priority {
+            Priority::Immediate => {
+                // The immediate rerun will include any background updates
+                self.background_scopes.remove(&order);
+                self.dirty_scopes.insert(order);
+            }
+            Priority::Background => {
+                if !self.dirty_scopes.contains(&order) {
+                    self.background_scopes.insert(order);
+                }
+            }
+        }
+    }
+
+    /// Remove a scope from the dirty scopes of every priority. This should be called when a scope is rerun or dropped
+    pub(crate) fn remove_dirty_scope(&mut self, order: &ScopeOrder) {
+        self.dirty_scopes.remove(order);
+        self.background_scopes.remove(order);
+        self.runtime.remove_transition_scope(order.id);
     }
 
     /// Check if there are any dirty scopes
     pub(crate) fn has_dirty_scopes(&self) -> bool {
-        !self.dirty_scopes.is_empty()
+        !self.dirty_scopes.is_empty() || !self.background_scopes.is_empty()
     }
 
     /// Take the top task from the highest scope
@@ -225,14 +334,25 @@ impl VirtualDom {
                 Some(Work::RerunScope(scope))
             }
             (None, Some(_)) => Some(Work::PollTask(self.pop_task().unwrap())),
-            (None, None) => None,
+            // Background scopes only run once all immediate work is done
+            (None, None) => self.pop_background_scope().map(Work::RerunBackgroundScope),
+        }
+    }
+
+    /// Take the highest background scope that still exists
+    fn pop_background_scope(&mut self) -> Option<ScopeOrder> {
+        let mut scope = self.background_scopes.pop_first()?;
+        while !self.scopes.contains(scope.id.0) {
+            scope = self.background_scopes.pop_first()?;
         }
+        Some(scope)
     }
 }
 
 #[derive(Debug)]
 pub enum Work {
     RerunScope(ScopeOrder),
+    RerunBackgroundScope(ScopeOrder),
     PollTask(Task),
 }
 
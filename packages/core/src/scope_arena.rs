@@ -58,6 +58,11 @@ impl VirtualDom {
         self.runtime.push_scope(scope_id);
 
         let scope = &self.scopes[scope_id.0];
+        let first_render = scope.last_rendered_node.is_none();
+        let profile_start = self
+            .profiler
+            .as_ref()
+            .map(|profiler| profiler.start_render());
         let output = {
             let scope_state = scope.state();
 
@@ -88,8 +93,14 @@ impl VirtualDom {
         }
 
         // remove this scope from dirty scopes
-        self.dirty_scopes
-            .remove(&ScopeOrder::new(scope_state.height, scope_id));
+        let order = ScopeOrder::new(scope_state.height, scope_id);
+        let name = scope_state.name;
+        drop(scope_state);
+        self.remove_dirty_scope(&order);
+
+        if let (Some(profiler), Some(start)) = (&mut self.profiler, profile_start) {
+            profiler.finish_render(scope_id, name, first_render, start);
+        }
 
         self.runtime.pop_scope();
 
@@ -115,15 +126,21 @@ impl VirtualDom {
                     .get_state(scope_id)
                     .unwrap()
                     .suspense_boundary();
+                // If we are rendering a transition and the scope already rendered successfully, keep showing the old nodes
+                // instead of the suspense fallback. We only do this if the boundary is still showing its children
+                let transition = self.runtime.rendering_transition()
+                    && matches!(&self.scopes[scope_id.0].last_rendered_node, Some(last) if last.node.is_ok())
+                    && !matches!(&boundary, SuspenseLocation::UnderSuspense(boundary) if boundary.showing_fallback());
                 let already_suspended = self
                     .runtime
                     .tasks
                     .borrow()
                     .get(task.id)
                     .expect("Suspended on a task that no longer exists")
-                    .suspend(boundary.clone());
+                    .suspend(boundary.clone(), transition);
                 if !already_suspended {
                     tracing::trace!("Suspending {:?} on {:?}", scope_id, task);
+                    e.transition = transition;
                     // Add this task to the suspended tasks list of the boundary
                     if let SuspenseLocation::UnderSuspense(boundary) = &boundary {
                         boundary.add_suspended_task(e.clone());
@@ -131,6 +148,9 @@ impl VirtualDom {
                     self.runtime
                         .suspended_tasks
                         .set(self.runtime.suspended_tasks.get() + 1);
+                    if transition {
+                        self.runtime.add_transition_suspension(task);
+                    }
                 }
                 e.placeholder = VNode::placeholder();
             }
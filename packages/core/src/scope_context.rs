@@ -1,14 +1,18 @@
-use crate::{innerlude::SchedulerMsg, Runtime, ScopeId, Task};
 use crate::{
     innerlude::{throw_into, CapturedError},
     prelude::SuspenseContext,
 };
+use crate::{
+    innerlude::{with_fallback_update_location, SchedulerMsg},
+    Runtime, ScopeId, Task,
+};
 use generational_box::{AnyStorage, Owner};
 use rustc_hash::FxHashSet;
 use std::{
     any::Any,
     cell::{Cell, RefCell},
     future::Future,
+    panic::Location,
     sync::Arc,
 };
 
@@ -59,6 +63,9 @@ pub(crate) struct Scope {
     /// The suspense boundary that this scope is currently in (if any)
     suspense_boundary: SuspenseLocation,
 
+    /// If this scope is part of a subtree that a keep alive component cached outside of the rendered tree
+    pub(crate) offscreen: Cell<bool>,
+
     pub(crate) status: RefCell<ScopeStatus>,
 }
 
@@ -86,6 +93,7 @@ impl Scope {
                 effects_queued: Vec::new(),
             }),
             suspense_boundary,
+            offscreen: Cell::new(false),
         }
     }
 
@@ -122,15 +130,18 @@ impl Scope {
     }
 
     /// Mark this scope as dirty, and schedule a render for it.
+    #[track_caller]
     pub fn needs_update(&self) {
         self.needs_update_any(self.id)
     }
 
     /// Mark this scope as dirty, and schedule a render for it.
+    #[track_caller]
     pub fn needs_update_any(&self, id: ScopeId) {
-        self.sender()
-            .unbounded_send(SchedulerMsg::Immediate(id))
-            .expect("Scheduler to exist if scope exists");
+        with_fallback_update_location(Location::caller(), || {
+            SchedulerMsg::send_mark_dirty(&self.sender(), id)
+        })
+        .expect("Scheduler to exist if scope exists");
     }
 
     /// Create a subscription that schedules a future render for the reference component
@@ -138,7 +149,7 @@ impl Scope {
     /// ## Notice: you should prefer using [`Self::schedule_update_any`] and [`Self::scope_id`]
     pub fn schedule_update(&self) -> Arc<dyn Fn() + Send + Sync + 'static> {
         let (chan, id) = (self.sender(), self.id);
-        Arc::new(move || drop(chan.unbounded_send(SchedulerMsg::Immediate(id))))
+        Arc::new(move || drop(SchedulerMsg::send_mark_dirty(&chan, id)))
     }
 
     /// Schedule an update for any component given its [`ScopeId`].
@@ -149,7 +160,7 @@ impl Scope {
     pub fn schedule_update_any(&self) -> Arc<dyn Fn(ScopeId) + Send + Sync> {
         let chan = self.sender();
         Arc::new(move |id| {
-            chan.unbounded_send(SchedulerMsg::Immediate(id)).unwrap();
+            SchedulerMsg::send_mark_dirty(&chan, id).unwrap();
         })
     }
 
@@ -491,8 +502,12 @@ impl ScopeId {
     }
 
     /// Mark the current scope as dirty, causing it to re-render
+    #[track_caller]
     pub fn needs_update(self) {
-        Runtime::with_scope(self, |cx| cx.needs_update());
+        let location = Location::caller();
+        Runtime::with_scope(self, |cx| {
+            with_fallback_update_location(location, || cx.needs_update())
+        });
     }
 
     /// Create a subscription that schedules a future render for the reference component. Unlike [`Self::needs_update`], this function will work outside of the dioxus runtime.
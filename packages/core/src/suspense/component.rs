@@ -269,6 +269,18 @@ use generational_box::Owner;
 #[allow(unused)]
 pub use SuspenseBoundary_completions::Component::SuspenseBoundary;
 
+/// Render the fallback of a suspense boundary unless the suspense list the boundary is in hides it
+fn render_fallback(
+    fallback: Callback<SuspenseContext, Element>,
+    suspense_context: SuspenseContext,
+) -> Element {
+    if suspense_context.fallback_hidden() {
+        VNode::empty()
+    } else {
+        fallback.call(suspense_context)
+    }
+}
+
 /// Suspense has a custom diffing algorithm that diffs the suspended nodes in the background without rendering them
 impl SuspenseBoundaryProps {
     /// Try to downcast [`AnyProps`] to [`SuspenseBoundaryProps`]
@@ -309,6 +321,12 @@ impl SuspenseBoundaryProps {
         if scope_id.is_placeholder() {
             {
                 let suspense_context = SuspenseContext::new();
+                let enclosing_boundary = dom
+                    .runtime
+                    .suspense_stack
+                    .borrow()
+                    .last()
+                    .and_then(|location| location.suspense_context().cloned());
 
                 dom.runtime.suspense_stack.borrow_mut().push(
                     crate::scope_context::SuspenseLocation::UnderSuspense(suspense_context.clone()),
@@ -321,6 +339,15 @@ impl SuspenseBoundaryProps {
                     scope_id = scope_state.id;
                 }
                 dom.runtime.suspense_stack.borrow_mut().pop();
+
+                // Join the closest suspense list so it can decide when this boundary is revealed
+                if let Some(list) = SuspenseListContext::for_new_boundary(
+                    &dom.runtime,
+                    scope_id,
+                    enclosing_boundary.as_ref(),
+                ) {
+                    suspense_context.join_list(list);
+                }
             }
 
             // Store the scope id for the next render
@@ -356,7 +383,7 @@ impl SuspenseBoundaryProps {
             .unwrap()
             .clone();
         // If there are suspended futures, render the fallback
-        let nodes_created = if !suspense_context.suspended_futures().is_empty() {
+        let nodes_created = if suspense_context.showing_fallback() {
             let props = Self::downcast_mut_from_props(&mut *scope_state.props).unwrap();
             props.suspended_nodes = Some(children.into());
 
@@ -365,7 +392,7 @@ impl SuspenseBoundaryProps {
                     suspense_context.clone(),
                 ),
             );
-            let suspense_placeholder = props.fallback.call(suspense_context);
+            let suspense_placeholder = render_fallback(props.fallback, suspense_context);
             let node = RenderReturn {
                 node: suspense_placeholder,
             };
@@ -386,6 +413,7 @@ impl SuspenseBoundaryProps {
             scope_state.last_rendered_node = Some(children);
             let props = Self::downcast_mut_from_props(&mut *dom.scopes[scope_id.0].props).unwrap();
             props.suspended_nodes = None;
+            suspense_context.mark_revealed();
             nodes_created
         };
 
@@ -461,6 +489,7 @@ impl SuspenseBoundaryProps {
         props.children = children.clone().node;
         scope_state.last_rendered_node = Some(children);
         props.suspended_nodes = None;
+        suspense_context.mark_revealed();
     }
 
     pub(crate) fn diff<M: WriteMutations>(
@@ -488,7 +517,12 @@ impl SuspenseBoundaryProps {
             .suspense_context()
             .unwrap()
             .clone();
-        let suspended = !suspense_context.suspended_futures().is_empty();
+        // The boundaries of a suspense list were all created by the time any of them rerenders
+        if let Some(list) = suspense_context.list() {
+            list.finish_mounting();
+        }
+        // Futures that suspended during a transition keep showing the current children
+        let suspended = suspense_context.showing_fallback();
         match (suspended_nodes, suspended) {
             // We already have suspended nodes that still need to be suspended
             // Just diff the normal and suspended nodes
@@ -503,7 +537,7 @@ impl SuspenseBoundaryProps {
                 );
                 let old_placeholder = last_rendered_node;
                 let new_placeholder = RenderReturn {
-                    node: fallback.call(suspense_context),
+                    node: render_fallback(fallback, suspense_context),
                 };
 
                 old_placeholder.diff_node(&new_placeholder, dom, to);
@@ -539,7 +573,7 @@ impl SuspenseBoundaryProps {
                 let new_children: VNode = RenderReturn { node: children }.into();
 
                 let new_placeholder = RenderReturn {
-                    node: fallback.call(suspense_context.clone()),
+                    node: render_fallback(fallback, suspense_context.clone()),
                 };
 
                 // Move the children to the background
@@ -590,6 +624,7 @@ impl SuspenseBoundaryProps {
                 let props =
                     Self::downcast_mut_from_props(&mut *dom.scopes[scope_id.0].props).unwrap();
                 props.suspended_nodes = None;
+                suspense_context.mark_revealed();
             }
         }
     }
@@ -0,0 +1,558 @@
+//! Suspense lists coordinate when the suspense boundaries inside them are revealed
+//!
+//! Every [`SuspenseBoundary`] that is created under a [`SuspenseList`] joins the list unless there is another suspense
+//! boundary between them. The list keeps the boundaries in the order they appear in the tree. Boundaries that are added
+//! after the list was first created are ordered once the diff that created them is finished, and the list holds back
+//! every boundary until then. A boundary that is not revealed yet keeps showing its fallback (or nothing, depending on
+//! the tail of the list) even if its own children are ready. Once a boundary is revealed, the list never holds it back
+//! again.
+//!
+//! The list decides which boundaries are held back in one pass over its boundaries whenever a boundary starts or stops
+//! loading, and only rerenders the boundaries whose state changed.
+//!
+//! Boundaries revealed backwards or together depend on the boundaries after them. Those boundaries don't exist yet
+//! when the first boundaries in the list are created, so the list holds every boundary back until all of them were
+//! created and reveals them in the next render. While that render is pending, the list counts as suspended work so
+//! server side rendering and streaming wait for it.
+
+use crate::innerlude::*;
+use std::{
+    cell::{Cell, RefCell},
+    rc::{Rc, Weak},
+};
+
+/// The order the suspense boundaries in a [`SuspenseList`] are revealed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RevealOrder {
+    /// Reveal the boundaries from first to last. A boundary is only revealed once every boundary before it is ready.
+    #[default]
+    Forwards,
+    /// Reveal the boundaries from last to first. A boundary is only revealed once every boundary after it is ready.
+    Backwards,
+    /// Reveal all boundaries at the same time once every boundary is ready
+    Together,
+}
+
+/// How a [`SuspenseList`] shows the fallbacks of the boundaries that are not revealed yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SuspenseListTail {
+    /// Show the fallback of every boundary that is not revealed yet
+    #[default]
+    Visible,
+    /// Only show the fallback of the next boundary that will be revealed
+    Collapsed,
+    /// Don't show any fallbacks for boundaries that are not revealed yet
+    Hidden,
+}
+
+/// A context with the boundaries a [`SuspenseList`] coordinates
+#[derive(Debug, Clone)]
+pub struct SuspenseListContext {
+    inner: Rc<SuspenseListInner>,
+}
+
+impl PartialEq for SuspenseListContext {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+#[derive(Debug)]
+struct SuspenseListInner {
+    id: ScopeId,
+    reveal_order: Cell<RevealOrder>,
+    tail: Cell<SuspenseListTail>,
+    /// The boundaries in the list in the order they appear in the tree
+    boundaries: RefCell<Vec<Weak<SuspenseBoundaryInner>>>,
+    /// If the list finished creating its first boundaries in tree order
+    created: Cell<bool>,
+    /// If boundaries were added to the end of the list since it was last ordered
+    unordered: Cell<bool>,
+    /// If the list is still creating its first boundaries
+    mounting: Cell<bool>,
+    /// If the list is counted as suspended work in the runtime while it is mounting
+    counted: Cell<bool>,
+    /// If the boundaries changed since the list last decided which of them are held back
+    stale: Cell<bool>,
+    runtime: Weak<Runtime>,
+}
+
+impl Drop for SuspenseListInner {
+    fn drop(&mut self) {
+        if let (true, Some(runtime)) = (self.counted.get(), self.runtime.upgrade()) {
+            runtime
+                .mounting_suspense_lists
+                .set(runtime.mounting_suspense_lists.get() - 1);
+        }
+    }
+}
+
+impl SuspenseListContext {
+    fn new(id: ScopeId, reveal_order: RevealOrder, tail: SuspenseListTail) -> Self {
+        let runtime = Runtime::current();
+        let list = Self {
+            inner: Rc::new(SuspenseListInner {
+                id,
+                reveal_order: Cell::new(reveal_order),
+                tail: Cell::new(tail),
+                boundaries: RefCell::new(Vec::new()),
+                created: Cell::new(false),
+                unordered: Cell::new(false),
+                mounting: Cell::new(true),
+                counted: Cell::new(false),
+                stale: Cell::new(true),
+                runtime: runtime.as_ref().map(Rc::downgrade).unwrap_or_default(),
+            }),
+        };
+        // The first boundaries are created in tree order. Order the list once they are all created so boundaries that
+        // are added later are ordered
+        if let Some(runtime) = runtime {
+            runtime
+                .unordered_suspense_lists
+                .borrow_mut()
+                .push(list.clone());
+        }
+        list
+    }
+
+    /// The scope of the [`SuspenseList`] component
+    pub fn id(&self) -> ScopeId {
+        self.inner.id
+    }
+
+    /// The order the boundaries in the list are revealed in
+    pub fn reveal_order(&self) -> RevealOrder {
+        self.inner.reveal_order.get()
+    }
+
+    /// How the fallbacks of boundaries that are not revealed yet are shown
+    pub fn tail(&self) -> SuspenseListTail {
+        self.inner.tail.get()
+    }
+
+    /// Find the list a new suspense boundary should join. Boundaries only join the closest list if there is no other
+    /// suspense boundary between them and the list.
+    pub(crate) fn for_new_boundary(
+        runtime: &Runtime,
+        boundary: ScopeId,
+        enclosing_boundary: Option<&SuspenseContext>,
+    ) -> Option<Self> {
+        let list = runtime
+            .get_state(boundary)?
+            .consume_context::<SuspenseListContext>()?;
+        let list_height = runtime.get_state(list.id())?.height();
+        match enclosing_boundary.and_then(|boundary| runtime.get_state(boundary.scope_id())) {
+            Some(enclosing) if enclosing.height() > list_height => None,
+            _ => Some(list),
+        }
+    }
+
+    /// Add a boundary to the end of the list. If the list was already created, the boundary may belong anywhere in the
+    /// list so the list is ordered again after the current diff.
+    pub(crate) fn register(&self, boundary: &Rc<SuspenseBoundaryInner>) {
+        self.inner
+            .boundaries
+            .borrow_mut()
+            .push(Rc::downgrade(boundary));
+        self.invalidate();
+
+        if self.inner.created.get() && !self.inner.unordered.replace(true) {
+            if let Some(runtime) = self.inner.runtime.upgrade() {
+                runtime
+                    .unordered_suspense_lists
+                    .borrow_mut()
+                    .push(self.clone());
+            }
+        }
+
+        // Boundaries that depend on the boundaries after them need another render to be revealed
+        if self.inner.mounting.get() && self.reveal_order() != RevealOrder::Forwards {
+            if !self.inner.counted.replace(true) {
+                if let Some(runtime) = self.inner.runtime.upgrade() {
+                    runtime
+                        .mounting_suspense_lists
+                        .set(runtime.mounting_suspense_lists.get() + 1);
+                }
+            }
+            self.update();
+        }
+    }
+
+    /// Mark the list as done creating its first boundaries
+    pub(crate) fn finish_mounting(&self) {
+        if !self.inner.mounting.replace(false) {
+            return;
+        }
+        if self.inner.counted.replace(false) {
+            if let Some(runtime) = self.inner.runtime.upgrade() {
+                runtime
+                    .mounting_suspense_lists
+                    .set(runtime.mounting_suspense_lists.get() - 1);
+            }
+        }
+        self.update();
+    }
+
+    /// Decide again which boundaries are held back and rerender the boundaries whose state changed. This is called
+    /// whenever a boundary starts or stops loading because that may change which boundaries are revealed.
+    pub(crate) fn update(&self) {
+        self.invalidate();
+        self.reveal();
+    }
+
+    /// Mark the state of the boundaries as out of date. It is decided again the next time a boundary reads it.
+    pub(crate) fn invalidate(&self) {
+        self.inner.stale.set(true);
+    }
+
+    /// Update the options of the list and rerender the boundaries if they changed
+    fn set_options(&self, reveal_order: RevealOrder, tail: SuspenseListTail) {
+        let order_changed = self.inner.reveal_order.replace(reveal_order) != reveal_order;
+        let tail_changed = self.inner.tail.replace(tail) != tail;
+        if order_changed || tail_changed {
+            self.update();
+        }
+    }
+
+    /// Sort the boundaries by their position in the tree under the list and rerender them if new boundaries were added
+    fn order(&self, dom: &VirtualDom) {
+        let mut positions = Vec::new();
+        if let Some(node) = dom
+            .get_scope(self.id())
+            .and_then(|scope| scope.try_root_node())
+        {
+            collect_boundaries(dom, node, &mut positions);
+        }
+        self.inner.boundaries.borrow_mut().sort_by_key(|boundary| {
+            boundary
+                .upgrade()
+                .and_then(|boundary| positions.iter().position(|id| *id == boundary.id.get()))
+                .unwrap_or(usize::MAX)
+        });
+
+        self.inner.created.set(true);
+        if self.inner.unordered.replace(false) {
+            self.update();
+        }
+    }
+
+    /// Get the boundaries that are still mounted in the order they appear in the tree
+    fn boundaries(&self) -> Vec<Rc<SuspenseBoundaryInner>> {
+        let mut boundaries = self.inner.boundaries.borrow_mut();
+        boundaries.retain(|boundary| boundary.strong_count() > 0);
+        boundaries.iter().filter_map(Weak::upgrade).collect()
+    }
+
+    /// Decide which boundaries are held back and which fallbacks are hidden if the list changed since it last decided.
+    /// Every boundary before (or after) the first boundary that is still loading is revealed, so this only needs one
+    /// pass over the boundaries.
+    fn reveal(&self) {
+        if !self.inner.stale.replace(false) {
+            return;
+        }
+        let boundaries = self.boundaries();
+        let order = self.reveal_order();
+        let blocking =
+            |boundary: &Rc<SuspenseBoundaryInner>| !boundary.revealed.get() && boundary.loading();
+        // New boundaries may belong anywhere in the list until it is ordered again. Boundaries revealed backwards or
+        // together depend on boundaries that may not exist yet while the list is mounting
+        let hold_all = self.inner.unordered.get()
+            || (order != RevealOrder::Forwards && self.inner.mounting.get());
+        let len = boundaries.len();
+        let waiting: Vec<bool> = match order {
+            _ if hold_all => vec![true; len],
+            RevealOrder::Forwards => {
+                let cutoff = boundaries.iter().position(blocking).unwrap_or(len);
+                (0..len).map(|index| index >= cutoff).collect()
+            }
+            RevealOrder::Backwards => {
+                let cutoff = boundaries.iter().rposition(blocking).map_or(0, |i| i + 1);
+                (0..len).map(|index| index < cutoff).collect()
+            }
+            RevealOrder::Together => vec![boundaries.iter().any(blocking); len],
+        };
+        let held: Vec<bool> = boundaries
+            .iter()
+            .zip(waiting)
+            .map(|(boundary, waiting)| waiting && !boundary.revealed.get())
+            .collect();
+
+        // Only the first boundary that is held back in reveal order shows its fallback with a collapsed tail
+        let next = match order {
+            RevealOrder::Backwards => held.iter().rposition(|held| *held),
+            _ => held.iter().position(|held| *held),
+        };
+        for (index, boundary) in boundaries.iter().enumerate() {
+            let fallback_hidden = held[index]
+                && match self.tail() {
+                    SuspenseListTail::Visible => false,
+                    SuspenseListTail::Hidden => true,
+                    SuspenseListTail::Collapsed => next != Some(index),
+                };
+            let held_changed = boundary.held.replace(held[index]) != held[index];
+            let hidden_changed =
+                boundary.fallback_hidden.replace(fallback_hidden) != fallback_hidden;
+            if held_changed || hidden_changed {
+                boundary.id.get().needs_update();
+            }
+        }
+    }
+
+    /// Check if the list holds a boundary back
+    pub(crate) fn holds(&self, boundary: &SuspenseBoundaryInner) -> bool {
+        self.reveal();
+        boundary.held.get()
+    }
+
+    /// Check if the tail of the list hides the fallback of a boundary that is held back
+    pub(crate) fn hides_fallback(&self, boundary: &SuspenseBoundaryInner) -> bool {
+        self.reveal();
+        boundary.fallback_hidden.get()
+    }
+}
+
+/// Collect the suspense boundaries under a node in the order they appear in the tree. The dynamic nodes of a template
+/// are numbered in the order they appear in the template. Boundaries nested inside other boundaries are skipped because
+/// they don't join the list.
+fn collect_boundaries(dom: &VirtualDom, node: &VNode, boundaries: &mut Vec<ScopeId>) {
+    for (index, dynamic_node) in node.dynamic_nodes.iter().enumerate() {
+        match dynamic_node {
+            DynamicNode::Component(component) => {
+                let Some(scope) = component.mounted_scope(index, node, dom) else {
+                    continue;
+                };
+                if SuspenseBoundaryProps::downcast_from_scope(scope).is_some() {
+                    boundaries.push(scope.id());
+                } else if let Some(portal) = PortalProps::downcast_from_scope(scope) {
+                    if let Some(children) = portal.rendered_children() {
+                        collect_boundaries(dom, children, boundaries);
+                    }
+                } else if let Some(node) = scope.try_root_node() {
+                    collect_boundaries(dom, node, boundaries);
+                }
+            }
+            DynamicNode::Fragment(nodes) => {
+                for node in nodes {
+                    collect_boundaries(dom, node, boundaries);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl VirtualDom {
+    /// Order the suspense lists that had boundaries added during the last diff. This runs after a whole scope was diffed
+    /// so the new boundaries are part of the tree under the list.
+    pub(crate) fn order_suspense_lists(&mut self) {
+        let lists = std::mem::take(&mut *self.runtime.unordered_suspense_lists.borrow_mut());
+        for list in lists {
+            list.order(self);
+        }
+    }
+}
+
+/// Properties for the [`SuspenseList()`] component.
+#[derive(Clone, PartialEq)]
+pub struct SuspenseListProps {
+    reveal_order: RevealOrder,
+    tail: SuspenseListTail,
+    children: Element,
+}
+impl SuspenseListProps {
+    /**
+    Create a builder for building `SuspenseListProps`.
+    On the builder, call `.reveal_order(...)`(optional), `.tail(...)`(optional), `.children(...)`(optional) to set the values of the fields.
+    Finally, call `.build()` to create the instance of `SuspenseListProps`.
+                        */
+    #[allow(dead_code)]
+    pub fn builder() -> SuspenseListPropsBuilder<((), (), ())> {
+        SuspenseListPropsBuilder {
+            fields: ((), (), ()),
+        }
+    }
+}
+#[must_use]
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub struct SuspenseListPropsBuilder<TypedBuilderFields> {
+    fields: TypedBuilderFields,
+}
+impl Properties for SuspenseListProps {
+    type Builder = SuspenseListPropsBuilder<((), (), ())>;
+    fn builder() -> Self::Builder {
+        SuspenseListProps::builder()
+    }
+    fn memoize(&mut self, new: &Self) -> bool {
+        let equal = self == new;
+        if !equal {
+            *self = new.clone();
+        }
+        equal
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub trait SuspenseListPropsBuilder_Optional<T> {
+    fn into_value<F: FnOnce() -> T>(self, default: F) -> T;
+}
+impl<T> SuspenseListPropsBuilder_Optional<T> for () {
+    fn into_value<F: FnOnce() -> T>(self, default: F) -> T {
+        default()
+    }
+}
+impl<T> SuspenseListPropsBuilder_Optional<T> for (T,) {
+    fn into_value<F: FnOnce() -> T>(self, _: F) -> T {
+        self.0
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__tail, __children> SuspenseListPropsBuilder<((), __tail, __children)> {
+    pub fn reveal_order(
+        self,
+        reveal_order: RevealOrder,
+    ) -> SuspenseListPropsBuilder<((RevealOrder,), __tail, __children)> {
+        let reveal_order = (reveal_order,);
+        let (_, tail, children) = self.fields;
+        SuspenseListPropsBuilder {
+            fields: (reveal_order, tail, children),
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub enum SuspenseListPropsBuilder_Error_Repeated_field_reveal_order {}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__tail, __children> SuspenseListPropsBuilder<((RevealOrder,), __tail, __children)> {
+    #[deprecated(note = "Repeated field reveal_order")]
+    pub fn reveal_order(
+        self,
+        _: SuspenseListPropsBuilder_Error_Repeated_field_reveal_order,
+    ) -> SuspenseListPropsBuilder<((RevealOrder,), __tail, __children)> {
+        self
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__reveal_order, __children> SuspenseListPropsBuilder<(__reveal_order, (), __children)> {
+    pub fn tail(
+        self,
+        tail: SuspenseListTail,
+    ) -> SuspenseListPropsBuilder<(__reveal_order, (SuspenseListTail,), __children)> {
+        let tail = (tail,);
+        let (reveal_order, _, children) = self.fields;
+        SuspenseListPropsBuilder {
+            fields: (reveal_order, tail, children),
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub enum SuspenseListPropsBuilder_Error_Repeated_field_tail {}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__reveal_order, __children>
+    SuspenseListPropsBuilder<(__reveal_order, (SuspenseListTail,), __children)>
+{
+    #[deprecated(note = "Repeated field tail")]
+    pub fn tail(
+        self,
+        _: SuspenseListPropsBuilder_Error_Repeated_field_tail,
+    ) -> SuspenseListPropsBuilder<(__reveal_order, (SuspenseListTail,), __children)> {
+        self
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__reveal_order, __tail> SuspenseListPropsBuilder<(__reveal_order, __tail, ())> {
+    pub fn children(
+        self,
+        children: Element,
+    ) -> SuspenseListPropsBuilder<(__reveal_order, __tail, (Element,))> {
+        let children = (children,);
+        let (reveal_order, tail, _) = self.fields;
+        SuspenseListPropsBuilder {
+            fields: (reveal_order, tail, children),
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub enum SuspenseListPropsBuilder_Error_Repeated_field_children {}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__reveal_order, __tail> SuspenseListPropsBuilder<(__reveal_order, __tail, (Element,))> {
+    #[deprecated(note = "Repeated field children")]
+    pub fn children(
+        self,
+        _: SuspenseListPropsBuilder_Error_Repeated_field_children,
+    ) -> SuspenseListPropsBuilder<(__reveal_order, __tail, (Element,))> {
+        self
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<
+        __reveal_order: SuspenseListPropsBuilder_Optional<RevealOrder>,
+        __tail: SuspenseListPropsBuilder_Optional<SuspenseListTail>,
+        __children: SuspenseListPropsBuilder_Optional<Element>,
+    > SuspenseListPropsBuilder<(__reveal_order, __tail, __children)>
+{
+    pub fn build(self) -> SuspenseListProps {
+        let (reveal_order, tail, children) = self.fields;
+        let reveal_order =
+            SuspenseListPropsBuilder_Optional::into_value(reveal_order, RevealOrder::default);
+        let tail = SuspenseListPropsBuilder_Optional::into_value(tail, SuspenseListTail::default);
+        let children = SuspenseListPropsBuilder_Optional::into_value(children, VNode::empty);
+        SuspenseListProps {
+            reveal_order,
+            tail,
+            children,
+        }
+    }
+}
+
+/// Suspense lists control the order the [`SuspenseBoundary`]s inside them are revealed in.
+///
+/// Without a suspense list, every suspense boundary shows its children as soon as they are ready, so a feed of items that
+/// load at different speeds pops in out of order. A suspense list holds boundaries back until the boundaries before them
+/// (with [`RevealOrder::Forwards`]), after them (with [`RevealOrder::Backwards`]) or all boundaries in the list (with
+/// [`RevealOrder::Together`]) are ready. The `tail` controls which fallbacks are shown for boundaries that are not
+/// revealed yet.
+///
+/// The list coordinates the closest suspense boundaries inside it. Boundaries nested inside another boundary in the list
+/// are revealed independently. When the list is rendered on the server, the boundaries are streamed to the client in
+/// the order they are revealed.
+///
+/// # Example
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # #[component]
+/// # fn Post(id: usize) -> Element { rsx! { "Post {id}" } }
+/// fn Feed() -> Element {
+///     rsx! {
+///         SuspenseList {
+///             reveal_order: RevealOrder::Forwards,
+///             tail: SuspenseListTail::Collapsed,
+///             for id in 0..10 {
+///                 SuspenseBoundary {
+///                     key: "{id}",
+///                     fallback: |_| rsx! { "Loading post..." },
+///                     Post { id }
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[allow(non_upper_case_globals, non_snake_case)]
+pub fn SuspenseList(props: SuspenseListProps) -> Element {
+    let list = use_hook(|| {
+        provide_context(SuspenseListContext::new(
+            current_scope_id().expect("to be in a dioxus runtime"),
+            props.reveal_order,
+            props.tail,
+        ))
+    });
+    list.set_options(props.reveal_order, props.tail);
+    props.children
+}
@@ -24,7 +24,9 @@
 //! Generally suspense placeholders should not be stateful because they are driven from the server. If they are stateful and the client renders something different, hydration will fail.
 
 mod component;
+mod list;
 pub use component::*;
+pub use list::*;
 
 use crate::innerlude::*;
 use std::{
@@ -39,6 +41,7 @@ pub struct SuspendedFuture {
     origin: ScopeId,
     task: Task,
     pub(crate) placeholder: VNode,
+    pub(crate) transition: bool,
 }
 
 impl SuspendedFuture {
@@ -48,6 +51,7 @@ impl SuspendedFuture {
             task,
             origin: current_scope_id().expect("to be in a dioxus runtime"),
             placeholder: VNode::placeholder(),
+            transition: false,
         }
     }
 
@@ -71,12 +75,19 @@ impl SuspendedFuture {
         self.task
     }
 
+    /// Check if the future suspended while rendering a transition. Futures that suspend during a transition keep showing the previous UI
+    /// instead of the suspense fallback.
+    pub fn in_transition(&self) -> bool {
+        self.transition
+    }
+
     /// Clone the future while retaining the mounted information of the future
     pub(crate) fn clone_mounted(&self) -> Self {
         Self {
             task: self.task,
             origin: self.origin,
             placeholder: self.placeholder.clone_mounted(),
+            transition: self.transition,
         }
     }
 }
@@ -100,6 +111,10 @@ impl SuspenseContext {
             inner: Rc::new(SuspenseBoundaryInner {
                 suspended_tasks: RefCell::new(vec![]),
                 id: Cell::new(ScopeId::ROOT),
+                list: RefCell::new(None),
+                revealed: Cell::new(false),
+                held: Cell::new(false),
+                fallback_hidden: Cell::new(false),
             }),
         }
     }
@@ -109,15 +124,63 @@ impl SuspenseContext {
         self.inner.id.set(scope);
     }
 
+    /// The scope of the suspense boundary
+    pub(crate) fn scope_id(&self) -> ScopeId {
+        self.inner.id.get()
+    }
+
     /// Check if there are any suspended tasks
     pub fn suspended(&self) -> bool {
         !self.inner.suspended_tasks.borrow().is_empty()
     }
 
+    /// Check if the suspense boundary should show its fallback. Futures that suspended during a transition don't cause the boundary to show
+    /// its fallback. Boundaries inside a [`SuspenseList`] also show their fallback while the list holds them back.
+    pub fn showing_fallback(&self) -> bool {
+        self.inner.loading() || self.held_by_list()
+    }
+
+    /// Check if a [`SuspenseList`] is holding this boundary back until the boundaries before it are revealed
+    pub fn held_by_list(&self) -> bool {
+        let list = self.inner.list.borrow();
+        list.as_ref().is_some_and(|list| list.holds(&self.inner))
+    }
+
+    /// Check if the fallback of this boundary is hidden by the tail of the [`SuspenseList`] it is in
+    pub(crate) fn fallback_hidden(&self) -> bool {
+        let list = self.inner.list.borrow();
+        list.as_ref()
+            .is_some_and(|list| list.hides_fallback(&self.inner))
+    }
+
+    /// Get the [`SuspenseList`] this boundary is coordinated by, if any
+    pub(crate) fn list(&self) -> Option<SuspenseListContext> {
+        self.inner.list.borrow().clone()
+    }
+
+    /// Mark the boundary as revealed after its children are rendered. A [`SuspenseList`] never holds back a boundary that
+    /// was already revealed.
+    pub(crate) fn mark_revealed(&self) {
+        if !self.inner.revealed.replace(true) {
+            if let Some(list) = self.list() {
+                list.invalidate();
+            }
+        }
+    }
+
+    /// Add this boundary to a [`SuspenseList`]
+    pub(crate) fn join_list(&self, list: SuspenseListContext) {
+        list.register(&self.inner);
+        *self.inner.list.borrow_mut() = Some(list);
+    }
+
     /// Add a suspended task
     pub(crate) fn add_suspended_task(&self, task: SuspendedFuture) {
         self.inner.suspended_tasks.borrow_mut().push(task);
         self.inner.id.get().needs_update();
+        if let Some(list) = self.list() {
+            list.update();
+        }
     }
 
     /// Remove a suspended task
@@ -127,6 +190,9 @@ impl SuspenseContext {
             .borrow_mut()
             .retain(|t| t.task != task);
         self.inner.id.get().needs_update();
+        if let Some(list) = self.list() {
+            list.update();
+        }
     }
 
     /// Get all suspended tasks
@@ -152,6 +218,33 @@ impl SuspenseContext {
 pub struct SuspenseBoundaryInner {
     suspended_tasks: RefCell<Vec<SuspendedFuture>>,
     id: Cell<ScopeId>,
+    /// The suspense list that decides when this boundary is revealed
+    list: RefCell<Option<SuspenseListContext>>,
+    /// If the suspense list has revealed this boundary. Once a boundary is revealed, the list never holds it back again
+    revealed: Cell<bool>,
+    /// If the suspense list holds this boundary back. The list keeps this up to date as its boundaries change
+    held: Cell<bool>,
+    /// If the tail of the suspense list hides the fallback of this boundary
+    fallback_hidden: Cell<bool>,
+}
+
+impl Drop for SuspenseBoundaryInner {
+    fn drop(&mut self) {
+        // Removing a boundary that is loading may reveal the boundaries after it
+        if let Some(list) = self.list.get_mut() {
+            list.invalidate();
+        }
+    }
+}
+
+impl SuspenseBoundaryInner {
+    /// Check if this boundary is waiting for any futures that didn't suspend during a transition
+    fn loading(&self) -> bool {
+        self.suspended_tasks
+            .borrow()
+            .iter()
+            .any(|task| !task.transition)
+    }
 }
 
 /// Provides context methods to [`Result<T, RenderError>`] to show loading indicators for suspended results
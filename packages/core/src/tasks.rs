@@ -1,6 +1,9 @@
 use crate::innerlude::Effect;
 use crate::innerlude::ScopeOrder;
-use crate::innerlude::{remove_future, spawn, Runtime};
+use crate::innerlude::{
+    current_priority, current_transition, current_update_source, remove_future, spawn, Priority,
+    Runtime, TransitionId, UpdateSource,
+};
 use crate::scope_context::ScopeStatus;
 use crate::scope_context::SuspenseLocation;
 use crate::ScopeId;
@@ -157,6 +160,11 @@ impl Runtime {
         task: impl Future<Output = ()> + 'static,
         ty: TaskType,
     ) -> Task {
+        // Tasks spawned by offscreen scopes start paused until the scope is shown again
+        let offscreen = self
+            .get_state(scope)
+            .is_some_and(|scope| scope.offscreen.get());
+
         // Insert the task, temporarily holding a borrow on the tasks map
         let (task, task_id) = {
             let mut tasks = self.tasks.borrow_mut();
@@ -168,7 +176,8 @@ impl Runtime {
 
                 let new_task = Rc::new(LocalTask {
                     scope,
-                    active: Cell::new(true),
+                    active: Cell::new(!offscreen),
+                    offscreen: Cell::new(offscreen),
                     parent: self.current_task(),
                     task: RefCell::new(Box::pin(task)),
                     waker: futures_util::task::waker(Arc::new(LocalTaskHandle {
@@ -297,8 +306,15 @@ impl Runtime {
 
         if let Some(task) = &task {
             // Remove the task from suspense
-            if let TaskType::Suspended { boundary } = &*task.ty.borrow() {
+            if let TaskType::Suspended {
+                boundary,
+                transition,
+            } = &*task.ty.borrow()
+            {
                 self.suspended_tasks.set(self.suspended_tasks.get() - 1);
+                if *transition {
+                    self.remove_transition_suspension(id);
+                }
                 if let SuspenseLocation::UnderSuspense(boundary) = boundary {
                     boundary.remove_suspended_task(id);
                 }
@@ -331,22 +347,37 @@ pub(crate) struct LocalTask {
     task: RefCell<Pin<Box<dyn Future<Output = ()> + 'static>>>,
     waker: Waker,
     ty: RefCell<TaskType>,
-    active: Cell<bool>,
+    pub(crate) active: Cell<bool>,
+    // If the task was paused because its scope was moved offscreen
+    pub(crate) offscreen: Cell<bool>,
 }
 
 impl LocalTask {
     /// Suspend the task, returns true if the task was already suspended
-    pub(crate) fn suspend(&self, boundary: SuspenseLocation) -> bool {
+    pub(crate) fn suspend(&self, boundary: SuspenseLocation, transition: bool) -> bool {
+        let mut ty = self.ty.borrow_mut();
+        // If the task was already suspended, it keeps the transition state it was first suspended with
+        let (already_suspended, transition) = match &*ty {
+            TaskType::Suspended { transition, .. } => (true, *transition),
+            _ => (false, transition),
+        };
         // Make this a suspended task so it runs during suspense
-        let old_type = self.ty.replace(TaskType::Suspended { boundary });
-        matches!(old_type, TaskType::Suspended { .. })
+        *ty = TaskType::Suspended {
+            boundary,
+            transition,
+        };
+        already_suspended
     }
 }
 
 #[derive(Clone)]
 enum TaskType {
     ClientOnly,
-    Suspended { boundary: SuspenseLocation },
+    Suspended {
+        boundary: SuspenseLocation,
+        // If the task suspended while rendering a transition
+        transition: bool,
+    },
     Isomorphic,
 }
 
@@ -364,6 +395,12 @@ pub(crate) enum SchedulerMsg {
     /// Immediate updates from Components that mark them as dirty
     Immediate(ScopeId),
 
+    /// Background updates from Components that mark them as dirty, and the transition that marked them dirty if there is one
+    Background(ScopeId, Option<TransitionId>),
+
+    /// The update that marked a component as dirty. This is only sent while a profiler is recording
+    UpdateTriggered(ScopeId, UpdateSource),
+
     /// A task has woken and needs to be progressed
     TaskNotified(slotmap::DefaultKey),
 
@@ -371,6 +408,24 @@ pub(crate) enum SchedulerMsg {
     EffectQueued,
 }
 
+impl SchedulerMsg {
+    /// Send a message that marks a scope as dirty with the current priority. If a profiler is recording, this also sends the update
+    /// that marked the scope dirty
+    pub(crate) fn send_mark_dirty(
+        sender: &futures_channel::mpsc::UnboundedSender<SchedulerMsg>,
+        id: ScopeId,
+    ) -> Result<(), futures_channel::mpsc::TrySendError<SchedulerMsg>> {
+        if let Some(source) = current_update_source() {
+            _ = sender.unbounded_send(SchedulerMsg::UpdateTriggered(id, source));
+        }
+        let msg = match current_priority() {
+            Priority::Immediate => SchedulerMsg::Immediate(id),
+            Priority::Background => SchedulerMsg::Background(id, current_transition()),
+        };
+        sender.unbounded_send(msg)
+    }
+}
+
 struct LocalTaskHandle {
     id: slotmap::DefaultKey,
     tx: futures_channel::mpsc::UnboundedSender<SchedulerMsg>,
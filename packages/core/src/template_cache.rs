@@ -0,0 +1,172 @@
+use crate::innerlude::*;
+
+/// A serializable collection of [`Template`]s that a renderer loads before the first render.
+///
+/// Every `rsx!` call creates a static template that is normally sent to the renderer the first time it is used. The
+/// templates can be extracted from the source code at build time instead, loaded into the renderer ahead of time, and
+/// passed to [`VirtualDom::preload_templates`] so the first render only sends the dynamic parts of each template.
+///
+/// Templates extracted from the source code are matched with the templates compiled into the app by the location of
+/// the `rsx!` call that created them, just like hot reloaded templates. Cached templates that no longer match the
+/// compiled template at that location are ignored and the compiled template is sent to the renderer as usual.
+///
+/// `dx build` writes the templates of the crate to `templates.json` in the output directory. Templates borrow their
+/// contents for the rest of the program, so caches read at runtime are loaded with [`TemplateCache::load`].
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_core::TemplateCache;
+/// fn app() -> Element {
+///     rsx! { div { "hello world" } }
+/// }
+///
+/// // Record the templates the app uses. These would usually be extracted at build time
+/// let mut dom = VirtualDom::new(app);
+/// let cache: TemplateCache = dom
+///     .rebuild_to_vec()
+///     .templates
+///     .into_iter()
+///     .collect();
+///
+/// // A renderer that already loaded the templates doesn't receive them again
+/// let mut dom = VirtualDom::new(app);
+/// dom.preload_templates(&cache);
+/// assert!(dom.rebuild_to_vec().templates.is_empty());
+/// ```
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(bound(deserialize = "'de: 'static")))]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TemplateCache {
+    templates: Vec<Template>,
+}
+
+impl TemplateCache {
+    /// Create an empty template cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a template to the cache. If a template with the same name is already in the cache, it is replaced.
+    pub fn insert(&mut self, template: Template) {
+        match self
+            .templates
+            .iter_mut()
+            .find(|cached| cached.name == template.name)
+        {
+            Some(cached) => *cached = template,
+            None => self.templates.push(template),
+        }
+    }
+
+    /// Get a template from the cache by name
+    pub fn get(&self, name: &str) -> Option<&Template> {
+        self.templates.iter().find(|template| template.name == name)
+    }
+
+    /// Iterate over the templates in the cache in the order they were inserted
+    pub fn iter(&self) -> impl Iterator<Item = &Template> {
+        self.templates.iter()
+    }
+
+    /// The number of templates in the cache
+    pub fn len(&self) -> usize {
+        self.templates.len()
+    }
+
+    /// Check if the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.templates.is_empty()
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl TemplateCache {
+    /// Load a serialized cache that was read at runtime, like the `templates.json` file `dx build` writes.
+    ///
+    /// The data is leaked so the templates can borrow from it for the rest of the program. `parse` deserializes the
+    /// cache from the leaked data with any serde format.
+    ///
+    /// ```rust, ignore
+    /// let data = std::fs::read("dist/templates.json")?;
+    /// let cache = TemplateCache::load(data, serde_json::from_slice)?;
+    /// ```
+    pub fn load<E>(
+        data: impl Into<Box<[u8]>>,
+        parse: impl FnOnce(&'static [u8]) -> std::result::Result<Self, E>,
+    ) -> std::result::Result<Self, E> {
+        parse(Box::leak(data.into()))
+    }
+}
+
+impl FromIterator<Template> for TemplateCache {
+    fn from_iter<T: IntoIterator<Item = Template>>(iter: T) -> Self {
+        let mut cache = Self::new();
+        cache.extend(iter);
+        cache
+    }
+}
+
+impl Extend<Template> for TemplateCache {
+    fn extend<T: IntoIterator<Item = Template>>(&mut self, iter: T) {
+        for template in iter {
+            self.insert(template);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a TemplateCache {
+    type Item = &'a Template;
+    type IntoIter = std::slice::Iter<'a, Template>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.templates.iter()
+    }
+}
+
+/// The location of the `rsx!` call that created a template. Template names are the location followed by an index that
+/// is only known when the template is compiled.
+pub(crate) fn template_location(name: &'static str) -> &'static str {
+    name.rsplit_once(':').map_or(name, |(location, _)| location)
+}
+
+impl VirtualDom {
+    /// Tell the virtual dom that the renderer already loaded the templates in a [`TemplateCache`].
+    ///
+    /// Templates that match a preloaded template are not sent to the renderer when they are first used. The renderer
+    /// must have registered every template in the cache under its cached name before the first render.
+    pub fn preload_templates(&mut self, cache: &TemplateCache) {
+        for template in cache {
+            self.preloaded_templates
+                .entry(template_location(template.name))
+                .or_default()
+                .push(*template);
+        }
+    }
+
+    /// Check if the renderer already loaded a template. If it was loaded under a different name, remember the name the
+    /// renderer knows it by.
+    pub(crate) fn take_preloaded_template(&mut self, template: &Template) -> bool {
+        let Some(preloaded) = self
+            .preloaded_templates
+            .get(template_location(template.name))
+        else {
+            return false;
+        };
+        let Some(preloaded) = preloaded.iter().find(|preloaded| {
+            preloaded.roots == template.roots
+                && preloaded.node_paths == template.node_paths
+                && preloaded.attr_paths == template.attr_paths
+        }) else {
+            return false;
+        };
+        if preloaded.name != template.name {
+            self.template_aliases.insert(template.name, preloaded.name);
+        }
+        true
+    }
+
+    /// Get the name the renderer knows a template by
+    pub(crate) fn renderer_template_name(&self, name: &'static str) -> &'static str {
+        self.template_aliases.get(name).copied().unwrap_or(name)
+    }
+}
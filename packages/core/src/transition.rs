@@ -0,0 +1,215 @@
+//! Transitions let you mark updates as low priority so they don't block more urgent updates like typing in an input.
+//!
+//! Updates made inside [`start_transition`] are queued with [`Priority::Background`]. While a transition is rendering, components
+//! that suspend keep showing their previous UI instead of the fallback of the nearest suspense boundary (as long as that boundary was
+//! already showing its children). Once the suspended futures resolve, the new UI is swapped in.
+
+use crate::innerlude::*;
+use rustc_hash::FxHashSet;
+use std::cell::Cell;
+
+/// Run a closure as a transition. Any components marked dirty inside of the closure will rerender with [`Priority::Background`]
+/// and components that suspend while rendering the transition will keep showing their previous UI.
+///
+/// If you need to know when the transition has finished rendering, use [`start_transition_then`] or the `use_transition` hook.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_core::prelude::*;
+/// fn app() -> Element {
+///     let mut tab = use_signal(|| "home");
+///
+///     rsx! {
+///         button {
+///             // Switching tabs may suspend while the new tab loads. Keep showing the old tab until it is ready
+///             onclick: move |_| start_transition(|| tab.set("posts")),
+///             "Posts"
+///         }
+///         "{tab}"
+///     }
+/// }
+/// ```
+pub fn start_transition<O>(f: impl FnOnce() -> O) -> O {
+    with_transition(TransitionId::next(), f)
+}
+
+/// Run a closure as a transition like [`start_transition`] and call `finished` once this transition has finished rendering and every future
+/// that suspended while rendering it has resolved. Unlike [`after_transitions`], this doesn't wait for any other transitions.
+///
+/// If the closure doesn't mark any components as dirty, `finished` will run the next time the virtual dom processes events.
+pub fn start_transition_then<O>(f: impl FnOnce() -> O, finished: impl FnOnce() + 'static) -> O {
+    let id = TransitionId::next();
+    Runtime::with(|rt| rt.after_transition(id, finished)).expect("to be in a dioxus runtime");
+    with_transition(id, f)
+}
+
+/// Run a callback once all pending transitions have finished rendering and every future that suspended during a transition has resolved.
+///
+/// If there are no pending transitions, the callback will run the next time the virtual dom processes events.
+pub fn after_transitions(f: impl FnOnce() + 'static) {
+    Runtime::with(|rt| rt.after_transitions(f)).expect("to be in a dioxus runtime")
+}
+
+/// A unique identifier for a transition started with [`start_transition`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct TransitionId(u64);
+
+impl TransitionId {
+    fn next() -> Self {
+        NEXT_TRANSITION.with(|next| {
+            let id = next.get();
+            next.set(id + 1);
+            Self(id)
+        })
+    }
+}
+
+thread_local! {
+    static NEXT_TRANSITION: Cell<u64> = const { Cell::new(0) };
+    static CURRENT_TRANSITION: Cell<Option<TransitionId>> = const { Cell::new(None) };
+}
+
+/// Get the transition that components marked dirty on this thread belong to
+pub(crate) fn current_transition() -> Option<TransitionId> {
+    CURRENT_TRANSITION.with(|transition| transition.get())
+}
+
+fn with_transition<O>(id: TransitionId, f: impl FnOnce() -> O) -> O {
+    struct ResetTransition(Option<TransitionId>);
+
+    impl Drop for ResetTransition {
+        fn drop(&mut self) {
+            CURRENT_TRANSITION.with(|transition| transition.set(self.0));
+        }
+    }
+
+    let _reset = ResetTransition(CURRENT_TRANSITION.with(|current| current.replace(Some(id))));
+    with_priority(Priority::Background, f)
+}
+
+/// The work a transition is waiting on before it is finished
+#[derive(Default)]
+pub(crate) struct TransitionState {
+    // Scopes marked dirty by the transition that haven't rerun yet
+    dirty_scopes: FxHashSet<ScopeId>,
+    // Tasks that suspended while rendering the transition
+    suspended_tasks: FxHashSet<Task>,
+    // Callbacks that run once the transition has finished
+    callbacks: Vec<Box<dyn FnOnce()>>,
+}
+
+impl TransitionState {
+    fn is_finished(&self) -> bool {
+        self.dirty_scopes.is_empty() && self.suspended_tasks.is_empty()
+    }
+}
+
+impl Runtime {
+    /// Run a callback once all pending transitions have finished rendering
+    pub fn after_transitions(&self, f: impl FnOnce() + 'static) {
+        self.transition_callbacks.borrow_mut().push(Box::new(f));
+        // Wake up the scheduler in case there is no other work queued
+        _ = self.sender.unbounded_send(SchedulerMsg::EffectQueued);
+    }
+
+    /// Run a callback once a specific transition has finished rendering
+    pub(crate) fn after_transition(&self, id: TransitionId, f: impl FnOnce() + 'static) {
+        self.transitions
+            .borrow_mut()
+            .entry(id)
+            .or_default()
+            .callbacks
+            .push(Box::new(f));
+        // Wake up the scheduler in case the transition doesn't mark anything dirty
+        _ = self.sender.unbounded_send(SchedulerMsg::EffectQueued);
+    }
+
+    /// Check if the virtual dom is currently rendering a transition
+    pub(crate) fn rendering_transition(&self) -> bool {
+        self.rendering_transition.get()
+    }
+
+    /// Record that a transition is waiting for a scope to rerun
+    pub(crate) fn add_transition_scope(&self, id: TransitionId, scope: ScopeId) {
+        self.transitions
+            .borrow_mut()
+            .entry(id)
+            .or_default()
+            .dirty_scopes
+            .insert(scope);
+    }
+
+    /// Start rendering a background scope. Any tasks that suspend before [`Self::finish_rendering_transition`] is called belong to the
+    /// transitions that were waiting for this scope
+    pub(crate) fn start_rendering_transition(&self, scope: ScopeId) {
+        self.rendering_transition.set(true);
+        let transitions = self.transitions.borrow();
+        let waiting = transitions
+            .iter()
+            .filter(|(_, transition)| transition.dirty_scopes.contains(&scope))
+            .map(|(id, _)| *id);
+        self.rendering_transitions.borrow_mut().extend(waiting);
+    }
+
+    /// Finish rendering a background scope
+    pub(crate) fn finish_rendering_transition(&self) {
+        self.rendering_transition.set(false);
+        self.rendering_transitions.borrow_mut().clear();
+    }
+
+    /// Stop waiting for a scope after it has been rerun or dropped
+    pub(crate) fn remove_transition_scope(&self, scope: ScopeId) {
+        for transition in self.transitions.borrow_mut().values_mut() {
+            transition.dirty_scopes.remove(&scope);
+        }
+    }
+
+    /// Record that a task suspended while rendering the current transitions
+    pub(crate) fn add_transition_suspension(&self, task: Task) {
+        self.transition_suspensions
+            .set(self.transition_suspensions.get() + 1);
+        let mut transitions = self.transitions.borrow_mut();
+        for id in self.rendering_transitions.borrow().iter() {
+            if let Some(transition) = transitions.get_mut(id) {
+                transition.suspended_tasks.insert(task);
+            }
+        }
+    }
+
+    /// Stop waiting for a task that suspended during a transition after it resolves or is dropped
+    pub(crate) fn remove_transition_suspension(&self, task: Task) {
+        self.transition_suspensions
+            .set(self.transition_suspensions.get() - 1);
+        for transition in self.transitions.borrow_mut().values_mut() {
+            transition.suspended_tasks.remove(&task);
+        }
+    }
+
+    /// Run any callbacks that are waiting for transitions to finish. This should only be called once all dirty scopes have been rerun.
+    /// Returns true if any callbacks were run.
+    pub(crate) fn finish_transitions(&self) -> bool {
+        let mut callbacks = Vec::new();
+        {
+            let mut transitions = self.transitions.borrow_mut();
+            transitions.retain(|_, transition| {
+                if transition.is_finished() {
+                    callbacks.append(&mut transition.callbacks);
+                    false
+                } else {
+                    true
+                }
+            });
+
+            // Callbacks waiting on every transition run once nothing is pending
+            if transitions.is_empty() && self.transition_suspensions.get() == 0 {
+                callbacks.append(&mut self.transition_callbacks.borrow_mut());
+            }
+        }
+
+        let ran = !callbacks.is_empty();
+        for callback in callbacks {
+            callback();
+        }
+        ran
+    }
+}
@@ -2,14 +2,14 @@
 //!
 //! This module provides the primary mechanics to create a hook-based, concurrent VDOM for Rust.
 
-use crate::innerlude::{SuspenseBoundaryProps, Work};
+use crate::innerlude::{SuspenseBoundaryProps, TransitionId, UpdateSource, Work};
 use crate::properties::RootProps;
 use crate::root_wrapper::RootScopeWrapper;
 use crate::{
     arena::ElementId,
     innerlude::{
-        ElementRef, NoOpMutations, SchedulerMsg, ScopeOrder, ScopeState, VNodeMount, VProps,
-        WriteMutations,
+        CountMutations, ElementRef, NoOpMutations, Priority, Profiler, RenderStatus, SchedulerMsg,
+        ScopeOrder, ScopeState, VNodeMount, VProps, WriteMutations,
     },
     nodes::{Template, TemplateId},
     runtime::{Runtime, RuntimeGuard},
@@ -207,6 +207,12 @@ pub struct VirtualDom {
 
     pub(crate) dirty_scopes: BTreeSet<ScopeOrder>,
 
+    // Scopes that were marked dirty with a background priority. These are only rerun once all immediate work is done
+    pub(crate) background_scopes: BTreeSet<ScopeOrder>,
+
+    // The profiler that records renders if profiling is enabled
+    pub(crate) profiler: Option<Profiler>,
+
     // Maps a template path to a map of byte indexes to templates
     // if hot reload is enabled, we need to keep track of template overrides
     #[cfg(debug_assertions)]
@@ -218,6 +224,12 @@ pub struct VirtualDom {
     // Templates changes that are queued for the next render
     pub(crate) queued_templates: Vec<Template>,
 
+    // Templates the renderer loaded from a template cache before the first render, keyed by their location
+    pub(crate) preloaded_templates: rustc_hash::FxHashMap<&'static str, Vec<Template>>,
+
+    // Maps the name of a compiled template to the name of the preloaded template the renderer knows it by
+    pub(crate) template_aliases: rustc_hash::FxHashMap<&'static str, &'static str>,
+
     // The element ids that are used in the renderer
     // These mark a specific place in a whole rsx block
     pub(crate) elements: Slab<Option<ElementRef>>,
@@ -227,6 +239,10 @@ pub struct VirtualDom {
     // Each mount is associated with a whole rsx block. [`VirtualDom::elements`] link to a specific node in the block
     pub(crate) mounts: Slab<VNodeMount>,
 
+    // The roots of portal children that were removed along with one of their ancestors without a mutation writer
+    // Portal children don't live under their ancestors in the renderer, so they are removed during the next diff
+    pub(crate) queued_portal_removals: Vec<ElementId>,
+
     pub(crate) runtime: Rc<Runtime>,
 
     rx: futures_channel::mpsc::UnboundedReceiver<SchedulerMsg>,
@@ -330,10 +346,15 @@ impl VirtualDom {
             runtime: Runtime::new(tx),
             scopes: Default::default(),
             dirty_scopes: Default::default(),
+            background_scopes: Default::default(),
+            profiler: None,
             templates: Default::default(),
             queued_templates: Default::default(),
+            preloaded_templates: Default::default(),
+            template_aliases: Default::default(),
             elements: Default::default(),
             mounts: Default::default(),
+            queued_portal_removals: Default::default(),
         };
 
         let root = VProps::new(
@@ -395,14 +416,63 @@ impl VirtualDom {
     ///
     /// Whenever the Runtime "works", it will re-render this scope
     pub fn mark_dirty(&mut self, id: ScopeId) {
+        self.mark_dirty_with_priority(id, Priority::Immediate);
+    }
+
+    /// Mark a scope as dirty with a [`Priority`]. Background scopes are only rerun once all immediate work is done.
+    pub fn mark_dirty_with_priority(&mut self, id: ScopeId, priority: Priority) {
         let Some(scope) = self.runtime.get_state(id) else {
             return;
         };
 
-        tracing::event!(tracing::Level::TRACE, "Marking scope {:?} as dirty", id);
+        tracing::event!(
+            tracing::Level::TRACE,
+            "Marking scope {:?} as dirty with {:?} priority",
+            id,
+            priority
+        );
         let order = ScopeOrder::new(scope.height(), id);
         drop(scope);
-        self.queue_scope(order);
+        self.queue_scope(order, priority);
+
+        if let Some(profiler) = &mut self.profiler {
+            profiler.mark_dirty(id);
+        }
+    }
+
+    /// Mark a scope as a background update. If a transition marked the scope dirty, the transition waits for the scope to rerun
+    fn mark_dirty_in_transition(&mut self, id: ScopeId, transition: Option<TransitionId>) {
+        if self.runtime.get_state(id).is_none() {
+            return;
+        }
+        if let Some(transition) = transition {
+            self.runtime.add_transition_scope(transition, id);
+        }
+        self.mark_dirty_with_priority(id, Priority::Background);
+    }
+
+    /// Start recording how long each component takes to render and diff. Any profiler that was already recording is replaced.
+    ///
+    /// Profiling adds some overhead to every render, so it should only be enabled while you are measuring performance.
+    pub fn start_profiling(&mut self, profiler: Profiler) {
+        self.profiler = Some(profiler);
+    }
+
+    /// Stop recording renders and return the profiler with everything it recorded
+    pub fn stop_profiling(&mut self) -> Option<Profiler> {
+        self.profiler.take()
+    }
+
+    /// Get the profiler that is currently recording renders
+    pub fn profiler(&self) -> Option<&Profiler> {
+        self.profiler.as_ref()
+    }
+
+    /// Record the update that marked a scope dirty
+    fn update_triggered(&mut self, id: ScopeId, source: UpdateSource) {
+        if let Some(profiler) = &mut self.profiler {
+            profiler.update_triggered(id, source);
+        }
     }
 
     /// Mark a task as dirty
@@ -496,6 +566,10 @@ impl VirtualDom {
     async fn wait_for_event(&mut self) {
         match self.rx.next().await.expect("channel should never close") {
             SchedulerMsg::Immediate(id) => self.mark_dirty(id),
+            SchedulerMsg::Background(id, transition) => {
+                self.mark_dirty_in_transition(id, transition)
+            }
+            SchedulerMsg::UpdateTriggered(id, source) => self.update_triggered(id, source),
             SchedulerMsg::TaskNotified(id) => {
                 // Instead of running the task immediately, we insert it into the runtime's task queue.
                 // The task may be marked dirty at the same time as the scope that owns the task is dropped.
@@ -511,6 +585,10 @@ impl VirtualDom {
         while let Ok(Some(msg)) = self.rx.try_next() {
             match msg {
                 SchedulerMsg::Immediate(id) => self.mark_dirty(id),
+                SchedulerMsg::Background(id, transition) => {
+                    self.mark_dirty_in_transition(id, transition)
+                }
+                SchedulerMsg::UpdateTriggered(id, source) => self.update_triggered(id, source),
                 SchedulerMsg::TaskNotified(task) => self.mark_task_dirty(Task::from_id(task)),
                 SchedulerMsg::EffectQueued => {}
             }
@@ -563,6 +641,11 @@ impl VirtualDom {
                 }
             }
         }
+
+        // Finally, all transitions have been rendered so we can let anything waiting on them know they are finished
+        if self.runtime.finish_transitions() {
+            self.queue_events();
+        }
     }
 
     /// Replace a template at runtime. This will re-render all components that use this template.
@@ -656,6 +739,18 @@ impl VirtualDom {
     /// ```
     #[instrument(skip(self, to), level = "trace", name = "VirtualDom::rebuild")]
     pub fn rebuild(&mut self, to: &mut impl WriteMutations) {
+        // If we are profiling, count the mutations that are written
+        match self
+            .profiler
+            .as_ref()
+            .map(|profiler| profiler.mutation_counter())
+        {
+            Some(count) => self.rebuild_to(&mut CountMutations::new(to, count)),
+            None => self.rebuild_to(to),
+        }
+    }
+
+    fn rebuild_to(&mut self, to: &mut impl WriteMutations) {
         self.flush_templates(to);
         let _runtime = RuntimeGuard::new(self.runtime.clone());
         let new_nodes = self.run_scope(ScopeId::ROOT);
@@ -664,14 +759,115 @@ impl VirtualDom {
 
         // Rebuilding implies we append the created elements to the root
         let m = self.create_scope(Some(to), ScopeId::ROOT, new_nodes, None);
+        self.order_suspense_lists();
 
         to.append_children(ElementId(0), m);
     }
 
+    /// Create the nodes a scope last rendered again from scratch without rerunning the scope or diffing against the old nodes.
+    ///
+    /// No mutations are written to remove the nodes that were mounted before and the state of child components is kept.
+    /// Renderers use this to recover from hydration mismatches by throwing away the nodes they hydrated and swapping in
+    /// the new nodes instead. The new nodes are left on the stack and the number of nodes created is returned.
+    pub fn remount_scope<M: WriteMutations>(&mut self, scope: ScopeId, to: &mut M) -> usize {
+        self.flush_templates(to);
+        let _runtime = RuntimeGuard::new(self.runtime.clone());
+        let Some(node) = self
+            .scopes
+            .get(scope.0)
+            .and_then(|scope| scope.last_rendered_node.clone())
+        else {
+            return 0;
+        };
+        let parent = node
+            .mount
+            .get()
+            .as_usize()
+            .and_then(|mount| self.mounts.get(mount))
+            .and_then(|mount| mount.parent);
+
+        // Forget about the old nodes and reclaim their ids without touching the renderer
+        node.remove_node_inner(self, Some(&mut NoOpMutations), false, None);
+
+        self.create_scope(Some(to), scope, node, parent)
+    }
+
     /// Render whatever the VirtualDom has ready as fast as possible without requiring an executor to progress
     /// suspended subtrees.
     #[instrument(skip(self, to), level = "trace", name = "VirtualDom::render_immediate")]
     pub fn render_immediate(&mut self, to: &mut impl WriteMutations) {
+        let status = self.render_until(to, || false);
+        debug_assert!(status.is_complete());
+    }
+
+    /// Render queued work until `deadline_passed` returns true, and then yield back to the caller. This lets the renderer break
+    /// up large updates across multiple frames so it can handle user input between them.
+    ///
+    /// The deadline is checked between every scope that is rerun, and at least one unit of work is always done. Immediate updates are
+    /// always rendered before [`Priority::Background`] updates. If this returns [`RenderStatus::Interrupted`], call it again
+    /// (usually on the next frame) to continue rendering where it left off. The mutations written so far are complete for every
+    /// component that was rerun and should be applied before the next render.
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_core::*;
+    /// # fn app() -> Element { rsx! { div {} } }
+    /// # async fn frame() {}
+    /// use std::time::{Duration, Instant};
+    ///
+    /// tokio::runtime::Runtime::new().unwrap().block_on(async {
+    ///     let mut dom = VirtualDom::new(app);
+    ///     dom.rebuild_in_place();
+    ///
+    ///     loop {
+    ///         dom.wait_for_work().await;
+    ///         // Render for at most 10 milliseconds before applying the mutations
+    ///         let deadline = Instant::now() + Duration::from_millis(10);
+    ///         let mut mutations = Mutations::default();
+    ///         let status = dom.render_with_deadline(&mut mutations, || Instant::now() >= deadline);
+    ///         // Apply the mutations to the real dom here
+    ///         if !status.is_complete() {
+    ///             // Give the renderer a chance to handle input before continuing
+    ///             frame().await;
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    #[instrument(
+        skip(self, to, deadline_passed),
+        level = "trace",
+        name = "VirtualDom::render_with_deadline"
+    )]
+    pub fn render_with_deadline(
+        &mut self,
+        to: &mut impl WriteMutations,
+        deadline_passed: impl FnMut() -> bool,
+    ) -> RenderStatus {
+        self.render_until(to, deadline_passed)
+    }
+
+    /// Run queued work until there is no work left or `should_yield` returns true
+    fn render_until(
+        &mut self,
+        to: &mut impl WriteMutations,
+        should_yield: impl FnMut() -> bool,
+    ) -> RenderStatus {
+        // If we are profiling, count the mutations that are written
+        match self
+            .profiler
+            .as_ref()
+            .map(|profiler| profiler.mutation_counter())
+        {
+            Some(count) => self.render_to(&mut CountMutations::new(to, count), should_yield),
+            None => self.render_to(to, should_yield),
+        }
+    }
+
+    fn render_to(
+        &mut self,
+        to: &mut impl WriteMutations,
+        mut should_yield: impl FnMut() -> bool,
+    ) -> RenderStatus {
         self.flush_templates(to);
 
         // Process any events that might be pending in the queue
@@ -680,8 +876,8 @@ impl VirtualDom {
         self.process_events();
 
         // Next, diff any dirty scopes
-        // We choose not to poll the deadline since we complete pretty quickly anyways
         let _runtime = RuntimeGuard::new(self.runtime.clone());
+        let mut status = RenderStatus::Complete;
         while let Some(work) = self.pop_work() {
             match work {
                 Work::PollTask(task) => {
@@ -692,11 +888,28 @@ impl VirtualDom {
                 Work::RerunScope(scope) => {
                     // If the scope is dirty, run the scope and get the mutations
                     self.run_and_diff_scope(Some(to), scope.id);
+                    self.order_suspense_lists();
+                }
+                Work::RerunBackgroundScope(scope) => {
+                    // Background scopes are rendered as part of a transition
+                    self.runtime.start_rendering_transition(scope.id);
+                    self.run_and_diff_scope(Some(to), scope.id);
+                    self.order_suspense_lists();
+                    self.runtime.finish_rendering_transition();
                 }
             }
+
+            // Check the deadline between each unit of work. We always finish at least one unit of work so the render makes progress
+            if self.has_dirty_scopes() && should_yield() {
+                status = RenderStatus::Interrupted;
+                break;
+            }
         }
 
+        // The effects of the scopes that were rerun can run once the mutations written so far are applied, even if the
+        // render was interrupted
         self.runtime.finish_render();
+        status
     }
 
     /// [`Self::render_immediate`] to a vector of mutations for testing purposes
@@ -725,9 +938,10 @@ impl VirtualDom {
         }
     }
 
-    /// Check if there are any suspended tasks remaining
+    /// Check if there are any suspended tasks remaining. Suspense lists that still need to reveal the boundaries they
+    /// just created count as suspended work.
     pub fn suspended_tasks_remaining(&self) -> bool {
-        self.runtime.suspended_tasks.get() > 0
+        self.runtime.suspended_tasks.get() > 0 || self.runtime.mounting_suspense_lists.get() > 0
     }
 
     /// Wait for the scheduler to have any work that should be run during suspense.
@@ -791,7 +1005,7 @@ impl VirtualDom {
                         let _ = self.runtime.handle_task_wakeup(task);
                     }
                 }
-                Work::RerunScope(scope) => {
+                Work::RerunScope(scope) | Work::RerunBackgroundScope(scope) => {
                     if self
                         .runtime
                         .get_state(scope.id)
@@ -805,6 +1019,7 @@ impl VirtualDom {
                                 .is_some();
                         // If the scope is dirty, run the scope and get the mutations
                         self.run_and_diff_scope(None::<&mut NoOpMutations>, scope.id);
+                        self.order_suspense_lists();
                         let scope_state = self.get_scope(scope.id).unwrap();
                         let is_now_suspended =
                             SuspenseBoundaryProps::downcast_ref_from_props(&*scope_state.props)
@@ -845,7 +1060,7 @@ impl VirtualDom {
 
     /// Flush any queued template changes
     #[instrument(skip(self, to), level = "trace", name = "VirtualDom::flush_templates")]
-    fn flush_templates(&mut self, to: &mut impl WriteMutations) {
+    pub(crate) fn flush_templates(&mut self, to: &mut impl WriteMutations) {
         for template in self.queued_templates.drain(..) {
             to.register_template(template);
         }
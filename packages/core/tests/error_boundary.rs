@@ -1,6 +1,7 @@
 #![allow(non_snake_case)]
 
 use dioxus::prelude::*;
+use std::cell::{Cell, RefCell};
 
 #[test]
 fn catches_panic() {
@@ -30,3 +31,162 @@ fn ThrowChild() -> Element {
 
     rsx! { div {} }
 }
+
+#[test]
+fn reset_renders_children_again() {
+    thread_local! {
+        static FAIL: Cell<bool> = const { Cell::new(true) };
+        static ERRORS: RefCell<Option<ErrorContext>> = const { RefCell::new(None) };
+    }
+
+    fn app() -> Element {
+        rsx! {
+            ErrorBoundary {
+                handle_error: |errors: ErrorContext| {
+                    ERRORS.with(|cx| *cx.borrow_mut() = Some(errors));
+                    rsx! { "failed" }
+                },
+                MaybeThrow {}
+            }
+        }
+    }
+
+    fn MaybeThrow() -> Element {
+        if FAIL.get() {
+            Err(std::io::Error::other("failed"))?;
+        }
+        rsx! { "succeeded" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "failed");
+
+    FAIL.set(false);
+    let errors = ERRORS.with(|cx| cx.borrow_mut().take()).unwrap();
+    dom.in_runtime(|| errors.reset());
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "succeeded");
+}
+
+#[test]
+fn reset_keys_reset_the_boundary() {
+    thread_local! {
+        static KEY: Cell<u32> = const { Cell::new(0) };
+    }
+
+    fn app() -> Element {
+        let key = KEY.get();
+        rsx! {
+            ErrorBoundary {
+                reset_keys: key,
+                handle_error: |_| rsx! { "failed" },
+                MaybeThrow { value: key }
+            }
+        }
+    }
+
+    #[component]
+    fn MaybeThrow(value: u32) -> Element {
+        if value == 0 {
+            Err(std::io::Error::other("failed"))?;
+        }
+        rsx! { "rendered {value}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "failed");
+
+    // Rerendering with the same keys keeps showing the error
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "failed");
+
+    KEY.set(1);
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "rendered 1");
+}
+
+#[test]
+fn on_error_is_called_once_per_error() {
+    thread_local! {
+        static REPORTED: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn app() -> Element {
+        rsx! {
+            ErrorBoundary {
+                on_error: |_: &CapturedError| REPORTED.set(REPORTED.get() + 1),
+                handle_error: |_| rsx! { "failed" },
+                ThrowChild {}
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+
+    // Rendering the boundary again doesn't report the same error twice
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+
+    assert_eq!(REPORTED.get(), 1);
+}
+
+#[test]
+fn errors_are_rethrown_to_the_parent_boundary() {
+    fn app() -> Element {
+        rsx! {
+            ErrorBoundary {
+                handle_error: |errors: ErrorContext| {
+                    let count = errors.errors().len();
+                    rsx! { "outer caught {count}" }
+                },
+                ErrorBoundary {
+                    handle_error: |errors: ErrorContext| {
+                        errors.rethrow();
+                        rsx! { "inner caught" }
+                    },
+                    ThrowChild {}
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    // The outer boundary reruns on the next render after the inner boundary passes the error up
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "outer caught 1");
+}
+
+#[test]
+fn failing_error_handlers_propagate_to_the_parent_boundary() {
+    fn app() -> Element {
+        rsx! {
+            ErrorBoundary {
+                handle_error: |_| rsx! { "outer caught" },
+                ErrorBoundary {
+                    handle_error: |_| {
+                        Err(std::io::Error::other("handler failed"))?;
+                        rsx! { "inner caught" }
+                    },
+                    ThrowChild {}
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    // The outer boundary reruns on the next render after the inner boundary passes the error up
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "outer caught");
+}
@@ -357,4 +357,6 @@ impl WriteMutations for InsertEventListenerMutationHandler<'_> {
     fn remove_node(&mut self, _: ElementId) {}
 
     fn push_root(&mut self, _: ElementId) {}
+
+    fn append_to_portal(&mut self, _: &str, _: usize) {}
 }
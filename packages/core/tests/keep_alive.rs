@@ -0,0 +1,162 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_core::{Mutation, NoOpMutations};
+use std::{cell::Cell, cell::RefCell, collections::HashMap};
+
+thread_local! {
+    static TAB: Cell<usize> = const { Cell::new(0) };
+    static MAX: Cell<usize> = const { Cell::new(10) };
+    static MOUNTS: RefCell<HashMap<usize, usize>> = RefCell::new(HashMap::new());
+    static DROPS: RefCell<HashMap<usize, usize>> = RefCell::new(HashMap::new());
+    static TASKS: RefCell<HashMap<usize, Task>> = RefCell::new(HashMap::new());
+    static COUNT: Cell<Option<Signal<usize>>> = const { Cell::new(None) };
+}
+
+fn mounts(id: usize) -> usize {
+    MOUNTS.with(|mounts| mounts.borrow().get(&id).copied().unwrap_or_default())
+}
+
+fn drops(id: usize) -> usize {
+    DROPS.with(|drops| drops.borrow().get(&id).copied().unwrap_or_default())
+}
+
+fn app() -> Element {
+    let tab = TAB.with(Cell::get);
+    use_hook(|| COUNT.with(|count| count.set(Some(Signal::new(0)))));
+    rsx! {
+        div {
+            KeepAlive { active: tab, max: MAX.with(Cell::get),
+                Tab { id: tab }
+            }
+        }
+    }
+}
+
+#[component]
+fn Tab(id: usize) -> Element {
+    use_hook(|| {
+        MOUNTS.with(|mounts| *mounts.borrow_mut().entry(id).or_default() += 1);
+        let task = spawn(std::future::pending());
+        TASKS.with(|tasks| tasks.borrow_mut().insert(id, task));
+    });
+    use_drop(move || DROPS.with(|drops| *drops.borrow_mut().entry(id).or_default() += 1));
+    let count = COUNT.with(Cell::get).unwrap();
+    if id == 3 {
+        return rsx! { "count {count}" };
+    }
+    rsx! { "tab {id}" }
+}
+
+fn switch_to(dom: &mut VirtualDom, tab: usize) {
+    TAB.with(|current| current.set(tab));
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut NoOpMutations);
+}
+
+fn task_paused(dom: &VirtualDom, id: usize) -> bool {
+    let task = TASKS.with(|tasks| tasks.borrow()[&id]);
+    dom.in_runtime(|| task.paused())
+}
+
+#[test]
+fn inactive_children_keep_their_state() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "<div>tab 0</div>");
+
+    switch_to(&mut dom, 1);
+    assert_eq!(dioxus_ssr::render(&dom), "<div>tab 1</div>");
+
+    // Switching back shows the cached tab without running its hooks again
+    switch_to(&mut dom, 0);
+    assert_eq!(mounts(0), 1);
+    assert_eq!(drops(0), 0);
+    assert_eq!(dioxus_ssr::render(&dom), "<div>tab 0</div>");
+}
+
+#[test]
+fn reattached_children_are_written_to_the_renderer() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+    switch_to(&mut dom, 1);
+
+    TAB.with(|current| current.set(0));
+    dom.mark_dirty(ScopeId::APP);
+    let mutations = dom.render_immediate_to_vec();
+    assert!(mutations.edits.iter().any(|edit| matches!(
+        edit,
+        Mutation::CreateTextNode { value, .. } if value == "tab 0"
+    )));
+    assert!(mutations
+        .edits
+        .iter()
+        .any(|edit| matches!(edit, Mutation::ReplaceWith { .. })));
+}
+
+#[test]
+fn tasks_are_paused_while_cached() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+    assert!(!task_paused(&dom, 0));
+
+    switch_to(&mut dom, 1);
+    assert!(task_paused(&dom, 0));
+    assert!(!task_paused(&dom, 1));
+
+    switch_to(&mut dom, 0);
+    assert!(!task_paused(&dom, 0));
+    assert!(task_paused(&dom, 1));
+}
+
+#[test]
+fn least_recently_used_children_are_dropped() {
+    MAX.with(|max| max.set(1));
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+
+    switch_to(&mut dom, 1);
+    switch_to(&mut dom, 2);
+    // Only one inactive tab fits in the cache, so the first tab was dropped
+    assert_eq!(drops(0), 1);
+    assert_eq!(drops(1), 0);
+
+    switch_to(&mut dom, 1);
+    assert_eq!(mounts(1), 1);
+    assert_eq!(drops(2), 0);
+
+    switch_to(&mut dom, 0);
+    assert_eq!(mounts(0), 2);
+    assert_eq!(drops(2), 1);
+}
+
+#[test]
+fn cached_children_are_dropped_with_the_keep_alive() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+    switch_to(&mut dom, 1);
+    switch_to(&mut dom, 2);
+
+    drop(dom);
+    assert_eq!(drops(0), 1);
+    assert_eq!(drops(1), 1);
+    assert_eq!(drops(2), 1);
+}
+
+#[test]
+fn cached_children_rerender_in_the_background() {
+    TAB.with(|current| current.set(3));
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+    switch_to(&mut dom, 1);
+
+    // The cached tab rerenders, but it is not in the renderer so nothing is written
+    let mut count = COUNT.with(Cell::get).unwrap();
+    dom.in_runtime(|| count.set(1));
+    let mutations = dom.render_immediate_to_vec();
+    assert!(mutations.edits.is_empty());
+
+    switch_to(&mut dom, 3);
+    assert_eq!(mounts(3), 1);
+    assert_eq!(dioxus_ssr::render(&dom), "<div>count 1</div>");
+}
@@ -0,0 +1,170 @@
+//! Verify that portals render their children into the portal target while keeping their place in the component tree
+
+use std::{cell::Cell, rc::Rc};
+
+use dioxus::dioxus_core::Mutation::*;
+use dioxus::prelude::*;
+use dioxus_core::ElementId;
+
+#[test]
+fn portal_children_are_appended_to_target() {
+    fn app() -> Element {
+        rsx! {
+            div {
+                Portal { target: "modal", p { "in the modal" } }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let edits = dom.rebuild_to_vec().santize();
+
+    assert_eq!(
+        edits.edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(1) },
+            LoadTemplate { name: "template", index: 0, id: ElementId(2) },
+            AppendToPortal { target: "modal".to_string(), m: 1 },
+            CreatePlaceholder { id: ElementId(3) },
+            ReplacePlaceholder { path: &[0], m: 1 },
+            AppendChildren { id: ElementId(0), m: 1 },
+        ]
+    );
+}
+
+#[test]
+fn portals_keep_context_and_bubble_events() {
+    thread_local! {
+        static CLICKS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn app() -> Element {
+        use_context_provider(|| "from the app");
+        rsx! {
+            div {
+                onclick: move |_| CLICKS.set(CLICKS.get() + 1),
+                Portal { target: "modal", Child {} }
+            }
+        }
+    }
+
+    #[component]
+    fn Child() -> Element {
+        // Context is read from the parents of the portal, not the portal target
+        let context: &'static str = use_context();
+        rsx! {
+            button { onclick: move |_| CLICKS.set(CLICKS.get() + 10), "{context}" }
+        }
+    }
+
+    set_event_converter(Box::new(dioxus::html::SerializedHtmlEventConverter));
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&dom), "<div></div>");
+
+    // The button is created before the portal placeholder. Clicking it bubbles up to the div the portal is placed in
+    dom.handle_event(
+        "click",
+        Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default())),
+        ElementId(2),
+        true,
+    );
+    assert_eq!(CLICKS.get(), 11);
+}
+
+#[test]
+fn removing_portals_removes_children_from_target() {
+    thread_local! {
+        static OPEN: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+    }
+
+    fn app() -> Element {
+        let open = use_signal(|| true);
+        OPEN.set(Some(open));
+        rsx! {
+            if open() {
+                div {
+                    Portal { target: "modal", p { "in the modal" } }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    OPEN.get().unwrap().set(false);
+    let edits = dom.render_immediate_to_vec().santize();
+
+    // The div is replaced with a placeholder. The paragraph doesn't live under the div, so it is removed separately
+    assert_eq!(
+        edits.edits,
+        [
+            CreatePlaceholder { id: ElementId(4) },
+            ReplaceWith { id: ElementId(1), m: 1 },
+            Remove { id: ElementId(2) },
+        ]
+    );
+}
+
+#[test]
+fn changing_the_target_moves_children() {
+    thread_local! {
+        static TARGET: Cell<Option<Signal<&'static str>>> = const { Cell::new(None) };
+    }
+
+    fn app() -> Element {
+        let target = use_signal(|| "first");
+        TARGET.set(Some(target));
+        rsx! {
+            Portal { target: target(), p { "in the modal" } }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    TARGET.get().unwrap().set("second");
+    let edits = dom.render_immediate_to_vec().santize();
+
+    assert_eq!(
+        edits.edits,
+        [
+            PushRoot { id: ElementId(1) },
+            AppendToPortal { target: "second".to_string(), m: 1 },
+        ]
+    );
+}
+
+#[test]
+fn remounting_portals_creates_the_children_once() {
+    fn app() -> Element {
+        rsx! {
+            div {
+                Portal { target: "outer",
+                    p { "outer" }
+                    Portal { target: "inner", span { "inner" } }
+                }
+            }
+        }
+    }
+
+    // Hydrating renderers don't write the portal children during the first render
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    let mut edits = dioxus_core::Mutations::default();
+    dom.remount_portals(ScopeId::ROOT, &mut edits);
+    let edits = edits.santize();
+
+    assert_eq!(
+        edits.edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(4) },
+            LoadTemplate { name: "template", index: 0, id: ElementId(3) },
+            AppendToPortal { target: "inner".to_string(), m: 1 },
+            CreatePlaceholder { id: ElementId(2) },
+            AppendToPortal { target: "outer".to_string(), m: 2 },
+        ]
+    );
+}
@@ -0,0 +1,140 @@
+//! Verify that the profiler records renders, diffs and the updates that triggered them
+
+use std::cell::Cell;
+
+use dioxus::prelude::*;
+use dioxus_core::{NoOpMutations, Profiler, RenderTrigger};
+
+thread_local! {
+    static COUNT: Cell<Option<Signal<i32>>> = const { Cell::new(None) };
+    static CHILD: Cell<Option<ScopeId>> = const { Cell::new(None) };
+}
+
+fn app() -> Element {
+    let count = use_signal(|| 0);
+    COUNT.set(Some(count));
+
+    rsx! {
+        div { "{count}" }
+        Child { count: count() }
+    }
+}
+
+#[component]
+fn Child(count: i32) -> Element {
+    CHILD.set(current_scope_id());
+
+    rsx! {
+        p { "child {count}" }
+    }
+}
+
+#[test]
+fn profiler_records_renders() {
+    let mut dom = VirtualDom::new(app);
+    dom.start_profiling(Profiler::new());
+    dom.rebuild(&mut NoOpMutations);
+
+    let renders = dom.profiler().unwrap().renders();
+    assert!(renders
+        .iter()
+        .all(|render| render.trigger == RenderTrigger::Mount));
+    // The app creates the div and the text node inside it. The child creates the paragraph
+    let app = renders
+        .iter()
+        .find(|render| render.scope == ScopeId::APP)
+        .unwrap();
+    assert!(app.diff_duration.is_some());
+    assert!(app.mutations > 0);
+    let child = renders
+        .iter()
+        .find(|render| render.name == "Child")
+        .unwrap();
+    assert!(child.mutations > 0);
+    let mounted = renders.len();
+
+    let line = line!() + 1;
+    COUNT.get().unwrap().set(1);
+    dom.render_immediate(&mut NoOpMutations);
+
+    let profiler = dom.stop_profiling().unwrap();
+    let renders = &profiler.renders()[mounted..];
+    assert_eq!(renders.len(), 2);
+    assert_eq!(renders[0].scope, ScopeId::APP);
+    let RenderTrigger::Update(sources) = &renders[0].trigger else {
+        panic!("expected the app to rerender because of an update");
+    };
+    assert_eq!(sources.len(), 1);
+    assert_eq!(sources[0].location.file(), file!());
+    assert_eq!(sources[0].location.line(), line);
+    // The update happened outside of any component
+    assert_eq!(sources[0].scope, None);
+    // The app only updates the text of the div
+    assert_eq!(renders[0].mutations, 1);
+
+    assert_eq!(renders[1].name, "Child");
+    assert_eq!(renders[1].trigger, RenderTrigger::Props);
+    assert_eq!(renders[1].mutations, 1);
+
+    let trace = profiler.to_chrome_trace();
+    assert!(trace.starts_with(r#"{"traceEvents":[{"name":"Render "#));
+    assert!(trace.contains(r#""name":"Diff Child","cat":"diff","ph":"X""#));
+    assert!(trace.contains(&format!(
+        r#""trigger":"update","updated_at":["{}:{line}:"#,
+        file!()
+    )));
+}
+
+#[test]
+fn profiler_records_the_component_that_triggered_an_update() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+    dom.start_profiling(Profiler::new());
+
+    // Event handlers, tasks and effects run with the scope that owns them on the scope stack
+    dom.in_runtime(|| {
+        Runtime::current()
+            .unwrap()
+            .on_scope(ScopeId::APP, || COUNT.get().unwrap().set(1))
+    });
+    dom.render_immediate(&mut NoOpMutations);
+
+    let profiler = dom.stop_profiling().unwrap();
+    let RenderTrigger::Update(sources) = &profiler.renders()[0].trigger else {
+        panic!("expected the app to rerender because of an update");
+    };
+    assert_eq!(sources[0].scope, Some(ScopeId::APP));
+    assert_eq!(sources[0].component, Some(profiler.renders()[0].name));
+    assert!(profiler.to_chrome_trace().contains(&format!(
+        r#""updated_by":["{} ({})"]"#,
+        profiler.renders()[0].name,
+        ScopeId::APP.0
+    )));
+}
+
+#[test]
+fn profiler_records_manual_updates() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+    let child = CHILD.get().unwrap();
+    dom.start_profiling(Profiler::new());
+
+    // Context providers mark the components that consume them dirty with needs_update
+    let line = line!() + 4;
+    dom.in_runtime(|| {
+        Runtime::current()
+            .unwrap()
+            .on_scope(ScopeId::APP, || needs_update_any(child))
+    });
+    dom.render_immediate(&mut NoOpMutations);
+
+    let profiler = dom.stop_profiling().unwrap();
+    assert_eq!(profiler.renders()[0].name, "Child");
+    let RenderTrigger::Update(sources) = &profiler.renders()[0].trigger else {
+        panic!("expected the child to rerender because of an update");
+    };
+    assert_eq!(sources.len(), 1);
+    assert_eq!(sources[0].location.file(), file!());
+    assert_eq!(sources[0].location.line(), line);
+    assert_eq!(sources[0].scope, Some(ScopeId::APP));
+}
@@ -0,0 +1,48 @@
+//! Verify that remounting a scope creates its nodes again without rerunning components
+
+use std::cell::Cell;
+
+use dioxus::dioxus_core::Mutation::*;
+use dioxus::prelude::*;
+use dioxus_core::ElementId;
+
+#[test]
+fn remounting_creates_nodes_from_scratch() {
+    thread_local! {
+        static RENDERS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn app() -> Element {
+        rsx! {
+            div {}
+            Child {}
+        }
+    }
+
+    #[component]
+    fn Child() -> Element {
+        RENDERS.set(RENDERS.get() + 1);
+        let count = use_signal(|| 1);
+        rsx! { "count: {count}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    let mut mutations = dioxus_core::Mutations::default();
+    let m = dom.remount_scope(ScopeId::ROOT, &mut mutations);
+    let edits = mutations.santize();
+
+    // The old ids are reclaimed and reused for the new nodes
+    assert_eq!(m, 2);
+    assert_eq!(
+        edits.edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(2) },
+            CreateTextNode { value: "count: 1".to_string(), id: ElementId(1) },
+        ]
+    );
+
+    // The child component is created again from the nodes it last rendered without rerunning
+    assert_eq!(RENDERS.get(), 1);
+}
@@ -0,0 +1,199 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use std::time::Duration;
+
+/// Run the virtual dom for the given number of milliseconds
+async fn run_for(dom: &mut VirtualDom, millis: u64) {
+    let work = async {
+        loop {
+            dom.wait_for_work().await;
+            dom.render_immediate(&mut dioxus_core::NoOpMutations);
+        }
+    };
+    tokio::select! {
+        _ = work => {},
+        _ = tokio::time::sleep(Duration::from_millis(millis)) => {}
+    }
+}
+
+fn run(test: impl std::future::Future<Output = ()>) {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap()
+        .block_on(test);
+}
+
+#[component]
+fn Item(id: usize, delay: u64) -> Element {
+    let mut ready = use_signal(|| delay == 0);
+    if !ready() {
+        let task = use_hook(|| {
+            spawn(async move {
+                tokio::time::sleep(Duration::from_millis(delay)).await;
+                ready.set(true);
+            })
+        });
+        suspend(task)?;
+    }
+    rsx! { "item {id}" }
+}
+
+#[component]
+fn Feed(reveal_order: RevealOrder, tail: SuspenseListTail, delays: Vec<u64>) -> Element {
+    rsx! {
+        SuspenseList { reveal_order, tail,
+            for (id , delay) in delays.into_iter().enumerate() {
+                SuspenseBoundary {
+                    key: "{id}",
+                    fallback: move |_| rsx! { "loading {id}" },
+                    Item { id, delay }
+                }
+            }
+        }
+    }
+}
+
+fn feed(reveal_order: RevealOrder, tail: SuspenseListTail, delays: Vec<u64>) -> VirtualDom {
+    let mut dom = VirtualDom::new_with_props(Feed, FeedProps { reveal_order, tail, delays });
+    dom.rebuild_in_place();
+    dom
+}
+
+#[test]
+fn forwards_reveals_boundaries_in_order() {
+    run(async {
+        let mut dom = feed(
+            RevealOrder::Forwards,
+            SuspenseListTail::Visible,
+            vec![100, 0, 200],
+        );
+        run_for(&mut dom, 50).await;
+        // The second item is ready, but it waits for the first item
+        assert_eq!(dioxus_ssr::render(&dom), "loading 0loading 1loading 2");
+
+        run_for(&mut dom, 100).await;
+        assert_eq!(dioxus_ssr::render(&dom), "item 0item 1loading 2");
+
+        run_for(&mut dom, 100).await;
+        assert_eq!(dioxus_ssr::render(&dom), "item 0item 1item 2");
+    });
+}
+
+#[test]
+fn backwards_reveals_boundaries_in_reverse_order() {
+    run(async {
+        let mut dom = feed(
+            RevealOrder::Backwards,
+            SuspenseListTail::Visible,
+            vec![0, 100, 0],
+        );
+        run_for(&mut dom, 50).await;
+        // The last item is revealed first
+        assert_eq!(dioxus_ssr::render(&dom), "loading 0loading 1item 2");
+
+        run_for(&mut dom, 100).await;
+        assert_eq!(dioxus_ssr::render(&dom), "item 0item 1item 2");
+    });
+}
+
+#[test]
+fn together_reveals_boundaries_at_the_same_time() {
+    run(async {
+        let mut dom = feed(
+            RevealOrder::Together,
+            SuspenseListTail::Visible,
+            vec![0, 100, 0],
+        );
+        run_for(&mut dom, 50).await;
+        assert_eq!(dioxus_ssr::render(&dom), "loading 0loading 1loading 2");
+
+        run_for(&mut dom, 100).await;
+        assert_eq!(dioxus_ssr::render(&dom), "item 0item 1item 2");
+    });
+}
+
+#[test]
+fn collapsed_tail_only_shows_the_next_fallback() {
+    run(async {
+        let mut dom = feed(
+            RevealOrder::Forwards,
+            SuspenseListTail::Collapsed,
+            vec![100, 200, 0],
+        );
+        run_for(&mut dom, 50).await;
+        assert_eq!(dioxus_ssr::render(&dom), "loading 0");
+
+        run_for(&mut dom, 100).await;
+        assert_eq!(dioxus_ssr::render(&dom), "item 0loading 1");
+
+        run_for(&mut dom, 100).await;
+        assert_eq!(dioxus_ssr::render(&dom), "item 0item 1item 2");
+    });
+}
+
+#[test]
+fn hidden_tail_hides_fallbacks() {
+    run(async {
+        let mut dom = feed(
+            RevealOrder::Forwards,
+            SuspenseListTail::Hidden,
+            vec![0, 100, 0],
+        );
+        run_for(&mut dom, 50).await;
+        assert_eq!(dioxus_ssr::render(&dom), "item 0");
+
+        run_for(&mut dom, 100).await;
+        assert_eq!(dioxus_ssr::render(&dom), "item 0item 1item 2");
+    });
+}
+
+#[test]
+fn server_rendering_waits_for_lists_to_reveal_boundaries() {
+    run(async {
+        // Nothing suspends, but the boundaries are only revealed together after they were all created
+        let mut dom = feed(RevealOrder::Together, SuspenseListTail::Visible, vec![0, 0]);
+        assert!(dom.suspended_tasks_remaining());
+        dom.wait_for_suspense().await;
+        assert_eq!(dioxus_ssr::render(&dom), "item 0item 1");
+    });
+}
+
+#[test]
+fn boundaries_inserted_mid_list_are_revealed_in_tree_order() {
+    static ITEMS: GlobalSignal<Vec<(usize, u64)>> = Signal::global(|| vec![(0, 0), (2, 200)]);
+
+    fn app() -> Element {
+        rsx! {
+            SuspenseList {
+                for (id , delay) in ITEMS() {
+                    SuspenseBoundary {
+                        key: "{id}",
+                        fallback: move |_| rsx! { "loading {id}" },
+                        Item { id, delay }
+                    }
+                }
+            }
+        }
+    }
+
+    run(async {
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        run_for(&mut dom, 50).await;
+        assert_eq!(dioxus_ssr::render(&dom), "item 0loading 2");
+
+        // The new boundary is added after the others were created, but it is still ordered before the last item
+        dom.in_runtime(|| ScopeId::APP.in_runtime(|| ITEMS.write().insert(1, (1, 300))));
+        run_for(&mut dom, 50).await;
+        assert_eq!(dioxus_ssr::render(&dom), "item 0loading 1loading 2");
+
+        // The last item is ready, but it waits for the new item
+        run_for(&mut dom, 200).await;
+        assert_eq!(dioxus_ssr::render(&dom), "item 0loading 1loading 2");
+
+        run_for(&mut dom, 150).await;
+        assert_eq!(dioxus_ssr::render(&dom), "item 0item 1item 2");
+    });
+}
@@ -0,0 +1,97 @@
+//! Verify that templates the renderer preloaded from a template cache are not sent to the renderer again
+
+use dioxus::dioxus_core::Mutation::*;
+use dioxus::prelude::*;
+use dioxus_core::{ElementId, Template, TemplateCache};
+
+fn app() -> Element {
+    rsx! {
+        div { "hello world" }
+    }
+}
+
+fn app_template() -> Template {
+    let mut dom = VirtualDom::new(app);
+    let templates = dom.rebuild_to_vec().templates;
+    assert_eq!(templates.len(), 1);
+    templates[0]
+}
+
+#[test]
+fn preloaded_templates_are_not_registered() {
+    let cache: TemplateCache = [app_template()].into_iter().collect();
+
+    let mut dom = VirtualDom::new(app);
+    dom.preload_templates(&cache);
+    let edits = dom.rebuild_to_vec();
+
+    assert!(edits.templates.is_empty());
+    assert_eq!(
+        edits.edits,
+        [
+            LoadTemplate { name: app_template().name, index: 0, id: ElementId(1) },
+            AppendChildren { id: ElementId(0), m: 1 },
+        ]
+    );
+}
+
+#[test]
+fn preloaded_templates_are_matched_by_location() {
+    // Templates extracted from the source code don't know the index the compiler gives the template
+    let compiled = app_template();
+    let (location, _) = compiled.name.rsplit_once(':').unwrap();
+    let extracted =
+        Template { name: Box::leak(format!("{location}:0").into_boxed_str()), ..compiled };
+    let cache: TemplateCache = [extracted].into_iter().collect();
+
+    let mut dom = VirtualDom::new(app);
+    dom.preload_templates(&cache);
+    let edits = dom.rebuild_to_vec();
+
+    // The renderer loads the template by the name it was preloaded with
+    assert!(edits.templates.is_empty());
+    assert_eq!(
+        edits.edits,
+        [
+            LoadTemplate { name: extracted.name, index: 0, id: ElementId(1) },
+            AppendChildren { id: ElementId(0), m: 1 },
+        ]
+    );
+}
+
+#[test]
+fn outdated_templates_are_registered() {
+    let compiled = app_template();
+    let outdated = Template { roots: &[], ..compiled };
+    let cache: TemplateCache = [outdated].into_iter().collect();
+
+    let mut dom = VirtualDom::new(app);
+    dom.preload_templates(&cache);
+    let edits = dom.rebuild_to_vec();
+
+    assert_eq!(edits.templates, [compiled]);
+}
+
+#[test]
+fn template_cache_replaces_templates_with_the_same_name() {
+    let compiled = app_template();
+    let mut cache = TemplateCache::new();
+    cache.insert(Template { roots: &[], ..compiled });
+    cache.insert(compiled);
+
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.get(compiled.name), Some(&compiled));
+}
+
+#[test]
+fn template_caches_load_from_data_read_at_runtime() {
+    let cache: TemplateCache = [app_template()].into_iter().collect();
+
+    let json = serde_json::to_vec(&cache).unwrap();
+    let loaded = TemplateCache::load(json, serde_json::from_slice).unwrap();
+    assert_eq!(loaded, cache);
+
+    let mut dom = VirtualDom::new(app);
+    dom.preload_templates(&loaded);
+    assert!(dom.rebuild_to_vec().templates.is_empty());
+}
@@ -0,0 +1,159 @@
+//! Verify that renders can be split across deadlines and that background updates run after immediate updates
+
+use std::{
+    cell::RefCell,
+    time::{Duration, Instant},
+};
+
+use dioxus::prelude::*;
+use dioxus_core::{NoOpMutations, RenderStatus};
+
+thread_local! {
+    static RENDERED: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+fn app() -> Element {
+    rsx! {
+        for id in 0..3 {
+            Child { id }
+        }
+    }
+}
+
+#[component]
+fn Child(id: usize) -> Element {
+    RENDERED.with(|rendered| rendered.borrow_mut().push(id));
+    rsx! { "{id}" }
+}
+
+fn child_scope(id: usize) -> ScopeId {
+    ScopeId(ScopeId::APP.0 + 1 + id)
+}
+
+fn take_rendered() -> Vec<usize> {
+    RENDERED.with(|rendered| std::mem::take(&mut *rendered.borrow_mut()))
+}
+
+#[test]
+fn render_yields_when_deadline_passes() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    take_rendered();
+
+    for id in 0..3 {
+        dom.mark_dirty(child_scope(id));
+    }
+
+    // The deadline has already passed, so each render should only rerun one scope
+    let mut renders = 0;
+    loop {
+        renders += 1;
+        let status = dom.render_with_deadline(&mut NoOpMutations, || true);
+        if status == RenderStatus::Complete {
+            break;
+        }
+    }
+    assert_eq!(renders, 3);
+    assert_eq!(take_rendered(), vec![0, 1, 2]);
+
+    // A deadline that never passes renders everything at once
+    for id in 0..3 {
+        dom.mark_dirty(child_scope(id));
+    }
+    let status = dom.render_with_deadline(&mut NoOpMutations, || false);
+    assert!(status.is_complete());
+    assert_eq!(take_rendered(), vec![0, 1, 2]);
+}
+
+#[test]
+fn render_yields_after_the_time_budget() {
+    fn slow_app() -> Element {
+        rsx! {
+            for id in 0..10 {
+                SlowChild { id }
+            }
+        }
+    }
+
+    #[component]
+    fn SlowChild(id: usize) -> Element {
+        std::thread::sleep(Duration::from_millis(5));
+        RENDERED.with(|rendered| rendered.borrow_mut().push(id));
+        rsx! { "{id}" }
+    }
+
+    let mut dom = VirtualDom::new(slow_app);
+    dom.rebuild_in_place();
+    take_rendered();
+
+    for id in 0..10 {
+        dom.mark_dirty(child_scope(id));
+    }
+
+    // Each child takes 5ms to render, so a 12ms budget can't fit all of them
+    let mut renders = 0;
+    let mut rendered = Vec::new();
+    loop {
+        renders += 1;
+        let deadline = Instant::now() + Duration::from_millis(12);
+        let status = dom.render_with_deadline(&mut NoOpMutations, || Instant::now() >= deadline);
+        let batch = take_rendered();
+        assert!(!batch.is_empty() && batch.len() < 10);
+        rendered.extend(batch);
+        if status.is_complete() {
+            break;
+        }
+    }
+    assert!(renders > 1);
+    assert_eq!(rendered, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn background_updates_run_after_immediate_updates() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    take_rendered();
+
+    dom.mark_dirty_with_priority(child_scope(0), Priority::Background);
+    dom.mark_dirty_with_priority(child_scope(1), Priority::Background);
+    dom.mark_dirty(child_scope(2));
+
+    let status = dom.render_with_deadline(&mut NoOpMutations, || true);
+    assert_eq!(status, RenderStatus::Interrupted);
+    assert_eq!(take_rendered(), vec![2]);
+
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(take_rendered(), vec![0, 1]);
+}
+
+#[test]
+fn immediate_updates_override_background_updates() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    take_rendered();
+
+    dom.mark_dirty(child_scope(2));
+    dom.mark_dirty_with_priority(child_scope(1), Priority::Background);
+    dom.mark_dirty(child_scope(1));
+    // Marking an immediate scope as a background update doesn't lower its priority
+    dom.mark_dirty_with_priority(child_scope(2), Priority::Background);
+
+    let status = dom.render_with_deadline(&mut NoOpMutations, || true);
+    assert_eq!(status, RenderStatus::Interrupted);
+    let status = dom.render_with_deadline(&mut NoOpMutations, || true);
+    assert!(status.is_complete());
+    assert_eq!(take_rendered(), vec![1, 2]);
+}
+
+#[test]
+fn updates_inherit_the_current_priority() {
+    assert_eq!(current_priority(), Priority::Immediate);
+    with_priority(Priority::Background, || {
+        assert_eq!(current_priority(), Priority::Background);
+        with_priority(Priority::Immediate, || {
+            assert_eq!(current_priority(), Priority::Immediate)
+        });
+        assert_eq!(current_priority(), Priority::Background);
+    });
+    assert_eq!(current_priority(), Priority::Immediate);
+}
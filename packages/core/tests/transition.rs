@@ -0,0 +1,150 @@
+//! Verify that components that suspend during a transition keep showing their previous UI
+
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+
+thread_local! {
+    static TAB: Cell<Option<Signal<usize>>> = const { Cell::new(None) };
+    static COUNT: Cell<Option<Signal<usize>>> = const { Cell::new(None) };
+}
+
+fn app() -> Element {
+    let tab = use_signal(|| 0);
+    TAB.set(Some(tab));
+
+    rsx! {
+        SuspenseBoundary {
+            fallback: |_| rsx! { "fallback" },
+            Tab { tab }
+        }
+    }
+}
+
+fn app_with_counter() -> Element {
+    let count = use_signal(|| 0);
+    COUNT.set(Some(count));
+
+    rsx! {
+        {app()}
+        " count {count}"
+    }
+}
+
+#[component]
+fn Tab(tab: ReadOnlySignal<usize>) -> Element {
+    let mut loaded = use_signal(|| 0);
+
+    if loaded() != tab() {
+        let task = spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            loaded.set(*tab.peek());
+        });
+        suspend(task)?;
+    }
+
+    rsx! { "tab {tab}" }
+}
+
+fn render(dom: &mut VirtualDom) {
+    dom.render_immediate(&mut NoOpMutations);
+    // Suspending marks the boundary as dirty, so we need to render again to see the result
+    dom.render_immediate(&mut NoOpMutations);
+}
+
+async fn render_until_idle(dom: &mut VirtualDom) {
+    loop {
+        tokio::select! {
+            _ = dom.wait_for_work() => dom.render_immediate(&mut NoOpMutations),
+            _ = tokio::time::sleep(Duration::from_millis(100)) => break,
+        }
+    }
+}
+
+#[test]
+fn suspended_updates_show_fallback() {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap()
+        .block_on(async {
+            let mut dom = VirtualDom::new(app);
+            dom.rebuild_in_place();
+            assert_eq!(dioxus_ssr::render(&dom), "tab 0");
+
+            TAB.get().unwrap().set(1);
+            render(&mut dom);
+            assert_eq!(dioxus_ssr::render(&dom), "fallback");
+
+            render_until_idle(&mut dom).await;
+            assert_eq!(dioxus_ssr::render(&dom), "tab 1");
+        });
+}
+
+#[test]
+fn transitions_keep_previous_ui() {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap()
+        .block_on(async {
+            let mut dom = VirtualDom::new(app);
+            dom.rebuild_in_place();
+            assert_eq!(dioxus_ssr::render(&dom), "tab 0");
+
+            start_transition(|| TAB.get().unwrap().set(1));
+            let finished = Rc::new(Cell::new(false));
+            dom.in_runtime(|| {
+                let finished = finished.clone();
+                after_transitions(move || finished.set(true))
+            });
+
+            // The tab suspends, but the old tab stays visible
+            render(&mut dom);
+            assert_eq!(dioxus_ssr::render(&dom), "tab 0");
+            dom.process_events();
+            assert!(!finished.get());
+
+            // Once the future resolves, the new tab is shown and the transition is finished
+            render_until_idle(&mut dom).await;
+            assert_eq!(dioxus_ssr::render(&dom), "tab 1");
+            assert!(finished.get());
+        });
+}
+
+#[test]
+fn transitions_finish_independently() {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap()
+        .block_on(async {
+            let mut dom = VirtualDom::new(app_with_counter);
+            dom.rebuild_in_place();
+            assert_eq!(dioxus_ssr::render(&dom), "tab 0 count 0");
+
+            let tab_finished = Rc::new(Cell::new(false));
+            let count_finished = Rc::new(Cell::new(false));
+            dom.in_runtime(|| {
+                let tab_finished = tab_finished.clone();
+                start_transition_then(|| TAB.get().unwrap().set(1), move || tab_finished.set(true));
+                let count_finished = count_finished.clone();
+                start_transition_then(
+                    || COUNT.get().unwrap().set(1),
+                    move || count_finished.set(true),
+                );
+            });
+
+            // The counter finishes rendering while the tab is still suspended
+            render(&mut dom);
+            dom.process_events();
+            assert_eq!(dioxus_ssr::render(&dom), "tab 0 count 1");
+            assert!(count_finished.get());
+            assert!(!tab_finished.get());
+
+            render_until_idle(&mut dom).await;
+            assert_eq!(dioxus_ssr::render(&dom), "tab 1 count 1");
+            assert!(tab_finished.get());
+        });
+}
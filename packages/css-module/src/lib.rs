@@ -0,0 +1,330 @@
+#![doc = include_str!("../README.md")]
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// The extension of the stylesheets `dx` collects and hot reloads as CSS modules
+pub const MODULE_EXTENSION: &str = ".module.css";
+
+/// A stylesheet with every class scoped to one module
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScopedCss {
+    /// The stylesheet with every class selector renamed to its scoped name
+    pub css: String,
+    /// The scoped name of every class in the stylesheet, keyed by the name it was written with
+    pub classes: BTreeMap<String, String>,
+}
+
+/// A stylesheet could not be scoped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopeError {
+    /// The line the error starts on, starting at 1
+    pub line: usize,
+    /// What went wrong
+    pub message: &'static str,
+}
+
+impl Display for ScopeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} on line {}", self.message, self.line)
+    }
+}
+
+impl std::error::Error for ScopeError {}
+
+/// Get the id of the CSS module at `path` in `package`.
+///
+/// The id only depends on where the stylesheet is, not what it contains, so editing the stylesheet keeps the same class
+/// names. `path` is relative to the root of the package, and is normalized so `./src/app.module.css` and
+/// `src\app.module.css` get the same id as `src/app.module.css`.
+pub fn module_id(package: &str, path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let path = path.trim_start_matches("./");
+
+    // FNV-1a so the id is the same on every platform and compiler version
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in package.bytes().chain([b':']).chain(path.bytes()) {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:08x}", (hash ^ (hash >> 32)) as u32)
+}
+
+/// Get the scoped name of a class in the module with the given id
+pub fn scoped_class(class: &str, id: &str) -> String {
+    format!("{class}_{id}")
+}
+
+/// Rename every class selector in a stylesheet to its scoped name.
+///
+/// Classes inside `:global(...)` are left as they are and the `:global` wrapper is removed. Declarations, at-rule
+/// preludes and keyframe selectors are never changed.
+pub fn scope_css(css: &str, id: &str) -> Result<ScopedCss, ScopeError> {
+    let mut scoper = Scoper {
+        id,
+        classes: BTreeMap::new(),
+    };
+    let css = scoper.scope_stylesheet(css)?;
+    Ok(ScopedCss {
+        css,
+        classes: scoper.classes,
+    })
+}
+
+/// What the contents of a block can contain
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Block {
+    /// Rules and declarations, like the top level of a stylesheet, `@media` or a style rule with nested rules
+    Rules,
+    /// Only declarations, like `@font-face`
+    Declarations,
+    /// Keyframe selectors like `from` or `50%`
+    Keyframes,
+}
+
+struct Scoper<'a> {
+    id: &'a str,
+    classes: BTreeMap<String, String>,
+}
+
+impl Scoper<'_> {
+    fn scope_stylesheet(&mut self, source: &str) -> Result<String, ScopeError> {
+        let chars: Vec<char> = source.chars().collect();
+        let mut out = String::with_capacity(source.len());
+        let mut stack = vec![Block::Rules];
+        // The text since the end of the last declaration or block
+        let mut prelude = String::new();
+        let mut prelude_start = 0;
+        let mut i = 0;
+
+        while i < chars.len() {
+            if prelude.trim_start().is_empty() {
+                prelude_start = i;
+            }
+            match chars[i] {
+                '/' if chars.get(i + 1) == Some(&'*') => {
+                    let end = comment_end(&chars, i)
+                        .ok_or_else(|| error(&chars, i, "unterminated comment"))?;
+                    prelude.extend(&chars[i..end]);
+                    i = end;
+                }
+                quote @ ('"' | '\'') => {
+                    let end = string_end(&chars, i, quote)
+                        .ok_or_else(|| error(&chars, i, "unterminated string"))?;
+                    prelude.extend(&chars[i..end]);
+                    i = end;
+                }
+                '\\' => {
+                    prelude.extend(chars.get(i..i + 2).unwrap_or(&chars[i..]));
+                    i += 2;
+                }
+                '{' => {
+                    let parent = *stack.last().unwrap();
+                    let trimmed = prelude.trim_start();
+                    let block = if parent == Block::Keyframes || parent == Block::Declarations {
+                        out.push_str(&prelude);
+                        Block::Declarations
+                    } else if let Some(at_rule) = trimmed.strip_prefix('@') {
+                        out.push_str(&prelude);
+                        let name: String = at_rule
+                            .chars()
+                            .take_while(|c| c.is_alphanumeric() || *c == '-')
+                            .collect::<String>()
+                            .to_ascii_lowercase();
+                        match name.as_str() {
+                            name if name.ends_with("keyframes") => Block::Keyframes,
+                            "media" | "supports" | "layer" | "container" | "document" | "scope"
+                            | "starting-style" => Block::Rules,
+                            _ => Block::Declarations,
+                        }
+                    } else {
+                        let selector = self
+                            .scope_selector(&prelude)
+                            .map_err(|message| error(&chars, prelude_start, message))?;
+                        out.push_str(&selector);
+                        Block::Rules
+                    };
+                    out.push('{');
+                    prelude.clear();
+                    stack.push(block);
+                    i += 1;
+                }
+                '}' => {
+                    if stack.len() == 1 {
+                        return Err(error(&chars, i, "unexpected `}`"));
+                    }
+                    stack.pop();
+                    out.push_str(&prelude);
+                    out.push('}');
+                    prelude.clear();
+                    i += 1;
+                }
+                ';' => {
+                    out.push_str(&prelude);
+                    out.push(';');
+                    prelude.clear();
+                    i += 1;
+                }
+                c => {
+                    prelude.push(c);
+                    i += 1;
+                }
+            }
+        }
+
+        if stack.len() > 1 {
+            return Err(error(&chars, chars.len(), "unclosed `{`"));
+        }
+        out.push_str(&prelude);
+
+        Ok(out)
+    }
+
+    fn scope_selector(&mut self, selector: &str) -> Result<String, &'static str> {
+        const GLOBAL: &str = ":global(";
+
+        let chars: Vec<char> = selector.chars().collect();
+        let mut out = String::with_capacity(selector.len());
+        let mut i = 0;
+
+        while i < chars.len() {
+            match chars[i] {
+                '/' if chars.get(i + 1) == Some(&'*') => {
+                    let end = comment_end(&chars, i).ok_or("unterminated comment")?;
+                    out.extend(&chars[i..end]);
+                    i = end;
+                }
+                quote @ ('"' | '\'') => {
+                    let end = string_end(&chars, i, quote).ok_or("unterminated string")?;
+                    out.extend(&chars[i..end]);
+                    i = end;
+                }
+                '\\' => {
+                    out.extend(chars.get(i..i + 2).unwrap_or(&chars[i..]));
+                    i += 2;
+                }
+                '[' => {
+                    let end = attribute_selector_end(&chars, i).ok_or("unclosed `[`")?;
+                    out.extend(&chars[i..end]);
+                    i = end;
+                }
+                ':' if starts_with(&chars, i, GLOBAL) => {
+                    let start = i + GLOBAL.len();
+                    let end = closing_paren(&chars, start).ok_or("unclosed `:global(`")?;
+                    out.extend(&chars[start..end]);
+                    i = end + 1;
+                }
+                '.' if is_ident_start(&chars, i + 1) => {
+                    let end = ident_end(&chars, i + 1);
+                    let class: String = chars[i + 1..end].iter().collect();
+                    let scoped = scoped_class(&class, self.id);
+                    out.push('.');
+                    out.push_str(&scoped);
+                    self.classes.insert(class, scoped);
+                    i = end;
+                }
+                c => {
+                    out.push(c);
+                    i += 1;
+                }
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+fn error(chars: &[char], at: usize, message: &'static str) -> ScopeError {
+    let line = chars[..at.min(chars.len())]
+        .iter()
+        .filter(|c| **c == '\n')
+        .count()
+        + 1;
+    ScopeError { line, message }
+}
+
+fn starts_with(chars: &[char], at: usize, pattern: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    chars.len() >= at + pattern.len()
+        && chars[at..at + pattern.len()]
+            .iter()
+            .zip(&pattern)
+            .all(|(a, b)| a.eq_ignore_ascii_case(b))
+}
+
+/// The index after the `*/` that closes the comment starting at `start`
+fn comment_end(chars: &[char], start: usize) -> Option<usize> {
+    (start + 2..chars.len().saturating_sub(1))
+        .find(|&i| chars[i] == '*' && chars[i + 1] == '/')
+        .map(|i| i + 2)
+}
+
+/// The index after the quote that closes the string starting at `start`
+fn string_end(chars: &[char], start: usize, quote: char) -> Option<usize> {
+    let mut i = start + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            '\n' => return None,
+            c if c == quote => return Some(i + 1),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// The index after the `]` that closes the attribute selector starting at `start`
+fn attribute_selector_end(chars: &[char], start: usize) -> Option<usize> {
+    let mut i = start + 1;
+    while i < chars.len() {
+        match chars[i] {
+            quote @ ('"' | '\'') => i = string_end(chars, i, quote)?,
+            '\\' => i += 2,
+            ']' => return Some(i + 1),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// The index of the `)` that closes a group whose contents start at `start`
+fn closing_paren(chars: &[char], start: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut i = start;
+    while i < chars.len() {
+        match chars[i] {
+            quote @ ('"' | '\'') => {
+                i = string_end(chars, i, quote)?;
+                continue;
+            }
+            '\\' => i += 1,
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(i),
+            ')' => depth -= 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii()
+}
+
+fn is_ident_start(chars: &[char], at: usize) -> bool {
+    let is_start = |c: char| c.is_ascii_alphabetic() || c == '_' || !c.is_ascii();
+    match chars.get(at) {
+        Some('-') => matches!(chars.get(at + 1), Some(&c) if is_start(c) || c == '-'),
+        Some(&c) => is_start(c),
+        None => false,
+    }
+}
+
+fn ident_end(chars: &[char], start: usize) -> usize {
+    (start..chars.len())
+        .find(|&i| !is_ident_char(chars[i]))
+        .unwrap_or(chars.len())
+}
@@ -0,0 +1,79 @@
+use dioxus_css_module::{module_id, scope_css, ScopeError};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn scopes_class_selectors() {
+    let scoped = scope_css(
+        r#"
+/* .comment { } */
+.button, .button-primary:hover > span.icon { color: red; margin: .5em; }
+a[href=".pdf"].link:not(.disabled) { content: ".text"; }
+:global(.theme-dark) .button { color: white }
+@media (max-width: 600px) {
+    .button { padding: 0.25rem }
+}
+@keyframes spin { from { opacity: 0.5 } 50.5% { opacity: 1 } }
+@font-face { font-family: "Inter"; src: url(inter.woff2) }
+.card { &.selected { border: 1px solid } }
+"#,
+        "abc123",
+    )
+    .unwrap();
+
+    assert_eq!(
+        scoped.css,
+        r#"
+/* .comment { } */
+.button_abc123, .button-primary_abc123:hover > span.icon_abc123 { color: red; margin: .5em; }
+a[href=".pdf"].link_abc123:not(.disabled_abc123) { content: ".text"; }
+.theme-dark .button_abc123 { color: white }
+@media (max-width: 600px) {
+    .button_abc123 { padding: 0.25rem }
+}
+@keyframes spin { from { opacity: 0.5 } 50.5% { opacity: 1 } }
+@font-face { font-family: "Inter"; src: url(inter.woff2) }
+.card_abc123 { &.selected_abc123 { border: 1px solid } }
+"#
+    );
+
+    let classes: Vec<_> = scoped.classes.keys().map(String::as_str).collect();
+    assert_eq!(
+        classes,
+        [
+            "button",
+            "button-primary",
+            "card",
+            "disabled",
+            "icon",
+            "link",
+            "selected"
+        ]
+    );
+    assert_eq!(scoped.classes["button-primary"], "button-primary_abc123");
+}
+
+#[test]
+fn reports_the_line_of_errors() {
+    assert_eq!(
+        scope_css(".a { color: red }\n.b { color: blue", "id"),
+        Err(ScopeError {
+            line: 2,
+            message: "unclosed `{`"
+        })
+    );
+    assert_eq!(
+        scope_css(".a {}\n}", "id").unwrap_err().to_string(),
+        "unexpected `}` on line 2"
+    );
+    assert_eq!(scope_css("\n:global(.a { }", "id").unwrap_err().line, 2);
+}
+
+#[test]
+fn module_ids_only_depend_on_the_location() {
+    let id = module_id("app", "src/button.module.css");
+    assert_eq!(id.len(), 8);
+    assert_eq!(id, module_id("app", "./src/button.module.css"));
+    assert_eq!(id, module_id("app", "src\\button.module.css"));
+    assert_ne!(id, module_id("other", "src/button.module.css"));
+    assert_ne!(id, module_id("app", "src/card.module.css"));
+}
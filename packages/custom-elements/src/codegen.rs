@@ -0,0 +1,588 @@
+use crate::manifest::{Declaration, Deprecated, Package, Type};
+use crate::Error;
+use convert_case::{Case, Casing};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::HashSet;
+use std::path::Path;
+
+const HEADER: &str = "// This file was generated by dioxus-custom-elements from a Custom Elements Manifest.\n// Do not edit it by hand.\n\n";
+
+/// Generates rsx bindings from a Custom Elements Manifest.
+///
+/// The generated file contains a `dioxus_elements` module with every element from `dioxus-html` plus:
+/// - an element for every custom element, written without the dashes in its tag name
+/// - a constant for every attribute of the element, along with an extension trait for the element
+/// - a listener for every event the element dispatches in `dioxus_elements::events`
+///
+/// Attributes that accept a fixed set of strings also get an enum, e.g. `SlButtonVariant` for the `variant` attribute of
+/// `<sl-button>`, next to the `dioxus_elements` module.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    dioxus_path: String,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            dioxus_path: "dioxus".to_string(),
+        }
+    }
+}
+
+impl Generator {
+    /// Create a generator with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the path the generated code uses to refer to the `dioxus` crate. Libraries that depend on `dioxus-lib`
+    /// instead of `dioxus` can set this to `dioxus_lib`. Defaults to `dioxus`.
+    pub fn dioxus_path(mut self, path: impl Into<String>) -> Self {
+        self.dioxus_path = path.into();
+        self
+    }
+
+    /// Generate bindings from the contents of a `custom-elements.json` file
+    pub fn generate(&self, manifest: &str) -> Result<String, Error> {
+        let package: Package = serde_json::from_str(manifest)?;
+        self.generate_package(&package)
+    }
+
+    /// Generate bindings from a `custom-elements.json` file and write them to the output file
+    pub fn generate_file(
+        &self,
+        manifest: impl AsRef<Path>,
+        output: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        let manifest = std::fs::read_to_string(manifest)?;
+        std::fs::write(output, self.generate(&manifest)?)?;
+        Ok(())
+    }
+
+    /// Generate bindings for a manifest that was already parsed
+    pub fn generate_package(&self, package: &Package) -> Result<String, Error> {
+        let dioxus: syn::Path = syn::parse_str(&self.dioxus_path)
+            .map_err(|_| Error::InvalidDioxusPath(self.dioxus_path.clone()))?;
+
+        let mut tags = HashSet::new();
+        let mut idents = HashSet::new();
+        let elements: Vec<_> = package
+            .custom_elements()
+            .filter(|declaration| tags.insert(declaration.tag_name.clone()))
+            .map(|declaration| CustomElement::new(declaration, &mut idents))
+            .collect();
+
+        let file: syn::File = syn::parse2(write_bindings(&dioxus, &elements))?;
+        Ok(format!("{HEADER}{}", prettyplease::unparse(&file)))
+    }
+}
+
+/// A custom element from the manifest, with the names it is given in rust
+struct CustomElement<'a> {
+    tag: &'a str,
+    ident: Ident,
+    docs: Vec<String>,
+    deprecated: Option<String>,
+    attributes: Vec<ElementAttribute>,
+    events: Vec<ElementEvent>,
+}
+
+struct ElementAttribute {
+    ident: Ident,
+    name: String,
+    docs: Vec<String>,
+    deprecated: Option<String>,
+    values: Option<AttributeValues>,
+}
+
+/// The enum generated for an attribute that accepts a fixed set of strings
+struct AttributeValues {
+    ident: Ident,
+    variants: Vec<(Ident, String)>,
+}
+
+struct ElementEvent {
+    ident: Ident,
+    name: String,
+    docs: Vec<String>,
+    deprecated: Option<String>,
+}
+
+impl<'a> CustomElement<'a> {
+    fn new(declaration: &'a Declaration, idents: &mut HashSet<String>) -> Self {
+        let tag = declaration.tag_name.as_deref().unwrap_or_default();
+        let type_name = tag.to_case(Case::UpperCamel);
+
+        // Elements with an underscore would be parsed as components, so the dashes are removed instead
+        let base: String = tag
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        let mut name = base.clone();
+        let mut suffix = 1;
+        while !idents.insert(name.clone()) {
+            suffix += 1;
+            name = format!("{base}{suffix}");
+        }
+
+        let mut docs = description(
+            declaration.summary.as_deref(),
+            declaration.description.as_deref(),
+        );
+        if !docs.is_empty() {
+            docs.push(String::new());
+        }
+        docs.push(format!("Rendered as `<{tag}>`."));
+
+        let mut attributes: Vec<ElementAttribute> = Vec::new();
+        let mut attribute_names = HashSet::new();
+        for attribute in &declaration.attributes {
+            if !attribute_names.insert(attribute.name.to_ascii_lowercase()) {
+                continue;
+            }
+            // The type is often only written on the field the attribute is reflected to
+            let ty = attribute.ty.as_ref().or_else(|| {
+                declaration
+                    .members
+                    .iter()
+                    .find(|member| {
+                        attribute.field_name.as_deref() == Some(member.name.as_str())
+                            || member.attribute.as_deref() == Some(attribute.name.as_str())
+                    })
+                    .and_then(|member| member.ty.as_ref())
+            });
+            attributes.push(ElementAttribute::new(
+                &type_name,
+                &attribute.name,
+                description(
+                    attribute.summary.as_deref(),
+                    attribute.description.as_deref(),
+                ),
+                ty,
+                attribute.default.as_deref(),
+                attribute.deprecated.as_ref(),
+            ));
+        }
+        // Fields can be reflected to attributes that aren't listed in the attributes of the element
+        for member in &declaration.members {
+            let Some(name) = &member.attribute else {
+                continue;
+            };
+            if member.kind != "field" || !attribute_names.insert(name.to_ascii_lowercase()) {
+                continue;
+            }
+            attributes.push(ElementAttribute::new(
+                &type_name,
+                name,
+                description(None, member.description.as_deref()),
+                member.ty.as_ref(),
+                None,
+                None,
+            ));
+        }
+
+        let mut event_names = HashSet::new();
+        let events = declaration
+            .events
+            .iter()
+            .filter_map(|event| {
+                let name = event.name.as_deref()?;
+                if !event_names.insert(name) {
+                    return None;
+                }
+                let mut docs = description(event.summary.as_deref(), event.description.as_deref());
+                if let Some(detail) = event.ty.as_ref().and_then(|ty| event_detail(&ty.text)) {
+                    if !docs.is_empty() {
+                        docs.push(String::new());
+                    }
+                    docs.push(format!("The `detail` of the event is `{detail}`."));
+                }
+                Some(ElementEvent {
+                    ident: rust_ident(&format!("on{}", name.to_case(Case::Snake))),
+                    name: name.to_string(),
+                    docs,
+                    deprecated: event.deprecated.as_ref().and_then(deprecation_note),
+                })
+            })
+            .collect();
+
+        Self {
+            tag,
+            ident: Ident::new(&name, Span::call_site()),
+            docs,
+            deprecated: declaration.deprecated.as_ref().and_then(deprecation_note),
+            attributes,
+            events,
+        }
+    }
+
+    /// The name of the extension trait for the element, following the naming of the extensions in `dioxus-html`
+    fn extension_ident(&self) -> Ident {
+        format_ident!(
+            "{}Extension",
+            self.ident.to_string().to_case(Case::UpperCamel)
+        )
+    }
+}
+
+impl ElementAttribute {
+    fn new(
+        type_name: &str,
+        name: &str,
+        mut docs: Vec<String>,
+        ty: Option<&Type>,
+        default: Option<&str>,
+        deprecated: Option<&Deprecated>,
+    ) -> Self {
+        let values = ty.and_then(|ty| string_literals(&ty.text)).map(|values| {
+            let mut variants: Vec<(Ident, String)> = Vec::new();
+            for value in values {
+                let mut variant = variant_name(&value);
+                if variants.iter().any(|(ident, _)| *ident == variant) {
+                    variant = format!("{variant}{}", variants.len() + 1);
+                }
+                variants.push((Ident::new(&variant, Span::call_site()), value));
+            }
+            AttributeValues {
+                ident: format_ident!("{type_name}{}", name.to_case(Case::UpperCamel)),
+                variants,
+            }
+        });
+
+        if !docs.is_empty() {
+            docs.push(String::new());
+        }
+        match (&values, ty) {
+            (Some(values), _) => docs.push(format!(
+                "Accepts a [`{0}`](super::super::super::{0}) or a string.",
+                values.ident
+            )),
+            (None, Some(ty)) if ty.text.trim() == "boolean" => docs.push(
+                "A boolean attribute. The element is enabled by the presence of the attribute, so leave it out instead of setting it to `false`."
+                    .to_string(),
+            ),
+            (None, Some(ty)) => docs.push(format!("Type: `{}`", ty.text.trim())),
+            (None, None) => {}
+        }
+        if let Some(default) = default {
+            docs.push(String::new());
+            docs.push(format!("Default: `{}`", default.trim()));
+        }
+
+        Self {
+            ident: rust_ident(&name.to_case(Case::Snake)),
+            name: name.to_string(),
+            docs,
+            deprecated: deprecated.and_then(deprecation_note),
+            values,
+        }
+    }
+}
+
+fn write_bindings(dioxus: &syn::Path, elements: &[CustomElement]) -> TokenStream {
+    let element_idents = elements.iter().map(|element| &element.ident);
+
+    let element_modules = elements.iter().map(|element| {
+        let CustomElement { tag, ident, .. } = element;
+        let attrs = item_attributes(&element.docs, element.deprecated.as_deref());
+        let attributes = element.attributes.iter().map(|attribute| {
+            let ElementAttribute { ident, name, .. } = attribute;
+            let attrs = item_attributes(&attribute.docs, attribute.deprecated.as_deref());
+            quote! {
+                #attrs
+                pub const #ident: AttributeDiscription = (#name, None, false);
+            }
+        });
+        quote! {
+            #attrs
+            pub mod #ident {
+                pub use #dioxus::html::global_attributes::*;
+                use #dioxus::html::AttributeDiscription;
+
+                pub const TAG_NAME: &str = #tag;
+                pub const NAME_SPACE: Option<&str> = None;
+
+                #(#attributes)*
+            }
+        }
+    });
+
+    let extensions = elements.iter().map(|element| {
+        let element_ident = &element.ident;
+        let extension = element.extension_ident();
+        let methods = element.attributes.iter().map(|attribute| {
+            let ident = &attribute.ident;
+            quote! {
+                fn #ident(self, value: impl IntoAttributeValue) -> Self {
+                    let d = super::elements::#element_ident::#ident;
+                    self.push_attribute(d.0, d.1, value, d.2)
+                }
+            }
+        });
+        quote! {
+            pub trait #extension: HasAttributes + Sized {
+                #(#methods)*
+            }
+        }
+    });
+
+    // Elements often dispatch the same events, but a listener can only be defined once
+    let mut event_names = HashSet::new();
+    let events = elements
+        .iter()
+        .flat_map(|element| &element.events)
+        .filter(|event| event_names.insert(event.ident.to_string()))
+        .map(|event| {
+            let ident = &event.ident;
+            let listener = format!("on{}", event.name);
+            let attrs = item_attributes(&event.docs, event.deprecated.as_deref());
+            quote! {
+                #attrs
+                #[doc(alias = #listener)]
+                #[inline]
+                pub fn #ident<__Marker>(
+                    mut _f: impl SuperInto<EventHandler<CustomEvent>, __Marker>,
+                ) -> Attribute {
+                    let event_handler = _f.super_into();
+                    Attribute::new(
+                        #listener,
+                        AttributeValue::listener(move |e: Event<PlatformEventData>| {
+                            event_handler.call(e.map(|e| e.into()));
+                        }),
+                        None,
+                        false,
+                    )
+                }
+
+                #[doc(hidden)]
+                pub mod #ident {
+                    use super::*;
+
+                    // rsx uses this version of the listener when it sees an inline closure for better type inference
+                    pub fn call_with_explicit_closure<
+                        __Marker,
+                        Return: SpawnIfAsync<__Marker> + 'static,
+                    >(
+                        event_handler: impl FnMut(CustomEvent) -> Return + 'static,
+                    ) -> Attribute {
+                        super::#ident(event_handler)
+                    }
+                }
+            }
+        });
+
+    let value_enums = elements
+        .iter()
+        .flat_map(|element| {
+            element
+                .attributes
+                .iter()
+                .filter_map(move |attribute| Some((element, attribute, attribute.values.as_ref()?)))
+        })
+        .map(|(element, attribute, values)| {
+            let AttributeValues { ident, variants } = values;
+            let description = format!(
+                " The values of the `{}` attribute of `<{}>`",
+                attribute.name, element.tag
+            );
+            let expected = format!("{} value", attribute.name);
+            let variant_idents: Vec<_> = variants.iter().map(|(ident, _)| ident).collect();
+            let variant_values: Vec<_> = variants.iter().map(|(_, value)| value).collect();
+            let variant_docs = variant_values.iter().map(|value| format!(" `{value}`"));
+            quote! {
+                #[doc = #description]
+                #[allow(dead_code)]
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+                pub enum #ident {
+                    #(
+                        #[doc = #variant_docs]
+                        #variant_idents,
+                    )*
+                }
+
+                impl #ident {
+                    /// Every valid value
+                    pub const ALL: &'static [Self] = &[#(Self::#variant_idents),*];
+
+                    /// Get the value as it is written in HTML
+                    pub fn as_str(&self) -> &'static str {
+                        match self {
+                            #(Self::#variant_idents => #variant_values,)*
+                        }
+                    }
+                }
+
+                impl std::fmt::Display for #ident {
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        f.write_str(self.as_str())
+                    }
+                }
+
+                impl std::str::FromStr for #ident {
+                    type Err = #dioxus::html::ParseAttributeValueError;
+
+                    fn from_str(value: &str) -> Result<Self, Self::Err> {
+                        Self::ALL
+                            .iter()
+                            .find(|valid| valid.as_str().eq_ignore_ascii_case(value.trim()))
+                            .copied()
+                            .ok_or_else(|| {
+                                #dioxus::html::ParseAttributeValueError::new(
+                                    #expected,
+                                    value,
+                                    Self::ALL.iter().map(Self::as_str),
+                                )
+                            })
+                    }
+                }
+
+                impl #dioxus::prelude::IntoAttributeValue for #ident {
+                    fn into_value(self) -> #dioxus::html::__private::AttributeValue {
+                        #dioxus::html::__private::AttributeValue::Text(self.as_str().to_string())
+                    }
+                }
+            }
+        });
+
+    quote! {
+        /// The elements from `dioxus-html` along with the custom elements from the manifest.
+        ///
+        /// Bring this module into scope after the prelude to use the custom elements in rsx.
+        #[allow(
+            non_upper_case_globals,
+            non_camel_case_types,
+            deprecated,
+            dead_code,
+            unused_imports
+        )]
+        pub mod dioxus_elements {
+            pub use #dioxus::html::*;
+            pub use self::elements::{#(#element_idents),*};
+
+            pub mod elements {
+                pub use #dioxus::html::elements::*;
+
+                #(#element_modules)*
+            }
+
+            pub mod extensions {
+                pub use #dioxus::html::extensions::*;
+                use #dioxus::prelude::{HasAttributes, IntoAttributeValue};
+
+                #(#extensions)*
+            }
+
+            pub mod events {
+                pub use #dioxus::html::events::*;
+                use #dioxus::html::__private::{
+                    Attribute, AttributeValue, Event, EventHandler, SpawnIfAsync, SuperInto,
+                };
+                use #dioxus::html::{CustomEvent, PlatformEventData};
+
+                #(#events)*
+            }
+        }
+
+        #(#value_enums)*
+    }
+}
+
+/// The doc comments and deprecation attribute for a generated item
+fn item_attributes(docs: &[String], deprecated: Option<&str>) -> TokenStream {
+    let docs = docs.iter().map(|line| format!(" {line}"));
+    let deprecated = deprecated.map(|note| match note {
+        "" => quote! { #[deprecated] },
+        note => quote! { #[deprecated = #note] },
+    });
+    quote! {
+        #(#[doc = #docs])*
+        #deprecated
+    }
+}
+
+/// Combine the summary and description of an item into lines of documentation
+fn description(summary: Option<&str>, description: Option<&str>) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut push = |text: &str| {
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.extend(text.lines().map(|line| line.trim_end().to_string()));
+    };
+    if let Some(summary) = summary {
+        push(summary);
+    }
+    if let Some(description) = description.filter(|description| Some(*description) != summary) {
+        push(description);
+    }
+    lines
+}
+
+fn deprecation_note(deprecated: &Deprecated) -> Option<String> {
+    deprecated.note().map(str::to_string)
+}
+
+/// Parse a union of string literals like `'small' | 'medium' | 'large'`
+fn string_literals(ty: &str) -> Option<Vec<String>> {
+    let mut values: Vec<String> = Vec::new();
+    for part in ty.split('|').map(str::trim) {
+        if matches!(part, "" | "undefined" | "null") {
+            continue;
+        }
+        let unquoted = ['\'', '"', '`'].iter().find_map(|quote| {
+            part.strip_prefix(*quote)
+                .and_then(|part| part.strip_suffix(*quote))
+        })?;
+        if !values.iter().any(|value| value == unquoted) {
+            values.push(unquoted.to_string());
+        }
+    }
+    (!values.is_empty()).then_some(values)
+}
+
+/// Get the type of the detail from an event type like `CustomEvent<{ value: string }>`
+fn event_detail(ty: &str) -> Option<&str> {
+    let detail = ty
+        .trim()
+        .strip_prefix("CustomEvent<")?
+        .strip_suffix('>')?
+        .trim();
+    (!detail.is_empty()).then_some(detail)
+}
+
+fn variant_name(value: &str) -> String {
+    let name: String = value
+        .to_case(Case::UpperCamel)
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
+    match name.chars().next() {
+        None => "Empty".to_string(),
+        Some(first) if first.is_ascii_digit() => format!("V{name}"),
+        Some(_) => name,
+    }
+}
+
+/// Turn a name into a rust identifier, escaping keywords
+fn rust_ident(name: &str) -> Ident {
+    let mut name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    match syn::parse_str::<Ident>(&name) {
+        Ok(ident) => ident,
+        Err(_) if matches!(name.as_str(), "self" | "super" | "crate" | "Self") => {
+            format_ident!("{name}_")
+        }
+        Err(_) => Ident::new_raw(&name, Span::call_site()),
+    }
+}
@@ -0,0 +1,45 @@
+#![doc = include_str!("../README.md")]
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+
+mod codegen;
+pub mod manifest;
+
+pub use codegen::Generator;
+
+use std::path::Path;
+
+/// An error that occurred while generating bindings
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to read the manifest: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse the manifest: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    #[error("`{0}` is not a valid path to the dioxus crate")]
+    InvalidDioxusPath(String),
+
+    #[error("Failed to generate bindings: {0}")]
+    Codegen(#[from] syn::Error),
+}
+
+/// Generate bindings for every custom element in the contents of a `custom-elements.json` file
+pub fn generate(manifest: &str) -> Result<String, Error> {
+    Generator::new().generate(manifest)
+}
+
+/// Generate bindings for every custom element in a `custom-elements.json` file and write them to the output file.
+///
+/// This is meant to be called from a build script:
+///
+/// ```rust, no_run
+/// // In the main function of build.rs
+/// println!("cargo:rerun-if-changed=custom-elements.json");
+/// let out = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("components.rs");
+/// dioxus_custom_elements::generate_file("custom-elements.json", out).unwrap();
+/// ```
+pub fn generate_file(manifest: impl AsRef<Path>, output: impl AsRef<Path>) -> Result<(), Error> {
+    Generator::new().generate_file(manifest, output)
+}
@@ -0,0 +1,167 @@
+//! The subset of the [Custom Elements Manifest](https://github.com/webcomponents/custom-elements-manifest) schema used
+//! to generate bindings. Fields that aren't needed are ignored when the manifest is parsed.
+
+use serde::Deserialize;
+
+/// The root of a `custom-elements.json` file
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Package {
+    /// The version of the schema the manifest was written with
+    #[serde(default)]
+    pub schema_version: Option<String>,
+    /// The modules in the package
+    #[serde(default)]
+    pub modules: Vec<Module>,
+}
+
+impl Package {
+    /// Iterate over every custom element declared in the package
+    pub fn custom_elements(&self) -> impl Iterator<Item = &Declaration> {
+        self.modules
+            .iter()
+            .flat_map(|module| &module.declarations)
+            .filter(|declaration| declaration.custom_element && declaration.tag_name.is_some())
+    }
+}
+
+/// A JavaScript module in the package
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Module {
+    /// The path to the module
+    pub path: String,
+    /// The classes, functions and variables declared in the module
+    #[serde(default)]
+    pub declarations: Vec<Declaration>,
+}
+
+/// A declaration in a module. Only class declarations with `customElement: true` are used.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Declaration {
+    /// The kind of declaration, e.g. `class`
+    #[serde(default)]
+    pub kind: String,
+    /// The name of the class
+    pub name: String,
+    /// If the class is a custom element
+    #[serde(default)]
+    pub custom_element: bool,
+    /// The tag name the element is registered with, e.g. `sl-button`
+    #[serde(default)]
+    pub tag_name: Option<String>,
+    /// A short description of the element
+    #[serde(default)]
+    pub summary: Option<String>,
+    /// A longer description of the element
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The attributes the element observes
+    #[serde(default)]
+    pub attributes: Vec<Attribute>,
+    /// The fields and methods of the class
+    #[serde(default)]
+    pub members: Vec<ClassMember>,
+    /// The events the element dispatches
+    #[serde(default)]
+    pub events: Vec<Event>,
+    /// If the element is deprecated, either `true` or the reason it was deprecated
+    #[serde(default)]
+    pub deprecated: Option<Deprecated>,
+}
+
+/// An attribute observed by a custom element
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attribute {
+    /// The name of the attribute, e.g. `help-text`
+    pub name: String,
+    /// A short description of the attribute
+    #[serde(default)]
+    pub summary: Option<String>,
+    /// A longer description of the attribute
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The TypeScript type of the attribute
+    #[serde(default, rename = "type")]
+    pub ty: Option<Type>,
+    /// The default value of the attribute as it is written in JavaScript
+    #[serde(default)]
+    pub default: Option<String>,
+    /// The name of the property the attribute is reflected to
+    #[serde(default)]
+    pub field_name: Option<String>,
+    /// If the attribute is deprecated, either `true` or the reason it was deprecated
+    #[serde(default)]
+    pub deprecated: Option<Deprecated>,
+}
+
+/// A field or method of a custom element class
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassMember {
+    /// Either `field` or `method`
+    #[serde(default)]
+    pub kind: String,
+    /// The name of the member
+    pub name: String,
+    /// A longer description of the member
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The TypeScript type of the field
+    #[serde(default, rename = "type")]
+    pub ty: Option<Type>,
+    /// The attribute the field is reflected to
+    #[serde(default)]
+    pub attribute: Option<String>,
+}
+
+/// An event dispatched by a custom element
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Event {
+    /// The name of the event, e.g. `sl-change`
+    #[serde(default)]
+    pub name: Option<String>,
+    /// A short description of the event
+    #[serde(default)]
+    pub summary: Option<String>,
+    /// A longer description of the event
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The TypeScript type of the event, e.g. `CustomEvent<{ value: string }>`
+    #[serde(default, rename = "type")]
+    pub ty: Option<Type>,
+    /// If the event is deprecated, either `true` or the reason it was deprecated
+    #[serde(default)]
+    pub deprecated: Option<Deprecated>,
+}
+
+/// A TypeScript type
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct Type {
+    /// The type as it is written in TypeScript, e.g. `'small' | 'medium' | 'large'`
+    pub text: String,
+}
+
+/// The deprecation status of a declaration
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum Deprecated {
+    /// `true` if the declaration is deprecated without a reason
+    Flag(bool),
+    /// The reason the declaration is deprecated
+    Reason(String),
+}
+
+impl Deprecated {
+    /// Get the note for a `#[deprecated]` attribute, or `None` if the declaration isn't deprecated
+    pub fn note(&self) -> Option<&str> {
+        match self {
+            Deprecated::Flag(true) => Some(""),
+            Deprecated::Flag(false) => None,
+            Deprecated::Reason(reason) => Some(reason),
+        }
+    }
+}
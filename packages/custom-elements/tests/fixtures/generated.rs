@@ -0,0 +1,403 @@
+// This file was generated by dioxus-custom-elements from a Custom Elements Manifest.
+// Do not edit it by hand.
+
+/// The elements from `dioxus-html` along with the custom elements from the manifest.
+///
+/// Bring this module into scope after the prelude to use the custom elements in rsx.
+#[allow(
+    non_upper_case_globals,
+    non_camel_case_types,
+    deprecated,
+    dead_code,
+    unused_imports
+)]
+pub mod dioxus_elements {
+    pub use dioxus::html::*;
+    pub use self::elements::{slbutton, slinput};
+    pub mod elements {
+        pub use dioxus::html::elements::*;
+        /// Buttons represent actions that are available to the user.
+        ///
+        /// Rendered as `<sl-button>`.
+        pub mod slbutton {
+            pub use dioxus::html::global_attributes::*;
+            use dioxus::html::AttributeDiscription;
+            pub const TAG_NAME: &str = "sl-button";
+            pub const NAME_SPACE: Option<&str> = None;
+            /// The button's theme variant.
+            ///
+            /// Accepts a [`SlButtonVariant`](super::super::super::SlButtonVariant) or a string.
+            ///
+            /// Default: `'default'`
+            pub const variant: AttributeDiscription = ("variant", None, false);
+            /// Accepts a [`SlButtonSize`](super::super::super::SlButtonSize) or a string.
+            pub const size: AttributeDiscription = ("size", None, false);
+            /// Draws a pill-style button with rounded edges.
+            ///
+            /// A boolean attribute. The element is enabled by the presence of the attribute, so leave it out instead of setting it to `false`.
+            ///
+            /// Default: `false`
+            pub const pill: AttributeDiscription = ("pill", None, false);
+            /// Accepts a [`SlButtonType`](super::super::super::SlButtonType) or a string.
+            pub const r#type: AttributeDiscription = ("type", None, false);
+            /// Type: `string`
+            pub const help_text: AttributeDiscription = ("help-text", None, false);
+            /// Draws the button with a caret.
+            ///
+            /// A boolean attribute. The element is enabled by the presence of the attribute, so leave it out instead of setting it to `false`.
+            pub const caret: AttributeDiscription = ("caret", None, false);
+        }
+        /// Rendered as `<sl-input>`.
+        pub mod slinput {
+            pub use dioxus::html::global_attributes::*;
+            use dioxus::html::AttributeDiscription;
+            pub const TAG_NAME: &str = "sl-input";
+            pub const NAME_SPACE: Option<&str> = None;
+            /// Type: `string`
+            pub const value: AttributeDiscription = ("value", None, false);
+            /// Accepts a [`SlInputAutocorrect`](super::super::super::SlInputAutocorrect) or a string.
+            #[deprecated = "Use `autocomplete` instead"]
+            pub const autocorrect: AttributeDiscription = ("autocorrect", None, false);
+        }
+    }
+    pub mod extensions {
+        pub use dioxus::html::extensions::*;
+        use dioxus::prelude::{HasAttributes, IntoAttributeValue};
+        pub trait SlbuttonExtension: HasAttributes + Sized {
+            fn variant(self, value: impl IntoAttributeValue) -> Self {
+                let d = super::elements::slbutton::variant;
+                self.push_attribute(d.0, d.1, value, d.2)
+            }
+            fn size(self, value: impl IntoAttributeValue) -> Self {
+                let d = super::elements::slbutton::size;
+                self.push_attribute(d.0, d.1, value, d.2)
+            }
+            fn pill(self, value: impl IntoAttributeValue) -> Self {
+                let d = super::elements::slbutton::pill;
+                self.push_attribute(d.0, d.1, value, d.2)
+            }
+            fn r#type(self, value: impl IntoAttributeValue) -> Self {
+                let d = super::elements::slbutton::r#type;
+                self.push_attribute(d.0, d.1, value, d.2)
+            }
+            fn help_text(self, value: impl IntoAttributeValue) -> Self {
+                let d = super::elements::slbutton::help_text;
+                self.push_attribute(d.0, d.1, value, d.2)
+            }
+            fn caret(self, value: impl IntoAttributeValue) -> Self {
+                let d = super::elements::slbutton::caret;
+                self.push_attribute(d.0, d.1, value, d.2)
+            }
+        }
+        pub trait SlinputExtension: HasAttributes + Sized {
+            fn value(self, value: impl IntoAttributeValue) -> Self {
+                let d = super::elements::slinput::value;
+                self.push_attribute(d.0, d.1, value, d.2)
+            }
+            fn autocorrect(self, value: impl IntoAttributeValue) -> Self {
+                let d = super::elements::slinput::autocorrect;
+                self.push_attribute(d.0, d.1, value, d.2)
+            }
+        }
+    }
+    pub mod events {
+        pub use dioxus::html::events::*;
+        use dioxus::html::__private::{
+            Attribute, AttributeValue, Event, EventHandler, SpawnIfAsync, SuperInto,
+        };
+        use dioxus::html::{CustomEvent, PlatformEventData};
+        /// Emitted when the button loses focus.
+        #[doc(alias = "onsl-blur")]
+        #[inline]
+        pub fn onsl_blur<__Marker>(
+            mut _f: impl SuperInto<EventHandler<CustomEvent>, __Marker>,
+        ) -> Attribute {
+            let event_handler = _f.super_into();
+            Attribute::new(
+                "onsl-blur",
+                AttributeValue::listener(move |e: Event<PlatformEventData>| {
+                    event_handler.call(e.map(|e| e.into()));
+                }),
+                None,
+                false,
+            )
+        }
+        #[doc(hidden)]
+        pub mod onsl_blur {
+            use super::*;
+            pub fn call_with_explicit_closure<
+                __Marker,
+                Return: SpawnIfAsync<__Marker> + 'static,
+            >(event_handler: impl FnMut(CustomEvent) -> Return + 'static) -> Attribute {
+                super::onsl_blur(event_handler)
+            }
+        }
+        /// Emitted when the button gains focus.
+        #[doc(alias = "onsl-focus")]
+        #[inline]
+        pub fn onsl_focus<__Marker>(
+            mut _f: impl SuperInto<EventHandler<CustomEvent>, __Marker>,
+        ) -> Attribute {
+            let event_handler = _f.super_into();
+            Attribute::new(
+                "onsl-focus",
+                AttributeValue::listener(move |e: Event<PlatformEventData>| {
+                    event_handler.call(e.map(|e| e.into()));
+                }),
+                None,
+                false,
+            )
+        }
+        #[doc(hidden)]
+        pub mod onsl_focus {
+            use super::*;
+            pub fn call_with_explicit_closure<
+                __Marker,
+                Return: SpawnIfAsync<__Marker> + 'static,
+            >(event_handler: impl FnMut(CustomEvent) -> Return + 'static) -> Attribute {
+                super::onsl_focus(event_handler)
+            }
+        }
+        /// Emitted when the control receives input.
+        ///
+        /// The `detail` of the event is `{ value: string }`.
+        #[doc(alias = "onsl-input")]
+        #[inline]
+        pub fn onsl_input<__Marker>(
+            mut _f: impl SuperInto<EventHandler<CustomEvent>, __Marker>,
+        ) -> Attribute {
+            let event_handler = _f.super_into();
+            Attribute::new(
+                "onsl-input",
+                AttributeValue::listener(move |e: Event<PlatformEventData>| {
+                    event_handler.call(e.map(|e| e.into()));
+                }),
+                None,
+                false,
+            )
+        }
+        #[doc(hidden)]
+        pub mod onsl_input {
+            use super::*;
+            pub fn call_with_explicit_closure<
+                __Marker,
+                Return: SpawnIfAsync<__Marker> + 'static,
+            >(event_handler: impl FnMut(CustomEvent) -> Return + 'static) -> Attribute {
+                super::onsl_input(event_handler)
+            }
+        }
+    }
+}
+/// The values of the `variant` attribute of `<sl-button>`
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SlButtonVariant {
+    /// `default`
+    Default,
+    /// `primary`
+    Primary,
+    /// `success`
+    Success,
+    /// `neutral`
+    Neutral,
+    /// `warning`
+    Warning,
+    /// `danger`
+    Danger,
+    /// `text`
+    Text,
+}
+impl SlButtonVariant {
+    /// Every valid value
+    pub const ALL: &'static [Self] = &[
+        Self::Default,
+        Self::Primary,
+        Self::Success,
+        Self::Neutral,
+        Self::Warning,
+        Self::Danger,
+        Self::Text,
+    ];
+    /// Get the value as it is written in HTML
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Primary => "primary",
+            Self::Success => "success",
+            Self::Neutral => "neutral",
+            Self::Warning => "warning",
+            Self::Danger => "danger",
+            Self::Text => "text",
+        }
+    }
+}
+impl std::fmt::Display for SlButtonVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for SlButtonVariant {
+    type Err = dioxus::html::ParseAttributeValueError;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|valid| valid.as_str().eq_ignore_ascii_case(value.trim()))
+            .copied()
+            .ok_or_else(|| {
+                dioxus::html::ParseAttributeValueError::new(
+                    "variant value",
+                    value,
+                    Self::ALL.iter().map(Self::as_str),
+                )
+            })
+    }
+}
+impl dioxus::prelude::IntoAttributeValue for SlButtonVariant {
+    fn into_value(self) -> dioxus::html::__private::AttributeValue {
+        dioxus::html::__private::AttributeValue::Text(self.as_str().to_string())
+    }
+}
+/// The values of the `size` attribute of `<sl-button>`
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SlButtonSize {
+    /// `small`
+    Small,
+    /// `medium`
+    Medium,
+    /// `large`
+    Large,
+}
+impl SlButtonSize {
+    /// Every valid value
+    pub const ALL: &'static [Self] = &[Self::Small, Self::Medium, Self::Large];
+    /// Get the value as it is written in HTML
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Small => "small",
+            Self::Medium => "medium",
+            Self::Large => "large",
+        }
+    }
+}
+impl std::fmt::Display for SlButtonSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for SlButtonSize {
+    type Err = dioxus::html::ParseAttributeValueError;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|valid| valid.as_str().eq_ignore_ascii_case(value.trim()))
+            .copied()
+            .ok_or_else(|| {
+                dioxus::html::ParseAttributeValueError::new(
+                    "size value",
+                    value,
+                    Self::ALL.iter().map(Self::as_str),
+                )
+            })
+    }
+}
+impl dioxus::prelude::IntoAttributeValue for SlButtonSize {
+    fn into_value(self) -> dioxus::html::__private::AttributeValue {
+        dioxus::html::__private::AttributeValue::Text(self.as_str().to_string())
+    }
+}
+/// The values of the `type` attribute of `<sl-button>`
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SlButtonType {
+    /// `button`
+    Button,
+    /// `submit`
+    Submit,
+    /// `reset`
+    Reset,
+}
+impl SlButtonType {
+    /// Every valid value
+    pub const ALL: &'static [Self] = &[Self::Button, Self::Submit, Self::Reset];
+    /// Get the value as it is written in HTML
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Button => "button",
+            Self::Submit => "submit",
+            Self::Reset => "reset",
+        }
+    }
+}
+impl std::fmt::Display for SlButtonType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for SlButtonType {
+    type Err = dioxus::html::ParseAttributeValueError;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|valid| valid.as_str().eq_ignore_ascii_case(value.trim()))
+            .copied()
+            .ok_or_else(|| {
+                dioxus::html::ParseAttributeValueError::new(
+                    "type value",
+                    value,
+                    Self::ALL.iter().map(Self::as_str),
+                )
+            })
+    }
+}
+impl dioxus::prelude::IntoAttributeValue for SlButtonType {
+    fn into_value(self) -> dioxus::html::__private::AttributeValue {
+        dioxus::html::__private::AttributeValue::Text(self.as_str().to_string())
+    }
+}
+/// The values of the `autocorrect` attribute of `<sl-input>`
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SlInputAutocorrect {
+    /// `off`
+    Off,
+    /// `on`
+    On,
+}
+impl SlInputAutocorrect {
+    /// Every valid value
+    pub const ALL: &'static [Self] = &[Self::Off, Self::On];
+    /// Get the value as it is written in HTML
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::On => "on",
+        }
+    }
+}
+impl std::fmt::Display for SlInputAutocorrect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl std::str::FromStr for SlInputAutocorrect {
+    type Err = dioxus::html::ParseAttributeValueError;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|valid| valid.as_str().eq_ignore_ascii_case(value.trim()))
+            .copied()
+            .ok_or_else(|| {
+                dioxus::html::ParseAttributeValueError::new(
+                    "autocorrect value",
+                    value,
+                    Self::ALL.iter().map(Self::as_str),
+                )
+            })
+    }
+}
+impl dioxus::prelude::IntoAttributeValue for SlInputAutocorrect {
+    fn into_value(self) -> dioxus::html::__private::AttributeValue {
+        dioxus::html::__private::AttributeValue::Text(self.as_str().to_string())
+    }
+}
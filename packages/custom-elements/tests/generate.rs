@@ -0,0 +1,62 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+
+mod components {
+    include!("fixtures/generated.rs");
+}
+use components::dioxus_elements;
+use components::*;
+
+#[test]
+fn generated_bindings_are_up_to_date() {
+    let generated =
+        dioxus_custom_elements::generate(include_str!("fixtures/custom-elements.json")).unwrap();
+    pretty_assertions::assert_eq!(generated, include_str!("fixtures/generated.rs"));
+}
+
+#[test]
+fn custom_elements_render_with_typed_attributes() {
+    fn app() -> Element {
+        rsx! {
+            slbutton {
+                variant: SlButtonVariant::Primary,
+                size: "small",
+                help_text: "Save your changes",
+                r#type: SlButtonType::Submit,
+                onsl_focus: |_| {},
+                "Save"
+            }
+            slinput { onsl_input: move |event| println!("{:?}", event.detail_json()) }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"<sl-button variant="primary" size="small" help-text="Save your changes" type="submit">Save</sl-button><sl-input></sl-input>"#
+    );
+}
+
+#[test]
+fn attribute_values_suggest_the_closest_value() {
+    assert_eq!("Danger".parse(), Ok(SlButtonVariant::Danger));
+    let error = "primry".parse::<SlButtonVariant>().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "`primry` is not a valid variant value, did you mean `primary`?"
+    );
+}
+
+#[test]
+fn only_custom_elements_are_generated() {
+    let generated =
+        dioxus_custom_elements::generate(r#"{ "modules": [{ "path": "a.js", "declarations": [{ "kind": "class", "name": "Plain" }] }] }"#)
+            .unwrap();
+    assert!(!generated.contains("Plain"));
+    assert!(dioxus_custom_elements::Generator::new()
+        .dioxus_path("not a path")
+        .generate("{}")
+        .is_err());
+}
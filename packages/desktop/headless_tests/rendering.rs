@@ -10,6 +10,10 @@ fn main() {
 }
 
 fn use_inner_html(id: &'static str) -> Option<String> {
+    use_element_property(id, "innerHTML")
+}
+
+fn use_element_property(id: &'static str, property: &'static str) -> Option<String> {
     let mut value = use_signal(|| None as Option<String>);
 
     use_effect(move || {
@@ -18,8 +22,8 @@ fn use_inner_html(id: &'static str) -> Option<String> {
 
             let res = eval(&format!(
                 r#"let element = document.getElementById('{}');
-                return element.innerHTML"#,
-                id
+                return element.{}"#,
+                id, property
             ))
             .await
             .unwrap();
@@ -34,18 +38,22 @@ fn use_inner_html(id: &'static str) -> Option<String> {
     value()
 }
 
-const EXPECTED_HTML: &str = r#"<div style="width: 100px; height: 100px; color: rgb(0, 0, 0);" id="5"><input type="checkbox"><h1>text</h1><div><p>hello world</p></div></div>"#;
+const EXPECTED_HTML: &str = r#"<div style="width: 100px; height: 100px; color: rgb(0, 0, 0);" id="5"><input type="checkbox"><h1>text</h1><div><p>hello world</p></div><math id="math"><mi>x</mi></math></div>"#;
+
+const MATHML_NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
 
 fn check_html_renders() -> Element {
     let inner_html = use_inner_html("main_div");
+    let math_namespace = use_element_property("math", "namespaceURI");
 
     let desktop_context: DesktopContext = consume_context();
 
-    if let Some(raw_html) = inner_html {
+    if let (Some(raw_html), Some(math_namespace)) = (inner_html, math_namespace) {
         println!("{}", raw_html);
         let fragment = &raw_html;
         let expected = EXPECTED_HTML;
         assert_eq!(raw_html, EXPECTED_HTML);
+        assert_eq!(math_namespace, MATHML_NAMESPACE);
         if fragment == expected {
             println!("html matches");
             desktop_context.close();
@@ -65,6 +73,7 @@ fn check_html_renders() -> Element {
                 input { "type": "checkbox" }
                 h1 { "text" }
                 {dyn_element}
+                math { id: "math", mi { "x" } }
             }
         }
     }
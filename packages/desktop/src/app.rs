@@ -343,6 +343,12 @@ impl App {
                     webview.kick_stylsheets();
                 }
             }
+
+            dioxus_hot_reload::HotReloadMsg::UpdateCssModule { id, css } => {
+                for webview in self.webviews.values_mut() {
+                    webview.update_css_module(&id, &css);
+                }
+            }
         }
     }
 
@@ -1,3 +1,4 @@
+use dioxus_core::TemplateCache;
 use std::borrow::Cow;
 use std::path::PathBuf;
 use tao::window::{Icon, WindowBuilder};
@@ -33,6 +34,7 @@ pub struct Config {
     pub(crate) root_name: String,
     pub(crate) background_color: Option<(u8, u8, u8, u8)>,
     pub(crate) last_window_close_behavior: WindowCloseBehaviour,
+    pub(crate) template_cache: Option<TemplateCache>,
 }
 
 pub(crate) type WryProtocol = (
@@ -73,6 +75,7 @@ impl Config {
             root_name: "main".to_string(),
             background_color: None,
             last_window_close_behavior: WindowCloseBehaviour::LastWindowExitsApp,
+            template_cache: None,
         }
     }
 
@@ -201,6 +204,14 @@ impl Config {
         self
     }
 
+    /// Load the templates in a [`TemplateCache`] before the first render.
+    ///
+    /// By default, the window loads the `templates.json` cache `dx build` writes next to the assets if it exists.
+    pub fn with_template_cache(mut self, cache: TemplateCache) -> Self {
+        self.template_cache = Some(cache);
+        self
+    }
+
     /// Sets the menu the window will use. This will override the default menu bar.
     ///
     /// > Note: A default menu bar will be enabled unless the menu is overridden or set to `None`.
@@ -1,8 +1,16 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
 use dioxus_core::ElementId;
 use dioxus_html::{
     geometry::{PixelsRect, PixelsSize, PixelsVector2D},
-    MountedResult, RenderedElementBacking,
+    IntersectionObservation, IntersectionOptions, MountedError, MountedResult, Observer,
+    RenderedElementBacking, ResizeObservation,
 };
+use serde::de::DeserializeOwned;
 
 use crate::{desktop_context::DesktopContext, query::QueryEngine};
 
@@ -18,6 +26,47 @@ impl DesktopElement {
     pub(crate) fn new(id: ElementId, webview: DesktopContext, query: QueryEngine) -> Self {
         Self { id, webview, query }
     }
+
+    /// Run a script that creates an observer for this element in the interpreter under a unique key. The script returns
+    /// `false` if the element can't be observed, and otherwise sends every observation back through the query until the
+    /// observer is dropped.
+    #[allow(clippy::type_complexity)]
+    fn observe<T: DeserializeOwned + 'static>(
+        &self,
+        script: impl FnOnce(usize) -> String,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<Observer<T>>>>> {
+        static NEXT_OBSERVER: AtomicUsize = AtomicUsize::new(0);
+        let key = NEXT_OBSERVER.fetch_add(1, Ordering::Relaxed);
+
+        let mut query = self
+            .query
+            .new_query::<bool>(&script(key), self.webview.clone());
+        let engine = self.query.clone();
+        let webview = self.webview.clone();
+        Box::pin(async move {
+            match query.result().await.map(serde_json::from_value::<bool>) {
+                Ok(Ok(true)) => {}
+                Ok(_) => {
+                    return Err(MountedError::OperationFailed(Box::new(
+                        DesktopQueryError::FailedToQuery,
+                    )))
+                }
+                Err(err) => return Err(MountedError::OperationFailed(Box::new(err))),
+            }
+
+            let observations = futures_util::stream::poll_fn(move |cx| {
+                query.poll_recv(cx).map(|message| {
+                    message
+                        .ok()
+                        .and_then(|value| serde_json::from_value(value).ok())
+                })
+            });
+            Ok(Observer::new(observations, move || {
+                let script = format!("window.interpreter.disconnectObserver({key}); return true;");
+                _ = engine.new_query::<bool>(&script, webview);
+            }))
+        })
+    }
 }
 
 macro_rules! scripted_getter {
@@ -124,6 +173,33 @@ impl RenderedElementBacking for DesktopElement {
             }
         })
     }
+
+    fn observe_resize(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<Observer<ResizeObservation>>>>> {
+        let id = self.id.0;
+        self.observe(|key| {
+            format!(
+                "return window.interpreter.observeResize({id}, {key}, (data) => dioxus.send(data));"
+            )
+        })
+    }
+
+    fn observe_intersection(
+        &self,
+        options: IntersectionOptions,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<Observer<IntersectionObservation>>>>> {
+        let id = self.id.0;
+        let root_margin =
+            serde_json::to_string(&options.root_margin).expect("Failed to serialize root margin");
+        let thresholds =
+            serde_json::to_string(&options.thresholds).expect("Failed to serialize thresholds");
+        self.observe(|key| {
+            format!(
+                "return window.interpreter.observeIntersection({id}, {key}, {root_margin}, {thresholds}, (data) => dioxus.send(data));"
+            )
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -17,6 +17,14 @@ impl HtmlEventConverter for SerializedHtmlEventConverter {
             .into()
     }
 
+    fn convert_before_input_data(&self, event: &PlatformEventData) -> BeforeInputData {
+        event
+            .downcast::<SerializedBeforeInputData>()
+            .cloned()
+            .unwrap()
+            .into()
+    }
+
     fn convert_clipboard_data(&self, event: &PlatformEventData) -> ClipboardData {
         event
             .downcast::<SerializedClipboardData>()
@@ -33,6 +41,14 @@ impl HtmlEventConverter for SerializedHtmlEventConverter {
             .into()
     }
 
+    fn convert_custom_data(&self, event: &PlatformEventData) -> CustomData {
+        event
+            .downcast::<SerializedCustomData>()
+            .cloned()
+            .unwrap()
+            .into()
+    }
+
     fn convert_drag_data(&self, event: &PlatformEventData) -> DragData {
         // Attempt a simple serialized data conversion
         if let Some(_data) = event.downcast::<SerializedDragData>() {
@@ -69,6 +85,14 @@ impl HtmlEventConverter for SerializedHtmlEventConverter {
             .into()
     }
 
+    fn convert_fullscreen_data(&self, event: &PlatformEventData) -> FullscreenData {
+        event
+            .downcast::<SerializedFullscreenData>()
+            .cloned()
+            .unwrap()
+            .into()
+    }
+
     fn convert_image_data(&self, event: &PlatformEventData) -> ImageData {
         event
             .downcast::<SerializedImageData>()
@@ -113,6 +137,14 @@ impl HtmlEventConverter for SerializedHtmlEventConverter {
             .into()
     }
 
+    fn convert_resize_data(&self, event: &PlatformEventData) -> ResizeData {
+        event
+            .downcast::<SerializedResizeData>()
+            .cloned()
+            .unwrap()
+            .into()
+    }
+
     fn convert_scroll_data(&self, event: &PlatformEventData) -> ScrollData {
         event
             .downcast::<SerializedScrollData>()
@@ -153,6 +185,14 @@ impl HtmlEventConverter for SerializedHtmlEventConverter {
             .into()
     }
 
+    fn convert_visibility_data(&self, event: &PlatformEventData) -> VisibilityData {
+        event
+            .downcast::<SerializedVisibilityData>()
+            .cloned()
+            .unwrap()
+            .into()
+    }
+
     fn convert_wheel_data(&self, event: &PlatformEventData) -> WheelData {
         event
             .downcast::<SerializedWheelData>()
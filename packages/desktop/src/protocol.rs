@@ -1,4 +1,5 @@
 use crate::{assets::*, edits::EditQueue};
+use dioxus_core::TemplateCache;
 use dioxus_interpreter_js::eval::NATIVE_EVAL_JS;
 use dioxus_interpreter_js::unified_bindings::SLEDGEHAMMER_JS;
 use dioxus_interpreter_js::NATIVE_JS;
@@ -227,6 +228,18 @@ fn module_loader(root_id: &str, headless: bool) -> String {
     )
 }
 
+/// Load the template cache `dx build` writes next to the assets if there is one
+pub(crate) fn load_template_cache() -> Option<TemplateCache> {
+    let data = std::fs::read(get_asset_root_or_default().join("templates.json")).ok()?;
+    match TemplateCache::load(data, serde_json::from_slice) {
+        Ok(cache) => Some(cache),
+        Err(err) => {
+            tracing::warn!("Failed to load the template cache: {err}");
+            None
+        }
+    }
+}
+
 /// Get the asset directory, following tauri/cargo-bundles directory discovery approach
 ///
 /// Defaults to the current directory if no asset directory is found, which is useful for development when the app
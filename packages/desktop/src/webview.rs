@@ -30,7 +30,7 @@ pub(crate) struct WebviewInstance {
 impl WebviewInstance {
     pub(crate) fn new(
         mut cfg: Config,
-        dom: VirtualDom,
+        mut dom: VirtualDom,
         shared: Rc<SharedContext>,
     ) -> WebviewInstance {
         let mut window = cfg.window.clone();
@@ -213,6 +213,19 @@ impl WebviewInstance {
             file_hover,
         ));
 
+        // Load the templates ahead of time so the first render only sends the dynamic parts of each template
+        if let Some(cache) = cfg
+            .template_cache
+            .take()
+            .or_else(protocol::load_template_cache)
+        {
+            desktop_context
+                .mutation_state
+                .borrow_mut()
+                .preload_templates(&cache);
+            dom.preload_templates(&cache);
+        }
+
         let provider: Rc<dyn EvalProvider> =
             Rc::new(DesktopEvalProvider::new(desktop_context.clone()));
 
@@ -268,4 +281,13 @@ impl WebviewInstance {
             .webview
             .evaluate_script("window.interpreter.kickAllStylesheetsOnPage()");
     }
+
+    #[cfg(all(feature = "hot-reload", debug_assertions))]
+    pub fn update_css_module(&self, id: &str, css: &str) {
+        // replace the contents of every style element rendered for the module
+        let css = serde_json::to_string(css).unwrap();
+        _ = self.desktop_context.webview.evaluate_script(&format!(
+            r#"document.querySelectorAll('style[data-css-module="{id}"]').forEach((style) => style.textContent = {css})"#
+        ));
+    }
 }
@@ -33,7 +33,7 @@ pub mod prelude {
 
     #[cfg(feature = "macro")]
     #[allow(deprecated)]
-    pub use dioxus_core_macro::{component, rsx, Props};
+    pub use dioxus_core_macro::{component, css_module, rsx, Props};
 
     #[cfg(feature = "macro")]
     pub use dioxus_config_macro::*;
@@ -42,7 +42,7 @@ pub mod prelude {
     pub use dioxus_html as dioxus_elements;
 
     #[cfg(feature = "html")]
-    pub use dioxus_elements::{global_attributes, prelude::*, svg_attributes};
+    pub use dioxus_elements::{global_attributes, mathml_attributes, prelude::*, svg_attributes};
 
     pub use dioxus_core;
 }
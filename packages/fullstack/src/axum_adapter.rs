@@ -66,6 +66,7 @@ use std::sync::Arc;
 
 use crate::launch::ContextProviders;
 use crate::prelude::*;
+use crate::websocket::{websocket_server_fns, WebsocketServerFn};
 
 /// A extension trait with utilities for integrating Dioxus with your Axum router.
 pub trait DioxusRouterExt<S> {
@@ -200,6 +201,27 @@ where
             };
         }
 
+        for websocket in websocket_server_fns() {
+            tracing::trace!(
+                "Registering websocket server function: {}",
+                websocket.path()
+            );
+            let context_providers = context_providers.clone();
+            let handler = move |req| {
+                handle_websocket_server_fn(
+                    websocket,
+                    move |server_context| {
+                        for context_provider in context_providers.iter() {
+                            let context = context_provider();
+                            server_context.insert_any(context);
+                        }
+                    },
+                    req,
+                )
+            };
+            self = self.route(websocket.path(), get(handler));
+        }
+
         self
     }
 
@@ -415,6 +437,88 @@ fn report_err<E: std::fmt::Display>(e: E) -> Response<axum::body::Body> {
         .unwrap()
 }
 
+/// Run the error mapper on a server function error response and set the status code from the error
+async fn map_server_fn_error(
+    res: Response<Body>,
+    mapper: Option<ServerFnErrorMapper>,
+) -> Response<Body> {
+    use crate::error::{decode_server_fn_error, server_fn_error_status};
+    use server_fn::error::{ServerFnErrorSerde, SERVER_FN_ERROR_HEADER};
+
+    if !res.headers().contains_key(SERVER_FN_ERROR_HEADER) {
+        return res;
+    }
+
+    let (mut parts, body) = res.into_parts();
+    let bytes = match body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(err) => return report_err(err),
+    };
+    let Some(error) = std::str::from_utf8(&bytes)
+        .ok()
+        .and_then(decode_server_fn_error)
+    else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+
+    let error = match mapper {
+        Some(mapper) => mapper.map(error),
+        None => error,
+    };
+    parts.status = StatusCode::from_u16(server_fn_error_status(&error))
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    // The body may have changed length
+    parts.headers.remove(CONTENT_LENGTH);
+    let body = error.ser().unwrap_or_else(|_| error.to_string());
+    Response::from_parts(parts, Body::from(body))
+}
+
+/// A handler for websocket server functions. This upgrades the connection and runs the server function with the
+/// server context of the handshake request.
+async fn handle_websocket_server_fn(
+    websocket: &'static WebsocketServerFn,
+    additional_context: impl Fn(&DioxusServerContext) + 'static + Clone + Send,
+    req: Request<Body>,
+) -> Response<Body> {
+    use axum::extract::{ws::WebSocketUpgrade, FromRequestParts};
+
+    let (mut parts, _) = req.into_parts();
+    let upgrade = match WebSocketUpgrade::from_request_parts(&mut parts, &()).await {
+        Ok(upgrade) => upgrade,
+        Err(rejection) => return rejection.into_response(),
+    };
+
+    let server_context = DioxusServerContext::new(parts);
+    additional_context(&server_context);
+
+    let middleware = websocket.middleware();
+    if let Some(middleware) = &middleware {
+        let before =
+            ProvideServerContext::new(middleware.before(&server_context), server_context.clone());
+        if let Err(error) = before.await {
+            return crate::middleware::rejection_response(websocket.path(), error);
+        }
+    }
+
+    let response = upgrade.on_upgrade({
+        let server_context = server_context.clone();
+        move |socket| ProvideServerContext::new(websocket.run(socket), server_context)
+    });
+
+    match middleware {
+        Some(middleware) => {
+            let (mut parts, body) = response.into_parts();
+            ProvideServerContext::new(
+                middleware.after(&server_context, &mut parts),
+                server_context.clone(),
+            )
+            .await;
+            Response::from_parts(parts, body)
+        }
+        None => response,
+    }
+}
+
 /// A handler for Dioxus server functions. This will run the server function and return the result.
 async fn handle_server_fns_inner(
     path: &str,
@@ -445,7 +549,10 @@ async fn handle_server_fns_inner(
             let referrer = req.headers().get(REFERER).cloned();
 
             // actually run the server fn (which may use the server context)
-            let mut res = ProvideServerContext::new(service.run(req), server_context.clone()).await;
+            let res = ProvideServerContext::new(service.run(req), server_context.clone()).await;
+
+            // map any errors from the server function and send them with the right status code
+            let mut res = map_server_fn_error(res, server_context.get::<ServerFnErrorMapper>()).await;
 
             // it it accepts text/html (i.e., is a plain form post) and doesn't already have a
             // Location set, then redirect to Referer
@@ -0,0 +1,178 @@
+//! Typed server function errors with HTTP status codes
+
+use std::{fmt::Display, str::FromStr};
+
+/// An error with an HTTP status code.
+///
+/// Use `ServerFnError<StatusError>` as the error type of a server function to receive the status code on the client. When a server
+/// function or [`ServerFnMiddleware`](crate::prelude::ServerFnMiddleware) returns a `StatusError`, the response is sent with the status code
+/// of the error.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+///
+/// #[server]
+/// async fn delete_post(id: u32) -> Result<(), ServerFnError<StatusError>> {
+///     if id == 0 {
+///         return Err(StatusError::not_found("That post does not exist").into());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StatusError {
+    /// The HTTP status code of the error
+    pub status: u16,
+    /// A message describing the error
+    pub message: String,
+}
+
+impl StatusError {
+    /// Create a new error with a status code and message
+    pub fn new(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+
+    /// Create a new `400 Bad Request` error
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(400, message)
+    }
+
+    /// Create a new `401 Unauthorized` error
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::new(401, message)
+    }
+
+    /// Create a new `403 Forbidden` error
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::new(403, message)
+    }
+
+    /// Create a new `404 Not Found` error
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(404, message)
+    }
+
+    /// Create a new `429 Too Many Requests` error
+    pub fn too_many_requests(message: impl Into<String>) -> Self {
+        Self::new(429, message)
+    }
+
+    /// Create a new `500 Internal Server Error` error
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(500, message)
+    }
+}
+
+impl Display for StatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.status, self.message)
+    }
+}
+
+impl std::error::Error for StatusError {}
+
+/// The status error could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseStatusError;
+
+impl Display for ParseStatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to parse status error")
+    }
+}
+
+impl std::error::Error for ParseStatusError {}
+
+impl FromStr for StatusError {
+    type Err = ParseStatusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (status, message) = s.split_once(' ').unwrap_or((s, ""));
+        let status = status.parse().map_err(|_| ParseStatusError)?;
+        Ok(Self::new(status, message))
+    }
+}
+
+#[cfg(feature = "server")]
+pub use server::*;
+
+#[cfg(feature = "server")]
+mod server {
+    use super::*;
+    use server_fn::{error::ServerFnErrorSerde, ServerFnError};
+    use std::sync::Arc;
+
+    /// A central place to map the errors returned by every server function before they are sent to the client.
+    ///
+    /// Provide the mapper as server context in the launch builder. The mapper receives every error from server functions
+    /// and [`ServerFnMiddleware`](crate::prelude::ServerFnMiddleware). The HTTP status code of the response is set from the
+    /// mapped error.
+    ///
+    /// ```rust, no_run
+    /// use dioxus::prelude::*;
+    ///
+    /// LaunchBuilder::new()
+    ///     .with_context(server_only! {
+    ///         ServerFnErrorMapper::new(|error| match error {
+    ///             // Don't leak the details of internal errors to the client
+    ///             ServerFnError::ServerError(_) => StatusError::internal("Something went wrong").into(),
+    ///             error => error,
+    ///         })
+    ///     })
+    ///     .launch(app);
+    ///
+    /// fn app() -> Element {
+    ///     rsx! {}
+    /// }
+    /// ```
+    #[derive(Clone)]
+    #[allow(clippy::type_complexity)]
+    pub struct ServerFnErrorMapper(
+        Arc<dyn Fn(ServerFnError<StatusError>) -> ServerFnError<StatusError> + Send + Sync>,
+    );
+
+    impl ServerFnErrorMapper {
+        /// Create a new error mapper from a function
+        pub fn new(
+            map: impl Fn(ServerFnError<StatusError>) -> ServerFnError<StatusError>
+                + Send
+                + Sync
+                + 'static,
+        ) -> Self {
+            Self(Arc::new(map))
+        }
+
+        /// Map an error
+        pub fn map(&self, error: ServerFnError<StatusError>) -> ServerFnError<StatusError> {
+            (self.0)(error)
+        }
+    }
+
+    /// Decode a serialized server function error. Returns `None` if the error is not a server function error or contains a
+    /// custom error type other than [`StatusError`].
+    pub(crate) fn decode_server_fn_error(data: &str) -> Option<ServerFnError<StatusError>> {
+        let (ty, error) = data.split_once('|')?;
+        if ty == "WrappedServerFn" && error.parse::<StatusError>().is_err() {
+            return None;
+        }
+        match ServerFnError::<StatusError>::de(data) {
+            ServerFnError::Deserialization(_) if ty != "Deserialization" => None,
+            error => Some(error),
+        }
+    }
+
+    /// Get the HTTP status code a server function error should be sent with
+    pub(crate) fn server_fn_error_status(error: &ServerFnError<StatusError>) -> u16 {
+        match error {
+            ServerFnError::WrappedServerError(error) => error.status,
+            ServerFnError::Args(_)
+            | ServerFnError::MissingArg(_)
+            | ServerFnError::Deserialization(_) => 400,
+            _ => 500,
+        }
+    }
+}
@@ -0,0 +1,635 @@
+//! Typed forms with validation that submit to server functions
+
+use dioxus_lib::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use server_fn::{error::NoCustomError, ServerFnError};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Display,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    str::FromStr,
+};
+
+/// Validation errors for the fields of a form.
+///
+/// Use `ServerFnError<FieldErrors>` as the error type of a server function to send errors for individual fields back to
+/// a form created with [`use_form`].
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// # #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)] struct SignUpForm { email: String }
+///
+/// #[server]
+/// async fn sign_up(form: SignUpForm) -> Result<(), ServerFnError<FieldErrors>> {
+///     if form.email == "taken@example.com" {
+///         return Err(FieldErrors::new().with_field("email", "That email is already taken").into());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldErrors {
+    /// The errors for each field of the form
+    pub fields: BTreeMap<String, Vec<String>>,
+    /// Errors that are not tied to a specific field
+    pub form: Vec<String>,
+}
+
+impl FieldErrors {
+    /// Create an empty set of errors
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an error for a field
+    pub fn with_field(mut self, field: impl Into<String>, error: impl Into<String>) -> Self {
+        self.add_field(field, error);
+        self
+    }
+
+    /// Add an error that is not tied to a specific field
+    pub fn with_form(mut self, error: impl Into<String>) -> Self {
+        self.add_form(error);
+        self
+    }
+
+    /// Add an error for a field
+    pub fn add_field(&mut self, field: impl Into<String>, error: impl Into<String>) {
+        self.fields
+            .entry(field.into())
+            .or_default()
+            .push(error.into());
+    }
+
+    /// Add an error that is not tied to a specific field
+    pub fn add_form(&mut self, error: impl Into<String>) {
+        self.form.push(error.into());
+    }
+
+    /// Get the errors for a field
+    pub fn field(&self, field: &str) -> &[String] {
+        self.fields
+            .get(field)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Add all errors from another set of errors
+    pub fn extend(&mut self, other: FieldErrors) {
+        for (field, errors) in other.fields {
+            self.fields.entry(field).or_default().extend(errors);
+        }
+        self.form.extend(other.form);
+    }
+
+    /// Check if there are no errors
+    pub fn is_empty(&self) -> bool {
+        self.form.is_empty() && self.fields.values().all(Vec::is_empty)
+    }
+}
+
+// The errors are sent from the server as JSON
+impl Display for FieldErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let json = serde_json::to_string(self).map_err(|_| std::fmt::Error)?;
+        write!(f, "{json}")
+    }
+}
+
+impl std::error::Error for FieldErrors {}
+
+impl FromStr for FieldErrors {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+impl From<ServerFnError<FieldErrors>> for FieldErrors {
+    fn from(error: ServerFnError<FieldErrors>) -> Self {
+        match error {
+            ServerFnError::WrappedServerError(errors) => errors,
+            error => FieldErrors::new().with_form(error.to_string()),
+        }
+    }
+}
+
+impl From<ServerFnError<NoCustomError>> for FieldErrors {
+    fn from(error: ServerFnError<NoCustomError>) -> Self {
+        FieldErrors::new().with_form(error.to_string())
+    }
+}
+
+/// Create a form that binds inputs to the fields of a serializable struct.
+///
+/// Every input is parsed into the type of its field as the user types. Values that fail to parse and errors from the
+/// validators are tracked per field. When the form is submitted, errors the server returns with [`FieldErrors`] are
+/// mapped back to the fields.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+///
+/// #[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+/// struct SignUpForm {
+///     email: String,
+///     age: u32,
+///     subscribe: bool,
+/// }
+///
+/// #[server]
+/// async fn sign_up(form: SignUpForm) -> Result<(), ServerFnError<FieldErrors>> {
+///     Ok(())
+/// }
+///
+/// fn App() -> Element {
+///     let mut form = use_form::<SignUpForm>();
+///     form.validate_field("email", |form| match form.email.contains('@') {
+///         true => Ok(()),
+///         false => Err("Enter a valid email".to_string()),
+///     });
+///     form.validate_field("age", |form| match form.age >= 13 {
+///         true => Ok(()),
+///         false => Err("You must be at least 13".to_string()),
+///     });
+///
+///     rsx! {
+///         form { onsubmit: move |_| form.submit(sign_up),
+///             input { r#type: "email", ..form.bind("email") }
+///             if form.is_touched("email") {
+///                 if let Some(error) = form.error("email") {
+///                     p { "{error}" }
+///                 }
+///             }
+///             input { r#type: "number", ..form.bind("age") }
+///             input { r#type: "checkbox", ..form.bind("subscribe") }
+///             button { disabled: form.submitting(), "Sign up" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_form<T>() -> UseForm<T>
+where
+    T: Serialize + DeserializeOwned + Clone + Default + 'static,
+{
+    use_hook(|| {
+        let values = T::default();
+        let initial = serde_json::to_value(&values).unwrap_or_default();
+        UseForm {
+            values: Signal::new(values),
+            json: Signal::new(initial.clone()),
+            state: Signal::new(FormState::default()),
+            validators: CopyValue::new(Validators {
+                initial,
+                field: Vec::new(),
+                form: None,
+                asynchronous: Vec::new(),
+                generations: HashMap::new(),
+                submissions: 0,
+            }),
+            scope: current_scope_id().expect("to be in a dioxus runtime"),
+        }
+    })
+}
+
+/// A form created with [`use_form`]
+pub struct UseForm<T: 'static> {
+    values: Signal<T>,
+    /// The values serialized to JSON. This is updated every time the values are written so reading a field doesn't
+    /// serialize the whole form
+    json: Signal<Value>,
+    state: Signal<FormState>,
+    validators: CopyValue<Validators<T>>,
+    scope: ScopeId,
+}
+
+impl<T> Clone for UseForm<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UseForm<T> {}
+
+impl<T> PartialEq for UseForm<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
+#[derive(Default)]
+struct FormState {
+    /// The text of inputs that could not be parsed into their field
+    inputs: HashMap<String, String>,
+    parse_errors: FieldErrors,
+    sync_errors: FieldErrors,
+    async_errors: HashMap<Option<&'static str>, FieldErrors>,
+    server_errors: FieldErrors,
+    touched: HashSet<String>,
+    validating: usize,
+    submitting: bool,
+}
+
+type FieldValidator<T> = Rc<dyn Fn(&T) -> Result<(), String>>;
+type FormValidator<T> = Rc<dyn Fn(&T) -> Result<(), FieldErrors>>;
+type AsyncValidator<T> = Rc<dyn Fn(T) -> Pin<Box<dyn Future<Output = Result<(), FieldErrors>>>>>;
+
+struct Validators<T> {
+    /// The serialized default values, used to check if fields are dirty
+    initial: Value,
+    field: Vec<(&'static str, FieldValidator<T>)>,
+    form: Option<FormValidator<T>>,
+    /// Async validators for a field, or for the whole form if the field is `None`
+    asynchronous: Vec<(Option<&'static str>, AsyncValidator<T>)>,
+    /// The number of times each async validator has started so results from outdated runs can be ignored
+    generations: HashMap<Option<&'static str>, usize>,
+    /// The number of times the form was submitted or reset so submissions from before a reset can be ignored
+    submissions: usize,
+}
+
+impl<T> UseForm<T>
+where
+    T: Serialize + DeserializeOwned + Clone + Default + 'static,
+{
+    /// Get the parsed values of the form
+    pub fn values(&self) -> ReadOnlySignal<T> {
+        self.values.into()
+    }
+
+    /// Get the current text of a field
+    pub fn value(&self, field: &str) -> String {
+        if let Some(input) = self.state.read().inputs.get(field) {
+            return input.clone();
+        }
+        match self.json.read().get(field) {
+            None | Some(Value::Null) => String::new(),
+            Some(Value::String(value)) => value.clone(),
+            Some(Value::Array(values)) => values
+                .iter()
+                .map(|value| match value {
+                    Value::String(value) => value.clone(),
+                    value => value.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(","),
+            Some(value) => value.to_string(),
+        }
+    }
+
+    /// Set the text of a field. The text is parsed into the type of the field and the form is validated again.
+    pub fn set(&mut self, field: &str, input: impl Into<String>) {
+        let input = input.into();
+        let mut values = self.json.peek().clone();
+        if !values.is_object() {
+            tracing::error!("The values of a form must serialize to a map");
+            return;
+        }
+
+        let mut error = None;
+        for candidate in candidates(values.get(field), &input) {
+            values[field] = candidate;
+            match T::deserialize(&values) {
+                Ok(parsed) => {
+                    self.json
+                        .set(serde_json::to_value(&parsed).unwrap_or_default());
+                    self.values.set(parsed);
+                    error = None;
+                    break;
+                }
+                Err(err) => {
+                    error.get_or_insert(err.to_string());
+                }
+            }
+        }
+
+        let mut state = self.state.write();
+        state.server_errors.fields.remove(field);
+        state.parse_errors.fields.remove(field);
+        match error {
+            Some(error) => {
+                state.inputs.insert(field.to_string(), input);
+                state.parse_errors.add_field(field, error);
+            }
+            None => {
+                state.inputs.remove(field);
+            }
+        }
+        drop(state);
+
+        self.run_validators(Some(field));
+    }
+
+    /// Mark a field as touched. Bound inputs are touched when they lose focus.
+    pub fn touch(&mut self, field: &str) {
+        if !self.state.peek().touched.contains(field) {
+            self.state.write().touched.insert(field.to_string());
+        }
+    }
+
+    /// Check if a field was touched
+    pub fn is_touched(&self, field: &str) -> bool {
+        self.state.read().touched.contains(field)
+    }
+
+    /// Check if the value of a field is different from its default value
+    pub fn is_dirty(&self, field: &str) -> bool {
+        self.state.read().inputs.contains_key(field)
+            || self.json.read().get(field) != self.validators.read().initial.get(field)
+    }
+
+    /// Check if any field is different from its default value
+    pub fn dirty(&self) -> bool {
+        !self.state.read().inputs.is_empty() || *self.json.read() != self.validators.read().initial
+    }
+
+    /// Get all errors from parsing, validation and the last submission
+    pub fn errors(&self) -> FieldErrors {
+        let state = self.state.read();
+        let mut errors = state.parse_errors.clone();
+        errors.extend(state.sync_errors.clone());
+        for async_errors in state.async_errors.values() {
+            errors.extend(async_errors.clone());
+        }
+        errors.extend(state.server_errors.clone());
+        errors
+    }
+
+    /// Get the first error for a field
+    pub fn error(&self, field: &str) -> Option<String> {
+        self.errors().field(field).first().cloned()
+    }
+
+    /// Check if the form has no errors
+    pub fn is_valid(&self) -> bool {
+        self.errors().is_empty()
+    }
+
+    /// Check if any async validators are running
+    pub fn validating(&self) -> bool {
+        self.state.read().validating > 0
+    }
+
+    /// Check if the form is being submitted
+    pub fn submitting(&self) -> bool {
+        self.state.read().submitting
+    }
+
+    /// Get the attributes that bind an input to a field. Spread them into an input, select or textarea element.
+    pub fn bind(&self, field: &'static str) -> Vec<Attribute> {
+        let mut form = *self;
+        let checkbox = matches!(self.json.read().get(field), Some(Value::Bool(_)));
+        let mut attributes = vec![Attribute::new("name", field, None, false)];
+        if checkbox {
+            attributes.push(Attribute::new(
+                "checked",
+                self.value(field) == "true",
+                None,
+                true,
+            ));
+        } else {
+            attributes.push(Attribute::new("value", self.value(field), None, true));
+        }
+        attributes.push(dioxus_elements::events::oninput(move |event: FormEvent| {
+            form.set(field, event.value())
+        }));
+        attributes.push(dioxus_elements::events::onblur(move |_| form.touch(field)));
+        attributes
+    }
+
+    /// Validate a field. The validator runs every time the form changes.
+    pub fn validate_field(
+        &mut self,
+        field: &'static str,
+        validator: impl Fn(&T) -> Result<(), String> + 'static,
+    ) {
+        let mut validators = self.validators.write();
+        validators.field.retain(|(existing, _)| *existing != field);
+        validators.field.push((field, Rc::new(validator)));
+    }
+
+    /// Validate the whole form. The validator runs every time the form changes and can return errors for any field.
+    pub fn validate(&mut self, validator: impl Fn(&T) -> Result<(), FieldErrors> + 'static) {
+        self.validators.write().form = Some(Rc::new(validator));
+    }
+
+    /// Validate a field asynchronously. The validator runs when the field changes and when the form is submitted.
+    pub fn validate_field_async<F>(
+        &mut self,
+        field: &'static str,
+        validator: impl Fn(T) -> F + 'static,
+    ) where
+        F: Future<Output = Result<(), String>> + 'static,
+    {
+        let validator = Rc::new(validator);
+        self.set_async_validator(
+            Some(field),
+            Rc::new(move |values| {
+                let validator = validator.clone();
+                Box::pin(async move {
+                    validator(values)
+                        .await
+                        .map_err(|error| FieldErrors::new().with_field(field, error))
+                })
+            }),
+        );
+    }
+
+    /// Validate the whole form asynchronously. The validator runs when any field changes and when the form is submitted.
+    pub fn validate_async<F>(&mut self, validator: impl Fn(T) -> F + 'static)
+    where
+        F: Future<Output = Result<(), FieldErrors>> + 'static,
+    {
+        let validator = Rc::new(validator);
+        self.set_async_validator(None, Rc::new(move |values| Box::pin(validator(values))));
+    }
+
+    fn set_async_validator(&mut self, field: Option<&'static str>, validator: AsyncValidator<T>) {
+        let mut validators = self.validators.write();
+        validators
+            .asynchronous
+            .retain(|(existing, _)| *existing != field);
+        validators.asynchronous.push((field, validator));
+    }
+
+    /// Validate the form and submit it if it is valid. Every field is marked as touched.
+    ///
+    /// The values are passed to `submit`, usually a server function. Errors it returns are mapped back to the fields.
+    pub fn submit<F, E>(&self, submit: impl FnOnce(T) -> F + 'static)
+    where
+        F: Future<Output = Result<(), E>> + 'static,
+        E: Into<FieldErrors>,
+    {
+        let mut form = *self;
+        if form.state.peek().submitting {
+            return;
+        }
+
+        let submission = {
+            let mut validators = form.validators.write();
+            validators.submissions += 1;
+            validators.submissions
+        };
+        {
+            let values = form.json.peek();
+            let mut state = form.state.write();
+            if let Some(fields) = values.as_object() {
+                state.touched.extend(fields.keys().cloned());
+            }
+            state.server_errors = FieldErrors::new();
+            state.submitting = true;
+        }
+        form.run_sync_validators();
+
+        let validators = form.start_async_validators(None, true);
+        form.scope.spawn(async move {
+            // The form may be reset while it is being submitted. The reset form is not submitting anymore, so the
+            // results of this submission are ignored
+            let current = move || form.validators.peek().submissions == submission;
+            for (field, generation, validator) in validators {
+                let result = validator.await;
+                form.finish_async_validator(field, generation, result);
+            }
+
+            if current() && form.is_valid() {
+                let values = form.values.peek().clone();
+                let result = submit(values).await;
+                if let (Err(errors), true) = (result, current()) {
+                    form.state.write().server_errors = errors.into();
+                }
+            }
+            if current() {
+                form.state.write().submitting = false;
+            }
+        });
+    }
+
+    /// Reset every field to its default value and clear all errors. Results from validators and submissions that are
+    /// still running are ignored.
+    pub fn reset(&mut self) {
+        let mut validators = self.validators.write();
+        for generation in validators.generations.values_mut() {
+            *generation += 1;
+        }
+        validators.submissions += 1;
+        self.json.set(validators.initial.clone());
+        drop(validators);
+        self.values.set(T::default());
+        self.state.set(FormState::default());
+    }
+
+    fn run_validators(&mut self, changed: Option<&str>) {
+        self.run_sync_validators();
+        for (field, generation, validator) in self.start_async_validators(changed, false) {
+            let mut form = *self;
+            self.scope.spawn(async move {
+                let result = validator.await;
+                form.finish_async_validator(field, generation, result);
+            });
+        }
+    }
+
+    fn run_sync_validators(&mut self) {
+        let validators = self.validators.peek();
+        let values = self.values.peek();
+        let mut errors = FieldErrors::new();
+        for (field, validator) in &validators.field {
+            if let Err(error) = validator(&values) {
+                errors.add_field(*field, error);
+            }
+        }
+        if let Some(validator) = &validators.form {
+            if let Err(form_errors) = validator(&values) {
+                errors.extend(form_errors);
+            }
+        }
+        drop((validators, values));
+        self.state.write().sync_errors = errors;
+    }
+
+    /// Start the async validators that depend on the changed field, or all of them if `all` is set
+    #[allow(clippy::type_complexity)]
+    fn start_async_validators(
+        &mut self,
+        changed: Option<&str>,
+        all: bool,
+    ) -> Vec<(
+        Option<&'static str>,
+        usize,
+        Pin<Box<dyn Future<Output = Result<(), FieldErrors>>>>,
+    )> {
+        let values = self.values.peek().clone();
+        let mut validators = self.validators.write();
+        let validators = &mut *validators;
+        let started: Vec<_> = validators
+            .asynchronous
+            .iter()
+            .filter(|(field, _)| all || field.is_none() || *field == changed)
+            .map(|(field, validator)| {
+                let generation = validators.generations.entry(*field).or_default();
+                *generation += 1;
+                (*field, *generation, validator(values.clone()))
+            })
+            .collect();
+
+        if !started.is_empty() {
+            self.state.write().validating += started.len();
+        }
+        started
+    }
+
+    fn finish_async_validator(
+        &mut self,
+        field: Option<&'static str>,
+        generation: usize,
+        result: Result<(), FieldErrors>,
+    ) {
+        let current = self.validators.peek().generations.get(&field).copied();
+        let mut state = self.state.write();
+        state.validating = state.validating.saturating_sub(1);
+        // A newer run of the same validator replaces this result
+        if current == Some(generation) {
+            state
+                .async_errors
+                .insert(field, result.err().unwrap_or_default());
+        }
+    }
+}
+
+/// The JSON values an input could represent, starting with the type the field currently holds
+fn candidates(current: Option<&Value>, input: &str) -> Vec<Value> {
+    let number = serde_json::from_str::<serde_json::Number>(input.trim())
+        .ok()
+        .map(Value::Number);
+    let boolean = match input {
+        "true" | "on" => Some(Value::Bool(true)),
+        "false" | "" => Some(Value::Bool(false)),
+        _ => None,
+    };
+    let list = || {
+        Value::Array(
+            input
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| {
+                    serde_json::from_str::<serde_json::Number>(item)
+                        .map(Value::Number)
+                        .unwrap_or_else(|_| Value::String(item.to_string()))
+                })
+                .collect(),
+        )
+    };
+    let text = Value::String(input.to_string());
+    let empty = input.trim().is_empty().then_some(Value::Null);
+
+    let candidates = match current {
+        Some(Value::Bool(_)) => vec![boolean, Some(text)],
+        Some(Value::Number(_)) => vec![number, empty, Some(text)],
+        Some(Value::Array(_)) => vec![Some(list()), Some(text)],
+        _ => vec![Some(text), empty, number, boolean],
+    };
+    candidates.into_iter().flatten().collect()
+}
@@ -1,2 +1,16 @@
+#[cfg(any(
+    feature = "web",
+    feature = "desktop",
+    feature = "mobile",
+    feature = "server"
+))]
+pub mod form;
 pub mod server_cached;
 pub mod server_future;
+#[cfg(any(
+    feature = "web",
+    feature = "desktop",
+    feature = "mobile",
+    feature = "server"
+))]
+pub mod server_stream;
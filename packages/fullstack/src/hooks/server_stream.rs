@@ -0,0 +1,128 @@
+use dioxus_lib::prelude::*;
+use futures_util::StreamExt;
+use server_fn::{error::NoCustomError, ServerFnError};
+use std::future::Future;
+
+use crate::server_stream::ServerStream;
+
+/// Calls a streaming server function and collects the items it returns into a signal as they arrive.
+///
+/// Like [`use_resource`], the closure is reactive. When any signals read in the closure change, the stream is cancelled and the
+/// server function is called again. The stream is cancelled when the component is dropped.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+///
+/// #[server(output = ServerSentEvents)]
+/// async fn generate(prompt: String) -> Result<ServerStream<String>, ServerFnError> {
+///     let tokens = prompt.split(' ').map(|token| token.to_string()).collect::<Vec<_>>();
+///     Ok(ServerStream::new(futures_util::stream::iter(tokens)))
+/// }
+///
+/// fn app() -> Element {
+///     let prompt = use_signal(|| "Hello world".to_string());
+///     let tokens = use_server_stream(move || generate(prompt()));
+///
+///     rsx! {
+///         p {
+///             for token in tokens.items().iter() {
+///                 "{token} "
+///             }
+///         }
+///         if let Some(error) = tokens.error()() {
+///             p { "Error: {error}" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_server_stream<T, E, F>(mut future: impl FnMut() -> F + 'static) -> UseServerStream<T, E>
+where
+    T: 'static,
+    E: 'static,
+    F: Future<Output = Result<ServerStream<T, E>, ServerFnError<E>>> + 'static,
+{
+    let mut items = use_signal(Vec::new);
+    let mut error = use_signal(|| None);
+    let mut finished = use_signal(|| false);
+
+    let resource = use_resource(move || {
+        let stream = future();
+        async move {
+            items.write().clear();
+            error.set(None);
+            finished.set(false);
+
+            match stream.await {
+                Ok(mut stream) => {
+                    while let Some(item) = stream.next().await {
+                        match item {
+                            Ok(item) => items.write().push(item),
+                            Err(err) => error.set(Some(err)),
+                        }
+                    }
+                }
+                Err(err) => error.set(Some(err)),
+            }
+
+            finished.set(true);
+        }
+    });
+
+    UseServerStream {
+        items,
+        error,
+        finished,
+        resource,
+    }
+}
+
+/// The state of a streaming server function created with [`use_server_stream`]
+pub struct UseServerStream<T: 'static, E: 'static = NoCustomError> {
+    items: Signal<Vec<T>>,
+    error: Signal<Option<ServerFnError<E>>>,
+    finished: Signal<bool>,
+    resource: Resource<()>,
+}
+
+impl<T, E> UseServerStream<T, E> {
+    /// Get the items that have been received so far
+    pub fn items(&self) -> ReadOnlySignal<Vec<T>> {
+        self.items.into()
+    }
+
+    /// Get the last error returned by the server function or the stream
+    pub fn error(&self) -> ReadOnlySignal<Option<ServerFnError<E>>> {
+        self.error.into()
+    }
+
+    /// Check if the stream has ended
+    pub fn finished(&self) -> bool {
+        (self.finished)()
+    }
+
+    /// Cancel the stream and call the server function again
+    pub fn restart(&mut self) {
+        self.resource.restart();
+    }
+
+    /// Cancel the stream. The items received so far are kept.
+    pub fn cancel(&mut self) {
+        self.resource.cancel();
+    }
+}
+
+impl<T, E> Clone for UseServerStream<T, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E> Copy for UseServerStream<T, E> {}
+
+impl<T, E> PartialEq for UseServerStream<T, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
+}
@@ -13,11 +13,31 @@ mod html_storage;
 mod axum_adapter;
 
 mod config;
+mod error;
 mod hooks;
 pub mod launch;
 
+// Streams, websockets and forms encode their data as JSON, which is only needed once a client or server platform is enabled
+#[cfg(any(
+    feature = "web",
+    feature = "desktop",
+    feature = "mobile",
+    feature = "server"
+))]
+mod server_stream;
+#[cfg(any(
+    feature = "web",
+    feature = "desktop",
+    feature = "mobile",
+    feature = "server"
+))]
+mod websocket;
+
 pub use config::*;
 
+#[cfg(feature = "axum")]
+mod middleware;
+
 #[cfg(feature = "server")]
 mod render;
 #[cfg(feature = "server")]
@@ -36,10 +56,58 @@ pub mod prelude {
     use crate::hooks;
     pub use hooks::{server_cached::use_server_cached, server_future::use_server_future};
 
+    #[cfg(any(
+        feature = "web",
+        feature = "desktop",
+        feature = "mobile",
+        feature = "server"
+    ))]
+    pub use hooks::{
+        form::{use_form, FieldErrors, UseForm},
+        server_stream::{use_server_stream, UseServerStream},
+    };
+
+    #[cfg(any(
+        feature = "web",
+        feature = "desktop",
+        feature = "mobile",
+        feature = "server"
+    ))]
+    pub use crate::server_stream::{JsonLines, ServerSentEvents, ServerStream};
+
+    #[cfg(any(
+        feature = "web",
+        feature = "desktop",
+        feature = "mobile",
+        feature = "server"
+    ))]
+    pub use crate::websocket::{Websocket, WebsocketReceiver, WebsocketSender};
+
+    #[cfg(any(
+        feature = "web",
+        feature = "desktop",
+        feature = "mobile",
+        feature = "server"
+    ))]
+    #[doc(hidden)]
+    pub use crate::websocket::__private as __websocket;
+
     #[cfg(feature = "axum")]
     #[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
     pub use crate::axum_adapter::*;
 
+    #[cfg(feature = "axum")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
+    pub use crate::middleware::{
+        ServerFnMiddleware, ServerFnMiddlewareLayer, ServerFnMiddlewareService,
+    };
+
+    pub use crate::error::{ParseStatusError, StatusError};
+
+    #[cfg(feature = "server")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
+    pub use crate::error::ServerFnErrorMapper;
+
     #[cfg(feature = "server")]
     #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
     pub use crate::render::{FullstackHTMLTemplate, SSRState};
@@ -55,8 +123,8 @@ pub mod prelude {
     #[cfg(feature = "server")]
     #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
     pub use crate::server_context::{
-        extract, server_context, with_server_context, DioxusServerContext, FromContext,
-        FromServerContext, ProvideServerContext,
+        extract, server_context, with_server_context, BearerToken, Cookies, DioxusServerContext,
+        FromContext, FromServerContext, ProvideServerContext,
     };
 
     #[cfg(feature = "server")]
@@ -0,0 +1,161 @@
+//! Middleware that runs before and after individual server functions
+
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use axum::body::Body;
+use http::{Request, Response, StatusCode};
+use server_fn::{
+    error::{ServerFnErrorSerde, SERVER_FN_ERROR_HEADER},
+    middleware::{BoxedService, Service},
+    ServerFnError,
+};
+
+use crate::prelude::{server_context, DioxusServerContext, StatusError};
+
+/// A middleware for server functions with access to the [`DioxusServerContext`] of the request.
+///
+/// Attach middleware to a server function with the `middleware` argument of the `#[server]` macro. Middleware
+/// runs in the order it is declared.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+///
+/// #[derive(Clone)]
+/// struct User(String);
+///
+/// struct RequireUser;
+///
+/// #[async_trait::async_trait]
+/// impl ServerFnMiddleware for RequireUser {
+///     async fn before(&self, context: &DioxusServerContext) -> Result<(), StatusError> {
+///         let user = context
+///             .request_parts()
+///             .headers
+///             .get("x-user")
+///             .and_then(|user| user.to_str().ok())
+///             .map(str::to_string)
+///             .ok_or_else(|| StatusError::unauthorized("You must be logged in"))?;
+///         // Values inserted into the context can be extracted in the server function
+///         context.insert(User(user));
+///         Ok(())
+///     }
+/// }
+///
+/// #[server(middleware = RequireUser)]
+/// async fn secret() -> Result<String, ServerFnError<StatusError>> {
+///     let FromContext(User(name)) = extract()
+///         .await
+///         .map_err(|_| StatusError::unauthorized("You must be logged in"))?;
+///     Ok(format!("Hello {name}"))
+/// }
+/// ```
+#[async_trait::async_trait]
+pub trait ServerFnMiddleware: Send + Sync + 'static {
+    /// Runs before the server function. Returning an error rejects the request without running the server function.
+    async fn before(&self, context: &DioxusServerContext) -> Result<(), StatusError> {
+        let _ = context;
+        Ok(())
+    }
+
+    /// Runs after the server function with the response that will be sent to the client.
+    async fn after(&self, context: &DioxusServerContext, response: &mut http::response::Parts) {
+        let _ = (context, response);
+    }
+}
+
+/// A [`server_fn::middleware::Layer`] that runs a list of [`ServerFnMiddleware`]. This is created automatically by the
+/// `middleware` arguments of the `#[server]` macro.
+///
+/// The `before` hooks run in the order the middleware was added and the `after` hooks run in the reverse order.
+#[derive(Clone)]
+pub struct ServerFnMiddlewareLayer {
+    middleware: Vec<Arc<dyn ServerFnMiddleware>>,
+}
+
+impl ServerFnMiddlewareLayer {
+    /// Create a new layer from a middleware
+    pub fn new(middleware: impl ServerFnMiddleware) -> Self {
+        Self {
+            middleware: vec![Arc::new(middleware)],
+        }
+    }
+
+    /// Add another middleware that runs inside the existing middleware
+    pub fn and(mut self, middleware: impl ServerFnMiddleware) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Run the `before` hook of every middleware, stopping at the first rejection
+    pub(crate) async fn before(&self, context: &DioxusServerContext) -> Result<(), StatusError> {
+        for middleware in &self.middleware {
+            middleware.before(context).await?;
+        }
+        Ok(())
+    }
+
+    /// Run the `after` hook of every middleware
+    pub(crate) async fn after(
+        &self,
+        context: &DioxusServerContext,
+        response: &mut http::response::Parts,
+    ) {
+        for middleware in self.middleware.iter().rev() {
+            middleware.after(context, response).await;
+        }
+    }
+}
+
+impl tower_layer::Layer<BoxedService<Request<Body>, Response<Body>>> for ServerFnMiddlewareLayer {
+    type Service = ServerFnMiddlewareService;
+
+    fn layer(&self, inner: BoxedService<Request<Body>, Response<Body>>) -> Self::Service {
+        ServerFnMiddlewareService {
+            layer: self.clone(),
+            inner: Arc::new(tokio::sync::Mutex::new(inner)),
+        }
+    }
+}
+
+/// The service created by [`ServerFnMiddlewareLayer`]
+pub struct ServerFnMiddlewareService {
+    layer: ServerFnMiddlewareLayer,
+    inner: Arc<tokio::sync::Mutex<BoxedService<Request<Body>, Response<Body>>>>,
+}
+
+impl Service<Request<Body>, Response<Body>> for ServerFnMiddlewareService {
+    fn run(&mut self, req: Request<Body>) -> Pin<Box<dyn Future<Output = Response<Body>> + Send>> {
+        let layer = self.layer.clone();
+        let inner = self.inner.clone();
+        Box::pin(async move {
+            // The server context is only provided while the server function future is polled
+            let context = server_context();
+
+            if let Err(error) = layer.before(&context).await {
+                return rejection_response(req.uri().path(), error);
+            }
+
+            let response = {
+                let future = inner.lock().await.0.run(req);
+                future.await
+            };
+
+            let (mut parts, body) = response.into_parts();
+            layer.after(&context, &mut parts).await;
+            Response::from_parts(parts, body)
+        })
+    }
+}
+
+/// The response sent when a middleware rejects a request
+pub(crate) fn rejection_response(path: &str, error: StatusError) -> Response<Body> {
+    let status = StatusCode::from_u16(error.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    let error = ServerFnError::WrappedServerError(error);
+    Response::builder()
+        .status(status)
+        .header(SERVER_FN_ERROR_HEADER, path)
+        .body(Body::from(
+            error.ser().unwrap_or_else(|_| error.to_string()),
+        ))
+        .unwrap()
+}
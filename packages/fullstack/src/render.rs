@@ -224,9 +224,24 @@ impl SsrRendererPool {
             if let Err(err) = wrapper.render_after_main(&mut initial_frame) {
                 throw_error!(err);
             }
+
+            // Portals render their children outside of the main element. We send a copy down with the initial frame so the
+            // page shows them before the wasm bundle loads. The client removes the copy and mounts its own portal children once it hydrates
+            for (target, contents) in renderer.take_portals() {
+                if let Err(err) = write!(
+                    &mut initial_frame,
+                    r#"<div id="{}" data-dioxus-ssr-portal="true">{contents}</div>"#,
+                    target.replace('&', "&amp;").replace('"', "&quot;")
+                ) {
+                    throw_error!(
+                        dioxus_ssr::incremental::IncrementalRendererError::RenderError(err)
+                    );
+                }
+            }
             stream.render(initial_frame);
 
-            // After the initial render, we need to resolve suspense
+            // After the initial render, we need to resolve suspense. Suspense lists count as suspended work until they reveal
+            // their boundaries, so boundaries in a list are streamed in the order the list reveals them
             while virtual_dom.suspended_tasks_remaining() {
                 ProvideServerContext::new(
                     virtual_dom.wait_for_suspense_work(),
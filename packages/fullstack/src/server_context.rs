@@ -1,3 +1,4 @@
+use crate::prelude::StatusError;
 use parking_lot::RwLock;
 use std::any::Any;
 use std::collections::HashMap;
@@ -240,6 +241,91 @@ impl<T: Send + Sync + Clone + 'static> FromServerContext for FromContext<T> {
     }
 }
 
+/// The cookies sent with the request
+///
+/// Example:
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+///
+/// #[server]
+/// async fn theme() -> Result<String, ServerFnError> {
+///     let cookies: Cookies = extract().await?;
+///     Ok(cookies.get("theme").unwrap_or("light").to_string())
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cookies(Vec<(String, String)>);
+
+impl Cookies {
+    /// Get the value of a cookie by name
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(cookie, _)| cookie == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Iterate over the name and value of every cookie
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+#[async_trait::async_trait]
+impl FromServerContext for Cookies {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request(req: &DioxusServerContext) -> Result<Self, Self::Rejection> {
+        let parts = req.request_parts();
+        let cookies = parts
+            .headers
+            .get_all(http::header::COOKIE)
+            .iter()
+            .filter_map(|header| header.to_str().ok())
+            .flat_map(|header| header.split(';'))
+            .filter_map(|cookie| {
+                let (name, value) = cookie.trim().split_once('=')?;
+                Some((name.to_string(), value.trim_matches('"').to_string()))
+            })
+            .collect();
+        Ok(Self(cookies))
+    }
+}
+
+/// The token from a `Authorization: Bearer <token>` header. Extraction fails with a `401 Unauthorized`
+/// [`StatusError`] if the request doesn't have a bearer token.
+///
+/// Example:
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+///
+/// #[server]
+/// async fn profile() -> Result<String, ServerFnError<StatusError>> {
+///     let BearerToken(token) = extract().await?;
+///     Ok(format!("Logged in with {token}"))
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BearerToken(pub String);
+
+#[async_trait::async_trait]
+impl FromServerContext for BearerToken {
+    type Rejection = StatusError;
+
+    async fn from_request(req: &DioxusServerContext) -> Result<Self, Self::Rejection> {
+        let parts = req.request_parts();
+        parts
+            .headers
+            .get(http::header::AUTHORIZATION)
+            .and_then(|header| header.to_str().ok())
+            .and_then(|header| header.strip_prefix("Bearer "))
+            .map(|token| Self(token.trim().to_string()))
+            .ok_or_else(|| StatusError::unauthorized("Missing bearer token"))
+    }
+}
+
 #[cfg(feature = "axum")]
 #[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
 /// An adapter for axum extractors for the server context
@@ -0,0 +1,351 @@
+//! Server functions that stream a sequence of values to the client
+
+use std::{
+    fmt::{Debug, Display},
+    pin::Pin,
+    str::FromStr,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt};
+use http::Method;
+use serde::{de::DeserializeOwned, Serialize};
+use server_fn::{
+    codec::{Encoding, FromRes, IntoRes},
+    error::{NoCustomError, ServerFnErrorSerde},
+    response::{ClientRes, Res},
+    ServerFnError,
+};
+
+/// Stream the output of a server function as [newline delimited JSON](https://github.com/ndjson/ndjson-spec).
+///
+/// Each line of the response is a JSON object with either an `Ok` field containing an item or an `Err` field containing a serialized
+/// server function error. A server function that uses this as its output encoding should return a [`ServerStream`].
+pub struct JsonLines;
+
+impl Encoding for JsonLines {
+    const CONTENT_TYPE: &'static str = "application/x-ndjson";
+    const METHOD: Method = Method::POST;
+}
+
+/// Stream the output of a server function as [server-sent events](https://html.spec.whatwg.org/multipage/server-sent-events.html).
+///
+/// Each item is sent as the JSON encoded `data` of a message. Errors are sent as an `error` event. A server function that uses this as its
+/// output encoding should return a [`ServerStream`].
+pub struct ServerSentEvents;
+
+impl Encoding for ServerSentEvents {
+    const CONTENT_TYPE: &'static str = "text/event-stream";
+    const METHOD: Method = Method::POST;
+}
+
+type BoxedItemStream<T, E> = Pin<Box<dyn Stream<Item = Result<T, ServerFnError<E>>> + Send>>;
+
+/// A stream of values returned from a server function with the [`JsonLines`] or [`ServerSentEvents`] output encoding.
+///
+/// On the server, create the stream from any [`Stream`] of values. On the client, the server function returns the stream as soon as the
+/// response starts, and items are yielded as they arrive. Dropping the stream on the client cancels the request.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+///
+/// #[server(output = JsonLines)]
+/// async fn count_to(to: u32) -> Result<ServerStream<u32>, ServerFnError> {
+///     Ok(ServerStream::new(futures_util::stream::iter(0..to)))
+/// }
+///
+/// fn app() -> Element {
+///     let numbers = use_server_stream(move || count_to(10));
+///
+///     rsx! {
+///         for number in numbers.items().iter() {
+///             p { "{number}" }
+///         }
+///     }
+/// }
+/// ```
+pub struct ServerStream<T, E = NoCustomError> {
+    stream: BoxedItemStream<T, E>,
+}
+
+impl<T, E> Debug for ServerStream<T, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServerStream").finish()
+    }
+}
+
+impl<T: Send + 'static, E: 'static> ServerStream<T, E> {
+    /// Create a new stream from a stream of items
+    pub fn new(stream: impl Stream<Item = T> + Send + 'static) -> Self {
+        Self::try_new(stream.map(Ok))
+    }
+
+    /// Create a new stream from a stream of results. Errors are sent to the client without ending the stream.
+    pub fn try_new(
+        stream: impl Stream<Item = Result<T, ServerFnError<E>>> + Send + 'static,
+    ) -> Self {
+        Self {
+            stream: Box::pin(stream),
+        }
+    }
+
+    /// Get the inner stream of results
+    pub fn into_inner(self) -> impl Stream<Item = Result<T, ServerFnError<E>>> + Send {
+        self.stream
+    }
+}
+
+impl<T, E> Stream for ServerStream<T, E> {
+    type Item = Result<T, ServerFnError<E>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx)
+    }
+}
+
+/// A frame of a streamed response. The wire format of [`JsonLines`] matches the serialization of `Result`.
+#[derive(serde::Serialize, serde::Deserialize)]
+enum JsonLine<T> {
+    Ok(T),
+    Err(String),
+}
+
+fn encode_json_line<T: Serialize, E: FromStr + Display>(
+    item: Result<T, ServerFnError<E>>,
+) -> Result<Bytes, ServerFnError<E>> {
+    let line = match item {
+        Ok(item) => JsonLine::Ok(item),
+        Err(error) => JsonLine::Err(serialize_error(error)),
+    };
+    let mut data =
+        serde_json::to_vec(&line).map_err(|e| ServerFnError::Serialization(e.to_string()))?;
+    data.push(b'\n');
+    Ok(data.into())
+}
+
+fn decode_json_line<T: DeserializeOwned, E: FromStr + Display>(
+    line: &str,
+) -> Result<T, ServerFnError<E>> {
+    match serde_json::from_str(line) {
+        Ok(JsonLine::Ok(item)) => Ok(item),
+        Ok(JsonLine::Err(error)) => Err(ServerFnError::de(&error)),
+        Err(e) => Err(ServerFnError::Deserialization(e.to_string())),
+    }
+}
+
+fn encode_event<T: Serialize, E: FromStr + Display>(
+    item: Result<T, ServerFnError<E>>,
+) -> Result<Bytes, ServerFnError<E>> {
+    let event = match item {
+        Ok(item) => {
+            let data = serde_json::to_string(&item)
+                .map_err(|e| ServerFnError::Serialization(e.to_string()))?;
+            format!("data: {data}\n\n")
+        }
+        Err(error) => {
+            // Event data can't contain new lines, so each line of the error is sent as a separate data field
+            let mut event = String::from("event: error\n");
+            for line in serialize_error(error).split('\n') {
+                event.push_str("data: ");
+                event.push_str(line);
+                event.push('\n');
+            }
+            event.push('\n');
+            event
+        }
+    };
+    Ok(event.into())
+}
+
+fn decode_event<T: DeserializeOwned, E: FromStr + Display>(
+    event: &str,
+) -> Option<Result<T, ServerFnError<E>>> {
+    let mut name = "message";
+    let mut data: Option<String> = None;
+    for line in event.lines() {
+        let (field, value) = line.split_once(':').unwrap_or((line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match field {
+            "event" => name = value,
+            "data" => match &mut data {
+                Some(data) => {
+                    data.push('\n');
+                    data.push_str(value);
+                }
+                None => data = Some(value.to_string()),
+            },
+            _ => {}
+        }
+    }
+
+    // Events without data are ignored by the spec
+    let data = data?;
+    Some(match name {
+        "error" => Err(ServerFnError::de(&data)),
+        _ => serde_json::from_str(&data).map_err(|e| ServerFnError::Deserialization(e.to_string())),
+    })
+}
+
+fn serialize_error<E: FromStr + Display>(error: ServerFnError<E>) -> String {
+    error.ser().unwrap_or_else(|_| {
+        ServerFnError::<NoCustomError>::Serialization(
+            "Failed to serialize server function error".into(),
+        )
+        .ser()
+        .unwrap_or_default()
+    })
+}
+
+type DecodeFrame<T, E> = fn(&str) -> Option<Result<T, ServerFnError<E>>>;
+
+/// Split a stream of bytes into frames separated by a delimiter, decoding each frame with a function
+fn split_frames<T, E: 'static, S>(
+    stream: S,
+    delimiter: &'static str,
+    decode: DecodeFrame<T, E>,
+) -> impl Stream<Item = Result<T, ServerFnError<E>>> + Send
+where
+    T: Send + 'static,
+    S: Stream<Item = Result<Bytes, ServerFnError>> + Send + 'static,
+{
+    struct State<S> {
+        stream: Pin<Box<S>>,
+        buffer: String,
+        // Bytes of a UTF-8 character that was split between chunks
+        partial: Vec<u8>,
+        finished: bool,
+    }
+
+    let state = State {
+        stream: Box::pin(stream),
+        buffer: String::new(),
+        partial: Vec::new(),
+        finished: false,
+    };
+
+    futures_util::stream::unfold(state, move |mut state| async move {
+        loop {
+            if let Some(end) = state.buffer.find(delimiter) {
+                let frame: String = state.buffer.drain(..end + delimiter.len()).collect();
+                match decode(&frame[..end]) {
+                    Some(item) => return Some((item, state)),
+                    None => continue,
+                }
+            }
+
+            if state.finished {
+                // The stream ended in the middle of a UTF-8 character
+                if !state.partial.is_empty() {
+                    state.partial.clear();
+                    state.buffer.clear();
+                    return Some((Err(invalid_utf8()), state));
+                }
+                // Decode anything left after the last delimiter
+                let frame = std::mem::take(&mut state.buffer);
+                return decode(&frame).map(|item| (item, state));
+            }
+
+            match state.stream.next().await {
+                Some(Ok(chunk)) => {
+                    state.partial.extend_from_slice(&chunk);
+                    let valid = match std::str::from_utf8(&state.partial) {
+                        Ok(text) => text.len(),
+                        // Only an incomplete character at the end can be completed by the next chunk
+                        Err(e) if e.error_len().is_some() => {
+                            state.finished = true;
+                            state.partial.clear();
+                            state.buffer.clear();
+                            return Some((Err(invalid_utf8()), state));
+                        }
+                        Err(e) => e.valid_up_to(),
+                    };
+                    let rest = state.partial.split_off(valid);
+                    // We just checked that this part is valid UTF-8
+                    state
+                        .buffer
+                        .push_str(std::str::from_utf8(&state.partial).unwrap());
+                    state.partial = rest;
+                }
+                Some(Err(error)) => {
+                    state.finished = true;
+                    state.buffer.clear();
+                    return Some((Err(convert_error(error)), state));
+                }
+                None => state.finished = true,
+            }
+        }
+    })
+}
+
+fn invalid_utf8<E>() -> ServerFnError<E> {
+    ServerFnError::Deserialization("The streamed response is not valid UTF-8".into())
+}
+
+/// Convert a transport error into a server function error with a custom error type
+fn convert_error<E>(error: ServerFnError) -> ServerFnError<E> {
+    match error {
+        ServerFnError::WrappedServerError(NoCustomError) => {
+            ServerFnError::Request("Unknown error".into())
+        }
+        ServerFnError::Registration(e) => ServerFnError::Registration(e),
+        ServerFnError::Request(e) => ServerFnError::Request(e),
+        ServerFnError::Response(e) => ServerFnError::Response(e),
+        ServerFnError::ServerError(e) => ServerFnError::ServerError(e),
+        ServerFnError::Deserialization(e) => ServerFnError::Deserialization(e),
+        ServerFnError::Serialization(e) => ServerFnError::Serialization(e),
+        ServerFnError::Args(e) => ServerFnError::Args(e),
+        ServerFnError::MissingArg(e) => ServerFnError::MissingArg(e),
+    }
+}
+
+impl<T, E, Response> IntoRes<JsonLines, Response, E> for ServerStream<T, E>
+where
+    Response: Res<E>,
+    T: Serialize + Send + 'static,
+    E: FromStr + Display + Send + 'static,
+{
+    async fn into_res(self) -> Result<Response, ServerFnError<E>> {
+        Response::try_from_stream(JsonLines::CONTENT_TYPE, self.stream.map(encode_json_line))
+    }
+}
+
+impl<T, E, Response> FromRes<JsonLines, Response, E> for ServerStream<T, E>
+where
+    Response: ClientRes<E> + Send,
+    T: DeserializeOwned + Send + 'static,
+    E: FromStr + Display + Send + 'static,
+{
+    async fn from_res(res: Response) -> Result<Self, ServerFnError<E>> {
+        let stream = res.try_into_stream()?;
+        Ok(Self::try_new(split_frames(stream, "\n", |line| {
+            (!line.trim().is_empty()).then(|| decode_json_line(line))
+        })))
+    }
+}
+
+impl<T, E, Response> IntoRes<ServerSentEvents, Response, E> for ServerStream<T, E>
+where
+    Response: Res<E>,
+    T: Serialize + Send + 'static,
+    E: FromStr + Display + Send + 'static,
+{
+    async fn into_res(self) -> Result<Response, ServerFnError<E>> {
+        Response::try_from_stream(
+            ServerSentEvents::CONTENT_TYPE,
+            self.stream.map(encode_event),
+        )
+    }
+}
+
+impl<T, E, Response> FromRes<ServerSentEvents, Response, E> for ServerStream<T, E>
+where
+    Response: ClientRes<E> + Send,
+    T: DeserializeOwned + Send + 'static,
+    E: FromStr + Display + Send + 'static,
+{
+    async fn from_res(res: Response) -> Result<Self, ServerFnError<E>> {
+        let stream = res.try_into_stream()?;
+        Ok(Self::try_new(split_frames(stream, "\n\n", decode_event)))
+    }
+}
@@ -0,0 +1,571 @@
+//! Bidirectional channels between the client and server created with websocket server functions
+
+use std::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_util::{Sink, SinkExt, Stream, StreamExt};
+use serde::{de::DeserializeOwned, Serialize};
+use server_fn::ServerFnError;
+
+#[cfg(not(target_arch = "wasm32"))]
+type TextSink = Pin<Box<dyn Sink<String, Error = ServerFnError> + Send>>;
+#[cfg(target_arch = "wasm32")]
+type TextSink = Pin<Box<dyn Sink<String, Error = ServerFnError>>>;
+
+#[cfg(not(target_arch = "wasm32"))]
+type TextStream = Pin<Box<dyn Stream<Item = Result<String, ServerFnError>> + Send>>;
+#[cfg(target_arch = "wasm32")]
+type TextStream = Pin<Box<dyn Stream<Item = Result<String, ServerFnError>>>>;
+
+/// A typed websocket connection created by a websocket server function. `S` is the type of the messages sent through the
+/// socket and `R` is the type of the messages received from the socket.
+///
+/// Messages are sent as JSON. The server function receives a `Websocket<Out, In>` as its last argument and the client receives
+/// a `Websocket<In, Out>` when it calls the server function. The socket implements both [`Sink`] and [`Stream`], and can be split into
+/// a [`WebsocketSender`] and [`WebsocketReceiver`] with [`Websocket::split`].
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// use futures_util::StreamExt;
+///
+/// #[server(protocol = websocket)]
+/// async fn echo(prefix: String, mut socket: Websocket<String, String>) -> Result<(), ServerFnError> {
+///     while let Some(message) = socket.recv().await {
+///         socket.send(format!("{prefix}{}", message?)).await?;
+///     }
+///     Ok(())
+/// }
+///
+/// fn app() -> Element {
+///     let mut responses = use_signal(Vec::new);
+///     let socket = use_coroutine(move |mut rx: UnboundedReceiver<String>| async move {
+///         let Ok(socket) = echo("echo: ".to_string()).await else {
+///             return;
+///         };
+///         let (mut sender, mut receiver) = socket.split();
+///         spawn(async move {
+///             while let Some(Ok(response)) = receiver.recv().await {
+///                 responses.push(response);
+///             }
+///         });
+///         while let Some(message) = rx.next().await {
+///             if sender.send(message).await.is_err() {
+///                 break;
+///             }
+///         }
+///     });
+///
+///     rsx! {
+///         button { onclick: move |_| socket.send("hello".to_string()), "Send" }
+///         for response in responses.iter() {
+///             p { "{response}" }
+///         }
+///     }
+/// }
+/// ```
+pub struct Websocket<S, R> {
+    sender: WebsocketSender<S>,
+    receiver: WebsocketReceiver<R>,
+}
+
+impl<S: Serialize, R: DeserializeOwned> Websocket<S, R> {
+    pub(crate) fn new(sink: TextSink, stream: TextStream) -> Self {
+        Self {
+            sender: WebsocketSender {
+                sink,
+                _marker: PhantomData,
+            },
+            receiver: WebsocketReceiver {
+                stream,
+                _marker: PhantomData,
+            },
+        }
+    }
+
+    /// Send a message through the socket
+    pub async fn send(&mut self, message: S) -> Result<(), ServerFnError> {
+        self.sender.send(message).await
+    }
+
+    /// Receive the next message from the socket. Returns `None` once the socket is closed.
+    pub async fn recv(&mut self) -> Option<Result<R, ServerFnError>> {
+        self.receiver.recv().await
+    }
+
+    /// Split the socket into a sender and receiver that can be used from different tasks
+    pub fn split(self) -> (WebsocketSender<S>, WebsocketReceiver<R>) {
+        (self.sender, self.receiver)
+    }
+}
+
+impl<S, R: DeserializeOwned> Stream for Websocket<S, R> {
+    type Item = Result<R, ServerFnError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
+impl<S: Serialize, R> Sink<S> for Websocket<S, R> {
+    type Error = ServerFnError;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.sender).poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: S) -> Result<(), Self::Error> {
+        Pin::new(&mut self.sender).start_send(item)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.sender).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.sender).poll_close(cx)
+    }
+}
+
+/// The sending half of a [`Websocket`]
+pub struct WebsocketSender<S> {
+    sink: TextSink,
+    _marker: PhantomData<fn(S)>,
+}
+
+impl<S: Serialize> WebsocketSender<S> {
+    /// Send a message through the socket
+    pub async fn send(&mut self, message: S) -> Result<(), ServerFnError> {
+        SinkExt::send(self, message).await
+    }
+}
+
+impl<S: Serialize> Sink<S> for WebsocketSender<S> {
+    type Error = ServerFnError;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.sink.as_mut().poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: S) -> Result<(), Self::Error> {
+        let message =
+            serde_json::to_string(&item).map_err(|e| ServerFnError::Serialization(e.to_string()));
+        message.and_then(|message| self.sink.as_mut().start_send(message))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.sink.as_mut().poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.sink.as_mut().poll_close(cx)
+    }
+}
+
+/// The receiving half of a [`Websocket`]
+pub struct WebsocketReceiver<R> {
+    stream: TextStream,
+    _marker: PhantomData<fn() -> R>,
+}
+
+impl<R: DeserializeOwned> WebsocketReceiver<R> {
+    /// Receive the next message from the socket. Returns `None` once the socket is closed.
+    pub async fn recv(&mut self) -> Option<Result<R, ServerFnError>> {
+        self.next().await
+    }
+}
+
+impl<R: DeserializeOwned> Stream for WebsocketReceiver<R> {
+    type Item = Result<R, ServerFnError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx).map(|message| {
+            message.map(|message| {
+                message.and_then(|message| {
+                    serde_json::from_str(&message)
+                        .map_err(|e| ServerFnError::Deserialization(e.to_string()))
+                })
+            })
+        })
+    }
+}
+
+/// The reason sent in the close frame when a websocket server function returns an error
+#[cfg(feature = "axum")]
+fn close_reason(error: &ServerFnError) -> String {
+    use server_fn::error::ServerFnErrorSerde;
+    let mut reason = error.ser().unwrap_or_else(|_| error.to_string());
+    // Close reasons are limited to 123 bytes
+    while reason.len() > 123 {
+        reason.pop();
+    }
+    reason
+}
+
+/// Convert the reason of an abnormal close frame back into an error
+#[cfg(any(
+    all(feature = "web", target_arch = "wasm32"),
+    all(feature = "desktop", not(target_arch = "wasm32"))
+))]
+fn close_error(reason: &str) -> ServerFnError {
+    use server_fn::error::ServerFnErrorSerde;
+    if reason.is_empty() {
+        request_error("The websocket closed unexpectedly")
+    } else {
+        ServerFnError::de(reason)
+    }
+}
+
+#[cfg(any(
+    all(feature = "web", target_arch = "wasm32"),
+    all(feature = "desktop", not(target_arch = "wasm32"))
+))]
+fn request_error(error: impl ToString) -> ServerFnError {
+    ServerFnError::Request(error.to_string())
+}
+
+#[doc(hidden)]
+pub mod __private {
+    //! Items used by the code generated for websocket server functions
+
+    use super::*;
+
+    /// Deserialize the arguments of a websocket server function
+    pub fn decode_args<T: DeserializeOwned>(args: &str) -> Result<T, ServerFnError> {
+        serde_json::from_str(args).map_err(|e| ServerFnError::Args(e.to_string()))
+    }
+
+    /// Connect to a websocket server function and send the arguments
+    #[allow(unused)]
+    pub async fn connect<S: Serialize, R: DeserializeOwned>(
+        path: &str,
+        args: &impl Serialize,
+    ) -> Result<Websocket<S, R>, ServerFnError> {
+        let args = match serde_json::to_string(args) {
+            Ok(args) => args,
+            Err(e) => return Err(ServerFnError::Serialization(e.to_string())),
+        };
+
+        #[cfg(all(feature = "web", target_arch = "wasm32"))]
+        return super::web::connect(path, args).await;
+
+        #[cfg(all(feature = "desktop", not(target_arch = "wasm32")))]
+        return super::desktop::connect(path, args).await;
+
+        #[allow(unreachable_code)]
+        Err(ServerFnError::Request(
+            "Websocket server functions can only be called from the web or desktop client"
+                .to_string(),
+        ))
+    }
+
+    #[cfg(feature = "server")]
+    pub use super::server::*;
+}
+
+#[cfg(feature = "axum")]
+pub(crate) use server::{websocket_server_fns, WebsocketServerFn};
+
+#[cfg(feature = "server")]
+mod server {
+    use super::*;
+    use std::future::Future;
+
+    /// A future returned by the handler of a websocket server function
+    pub type WebsocketFuture = Pin<Box<dyn Future<Output = Result<(), ServerFnError>> + Send>>;
+
+    /// The connection a websocket server function handler receives before the messages are typed
+    pub struct RawWebsocket {
+        sink: TextSink,
+        stream: TextStream,
+    }
+
+    impl RawWebsocket {
+        /// Type the messages of the socket
+        pub fn into_typed<S: Serialize, R: DeserializeOwned>(self) -> Websocket<S, R> {
+            Websocket::new(self.sink, self.stream)
+        }
+    }
+
+    /// A websocket server function registered with the server
+    pub struct WebsocketServerFn {
+        path: &'static str,
+        handler: fn(String, RawWebsocket) -> WebsocketFuture,
+        #[cfg(feature = "axum")]
+        middleware: Option<fn() -> crate::middleware::ServerFnMiddlewareLayer>,
+    }
+
+    impl WebsocketServerFn {
+        /// Create a new websocket server function. The handler receives the serialized arguments and the socket.
+        pub const fn new(
+            path: &'static str,
+            handler: fn(String, RawWebsocket) -> WebsocketFuture,
+        ) -> Self {
+            Self {
+                path,
+                handler,
+                #[cfg(feature = "axum")]
+                middleware: None,
+            }
+        }
+
+        /// Run middleware on the handshake request before the connection is upgraded
+        #[cfg(feature = "axum")]
+        pub const fn with_middleware(
+            mut self,
+            middleware: fn() -> crate::middleware::ServerFnMiddlewareLayer,
+        ) -> Self {
+            self.middleware = Some(middleware);
+            self
+        }
+
+        /// The path the websocket server function is served at
+        pub fn path(&self) -> &'static str {
+            self.path
+        }
+
+        /// The middleware that runs on the handshake request
+        #[cfg(feature = "axum")]
+        pub(crate) fn middleware(&self) -> Option<crate::middleware::ServerFnMiddlewareLayer> {
+            self.middleware.map(|middleware| middleware())
+        }
+
+        /// Run the websocket server function on an upgraded axum connection
+        #[cfg(feature = "axum")]
+        pub(crate) async fn run(&self, socket: axum::extract::ws::WebSocket) {
+            use axum::extract::ws::{CloseFrame, Message};
+
+            let (sink, stream) = socket.split();
+            let sink = sink.sink_map_err(|e| ServerFnError::Response(e.to_string()));
+            let mut stream = Box::pin(stream.filter_map(|message| async move {
+                match message {
+                    Ok(Message::Text(text)) => Some(Ok(text)),
+                    Ok(_) => None,
+                    Err(e) => Some(Err(ServerFnError::Request(e.to_string()))),
+                }
+            }));
+
+            // The client sends the arguments in the first message
+            let result = match stream.next().await {
+                Some(Ok(args)) => {
+                    let sink = SharedSink::new(sink);
+                    let raw = RawWebsocket {
+                        sink: Box::pin(sink.clone()),
+                        stream,
+                    };
+                    let result = (self.handler)(args, raw).await;
+                    // Close the connection as soon as the server function returns, even if it moved the sender
+                    // into a task that is still running
+                    sink.take().map(|sink| (sink, result))
+                }
+                Some(Err(error)) => Some((sink, Err(error))),
+                None => None,
+            };
+
+            if let Some((mut sink, result)) = result {
+                let frame = match result {
+                    Ok(()) => CloseFrame {
+                        code: axum::extract::ws::close_code::NORMAL,
+                        reason: "".into(),
+                    },
+                    Err(error) => {
+                        tracing::error!("Websocket server function {} failed: {error}", self.path);
+                        CloseFrame {
+                            code: axum::extract::ws::close_code::ERROR,
+                            reason: close_reason(&error).into(),
+                        }
+                    }
+                };
+                _ = sink.send(Message::Close(Some(frame))).await;
+            }
+        }
+    }
+
+    /// A sink shared between the server function and the connection. The connection takes the inner sink back when the
+    /// server function returns so it can close the socket with a status. Sending after that fails.
+    #[cfg(feature = "axum")]
+    struct SharedSink<T> {
+        sink: std::sync::Arc<std::sync::Mutex<Option<T>>>,
+    }
+
+    #[cfg(feature = "axum")]
+    impl<T> Clone for SharedSink<T> {
+        fn clone(&self) -> Self {
+            Self {
+                sink: self.sink.clone(),
+            }
+        }
+    }
+
+    #[cfg(feature = "axum")]
+    impl<T: Unpin> SharedSink<T> {
+        fn new(sink: T) -> Self {
+            Self {
+                sink: std::sync::Arc::new(std::sync::Mutex::new(Some(sink))),
+            }
+        }
+
+        /// Take the inner sink back. Every clone of the sink fails to send afterwards.
+        fn take(&self) -> Option<T> {
+            self.sink.lock().unwrap().take()
+        }
+
+        fn with_sink<O>(
+            &self,
+            f: impl FnOnce(Pin<&mut T>) -> Result<O, ServerFnError>,
+        ) -> Result<O, ServerFnError> {
+            match self.sink.lock().unwrap().as_mut() {
+                Some(sink) => f(Pin::new(sink)),
+                None => Err(ServerFnError::Request(
+                    "The websocket server function already returned".to_string(),
+                )),
+            }
+        }
+
+        fn poll_with(
+            &self,
+            f: impl FnOnce(Pin<&mut T>) -> Poll<Result<(), ServerFnError>>,
+        ) -> Poll<Result<(), ServerFnError>> {
+            match self.with_sink(|sink| Ok(f(sink))) {
+                Ok(poll) => poll,
+                Err(error) => Poll::Ready(Err(error)),
+            }
+        }
+    }
+
+    #[cfg(feature = "axum")]
+    impl<T: Sink<axum::extract::ws::Message, Error = ServerFnError> + Unpin> Sink<String>
+        for SharedSink<T>
+    {
+        type Error = ServerFnError;
+
+        fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            self.poll_with(|sink| sink.poll_ready(cx))
+        }
+
+        fn start_send(self: Pin<&mut Self>, item: String) -> Result<(), Self::Error> {
+            self.with_sink(|sink| sink.start_send(axum::extract::ws::Message::Text(item)))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            self.poll_with(|sink| sink.poll_flush(cx))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            self.poll_with(|sink| sink.poll_close(cx))
+        }
+    }
+
+    server_fn::inventory::collect!(WebsocketServerFn);
+
+    /// Get all websocket server functions registered in the binary
+    pub(crate) fn websocket_server_fns() -> impl Iterator<Item = &'static WebsocketServerFn> {
+        server_fn::inventory::iter::<WebsocketServerFn>.into_iter()
+    }
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+mod web {
+    use super::*;
+    use gloo_net::websocket::{futures::WebSocket, Message, WebSocketError};
+
+    pub(super) async fn connect<S: Serialize, R: DeserializeOwned>(
+        path: &str,
+        args: String,
+    ) -> Result<Websocket<S, R>, ServerFnError> {
+        let location = web_sys::window()
+            .ok_or_else(|| request_error("No window found"))?
+            .location();
+        let protocol = match location.protocol().as_deref() {
+            Ok("https:") => "wss:",
+            _ => "ws:",
+        };
+        let host = location
+            .host()
+            .map_err(|_| request_error("Failed to read the host"))?;
+        let url = format!("{protocol}//{host}{path}");
+
+        let socket = WebSocket::open(&url).map_err(request_error)?;
+        let (mut sink, stream) = socket.split();
+        sink.send(Message::Text(args))
+            .await
+            .map_err(request_error)?;
+
+        let sink = sink
+            .sink_map_err(request_error)
+            .with(|text: String| async move { Ok::<_, ServerFnError>(Message::Text(text)) });
+        let stream = stream
+            .take_while(|message| {
+                // A normal close ends the stream without an error
+                let closed_normally = matches!(
+                    message,
+                    Err(WebSocketError::ConnectionClose(event)) if event.code == 1000
+                );
+                std::future::ready(!closed_normally)
+            })
+            .filter_map(|message| async move {
+                match message {
+                    Ok(Message::Text(text)) => Some(Ok(text)),
+                    Ok(Message::Bytes(_)) => None,
+                    Err(WebSocketError::ConnectionClose(event)) => {
+                        Some(Err(close_error(&event.reason)))
+                    }
+                    Err(e) => Some(Err(request_error(e))),
+                }
+            });
+
+        Ok(Websocket::new(Box::pin(sink), Box::pin(stream)))
+    }
+}
+
+#[cfg(all(feature = "desktop", not(target_arch = "wasm32")))]
+mod desktop {
+    use super::*;
+    use tokio_tungstenite::tungstenite::{protocol::frame::coding::CloseCode, Message};
+
+    pub(super) async fn connect<S: Serialize, R: DeserializeOwned>(
+        path: &str,
+        args: String,
+    ) -> Result<Websocket<S, R>, ServerFnError> {
+        let server_url = server_fn::client::get_server_url();
+        let server_url = server_url
+            .strip_prefix("http")
+            .map(|rest| format!("ws{rest}"))
+            .unwrap_or_else(|| server_url.to_string());
+        let url = format!("{server_url}{path}");
+
+        let (socket, _) = tokio_tungstenite::connect_async(url)
+            .await
+            .map_err(request_error)?;
+        let (mut sink, stream) = socket.split();
+        sink.send(Message::Text(args))
+            .await
+            .map_err(request_error)?;
+
+        let sink = sink
+            .sink_map_err(request_error)
+            .with(|text: String| async move { Ok::<_, ServerFnError>(Message::Text(text)) });
+        let stream = stream
+            .take_while(|message| {
+                // A normal close ends the stream without an error
+                let closed_normally = match message {
+                    Ok(Message::Close(None)) => true,
+                    Ok(Message::Close(Some(frame))) => frame.code == CloseCode::Normal,
+                    _ => false,
+                };
+                std::future::ready(!closed_normally)
+            })
+            .filter_map(|message| async move {
+                match message {
+                    Ok(Message::Text(text)) => Some(Ok(text)),
+                    Ok(Message::Close(Some(frame))) => Some(Err(close_error(&frame.reason))),
+                    Ok(_) => None,
+                    Err(e) => Some(Err(request_error(e))),
+                }
+            });
+
+        Ok(Websocket::new(Box::pin(sink), Box::pin(stream)))
+    }
+}
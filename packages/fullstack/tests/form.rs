@@ -0,0 +1,313 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+struct SignUp {
+    email: String,
+    age: u32,
+    subscribe: bool,
+    tags: Vec<String>,
+    referrer: Option<u32>,
+}
+
+thread_local! {
+    static FORM: Cell<Option<UseForm<SignUp>>> = const { Cell::new(None) };
+}
+
+fn app() -> Element {
+    let form = use_form::<SignUp>();
+    FORM.with(|cell| cell.set(Some(form)));
+    rsx! {}
+}
+
+/// Create a virtual dom with a form and run `f` with the form inside the app scope
+fn with_form<O>(dom: &VirtualDom, f: impl FnOnce(UseForm<SignUp>) -> O) -> O {
+    let form = FORM
+        .with(|cell| cell.get())
+        .expect("the form to be created");
+    dom.in_runtime(|| ScopeId::APP.in_runtime(|| f(form)))
+}
+
+fn form_dom() -> VirtualDom {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom
+}
+
+/// Run the virtual dom for the given number of milliseconds
+async fn run_for(dom: &mut VirtualDom, millis: u64) {
+    let work = async {
+        loop {
+            dom.wait_for_work().await;
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        }
+    };
+    tokio::select! {
+        _ = work => {},
+        _ = tokio::time::sleep(Duration::from_millis(millis)) => {}
+    }
+}
+
+#[test]
+fn inputs_are_parsed_into_the_type_of_their_field() {
+    let dom = form_dom();
+    with_form(&dom, |mut form| {
+        form.set("age", "42");
+        form.set("subscribe", "on");
+        form.set("tags", "rust, dioxus");
+        form.set("referrer", "7");
+        assert_eq!(
+            *form.values().read(),
+            SignUp {
+                age: 42,
+                subscribe: true,
+                tags: vec!["rust".to_string(), "dioxus".to_string()],
+                referrer: Some(7),
+                ..Default::default()
+            }
+        );
+        assert!(form.is_valid());
+
+        // Clearing an optional field sets it to none
+        form.set("referrer", "");
+        assert_eq!(form.values().read().referrer, None);
+    });
+}
+
+#[test]
+fn inputs_that_fail_to_parse_keep_their_text_and_error() {
+    let dom = form_dom();
+    with_form(&dom, |mut form| {
+        form.set("age", "42");
+        form.set("age", "forty");
+        // The last value that parsed is kept, but the input shows what the user typed
+        assert_eq!(form.values().read().age, 42);
+        assert_eq!(form.value("age"), "forty");
+        assert!(form.error("age").is_some());
+        assert!(!form.is_valid());
+
+        form.set("age", "43");
+        assert_eq!(form.values().read().age, 43);
+        assert_eq!(form.value("age"), "43");
+        assert_eq!(form.error("age"), None);
+    });
+}
+
+#[test]
+fn sync_validators_run_when_the_form_changes() {
+    let dom = form_dom();
+    with_form(&dom, |mut form| {
+        form.validate_field("email", |values| match values.email.contains('@') {
+            true => Ok(()),
+            false => Err("Enter a valid email".to_string()),
+        });
+        form.validate(|values| match values.age >= 13 {
+            true => Ok(()),
+            false => Err(FieldErrors::new().with_field("age", "You must be at least 13")),
+        });
+
+        form.set("email", "dioxus");
+        assert_eq!(form.error("email").as_deref(), Some("Enter a valid email"));
+        assert_eq!(
+            form.error("age").as_deref(),
+            Some("You must be at least 13")
+        );
+
+        form.set("email", "hello@dioxuslabs.com");
+        form.set("age", "30");
+        assert!(form.is_valid());
+    });
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn async_validators_ignore_outdated_results() {
+    let mut dom = form_dom();
+    with_form(&dom, |mut form| {
+        form.validate_field_async("email", |values| async move {
+            let taken = values.email == "taken@example.com";
+            // Checking the taken email is slower, so the first run finishes last
+            let delay = if taken { 200 } else { 50 };
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+            match taken {
+                true => Err("That email is already taken".to_string()),
+                false => Ok(()),
+            }
+        });
+        form.set("email", "taken@example.com");
+        form.set("email", "free@example.com");
+    });
+    run_for(&mut dom, 100).await;
+    assert!(with_form(&dom, |form| form.validating()));
+
+    // The first run finished, but the email changed since it started
+    run_for(&mut dom, 200).await;
+    with_form(&dom, |form| {
+        assert!(!form.validating());
+        assert_eq!(form.error("email"), None);
+    });
+
+    with_form(&dom, |mut form| form.set("email", "taken@example.com"));
+    run_for(&mut dom, 250).await;
+    with_form(&dom, |form| {
+        assert_eq!(
+            form.error("email").as_deref(),
+            Some("That email is already taken")
+        )
+    });
+}
+
+#[test]
+fn fields_track_touched_and_dirty_state() {
+    let dom = form_dom();
+    with_form(&dom, |mut form| {
+        assert!(!form.is_touched("email"));
+        assert!(!form.dirty());
+
+        form.touch("email");
+        assert!(form.is_touched("email"));
+        assert!(!form.is_touched("age"));
+
+        form.set("age", "5");
+        assert!(form.is_dirty("age"));
+        assert!(!form.is_dirty("email"));
+        assert!(form.dirty());
+
+        // Setting the default value again makes the field clean
+        form.set("age", "0");
+        assert!(!form.is_dirty("age"));
+        assert!(!form.dirty());
+
+        // Text that doesn't parse is a change from the default value
+        form.set("age", "zero");
+        assert!(form.is_dirty("age"));
+    });
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn submit_maps_server_errors_back_to_fields() {
+    let mut dom = form_dom();
+    with_form(&dom, |form| {
+        form.submit(|_| async move {
+            Err::<(), _>(ServerFnError::WrappedServerError(
+                FieldErrors::new()
+                    .with_field("email", "That email is already taken")
+                    .with_form("Try again later"),
+            ))
+        });
+        assert!(form.submitting());
+    });
+    run_for(&mut dom, 50).await;
+    with_form(&dom, |mut form| {
+        assert!(!form.submitting());
+        assert!(form.is_touched("email") && form.is_touched("age"));
+        assert_eq!(
+            form.error("email").as_deref(),
+            Some("That email is already taken")
+        );
+        assert_eq!(form.errors().form, vec!["Try again later".to_string()]);
+
+        // Editing the field clears the error from the server
+        form.set("email", "new@example.com");
+        assert_eq!(form.error("email"), None);
+    });
+
+    // Errors that are not field errors are shown for the whole form
+    with_form(&dom, |form| {
+        form.submit(|_| async move {
+            Err::<(), _>(ServerFnError::<FieldErrors>::ServerError(
+                "database offline".to_string(),
+            ))
+        })
+    });
+    run_for(&mut dom, 50).await;
+    with_form(&dom, |form| {
+        let errors = form.errors();
+        assert_eq!(errors.form.len(), 1);
+        assert!(errors.form[0].contains("database offline"));
+    });
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn submit_skips_invalid_forms() {
+    let mut dom = form_dom();
+    let submitted = Rc::new(Cell::new(false));
+    with_form(&dom, |mut form| {
+        form.validate_field("email", |values| match values.email.is_empty() {
+            true => Err("Enter an email".to_string()),
+            false => Ok(()),
+        });
+        let submitted = submitted.clone();
+        form.submit(move |_| async move {
+            submitted.set(true);
+            Ok::<(), ServerFnError<FieldErrors>>(())
+        });
+    });
+    run_for(&mut dom, 50).await;
+    assert!(!submitted.get());
+    with_form(&dom, |form| {
+        assert!(!form.submitting());
+        assert_eq!(form.error("email").as_deref(), Some("Enter an email"));
+    });
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn reset_clears_values_errors_and_pending_validation() {
+    let mut dom = form_dom();
+    with_form(&dom, |mut form| {
+        form.validate_field_async("email", |_| async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Err("That email is already taken".to_string())
+        });
+        form.set("email", "reset@example.com");
+        form.set("age", "old");
+        form.touch("age");
+
+        form.reset();
+        assert_eq!(*form.values().read(), SignUp::default());
+        assert!(form.is_valid());
+        assert!(!form.is_touched("age"));
+        assert!(!form.dirty());
+    });
+
+    // The validator that was running before the reset finishes, but its result is discarded
+    run_for(&mut dom, 100).await;
+    with_form(&dom, |form| {
+        assert!(!form.validating());
+        assert!(form.is_valid());
+    });
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn reset_ignores_the_submission_in_flight() {
+    let mut dom = form_dom();
+    with_form(&dom, |mut form| {
+        form.submit(|_| async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Err::<(), _>(ServerFnError::WrappedServerError(
+                FieldErrors::new().with_form("Try again later"),
+            ))
+        });
+        form.reset();
+        assert!(!form.submitting());
+
+        // The reset form can be submitted again right away
+        form.submit(|_| async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Ok::<(), ServerFnError<FieldErrors>>(())
+        });
+    });
+
+    // The first submission finishes, but it doesn't touch the reset form
+    run_for(&mut dom, 75).await;
+    with_form(&dom, |form| {
+        assert!(form.submitting());
+        assert!(form.is_valid());
+    });
+
+    run_for(&mut dom, 100).await;
+    with_form(&dom, |form| {
+        assert!(!form.submitting());
+        assert!(form.is_valid());
+    });
+}
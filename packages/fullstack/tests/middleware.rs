@@ -0,0 +1,212 @@
+use std::{fmt::Display, str::FromStr, sync::Arc};
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Request, StatusCode},
+    Router,
+};
+use dioxus::prelude::*;
+use server_fn::error::ServerFnErrorSerde;
+use tower::ServiceExt;
+
+#[derive(Clone)]
+struct User(String);
+
+struct RequireUser;
+
+#[async_trait::async_trait]
+impl ServerFnMiddleware for RequireUser {
+    async fn before(&self, context: &DioxusServerContext) -> Result<(), StatusError> {
+        let BearerToken(token) = context.extract().await?;
+        if token != "secret" {
+            return Err(StatusError::forbidden("Wrong token"));
+        }
+        context.insert(User("ferris".to_string()));
+        Ok(())
+    }
+
+    async fn after(&self, _: &DioxusServerContext, response: &mut http::response::Parts) {
+        response
+            .headers
+            .insert("x-checked", "true".parse().unwrap());
+    }
+}
+
+#[server(endpoint = "profile", middleware = RequireUser)]
+async fn profile() -> Result<String, ServerFnError<StatusError>> {
+    let FromContext(User(name)) = extract()
+        .await
+        .map_err(|_| StatusError::internal("The middleware did not run"))?;
+    let cookies: Cookies = extract().await.unwrap_or_default();
+    Ok(format!("{name} {}", cookies.get("theme").unwrap_or("none")))
+}
+
+#[server(endpoint = "lookup")]
+async fn lookup(id: u32) -> Result<u32, ServerFnError<StatusError>> {
+    if id == 0 {
+        return Err(StatusError::not_found("No such id").into());
+    }
+    Ok(id)
+}
+
+#[server(endpoint = "broken")]
+async fn broken() -> Result<(), ServerFnError> {
+    Err(ServerFnError::ServerError(
+        "The database is down".to_string(),
+    ))
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct OtherError;
+
+impl Display for OtherError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "other")
+    }
+}
+
+impl FromStr for OtherError {
+    type Err = ();
+
+    fn from_str(_: &str) -> Result<Self, Self::Err> {
+        Ok(Self)
+    }
+}
+
+#[server(endpoint = "other")]
+async fn other() -> Result<(), ServerFnError<OtherError>> {
+    Err(OtherError.into())
+}
+
+async fn call(router: &Router, request: Request<Body>) -> (StatusCode, http::HeaderMap, String) {
+    let response = router.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, headers, String::from_utf8(body.to_vec()).unwrap())
+}
+
+fn post(path: &str) -> http::request::Builder {
+    Request::post(path).header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+}
+
+fn status_error(body: &str) -> StatusError {
+    match ServerFnError::<StatusError>::de(body) {
+        ServerFnError::WrappedServerError(error) => error,
+        error => panic!("expected a status error, found {error:?}"),
+    }
+}
+
+#[tokio::test]
+async fn middleware_guards_the_server_function() {
+    let router = Router::new().register_server_functions();
+
+    // No token
+    let (status, headers, body) =
+        call(&router, post("/api/profile").body(Body::empty()).unwrap()).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        status_error(&body),
+        StatusError::unauthorized("Missing bearer token")
+    );
+    assert!(!headers.contains_key("x-checked"));
+
+    // Wrong token
+    let request = post("/api/profile")
+        .header(header::AUTHORIZATION, "Bearer guess")
+        .body(Body::empty())
+        .unwrap();
+    let (status, _, body) = call(&router, request).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(status_error(&body).message, "Wrong token");
+
+    // The middleware passes context to the server function and edits the response
+    let request = post("/api/profile")
+        .header(header::AUTHORIZATION, "Bearer secret")
+        .header(header::COOKIE, "session=1; theme=dark")
+        .body(Body::empty())
+        .unwrap();
+    let (status, headers, body) = call(&router, request).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, r#""ferris dark""#);
+    assert_eq!(headers["x-checked"], "true");
+}
+
+#[tokio::test]
+async fn errors_are_sent_with_their_status() {
+    let router = Router::new().register_server_functions();
+
+    let lookup = |body: &'static str| post("/api/lookup").body(Body::from(body)).unwrap();
+
+    let (status, _, body) = call(&router, lookup("id=7")).await;
+    assert_eq!((status, body.as_str()), (StatusCode::OK, "7"));
+
+    let (status, _, body) = call(&router, lookup("id=0")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(status_error(&body), StatusError::not_found("No such id"));
+
+    // Arguments that fail to parse are the client's fault
+    let (status, _, _) = call(&router, lookup("id=seven")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _, body) = call(&router, post("/api/broken").body(Body::empty()).unwrap()).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(body.contains("The database is down"));
+}
+
+#[tokio::test]
+async fn the_error_mapper_sees_every_status_error() {
+    let mapper = ServerFnErrorMapper::new(|error| match error {
+        ServerFnError::ServerError(_) => StatusError::new(503, "Try again later").into(),
+        error => error,
+    });
+    let router =
+        Router::new().register_server_functions_with_context(Arc::new(vec![Box::new(move || {
+            Box::new(mapper.clone())
+        })]));
+
+    let (status, _, body) = call(&router, post("/api/broken").body(Body::empty()).unwrap()).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(
+        status_error(&body),
+        StatusError::new(503, "Try again later")
+    );
+
+    // Errors with other custom error types can't be decoded, so they are sent unchanged
+    let (status, _, body) = call(&router, post("/api/other").body(Body::empty()).unwrap()).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(body, "WrappedServerFn|other");
+}
+
+static ORDER: std::sync::Mutex<Vec<&str>> = std::sync::Mutex::new(Vec::new());
+
+struct Log(&'static str);
+
+#[async_trait::async_trait]
+impl ServerFnMiddleware for Log {
+    async fn before(&self, _: &DioxusServerContext) -> Result<(), StatusError> {
+        ORDER.lock().unwrap().push(self.0);
+        Ok(())
+    }
+
+    async fn after(&self, _: &DioxusServerContext, _: &mut http::response::Parts) {
+        ORDER.lock().unwrap().push(self.0);
+    }
+}
+
+#[server(endpoint = "ordered", middleware = Log("first"), middleware = Log("second"))]
+async fn ordered() -> Result<(), ServerFnError> {
+    ORDER.lock().unwrap().push("server function");
+    Ok(())
+}
+
+#[tokio::test]
+async fn middleware_runs_in_the_order_it_is_declared() {
+    let router = Router::new().register_server_functions();
+    let (status, _, _) = call(&router, post("/api/ordered").body(Body::empty()).unwrap()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        *ORDER.lock().unwrap(),
+        ["first", "second", "server function", "second", "first"]
+    );
+}
@@ -0,0 +1,49 @@
+use dioxus::fullstack::prelude::{DioxusServerContext, SSRState, ServeConfigBuilder};
+use dioxus::prelude::*;
+use futures_util::StreamExt;
+
+const INDEX: &str =
+    r#"<html><body><div id="main"></div><script src="app.js"></script></body></html>"#;
+
+async fn render_page(app: fn() -> Element) -> String {
+    let cfg = ServeConfigBuilder::new()
+        .index_html(INDEX.to_string())
+        .build();
+    let state = SSRState::new(&cfg);
+    let (_, stream) = state
+        .render(
+            "/".to_string(),
+            &cfg,
+            move || VirtualDom::new(app),
+            &DioxusServerContext::default(),
+        )
+        .await
+        .unwrap();
+    stream
+        .map(|chunk| chunk.unwrap())
+        .collect::<Vec<_>>()
+        .await
+        .concat()
+}
+
+#[tokio::test]
+async fn portals_are_written_after_the_main_element() {
+    fn app() -> Element {
+        rsx! {
+            div {
+                "page"
+                Portal { target: "modal-root", p { "in the modal" } }
+            }
+        }
+    }
+
+    let page = render_page(app).await;
+
+    // The portal children are not part of the hydrated tree, so they don't get hydration ids and are written outside of the main element
+    let main_end = page.find(r#"<script src="app.js">"#).unwrap();
+    let portal = page
+        .find(r#"<div id="modal-root" data-dioxus-ssr-portal="true"><p>in the modal</p></div>"#)
+        .expect("the portal to be rendered into the page");
+    assert!(portal > main_end);
+    assert_eq!(page.matches("in the modal").count(), 1);
+}
@@ -0,0 +1,244 @@
+use std::{fmt::Display, str::FromStr};
+
+use bytes::Bytes;
+use dioxus::prelude::*;
+use futures_util::{stream, FutureExt, Stream, StreamExt};
+use server_fn::{
+    codec::{Encoding, FromRes, IntoRes},
+    response::{ClientRes, Res},
+};
+
+/// A response that stores the streamed body on the server and replays it in chunks on the client
+struct FakeResponse {
+    content_type: String,
+    chunks: Vec<Bytes>,
+}
+
+impl<E: Send + 'static> Res<E> for FakeResponse {
+    fn try_from_string(_: &str, _: String) -> Result<Self, ServerFnError<E>> {
+        unimplemented!()
+    }
+
+    fn try_from_bytes(_: &str, _: Bytes) -> Result<Self, ServerFnError<E>> {
+        unimplemented!()
+    }
+
+    fn try_from_stream(
+        content_type: &str,
+        data: impl Stream<Item = Result<Bytes, ServerFnError<E>>> + Send + 'static,
+    ) -> Result<Self, ServerFnError<E>> {
+        // The streams in these tests are always ready
+        let chunks = data
+            .map(|chunk| chunk.unwrap_or_else(|_| panic!("encoding failed")))
+            .collect::<Vec<_>>()
+            .now_or_never()
+            .unwrap();
+        Ok(Self {
+            content_type: content_type.to_string(),
+            chunks,
+        })
+    }
+
+    fn error_response(_: &str, _: &ServerFnError<E>) -> Self {
+        unimplemented!()
+    }
+
+    fn redirect(&mut self, _: &str) {}
+}
+
+impl<E: Send> ClientRes<E> for FakeResponse {
+    async fn try_into_string(self) -> Result<String, ServerFnError<E>> {
+        unimplemented!()
+    }
+
+    async fn try_into_bytes(self) -> Result<Bytes, ServerFnError<E>> {
+        unimplemented!()
+    }
+
+    fn try_into_stream(
+        self,
+    ) -> Result<
+        impl Stream<Item = Result<Bytes, ServerFnError>> + Send + Sync + 'static,
+        ServerFnError<E>,
+    > {
+        Ok(stream::iter(self.chunks.into_iter().map(Ok)))
+    }
+
+    fn status(&self) -> u16 {
+        200
+    }
+
+    fn status_text(&self) -> String {
+        "OK".to_string()
+    }
+
+    fn location(&self) -> String {
+        String::new()
+    }
+
+    fn has_redirect(&self) -> bool {
+        false
+    }
+}
+
+impl FakeResponse {
+    fn body(&self) -> String {
+        String::from_utf8(self.chunks.concat()).unwrap()
+    }
+
+    /// Split the body into chunks of a fixed number of bytes, ignoring frame and character boundaries
+    fn rechunk(self, size: usize) -> Self {
+        let body = self.chunks.concat();
+        Self {
+            content_type: self.content_type,
+            chunks: body.chunks(size).map(Bytes::copy_from_slice).collect(),
+        }
+    }
+
+    fn from_body(body: &[u8]) -> Self {
+        Self {
+            content_type: String::new(),
+            chunks: vec![Bytes::copy_from_slice(body)],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct MultiLineError(String);
+
+impl Display for MultiLineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for MultiLineError {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.to_string()))
+    }
+}
+
+fn items() -> Vec<Result<String, ServerFnError<MultiLineError>>> {
+    vec![
+        Ok("plain".to_string()),
+        Ok("crab 🦀 and\nnew line".to_string()),
+        Err(MultiLineError("first line\nsecond line".to_string()).into()),
+        Ok("after the error".to_string()),
+    ]
+}
+
+async fn encode<Enc>() -> FakeResponse
+where
+    Enc: Encoding,
+    ServerStream<String, MultiLineError>: IntoRes<Enc, FakeResponse, MultiLineError>,
+{
+    let stream = ServerStream::try_new(stream::iter(items()));
+    IntoRes::<Enc, FakeResponse, MultiLineError>::into_res(stream)
+        .await
+        .unwrap()
+}
+
+async fn decode<Enc>(response: FakeResponse) -> Vec<Result<String, ServerFnError<MultiLineError>>>
+where
+    Enc: Encoding,
+    ServerStream<String, MultiLineError>: FromRes<Enc, FakeResponse, MultiLineError>,
+{
+    let stream = <ServerStream<String, MultiLineError> as FromRes<Enc, _, _>>::from_res(response)
+        .await
+        .unwrap();
+    stream.collect().await
+}
+
+#[tokio::test]
+async fn json_lines_round_trip() {
+    let response = encode::<JsonLines>().await;
+    assert_eq!(response.content_type, "application/x-ndjson");
+    assert_eq!(
+        response.body(),
+        concat!(
+            "{\"Ok\":\"plain\"}\n",
+            "{\"Ok\":\"crab 🦀 and\\nnew line\"}\n",
+            "{\"Err\":\"WrappedServerFn|first line\\nsecond line\"}\n",
+            "{\"Ok\":\"after the error\"}\n",
+        )
+    );
+
+    // Frames and characters split between chunks are put back together
+    for size in [1, 2, 3, 7, 1024] {
+        let decoded = decode::<JsonLines>(encode::<JsonLines>().await.rechunk(size)).await;
+        assert_eq!(decoded, items(), "chunk size {size}");
+    }
+}
+
+#[tokio::test]
+async fn server_sent_events_round_trip() {
+    let response = encode::<ServerSentEvents>().await;
+    assert_eq!(response.content_type, "text/event-stream");
+    assert_eq!(
+        response.body(),
+        concat!(
+            "data: \"plain\"\n\n",
+            "data: \"crab 🦀 and\\nnew line\"\n\n",
+            "event: error\ndata: WrappedServerFn|first line\ndata: second line\n\n",
+            "data: \"after the error\"\n\n",
+        )
+    );
+
+    for size in [1, 2, 3, 7, 1024] {
+        let decoded =
+            decode::<ServerSentEvents>(encode::<ServerSentEvents>().await.rechunk(size)).await;
+        assert_eq!(decoded, items(), "chunk size {size}");
+    }
+}
+
+#[tokio::test]
+async fn server_sent_events_follow_the_spec() {
+    // Comments and events without data are skipped, and multiple data fields are joined with new lines
+    let body = ": keep alive\n\nevent: ping\n\ndata: [1,\ndata:2]\n\ndata: [3]";
+    let decoded = <ServerStream<Vec<u32>> as FromRes<ServerSentEvents, _, _>>::from_res(
+        FakeResponse::from_body(body.as_bytes()),
+    )
+    .await
+    .unwrap();
+    let decoded: Vec<_> = decoded.map(Result::unwrap).collect().await;
+    assert_eq!(decoded, [vec![1, 2], vec![3]]);
+}
+
+fn assert_invalid_utf8<T: std::fmt::Debug>(result: &Result<T, ServerFnError>) {
+    match result {
+        Err(ServerFnError::Deserialization(message)) => assert!(message.contains("UTF-8")),
+        other => panic!("expected a UTF-8 error, found {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn invalid_utf8_is_reported() {
+    // An invalid byte ends the stream with an error
+    let mut body = b"{\"Ok\":1}\n{\"Ok\":".to_vec();
+    body.push(0xff);
+    body.extend_from_slice(b"2}\n{\"Ok\":3}\n");
+    let decoded = <ServerStream<u32> as FromRes<JsonLines, _, _>>::from_res(
+        FakeResponse::from_body(&body).rechunk(4),
+    )
+    .await
+    .unwrap();
+    let decoded: Vec<_> = decoded.collect().await;
+    assert_eq!(decoded.len(), 2);
+    assert_eq!(decoded[0], Ok(1));
+    assert_invalid_utf8(&decoded[1]);
+
+    // A stream that ends in the middle of a character
+    let mut body = b"{\"Ok\":\"a\"}\n".to_vec();
+    body.extend_from_slice(&"🦀".as_bytes()[..2]);
+    let decoded = <ServerStream<String> as FromRes<JsonLines, _, _>>::from_res(
+        FakeResponse::from_body(&body),
+    )
+    .await
+    .unwrap();
+    let decoded: Vec<_> = decoded.collect().await;
+    assert_eq!(decoded.len(), 2);
+    assert_eq!(decoded[0], Ok("a".to_string()));
+    assert_invalid_utf8(&decoded[1]);
+}
@@ -0,0 +1,84 @@
+#![allow(non_snake_case)]
+
+use dioxus::fullstack::prelude::{DioxusServerContext, SSRState, ServeConfigBuilder};
+use dioxus::prelude::*;
+use futures_util::StreamExt;
+use std::time::Duration;
+
+const INDEX: &str =
+    r#"<html><body><div id="main"></div><script src="app.js"></script></body></html>"#;
+
+/// Render the page and collect the chunks in the order they are streamed
+async fn stream_page(app: fn() -> Element) -> Vec<String> {
+    let cfg = ServeConfigBuilder::new()
+        .index_html(INDEX.to_string())
+        .build();
+    let state = SSRState::new(&cfg);
+    let (_, stream) = state
+        .render(
+            "/".to_string(),
+            &cfg,
+            move || VirtualDom::new(app),
+            &DioxusServerContext::default(),
+        )
+        .await
+        .unwrap();
+    stream.map(|chunk| chunk.unwrap()).collect().await
+}
+
+#[component]
+fn Item(id: usize, delay: u64) -> Element {
+    let mut ready = use_signal(|| delay == 0);
+    if !ready() {
+        let task = use_hook(|| {
+            spawn(async move {
+                tokio::time::sleep(Duration::from_millis(delay)).await;
+                ready.set(true);
+            })
+        });
+        suspend(task)?;
+    }
+    rsx! { "item {id}" }
+}
+
+#[tokio::test]
+async fn boundaries_in_a_forwards_list_are_streamed_in_list_order() {
+    fn app() -> Element {
+        // The boundaries resolve last to first
+        rsx! {
+            SuspenseList { reveal_order: RevealOrder::Forwards,
+                for (id , delay) in [(0, 300), (1, 200), (2, 100), (3, 0)] {
+                    SuspenseBoundary {
+                        key: "{id}",
+                        fallback: move |_| rsx! { "loading {id}" },
+                        Item { id, delay }
+                    }
+                }
+            }
+        }
+    }
+
+    let chunks = stream_page(app).await;
+
+    // Every boundary is held back in the initial frame, even the boundary that was ready right away
+    let initial_frame = chunks
+        .iter()
+        .position(|chunk| chunk.contains("loading 0"))
+        .unwrap();
+    for id in 0..4 {
+        assert!(chunks[initial_frame].contains(&format!("loading {id}")));
+        assert!(!chunks[initial_frame].contains(&format!("item {id}")));
+    }
+
+    let streamed_at = |id: usize| {
+        chunks
+            .iter()
+            .position(|chunk| chunk.contains(&format!("item {id}")))
+            .unwrap_or_else(|| panic!("item {id} to be streamed"))
+    };
+    let order: Vec<_> = (0..4).map(streamed_at).collect();
+    assert!(
+        order.windows(2).all(|pair| pair[0] < pair[1]),
+        "boundaries were streamed out of order: {chunks:#?}"
+    );
+}
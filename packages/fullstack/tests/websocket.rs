@@ -0,0 +1,212 @@
+use std::net::SocketAddr;
+
+use axum::Router;
+use dioxus::fullstack::prelude::{
+    server, BearerToken, DioxusRouterExt, DioxusServerContext, ServerFnError, ServerFnMiddleware,
+    StatusError, Websocket,
+};
+use futures_util::{SinkExt, StreamExt};
+use server_fn::error::ServerFnErrorSerde;
+use tokio::net::TcpStream;
+use tokio_tungstenite::{
+    tungstenite::{client::IntoClientRequest, protocol::frame::coding::CloseCode, Error, Message},
+    MaybeTlsStream, WebSocketStream,
+};
+
+struct RequireToken;
+
+#[async_trait::async_trait]
+impl ServerFnMiddleware for RequireToken {
+    async fn before(&self, context: &DioxusServerContext) -> Result<(), StatusError> {
+        let BearerToken(token) = context.extract().await?;
+        if token != "secret" {
+            return Err(StatusError::forbidden("Wrong token"));
+        }
+        Ok(())
+    }
+
+    async fn after(&self, _: &DioxusServerContext, response: &mut http::response::Parts) {
+        response
+            .headers
+            .insert("x-checked", "true".parse().unwrap());
+    }
+}
+
+// The macro must not rely on anything other than the types in the signature being in scope
+#[server(protocol = websocket, endpoint = "echo")]
+async fn echo(prefix: String, mut socket: Websocket<String, String>) -> Result<(), ServerFnError> {
+    while let Some(message) = socket.recv().await {
+        socket.send(format!("{prefix}{}", message?)).await?;
+    }
+    Ok(())
+}
+
+#[server(protocol = websocket, endpoint = "fail")]
+async fn fail(mut socket: Websocket<u32, u32>) -> Result<(), ServerFnError> {
+    let number = match socket.recv().await {
+        Some(number) => number?,
+        None => return Ok(()),
+    };
+    Err(ServerFnError::ServerError(format!("Refusing {number}")))
+}
+
+#[server(protocol = websocket, endpoint = "guarded", middleware = RequireToken)]
+async fn guarded(mut socket: Websocket<String, String>) -> Result<(), ServerFnError> {
+    socket.send("welcome".to_string()).await
+}
+
+#[server(protocol = websocket, endpoint = "linger")]
+async fn linger(socket: Websocket<String, String>) -> Result<(), ServerFnError> {
+    let (mut sender, _) = socket.split();
+    sender.send("started".to_string()).await?;
+    // The task keeps the sender alive after the server function returns
+    tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+        _ = sender.send("too late".to_string()).await;
+    });
+    Ok(())
+}
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+async fn serve() -> SocketAddr {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, Router::new().register_server_functions())
+            .await
+            .unwrap();
+    });
+    addr
+}
+
+/// Open a websocket to a server function and send the arguments the same way the client does
+async fn connect(
+    addr: SocketAddr,
+    endpoint: &str,
+    token: Option<&str>,
+    args: &str,
+) -> Result<(Socket, http::HeaderMap), Error> {
+    let mut request = format!("ws://{addr}/api/{endpoint}")
+        .into_client_request()
+        .unwrap();
+    if let Some(token) = token {
+        request.headers_mut().insert(
+            http::header::AUTHORIZATION,
+            format!("Bearer {token}").parse().unwrap(),
+        );
+    }
+    let (mut socket, response) = tokio_tungstenite::connect_async(request).await?;
+    socket.send(Message::Text(args.to_string())).await.unwrap();
+    Ok((socket, response.headers().clone()))
+}
+
+async fn next_message(socket: &mut Socket) -> Message {
+    tokio::time::timeout(std::time::Duration::from_secs(5), socket.next())
+        .await
+        .expect("timed out waiting for a message")
+        .expect("the socket ended without a close frame")
+        .unwrap()
+}
+
+fn close_error(reason: &str) -> ServerFnError {
+    ServerFnError::de(reason)
+}
+
+#[tokio::test]
+async fn messages_round_trip() {
+    let addr = serve().await;
+    let (mut socket, _) = connect(addr, "echo", None, r#"{"prefix":"echo: "}"#)
+        .await
+        .unwrap();
+
+    for message in ["hello", "world"] {
+        socket
+            .send(Message::Text(format!("\"{message}\"")))
+            .await
+            .unwrap();
+        assert_eq!(
+            next_message(&mut socket).await,
+            Message::Text(format!("\"echo: {message}\""))
+        );
+    }
+
+    socket.close(None).await.unwrap();
+    assert!(matches!(next_message(&mut socket).await, Message::Close(_)));
+}
+
+#[tokio::test]
+async fn server_errors_are_sent_in_the_close_frame() {
+    let addr = serve().await;
+    let (mut socket, _) = connect(addr, "fail", None, "{}").await.unwrap();
+    socket.send(Message::Text("7".to_string())).await.unwrap();
+
+    match next_message(&mut socket).await {
+        Message::Close(Some(frame)) => {
+            assert_eq!(frame.code, CloseCode::Error);
+            assert_eq!(
+                close_error(&frame.reason),
+                ServerFnError::ServerError("Refusing 7".to_string())
+            );
+        }
+        other => panic!("expected an error close frame, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn invalid_arguments_close_the_socket_with_an_error() {
+    let addr = serve().await;
+    let (mut socket, _) = connect(addr, "echo", None, r#"{"prefix":1}"#)
+        .await
+        .unwrap();
+
+    match next_message(&mut socket).await {
+        Message::Close(Some(frame)) => {
+            assert_eq!(frame.code, CloseCode::Error);
+            assert!(matches!(close_error(&frame.reason), ServerFnError::Args(_)));
+        }
+        other => panic!("expected an error close frame, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn the_socket_closes_when_the_server_function_returns() {
+    let addr = serve().await;
+    let (mut socket, _) = connect(addr, "linger", None, "{}").await.unwrap();
+
+    assert_eq!(
+        next_message(&mut socket).await,
+        Message::Text("\"started\"".to_string())
+    );
+    match next_message(&mut socket).await {
+        Message::Close(Some(frame)) => assert_eq!(frame.code, CloseCode::Normal),
+        other => panic!("expected a normal close frame, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn middleware_runs_on_the_handshake() {
+    let addr = serve().await;
+
+    let (mut socket, headers) = connect(addr, "guarded", Some("secret"), "{}")
+        .await
+        .unwrap();
+    assert_eq!(headers.get("x-checked").unwrap(), "true");
+    assert_eq!(
+        next_message(&mut socket).await,
+        Message::Text("\"welcome\"".to_string())
+    );
+    // The server function returned successfully, so the server closes the socket normally
+    match next_message(&mut socket).await {
+        Message::Close(Some(frame)) => assert_eq!(frame.code, CloseCode::Normal),
+        other => panic!("expected a normal close frame, got {other:?}"),
+    }
+
+    for (token, status) in [(None, 401), (Some("wrong"), 403)] {
+        match connect(addr, "guarded", token, "{}").await {
+            Err(Error::Http(response)) => assert_eq!(response.status(), status),
+            Err(other) => panic!("expected the handshake to be rejected, got {other}"),
+            Ok(_) => panic!("expected the handshake to be rejected"),
+        }
+    }
+}
@@ -95,3 +95,12 @@ pub use use_signal::*;
 
 mod use_set_compare;
 pub use use_set_compare::*;
+
+mod use_transition;
+pub use use_transition::*;
+
+mod use_deferred_value;
+pub use use_deferred_value::*;
+
+mod use_observer;
+pub use use_observer::*;
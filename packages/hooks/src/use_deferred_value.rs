@@ -0,0 +1,50 @@
+use crate::use_signal;
+use dioxus_core::prelude::*;
+use dioxus_signals::*;
+
+/// A hook that returns a copy of a value that lags behind the latest value.
+///
+/// When the value changes, the deferred value is updated in a transition after the current render. Components that read the deferred value
+/// rerender with [`Priority::Background`], so expensive parts of the UI don't block urgent updates like typing in an input. If those
+/// components suspend, they keep showing the UI for the previous value until the new UI is ready.
+///
+/// ## Example
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// fn app() -> Element {
+///     let mut query = use_signal(String::new);
+///     // The input updates immediately, and the results catch up in the background
+///     let deferred_query = use_deferred_value(query());
+///
+///     rsx! {
+///         input {
+///             value: "{query}",
+///             oninput: move |event| query.set(event.value()),
+///         }
+///         SearchResults { query: deferred_query }
+///     }
+/// }
+///
+/// #[component]
+/// fn SearchResults(query: ReadOnlySignal<String>) -> Element {
+///     rsx! { "Results for {query}" }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_deferred_value<T: Clone + PartialEq + 'static>(value: T) -> ReadOnlySignal<T> {
+    let mut deferred = use_signal(|| value.clone());
+
+    if *deferred.peek() != value {
+        // Wait until the urgent render finishes before starting the transition
+        queue_effect(move || {
+            start_transition(|| {
+                if *deferred.peek() != value {
+                    deferred.set(value);
+                }
+            })
+        });
+    }
+
+    deferred.into()
+}
@@ -0,0 +1,107 @@
+use std::rc::Rc;
+
+use dioxus_core::prelude::use_hook;
+use dioxus_html::{IntersectionObservation, IntersectionOptions, MountedData, ResizeObservation};
+use dioxus_signals::{ReadOnlySignal, Readable, Writable};
+
+use crate::{use_resource, use_signal};
+
+/// Track the size of a mounted element. The signal holds the latest observation, or `None` until the element is mounted
+/// and the renderer reports its size.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let mut element = use_signal(|| None);
+///     let size = use_resize_observer(element);
+///
+///     rsx! {
+///         textarea { onmounted: move |event| element.set(Some(event.data())) }
+///         if let Some(size) = size() {
+///             "The text area is {size.border_box.width}px wide"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_resize_observer(
+    element: impl Into<ReadOnlySignal<Option<Rc<MountedData>>>>,
+) -> ReadOnlySignal<Option<ResizeObservation>> {
+    let element = element.into();
+    let mut observation = use_signal(|| None);
+
+    // Restart the observer whenever the element changes
+    _ = use_resource(move || async move {
+        let Some(element) = element.cloned() else {
+            return;
+        };
+        let mut observer = match element.observe_resize().await {
+            Ok(observer) => observer,
+            Err(err) => {
+                tracing::error!("Failed to observe the size of an element: {err}");
+                return;
+            }
+        };
+        while let Some(next) = observer.next().await {
+            observation.set(Some(next));
+        }
+    });
+
+    observation.into()
+}
+
+/// Track how much of a mounted element is visible in the viewport. The signal holds the latest observation, or `None`
+/// until the element is mounted and the renderer reports it.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let mut sentinel = use_signal(|| None);
+///     let visible = use_intersection_observer(
+///         sentinel,
+///         IntersectionOptions::new().with_root_margin("200px"),
+///     );
+///
+///     // Load the next page when the end of the list scrolls into view
+///     let near_end = visible().is_some_and(|visible| visible.is_intersecting);
+///
+///     rsx! {
+///         for i in 0..100 {
+///             p { "Item {i}" }
+///         }
+///         div { onmounted: move |event| sentinel.set(Some(event.data())) }
+///         if near_end {
+///             "Loading more..."
+///         }
+///     }
+/// }
+/// ```
+pub fn use_intersection_observer(
+    element: impl Into<ReadOnlySignal<Option<Rc<MountedData>>>>,
+    options: IntersectionOptions,
+) -> ReadOnlySignal<Option<IntersectionObservation>> {
+    let element = element.into();
+    let mut observation = use_signal(|| None);
+
+    // The options are only read when the observer is created
+    let options = use_hook(|| options);
+    _ = use_resource(move || {
+        let options = options.clone();
+        async move {
+            let Some(element) = element.cloned() else {
+                return;
+            };
+            let mut observer = match element.observe_intersection(options).await {
+                Ok(observer) => observer,
+                Err(err) => {
+                    tracing::error!("Failed to observe the visibility of an element: {err}");
+                    return;
+                }
+            };
+            while let Some(next) = observer.next().await {
+                observation.set(Some(next));
+            }
+        }
+    });
+
+    observation.into()
+}
@@ -0,0 +1,73 @@
+use crate::use_signal;
+use dioxus_core::prelude::*;
+use dioxus_signals::*;
+
+/// A hook that lets you mark updates as transitions and track if the transition is still rendering.
+///
+/// Updates made inside [`UseTransition::start`] render with [`Priority::Background`] so they don't block more urgent updates. While the
+/// transition is rendering, components that suspend keep showing their previous UI instead of the suspense fallback.
+/// [`UseTransition::is_pending`] is true until every transition started by this hook has finished rendering and every future it suspended on
+/// has resolved. Transitions started elsewhere in the app don't affect it.
+///
+/// ## Example
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// fn app() -> Element {
+///     let mut tab = use_signal(|| "home");
+///     let transition = use_transition();
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| transition.start(move || tab.set("posts")),
+///             "Posts"
+///         }
+///         if transition.is_pending()() {
+///             "Loading..."
+///         }
+///         "{tab}"
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_transition() -> UseTransition {
+    let pending = use_signal(|| false);
+    let running = use_hook(|| CopyValue::new(0));
+    UseTransition { pending, running }
+}
+
+/// A handle to start transitions created with [`use_transition`]
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseTransition {
+    pending: Signal<bool>,
+    // The number of transitions started by this hook that haven't finished yet
+    running: CopyValue<usize>,
+}
+
+impl UseTransition {
+    /// Run a closure as a transition. Any signals written inside of the closure will rerender their subscribers with [`Priority::Background`].
+    pub fn start(&self, f: impl FnOnce()) {
+        let mut pending = self.pending;
+        let mut running = self.running;
+        // Set the pending state outside of the transition so it renders immediately
+        pending.set(true);
+        running += 1;
+        // Only wait for this transition. Other transitions in the app don't affect the pending state
+        start_transition_then(f, move || {
+            // The component may have been dropped before the transition finished
+            let Ok(mut count) = running.try_write() else {
+                return;
+            };
+            *count -= 1;
+            if *count == 0 {
+                drop(count);
+                pending.set(false);
+            }
+        });
+    }
+
+    /// Get a signal that is true while the transition is rendering
+    pub fn is_pending(&self) -> ReadOnlySignal<bool> {
+        self.pending.into()
+    }
+}
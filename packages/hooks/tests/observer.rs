@@ -0,0 +1,95 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_html::{geometry::PixelsSize, FakeElement, MountedData};
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+thread_local! {
+    static ELEMENT: RefCell<FakeElement> = RefCell::new(FakeElement::new());
+    static SHOW: RefCell<bool> = const { RefCell::new(true) };
+    static RENDERED: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+fn element() -> FakeElement {
+    ELEMENT.with(|element| element.borrow().clone())
+}
+
+fn rendered() -> String {
+    RENDERED.with(|rendered| rendered.borrow().clone())
+}
+
+/// Run the virtual dom for the given number of milliseconds
+async fn run_for(dom: &mut VirtualDom, millis: u64) {
+    let work = async {
+        loop {
+            dom.wait_for_work().await;
+            dom.render_immediate(&mut NoOpMutations);
+        }
+    };
+    tokio::select! {
+        _ = work => {}
+        _ = tokio::time::sleep(Duration::from_millis(millis)) => {}
+    }
+}
+
+fn app() -> Element {
+    if SHOW.with(|show| *show.borrow()) {
+        rsx! { Observed {} }
+    } else {
+        rsx! {}
+    }
+}
+
+fn Observed() -> Element {
+    let mounted = use_signal(|| Some(Rc::new(MountedData::new(element()))));
+    let size = use_resize_observer(mounted);
+    let visible = use_intersection_observer(
+        mounted,
+        IntersectionOptions::new().with_thresholds([0.0, 0.5, 1.0]),
+    );
+    let width = size().map(|size| size.border_box.width).unwrap_or_default();
+    let visible = visible().is_some_and(|visible| visible.is_intersecting);
+    RENDERED.with(|rendered| *rendered.borrow_mut() = format!("width {width} visible {visible}"));
+    rsx! {}
+}
+
+#[tokio::test]
+async fn observations_update_the_signals() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+    run_for(&mut dom, 50).await;
+    assert_eq!(rendered(), "width 0 visible false");
+    assert_eq!(element().observer_count(), 2);
+    assert_eq!(
+        element().intersection_options()[0].thresholds,
+        vec![0.0, 0.5, 1.0]
+    );
+
+    let size = PixelsSize::new(120.0, 40.0);
+    element().resize(ResizeObservation {
+        content_box: size,
+        border_box: size,
+    });
+    element().intersect(IntersectionObservation {
+        is_intersecting: true,
+        intersection_ratio: 1.0,
+        bounding_client_rect: Default::default(),
+        intersection_rect: Default::default(),
+    });
+    run_for(&mut dom, 50).await;
+    assert_eq!(rendered(), "width 120 visible true");
+}
+
+#[tokio::test]
+async fn observers_disconnect_when_the_component_is_dropped() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+    run_for(&mut dom, 50).await;
+    assert_eq!(element().observer_count(), 2);
+
+    SHOW.with(|show| *show.borrow_mut() = false);
+    dom.mark_dirty(ScopeId::APP);
+    run_for(&mut dom, 50).await;
+    assert_eq!(element().observer_count(), 0);
+}
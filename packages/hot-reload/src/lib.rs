@@ -33,6 +33,14 @@ pub enum HotReloadMsg {
     /// An asset discovered by rsx! has been updated
     UpdateAsset(PathBuf),
 
+    /// The stylesheet of a CSS module has been updated
+    UpdateCssModule {
+        /// The id of the module
+        id: String,
+        /// The new scoped stylesheet
+        css: String,
+    },
+
     /// The program needs to be recompiled, and the client should shut down
     Shutdown,
 }
@@ -260,6 +260,9 @@ async fn hotreload_loop(
                 HotReloadMsg::UpdateAsset(asset) => {
                     Message::Text(format!("reload-asset: {}", asset.display()))
                 }
+                HotReloadMsg::UpdateCssModule { id, css } => {
+                    Message::Text(format!("reload-css-module: {id}\n{css}"))
+                }
                 HotReloadMsg::Shutdown => {
                     tracing::info!("🔥 Hot Reload WebSocket shutting down");
                     break;
@@ -344,6 +344,9 @@ mod_methods! {
     spellcheck;
 
     /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/style>
+    ///
+    /// Accepts a string or a [`Style`](crate::inline_style::Style). Spread a [`Style`](crate::inline_style::Style) into
+    /// the element instead to update each property on its own.
     style;
 
     /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/tabindex>
@@ -2500,3 +2503,52 @@ mod_methods! {
     zoom_and_pan: "zoomAndPan";
 
 }
+
+mod_methods! {
+    @base
+    mathml_attributes;
+    map_mathml_attributes;
+    map_html_mathml_attributes_to_rsx;
+
+    /// Prevent the default action for this element.
+    ///
+    /// For more information, see the MDN docs:
+    /// <https://developer.mozilla.org/en-US/docs/Web/API/Event/preventDefault>
+    prevent_default: "dioxus-prevent-default";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/autofocus>
+    autofocus;
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/class>
+    class;
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/dir>
+    dir;
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/displaystyle>
+    displaystyle;
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/id>
+    id;
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/mathbackground>
+    mathbackground;
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/mathcolor>
+    mathcolor;
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/mathsize>
+    mathsize;
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/nonce>
+    nonce;
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/scriptlevel>
+    scriptlevel;
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/style>
+    style;
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/tabindex>
+    tabindex;
+}
@@ -0,0 +1,633 @@
+//! Typed values for enumerated attributes
+//!
+//! Attributes accept any string, so a typo like `r#type: "chekbox"` compiles and silently falls back to the default
+//! behavior in the browser. These enums can be used anywhere the string is accepted, and a typo in a variant name is a
+//! compile error with a suggestion for the closest variant:
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//!
+//! fn app() -> Element {
+//!     rsx! {
+//!         input { r#type: InputType::Checkbox, autocomplete: Autocomplete::Off }
+//!         div { role: AriaRole::Button, aria_pressed: AriaChecked::Mixed, tabindex: 0 }
+//!         a { href: "https://dioxuslabs.com", target: Target::Blank, rel: LinkType::Noopener }
+//!     }
+//! }
+//! ```
+
+use std::fmt::{Display, Formatter};
+
+/// A string could not be parsed into the value of an enumerated attribute
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseAttributeValueError {
+    /// The name of the type the value was parsed into
+    pub expected: &'static str,
+    /// The value that failed to parse
+    pub value: String,
+    /// The closest valid value, if any are close enough to be a likely typo
+    pub suggestion: Option<&'static str>,
+}
+
+impl ParseAttributeValueError {
+    /// Create an error for a value that isn't one of the valid values, suggesting the closest valid value
+    pub fn new(
+        expected: &'static str,
+        value: impl Into<String>,
+        valid: impl IntoIterator<Item = &'static str>,
+    ) -> Self {
+        let value = value.into();
+        Self {
+            expected,
+            suggestion: closest(&value, valid.into_iter()),
+            value,
+        }
+    }
+}
+
+impl Display for ParseAttributeValueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` is not a valid {}", self.value, self.expected)?;
+        if let Some(suggestion) = self.suggestion {
+            write!(f, ", did you mean `{suggestion}`?")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseAttributeValueError {}
+
+/// Find the valid value closest to a typo
+fn closest(value: &str, valid: impl Iterator<Item = &'static str>) -> Option<&'static str> {
+    let value = value.to_ascii_lowercase();
+    valid
+        .map(|candidate| (edit_distance(&value, candidate), candidate))
+        .filter(|(distance, candidate)| *distance <= (candidate.len() / 3).max(1))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+macro_rules! attribute_value {
+    (
+        $(#[$attr:meta])*
+        $name:ident ($description:literal) {
+            $(
+                $(#[$variant_attr:meta])*
+                $variant:ident = $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+        pub enum $name {
+            $(
+                $(#[$variant_attr])*
+                #[doc = concat!("`", $value, "`")]
+                #[cfg_attr(feature = "serialize", serde(rename = $value))]
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// Every valid value
+            pub const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+            /// Get the value as it is written in HTML
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value,)*
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::ParseAttributeValueError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Self::ALL
+                    .iter()
+                    .find(|valid| valid.as_str().eq_ignore_ascii_case(value.trim()))
+                    .copied()
+                    .ok_or_else(|| {
+                        $crate::ParseAttributeValueError::new(
+                            $description,
+                            value,
+                            Self::ALL.iter().map(Self::as_str),
+                        )
+                    })
+            }
+        }
+
+        impl dioxus_core::prelude::IntoAttributeValue for $name {
+            fn into_value(self) -> dioxus_core::AttributeValue {
+                dioxus_core::AttributeValue::Text(self.as_str().to_string())
+            }
+        }
+    };
+}
+
+pub(crate) use attribute_value;
+
+attribute_value! {
+    /// The `type` of an [`input`](crate::elements::input) element
+    InputType("input type") {
+        Button = "button",
+        Checkbox = "checkbox",
+        Color = "color",
+        Date = "date",
+        DatetimeLocal = "datetime-local",
+        Email = "email",
+        File = "file",
+        Hidden = "hidden",
+        Image = "image",
+        Month = "month",
+        Number = "number",
+        Password = "password",
+        Radio = "radio",
+        Range = "range",
+        Reset = "reset",
+        Search = "search",
+        Submit = "submit",
+        Tel = "tel",
+        Text = "text",
+        Time = "time",
+        Url = "url",
+        Week = "week",
+    }
+}
+
+attribute_value! {
+    /// The `type` of a [`button`](crate::elements::button) element
+    ButtonType("button type") {
+        Submit = "submit",
+        Reset = "reset",
+        Button = "button",
+    }
+}
+
+attribute_value! {
+    /// The hint for the browser to autofill a form control with the `autocomplete` attribute
+    Autocomplete("autocomplete value") {
+        Off = "off",
+        On = "on",
+        Name = "name",
+        HonorificPrefix = "honorific-prefix",
+        GivenName = "given-name",
+        AdditionalName = "additional-name",
+        FamilyName = "family-name",
+        HonorificSuffix = "honorific-suffix",
+        Nickname = "nickname",
+        Email = "email",
+        Username = "username",
+        NewPassword = "new-password",
+        CurrentPassword = "current-password",
+        OneTimeCode = "one-time-code",
+        OrganizationTitle = "organization-title",
+        Organization = "organization",
+        StreetAddress = "street-address",
+        AddressLine1 = "address-line1",
+        AddressLine2 = "address-line2",
+        AddressLine3 = "address-line3",
+        AddressLevel1 = "address-level1",
+        AddressLevel2 = "address-level2",
+        AddressLevel3 = "address-level3",
+        AddressLevel4 = "address-level4",
+        Country = "country",
+        CountryName = "country-name",
+        PostalCode = "postal-code",
+        CcName = "cc-name",
+        CcGivenName = "cc-given-name",
+        CcAdditionalName = "cc-additional-name",
+        CcFamilyName = "cc-family-name",
+        CcNumber = "cc-number",
+        CcExp = "cc-exp",
+        CcExpMonth = "cc-exp-month",
+        CcExpYear = "cc-exp-year",
+        CcCsc = "cc-csc",
+        CcType = "cc-type",
+        TransactionCurrency = "transaction-currency",
+        TransactionAmount = "transaction-amount",
+        Language = "language",
+        Bday = "bday",
+        BdayDay = "bday-day",
+        BdayMonth = "bday-month",
+        BdayYear = "bday-year",
+        Sex = "sex",
+        Tel = "tel",
+        TelCountryCode = "tel-country-code",
+        TelNational = "tel-national",
+        TelAreaCode = "tel-area-code",
+        TelLocal = "tel-local",
+        TelExtension = "tel-extension",
+        Impp = "impp",
+        Url = "url",
+        Photo = "photo",
+        Webauthn = "webauthn",
+    }
+}
+
+attribute_value! {
+    /// The relationship of a linked resource to the current document with the `rel` attribute
+    LinkType("link type") {
+        Alternate = "alternate",
+        Author = "author",
+        Bookmark = "bookmark",
+        Canonical = "canonical",
+        DnsPrefetch = "dns-prefetch",
+        External = "external",
+        Help = "help",
+        Icon = "icon",
+        License = "license",
+        Manifest = "manifest",
+        Me = "me",
+        Modulepreload = "modulepreload",
+        Next = "next",
+        Nofollow = "nofollow",
+        Noopener = "noopener",
+        Noreferrer = "noreferrer",
+        Opener = "opener",
+        Pingback = "pingback",
+        Preconnect = "preconnect",
+        Prefetch = "prefetch",
+        Preload = "preload",
+        Prev = "prev",
+        Search = "search",
+        Stylesheet = "stylesheet",
+        Tag = "tag",
+    }
+}
+
+attribute_value! {
+    /// Where to open a link or show the response of a form with the `target` attribute
+    Target("target") {
+        Self_ = "_self",
+        Blank = "_blank",
+        Parent = "_parent",
+        Top = "_top",
+    }
+}
+
+attribute_value! {
+    /// How to fetch a resource across origins with the `crossorigin` attribute
+    CrossOrigin("crossorigin value") {
+        Anonymous = "anonymous",
+        UseCredentials = "use-credentials",
+    }
+}
+
+attribute_value! {
+    /// How much of the referrer to send when fetching a resource with the `referrerpolicy` attribute
+    ReferrerPolicy("referrer policy") {
+        NoReferrer = "no-referrer",
+        NoReferrerWhenDowngrade = "no-referrer-when-downgrade",
+        Origin = "origin",
+        OriginWhenCrossOrigin = "origin-when-cross-origin",
+        SameOrigin = "same-origin",
+        StrictOrigin = "strict-origin",
+        StrictOriginWhenCrossOrigin = "strict-origin-when-cross-origin",
+        UnsafeUrl = "unsafe-url",
+    }
+}
+
+attribute_value! {
+    /// When to load an image or iframe with the `loading` attribute
+    Loading("loading value") {
+        Eager = "eager",
+        Lazy = "lazy",
+    }
+}
+
+attribute_value! {
+    /// How to decode an image with the `decoding` attribute
+    ImageDecoding("image decoding") {
+        Sync = "sync",
+        Async = "async",
+        Auto = "auto",
+    }
+}
+
+attribute_value! {
+    /// How much of a media element to load before it is played with the `preload` attribute
+    Preload("preload value") {
+        None = "none",
+        Metadata = "metadata",
+        Auto = "auto",
+    }
+}
+
+attribute_value! {
+    /// The HTTP method a form is submitted with
+    FormMethod("form method") {
+        Get = "get",
+        Post = "post",
+    }
+}
+
+attribute_value! {
+    /// The method a form is submitted with, including `dialog` which closes the dialog the form is in
+    FormDialogMethod("form method") {
+        Get = "get",
+        Post = "post",
+        Dialog = "dialog",
+    }
+}
+
+attribute_value! {
+    /// How the data of a form is encoded when it is submitted with the `enctype` attribute
+    FormEncodingType("form encoding type") {
+        UrlEncoded = "application/x-www-form-urlencoded",
+        MultipartFormData = "multipart/form-data",
+        TextPlain = "text/plain",
+    }
+}
+
+attribute_value! {
+    /// How the text of a textarea is wrapped when the form is submitted with the `wrap` attribute
+    Wrap("wrap value") {
+        Hard = "hard",
+        Soft = "soft",
+        Off = "off",
+    }
+}
+
+attribute_value! {
+    /// The cells a table header applies to with the `scope` attribute
+    TableHeaderScope("table header scope") {
+        Row = "row",
+        Col = "col",
+        Rowgroup = "rowgroup",
+        Colgroup = "colgroup",
+    }
+}
+
+attribute_value! {
+    /// The virtual keyboard to show for an editable element with the `inputmode` attribute
+    InputMode("input mode") {
+        None = "none",
+        Text = "text",
+        Decimal = "decimal",
+        Numeric = "numeric",
+        Tel = "tel",
+        Search = "search",
+        Email = "email",
+        Url = "url",
+    }
+}
+
+attribute_value! {
+    /// The label of the enter key on a virtual keyboard with the `enterkeyhint` attribute
+    EnterKeyHint("enter key hint") {
+        Enter = "enter",
+        Done = "done",
+        Go = "go",
+        Next = "next",
+        Previous = "previous",
+        Search = "search",
+        Send = "send",
+    }
+}
+
+attribute_value! {
+    /// A WAI-ARIA role for the `role` attribute
+    AriaRole("ARIA role") {
+        Alert = "alert",
+        AlertDialog = "alertdialog",
+        Application = "application",
+        Article = "article",
+        Banner = "banner",
+        Blockquote = "blockquote",
+        Button = "button",
+        Caption = "caption",
+        Cell = "cell",
+        Checkbox = "checkbox",
+        Code = "code",
+        ColumnHeader = "columnheader",
+        Combobox = "combobox",
+        Complementary = "complementary",
+        ContentInfo = "contentinfo",
+        Definition = "definition",
+        Deletion = "deletion",
+        Dialog = "dialog",
+        Directory = "directory",
+        Document = "document",
+        Emphasis = "emphasis",
+        Feed = "feed",
+        Figure = "figure",
+        Form = "form",
+        Generic = "generic",
+        GraphicsDocument = "graphics-document",
+        GraphicsObject = "graphics-object",
+        GraphicsSymbol = "graphics-symbol",
+        Grid = "grid",
+        GridCell = "gridcell",
+        Group = "group",
+        Heading = "heading",
+        Img = "img",
+        Insertion = "insertion",
+        Link = "link",
+        List = "list",
+        Listbox = "listbox",
+        ListItem = "listitem",
+        Log = "log",
+        Main = "main",
+        Marquee = "marquee",
+        Math = "math",
+        Menu = "menu",
+        Menubar = "menubar",
+        MenuItem = "menuitem",
+        MenuItemCheckbox = "menuitemcheckbox",
+        MenuItemRadio = "menuitemradio",
+        Meter = "meter",
+        Navigation = "navigation",
+        None = "none",
+        Note = "note",
+        Option = "option",
+        Paragraph = "paragraph",
+        Presentation = "presentation",
+        Progressbar = "progressbar",
+        Radio = "radio",
+        RadioGroup = "radiogroup",
+        Region = "region",
+        Row = "row",
+        RowGroup = "rowgroup",
+        RowHeader = "rowheader",
+        Scrollbar = "scrollbar",
+        Search = "search",
+        Searchbox = "searchbox",
+        Separator = "separator",
+        Slider = "slider",
+        SpinButton = "spinbutton",
+        Status = "status",
+        Strong = "strong",
+        Subscript = "subscript",
+        Superscript = "superscript",
+        Switch = "switch",
+        Tab = "tab",
+        Table = "table",
+        TabList = "tablist",
+        TabPanel = "tabpanel",
+        Term = "term",
+        Textbox = "textbox",
+        Time = "time",
+        Timer = "timer",
+        Toolbar = "toolbar",
+        Tooltip = "tooltip",
+        Tree = "tree",
+        TreeGrid = "treegrid",
+        TreeItem = "treeitem",
+    }
+}
+
+attribute_value! {
+    /// A tri-state value for the `aria-checked` and `aria-pressed` attributes
+    AriaChecked("aria-checked value") {
+        True = "true",
+        False = "false",
+        Mixed = "mixed",
+        Undefined = "undefined",
+    }
+}
+
+attribute_value! {
+    /// The kind of current item an element represents with the `aria-current` attribute
+    AriaCurrent("aria-current value") {
+        Page = "page",
+        Step = "step",
+        Location = "location",
+        Date = "date",
+        Time = "time",
+        True = "true",
+        False = "false",
+    }
+}
+
+attribute_value! {
+    /// How assistive technology announces updates to a live region with the `aria-live` attribute
+    AriaLive("aria-live value") {
+        Off = "off",
+        Polite = "polite",
+        Assertive = "assertive",
+    }
+}
+
+attribute_value! {
+    /// The kind of popup an element opens with the `aria-haspopup` attribute
+    AriaHasPopup("aria-haspopup value") {
+        False = "false",
+        True = "true",
+        Menu = "menu",
+        Listbox = "listbox",
+        Tree = "tree",
+        Grid = "grid",
+        Dialog = "dialog",
+    }
+}
+
+attribute_value! {
+    /// Why the value of an element is invalid with the `aria-invalid` attribute
+    AriaInvalid("aria-invalid value") {
+        False = "false",
+        True = "true",
+        Grammar = "grammar",
+        Spelling = "spelling",
+    }
+}
+
+attribute_value! {
+    /// How suggestions are shown for an input with the `aria-autocomplete` attribute
+    AriaAutocomplete("aria-autocomplete value") {
+        None = "none",
+        Inline = "inline",
+        List = "list",
+        Both = "both",
+    }
+}
+
+attribute_value! {
+    /// The orientation of an element with the `aria-orientation` attribute
+    AriaOrientation("aria-orientation value") {
+        Horizontal = "horizontal",
+        Vertical = "vertical",
+        Undefined = "undefined",
+    }
+}
+
+attribute_value! {
+    /// The direction a table or grid column is sorted in with the `aria-sort` attribute
+    AriaSort("aria-sort value") {
+        None = "none",
+        Ascending = "ascending",
+        Descending = "descending",
+        Other = "other",
+    }
+}
+
+/// Convert an enumerated HTML attribute value into the typed value for rsx, e.g. `InputType::Checkbox` for the `type`
+/// of an `input`. Returns `None` if the attribute is not enumerated or the value is not valid.
+#[cfg(feature = "html-to-rsx")]
+pub fn map_html_attribute_value_to_rsx(
+    element: &str,
+    attribute: &str,
+    value: &str,
+) -> Option<String> {
+    macro_rules! typed {
+        ($ty:ident) => {
+            value
+                .parse::<$ty>()
+                .ok()
+                .map(|value| format!("{}::{:?}", stringify!($ty), value))
+        };
+    }
+
+    match (element, attribute) {
+        ("input", "type") => typed!(InputType),
+        ("button", "type") => typed!(ButtonType),
+        ("input" | "select" | "textarea", "autocomplete") => typed!(Autocomplete),
+        ("a" | "area" | "link", "rel") => typed!(LinkType),
+        ("a" | "area" | "base" | "form", "target") => typed!(Target),
+        ("button" | "input", "formtarget") => typed!(Target),
+        (_, "crossorigin") => typed!(CrossOrigin),
+        (_, "referrerpolicy") => typed!(ReferrerPolicy),
+        ("img" | "iframe", "loading") => typed!(Loading),
+        ("img", "decoding") => typed!(ImageDecoding),
+        ("audio" | "video", "preload") => typed!(Preload),
+        ("form", "method") => typed!(FormDialogMethod),
+        ("button" | "input", "formmethod") => typed!(FormDialogMethod),
+        ("form", "enctype") | ("button" | "input", "formenctype") => typed!(FormEncodingType),
+        ("textarea", "wrap") => typed!(Wrap),
+        ("th", "scope") => typed!(TableHeaderScope),
+        (_, "inputmode") => typed!(InputMode),
+        (_, "enterkeyhint") => typed!(EnterKeyHint),
+        (_, "role") => typed!(AriaRole),
+        (_, "aria-checked" | "aria-pressed") => typed!(AriaChecked),
+        (_, "aria-current") => typed!(AriaCurrent),
+        (_, "aria-live") => typed!(AriaLive),
+        (_, "aria-haspopup") => typed!(AriaHasPopup),
+        (_, "aria-invalid") => typed!(AriaInvalid),
+        (_, "aria-autocomplete") => typed!(AriaAutocomplete),
+        (_, "aria-orientation") => typed!(AriaOrientation),
+        (_, "aria-sort") => typed!(AriaSort),
+        _ => None,
+    }
+}
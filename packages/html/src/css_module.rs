@@ -0,0 +1,169 @@
+//! Stylesheets scoped to a component
+//!
+//! The `css_module!` macro reads a stylesheet at compile time, renames every class in it to a name that is unique to
+//! the stylesheet, and exposes the new names as constants. [`use_css_module`] renders the scoped stylesheet once no matter
+//! how many components use it:
+//!
+//! ```rust, ignore
+//! use dioxus::prelude::*;
+//!
+//! // Paths are relative to the root of the crate
+//! css_module!(Styles = "src/button.module.css");
+//!
+//! #[component]
+//! fn Button() -> Element {
+//!     let stylesheet = use_css_module(&Styles::MODULE);
+//!
+//!     rsx! {
+//!         {stylesheet}
+//!         button { class: Styles::primary, "Click me" }
+//!     }
+//! }
+//! ```
+//!
+//! The stylesheet is a normal `style` element, so server side rendering inlines the styles of every module the page
+//! uses before the first element that uses them.
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+    sync::Arc,
+};
+
+use dioxus_core::prelude::*;
+use dioxus_core::{Attribute, Template, TemplateAttribute, TemplateNode};
+
+/// A stylesheet with every class scoped to the module. This is created by the `css_module!` macro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CssModule {
+    id: &'static str,
+    path: &'static str,
+    css: &'static str,
+}
+
+impl CssModule {
+    /// Create a new CSS module
+    #[doc(hidden)]
+    pub const fn new(id: &'static str, path: &'static str, css: &'static str) -> Self {
+        Self { id, path, css }
+    }
+
+    /// The id of the module. Every class in the module ends with `_{id}`.
+    pub const fn id(&self) -> &'static str {
+        self.id
+    }
+
+    /// The path of the stylesheet relative to the root of the crate
+    pub const fn path(&self) -> &'static str {
+        self.path
+    }
+
+    /// The scoped stylesheet
+    pub const fn css(&self) -> &'static str {
+        self.css
+    }
+}
+
+/// The components using each module in the order they started using it. The first one renders the stylesheet.
+type ModuleUsers = HashMap<&'static str, Vec<(usize, ScopeId)>>;
+
+/// Keeps track of every component that uses each module so only one of them renders the stylesheet
+#[derive(Clone)]
+struct CssModuleRegistry {
+    users: Rc<RefCell<ModuleUsers>>,
+    next_user: Rc<Cell<usize>>,
+    schedule_update: Arc<dyn Fn(ScopeId) + Send + Sync>,
+}
+
+impl CssModuleRegistry {
+    fn new() -> Self {
+        Self {
+            users: Default::default(),
+            next_user: Default::default(),
+            schedule_update: schedule_update_any(),
+        }
+    }
+
+    fn add(&self, module: &'static str, scope: ScopeId) -> usize {
+        let user = self.next_user.get();
+        self.next_user.set(user + 1);
+        self.users
+            .borrow_mut()
+            .entry(module)
+            .or_default()
+            .push((user, scope));
+        user
+    }
+
+    fn remove(&self, module: &'static str, user: usize) {
+        let mut users = self.users.borrow_mut();
+        let Some(module_users) = users.get_mut(module) else {
+            return;
+        };
+        let was_rendering = module_users.first().map(|(first, _)| *first) == Some(user);
+        module_users.retain(|(other, _)| *other != user);
+
+        // Hand the stylesheet off to the next component using the module
+        if was_rendering {
+            if let Some((_, scope)) = module_users.first() {
+                (self.schedule_update)(*scope);
+            }
+        }
+        if module_users.is_empty() {
+            users.remove(module);
+        }
+    }
+
+    fn renders(&self, module: &'static str, user: usize) -> bool {
+        self.users
+            .borrow()
+            .get(module)
+            .and_then(|users| users.first())
+            .is_some_and(|(first, _)| *first == user)
+    }
+}
+
+/// Use a CSS module in the current component.
+///
+/// This returns the `style` element for the module if this is the first component that is currently using it, and an
+/// empty element otherwise. Render the returned element somewhere in the component. If the component that renders the
+/// stylesheet is removed, the next component using the module renders it instead.
+pub fn use_css_module(module: &'static CssModule) -> Element {
+    // The registry is shared by the whole app and created by the first component that uses a module
+    let registry = use_hook(|| {
+        try_consume_context::<CssModuleRegistry>()
+            .unwrap_or_else(|| provide_root_context(CssModuleRegistry::new()))
+    });
+    let user = use_hook(|| registry.add(module.id, current_scope_id().unwrap()));
+    use_drop({
+        let registry = registry.clone();
+        move || registry.remove(module.id, user)
+    });
+
+    if !registry.renders(module.id, user) {
+        return VNode::empty();
+    }
+
+    static TEMPLATE: Template = Template {
+        name: "css_module.rs:143:5:0",
+        roots: &[TemplateNode::Element {
+            tag: "style",
+            namespace: None,
+            attrs: &[TemplateAttribute::Dynamic { id: 0 }],
+            children: &[],
+        }],
+        node_paths: &[],
+        attr_paths: &[&[0]],
+    };
+
+    Ok(VNode::new(
+        None,
+        TEMPLATE,
+        Box::new([]),
+        Box::new([Box::new([
+            Attribute::new("dangerous_inner_html", module.css, None, false),
+            Attribute::new("data-css-module", module.id, None, false),
+        ])]),
+    ))
+}
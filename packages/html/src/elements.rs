@@ -7,7 +7,7 @@ use dioxus_html_internal_macro::impl_extension_attributes;
 use dioxus_rsx::HotReloadingContext;
 
 #[cfg(feature = "hot-reload-context")]
-use crate::{map_global_attributes, map_svg_attributes};
+use crate::{map_global_attributes, map_mathml_attributes, map_svg_attributes};
 
 pub type AttributeDiscription = (&'static str, Option<&'static str>, bool);
 
@@ -267,6 +267,113 @@ macro_rules! impl_element {
         }
     };
 
+    (
+        $(#[$attr:meta])*
+        $name:ident "http://www.w3.org/1998/Math/MathML" {
+            $(
+                $(#[$attr_method:meta])*
+                $fil:ident: $vil:ident $extra:tt,
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        ///
+        /// ## Usage in rsx
+        ///
+        /// ```rust, no_run
+        /// # use dioxus::prelude::*;
+        /// # let attributes = vec![];
+        /// # fn ChildComponent() -> Element { unimplemented!() }
+        /// # let raw_expression: Element = rsx! {};
+        /// rsx! {
+        ///     // Elements are followed by braces that surround any attributes and children for that element
+        #[doc = concat!("    ", stringify!($name), " {")]
+        ///         // Add any attributes first
+        ///         mathcolor: "red",
+        ///         "custom-attribute-name": "value",
+        ///         // Then add any attributes you are spreading into this element
+        ///         ..attributes,
+        ///         // Then add any children elements, components, text nodes, or raw expressions
+        ///         mi { "x" }
+        ///         ChildComponent {}
+        ///         "child text"
+        ///         {raw_expression}
+        ///     }
+        /// };
+        /// ```
+        pub mod $name {
+            #[allow(unused)]
+            use super::*;
+            pub use crate::attribute_groups::mathml_attributes::*;
+
+            pub const TAG_NAME: &'static str = stringify!($name);
+            pub const NAME_SPACE: Option<&'static str> = Some("http://www.w3.org/1998/Math/MathML");
+
+            $(
+                impl_attribute!(
+                    $name {
+                        $(#[$attr_method])*
+                        $fil: $vil ($extra),
+                    }
+                );
+            )*
+        }
+    };
+
+    (
+        $(#[$attr:meta])*
+        $element:ident [$name:literal, "http://www.w3.org/1998/Math/MathML"] {
+            $(
+                $(#[$attr_method:meta])*
+                $fil:ident: $vil:ident $extra:tt,
+            )*
+        }
+    ) => {
+        #[allow(non_camel_case_types)]
+        $(#[$attr])*
+        ///
+        /// ## Usage in rsx
+        ///
+        /// ```rust, no_run
+        /// # use dioxus::prelude::*;
+        /// # let attributes = vec![];
+        /// # fn ChildComponent() -> Element { unimplemented!() }
+        /// # let raw_expression: Element = rsx! {};
+        /// rsx! {
+        ///     // Elements are followed by braces that surround any attributes and children for that element
+        #[doc = concat!("    ", stringify!($element), " {")]
+        ///         // Add any attributes first
+        ///         mathcolor: "red",
+        ///         "custom-attribute-name": "value",
+        ///         // Then add any attributes you are spreading into this element
+        ///         ..attributes,
+        ///         // Then add any children elements, components, text nodes, or raw expressions
+        ///         mi { "x" }
+        ///         ChildComponent {}
+        ///         "child text"
+        ///         {raw_expression}
+        ///     }
+        /// };
+        /// ```
+        pub mod $element {
+            #[allow(unused)]
+            use super::*;
+            pub use crate::attribute_groups::mathml_attributes::*;
+
+            pub const TAG_NAME: &'static str = $name;
+            pub const NAME_SPACE: Option<&'static str> = Some("http://www.w3.org/1998/Math/MathML");
+
+            $(
+                impl_attribute!(
+                    $element {
+                        $(#[$attr_method])*
+                        $fil: $vil ($extra),
+                    }
+                );
+            )*
+        }
+    };
+
     (
         $(#[$attr:meta])*
         $name:ident $namespace:literal {
@@ -402,14 +509,14 @@ macro_rules! impl_element_match {
     };
 
     (
-        $el:ident $name:ident [$_:literal, $namespace:tt] {
+        $el:ident $name:ident [$tag:literal, $namespace:tt] {
             $(
                 $fil:ident: $vil:ident $extra:tt,
             )*
         }
     ) => {
         if $el == stringify!($name) {
-            return Some((stringify!($name), Some($namespace)));
+            return Some(($tag, Some($namespace)));
         }
     };
 }
@@ -466,6 +573,8 @@ macro_rules! impl_map_global_attributes {
     ) => {
         if $namespace == "http://www.w3.org/2000/svg" {
             map_svg_attributes($attr)
+        } else if $namespace == "http://www.w3.org/1998/Math/MathML" {
+            map_mathml_attributes($attr)
         } else {
             map_global_attributes($attr)
         }
@@ -476,12 +585,33 @@ macro_rules! impl_map_global_attributes {
     ) => {
         if $namespace == "http://www.w3.org/2000/svg" {
             map_svg_attributes($attr)
+        } else if $namespace == "http://www.w3.org/1998/Math/MathML" {
+            map_mathml_attributes($attr)
         } else {
             map_global_attributes($attr)
         }
     };
 }
 
+#[cfg(feature = "html-to-rsx")]
+macro_rules! impl_html_to_rsx_element_match {
+    (
+        $html:ident $name:ident [$tag:literal, $_:tt]
+    ) => {
+        if $html == $tag {
+            return Some(stringify!($name));
+        }
+    };
+
+    (
+        $html:ident $name:ident $_:tt
+    ) => {
+        if $html == stringify!($name) {
+            return Some(stringify!($name));
+        }
+    };
+}
+
 macro_rules! builder_constructors {
     (
         $(
@@ -544,15 +674,19 @@ macro_rules! builder_constructors {
                 return Some(name);
             }
 
+            if let Some(name) = crate::map_html_mathml_attributes_to_rsx(html) {
+                return Some(name);
+            }
+
             None
         }
 
         #[cfg(feature = "html-to-rsx")]
         pub fn map_html_element_to_rsx(html: &str) -> Option<&'static str> {
             $(
-                if html == stringify!($name) {
-                    return Some(stringify!($name));
-                }
+                impl_html_to_rsx_element_match!(
+                    html $name $namespace
+                );
             )*
 
             None
@@ -1091,7 +1225,7 @@ builder_constructors! {
         decoding: ImageDecoding DEFAULT,
         height: usize DEFAULT,
         ismap: Bool DEFAULT,
-        loading: String DEFAULT,
+        loading: Loading DEFAULT,
         src: Uri DEFAULT,
         srcset: String DEFAULT, // FIXME this is much more complicated
         usemap: String DEFAULT, // FIXME should be a fragment starting with '#'
@@ -1372,7 +1506,7 @@ builder_constructors! {
         popovertarget: String DEFAULT,
         popovertargetaction: String DEFAULT,
         value: String DEFAULT,
-        r#type: String "type",
+        r#type: ButtonType "type",
     };
 
     /// Build a
@@ -1405,7 +1539,7 @@ builder_constructors! {
     input None {
         accept: String DEFAULT,
         alt: String DEFAULT,
-        autocomplete: String DEFAULT,
+        autocomplete: Autocomplete DEFAULT,
         autofocus: Bool DEFAULT,
         capture: String DEFAULT,
         checked: Bool DEFAULT,
@@ -1543,7 +1677,7 @@ builder_constructors! {
     select None {
         // defined below
         // value: String,
-        autocomplete: String DEFAULT,
+        autocomplete: Autocomplete DEFAULT,
         autofocus: Bool DEFAULT,
         disabled: Bool DEFAULT,
         form: Id DEFAULT,
@@ -1958,14 +2092,22 @@ builder_constructors! {
     /// [`<annotation>`](https://w3c.github.io/mathml-core/#dfn-annotation)
     /// element.
     annotation "http://www.w3.org/1998/Math/MathML" {
-            encoding: String DEFAULT,
+        encoding: String DEFAULT,
     };
 
     /// Build a
     /// [`<annotation-xml>`](https://w3c.github.io/mathml-core/#dfn-annotation-xml)
     /// element.
     annotationXml ["annotation-xml", "http://www.w3.org/1998/Math/MathML"] {
-            encoding: String DEFAULT,
+        encoding: String DEFAULT,
+    };
+
+    /// Build a
+    /// [`<maction>`](https://w3c.github.io/mathml-core/#enlivening-expressions)
+    /// element.
+    maction "http://www.w3.org/1998/Math/MathML" {
+        actiontype: String DEFAULT,
+        selection: usize DEFAULT,
     };
 
     /// Build a
@@ -1997,7 +2139,7 @@ builder_constructors! {
     /// Build a
     /// [`<mmultiscripts>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mmultiscripts)
     /// element.
-    mmultiscripts "http://www.w3.org/1998/math/mathml" {};
+    mmultiscripts "http://www.w3.org/1998/Math/MathML" {};
 
     /// Build a
     /// [`<mn>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mn)
@@ -2009,6 +2151,7 @@ builder_constructors! {
     /// element.
     mo "http://www.w3.org/1998/Math/MathML" {
         fence: Bool DEFAULT,
+        form: String DEFAULT,
         largeop: Bool DEFAULT,
         lspace: usize DEFAULT,
         maxsize: usize DEFAULT,
@@ -2056,9 +2199,7 @@ builder_constructors! {
     /// Build a
     /// [`<mrow>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mrow)
     /// element.
-    mrow "http://www.w3.org/1998/Math/MathML" {
-
-    };
+    mrow "http://www.w3.org/1998/Math/MathML" {};
 
     /// Build a
     /// [`<ms>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/ms)
@@ -0,0 +1,158 @@
+use dioxus_core::Event;
+
+pub type BeforeInputEvent = Event<BeforeInputData>;
+
+/// The data of a [`beforeinput`](https://developer.mozilla.org/en-US/docs/Web/API/Element/beforeinput_event) event,
+/// which fires before the value of an input or a contenteditable element is modified.
+pub struct BeforeInputData {
+    inner: Box<dyn HasBeforeInputData>,
+}
+
+impl std::fmt::Debug for BeforeInputData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BeforeInputData")
+            .field("input_type", &self.input_type())
+            .field("data", &self.data())
+            .field("is_composing", &self.is_composing())
+            .finish()
+    }
+}
+
+impl<E: HasBeforeInputData> From<E> for BeforeInputData {
+    fn from(e: E) -> Self {
+        Self { inner: Box::new(e) }
+    }
+}
+
+impl PartialEq for BeforeInputData {
+    fn eq(&self, other: &Self) -> bool {
+        self.input_type() == other.input_type()
+            && self.data() == other.data()
+            && self.is_composing() == other.is_composing()
+    }
+}
+
+impl BeforeInputData {
+    /// Create a new BeforeInputData
+    pub fn new(inner: impl HasBeforeInputData + 'static) -> Self {
+        Self {
+            inner: Box::new(inner),
+        }
+    }
+
+    /// The kind of change that is about to be made, like `insertText`, `deleteContentBackward` or `insertFromPaste`.
+    ///
+    /// See the [input events spec](https://w3c.github.io/input-events/#interface-InputEvent-Attributes) for every
+    /// input type.
+    pub fn input_type(&self) -> String {
+        self.inner.input_type()
+    }
+
+    /// The text that is about to be inserted, if any
+    pub fn data(&self) -> Option<String> {
+        self.inner.data()
+    }
+
+    /// Whether the change is part of a composition session, like typing with an input method editor
+    pub fn is_composing(&self) -> bool {
+        self.inner.is_composing()
+    }
+
+    /// Downcast this event to a concrete event type
+    pub fn downcast<T: 'static>(&self) -> Option<&T> {
+        self.inner.as_any().downcast_ref::<T>()
+    }
+}
+
+#[cfg(feature = "serialize")]
+/// A serialized version of BeforeInputData
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct SerializedBeforeInputData {
+    input_type: String,
+    #[serde(default)]
+    data: Option<String>,
+    #[serde(default)]
+    is_composing: bool,
+}
+
+#[cfg(feature = "serialize")]
+impl SerializedBeforeInputData {
+    /// Create a new SerializedBeforeInputData
+    pub fn new(input_type: String, data: Option<String>, is_composing: bool) -> Self {
+        Self {
+            input_type,
+            data,
+            is_composing,
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl From<&BeforeInputData> for SerializedBeforeInputData {
+    fn from(data: &BeforeInputData) -> Self {
+        Self::new(data.input_type(), data.data(), data.is_composing())
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl HasBeforeInputData for SerializedBeforeInputData {
+    fn input_type(&self) -> String {
+        self.input_type.clone()
+    }
+
+    fn data(&self) -> Option<String> {
+        self.data.clone()
+    }
+
+    fn is_composing(&self) -> bool {
+        self.is_composing
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for BeforeInputData {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedBeforeInputData::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserialize<'de> for BeforeInputData {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = SerializedBeforeInputData::deserialize(deserializer)?;
+        Ok(Self {
+            inner: Box::new(data),
+        })
+    }
+}
+
+/// A trait for any object that has the data for a beforeinput event
+pub trait HasBeforeInputData: std::any::Any {
+    /// The kind of change that is about to be made
+    fn input_type(&self) -> String;
+
+    /// The text that is about to be inserted, if any
+    fn data(&self) -> Option<String>;
+
+    /// Whether the change is part of a composition session
+    fn is_composing(&self) -> bool;
+
+    /// return self as Any
+    fn as_any(&self) -> &dyn std::any::Any;
+}
+
+impl_event! [
+    BeforeInputData;
+
+    /// Execute a callback before the value of an input, textarea or contenteditable element is modified.
+    ///
+    /// - Bubbles: Yes
+    /// - Cancelable: Yes, for most input types
+    ///
+    /// Call [`Event::prevent_default`] to stop the change on the web and desktop renderers.
+    onbeforeinput
+];
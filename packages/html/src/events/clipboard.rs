@@ -38,15 +38,37 @@ impl ClipboardData {
     }
 }
 
+impl crate::HasFileData for ClipboardData {
+    /// The files on the clipboard, if any files were pasted
+    #[cfg(feature = "file-engine")]
+    fn files(&self) -> Option<std::sync::Arc<dyn crate::file_data::FileEngine>> {
+        self.inner.files()
+    }
+}
+
 #[cfg(feature = "serialize")]
 /// A serialized version of ClipboardData
-#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
-pub struct SerializedClipboardData {}
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct SerializedClipboardData {
+    #[cfg(feature = "file-engine")]
+    #[serde(default)]
+    files: Option<crate::file_data::SerializedFileEngine>,
+}
+
+#[cfg(feature = "serialize")]
+impl SerializedClipboardData {
+    #[cfg(feature = "file-engine")]
+    /// Add pasted files to the serialized clipboard data
+    pub fn with_files(mut self, files: crate::file_data::SerializedFileEngine) -> Self {
+        self.files = Some(files);
+        self
+    }
+}
 
 #[cfg(feature = "serialize")]
 impl From<&ClipboardData> for SerializedClipboardData {
     fn from(_: &ClipboardData) -> Self {
-        Self {}
+        Self::default()
     }
 }
 
@@ -57,6 +79,16 @@ impl HasClipboardData for SerializedClipboardData {
     }
 }
 
+#[cfg(feature = "serialize")]
+impl crate::file_data::HasFileData for SerializedClipboardData {
+    #[cfg(feature = "file-engine")]
+    fn files(&self) -> Option<std::sync::Arc<dyn crate::file_data::FileEngine>> {
+        self.files
+            .as_ref()
+            .map(|files| std::sync::Arc::new(files.clone()) as _)
+    }
+}
+
 #[cfg(feature = "serialize")]
 impl serde::Serialize for ClipboardData {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -74,7 +106,7 @@ impl<'de> serde::Deserialize<'de> for ClipboardData {
     }
 }
 
-pub trait HasClipboardData: std::any::Any {
+pub trait HasClipboardData: crate::HasFileData + std::any::Any {
     /// return self as Any
     fn as_any(&self) -> &dyn std::any::Any;
 }
@@ -88,6 +120,8 @@ impl_event![
     /// oncut
     oncut
 
-    /// onpaste
+    /// Execute a callback when the user pastes into the element.
+    ///
+    /// Pasted files are available through [`HasFileData::files`](crate::HasFileData::files).
     onpaste
 ];
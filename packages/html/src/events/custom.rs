@@ -0,0 +1,125 @@
+use dioxus_core::Event;
+
+pub type CustomEvent = Event<CustomData>;
+
+/// The data of a [`CustomEvent`](https://developer.mozilla.org/en-US/docs/Web/API/CustomEvent) dispatched by a web
+/// component.
+///
+/// Custom events don't have listeners in this crate. Typed listeners for the events a web component dispatches can be
+/// generated from its custom elements manifest with `dioxus-custom-elements`.
+pub struct CustomData {
+    inner: Box<dyn HasCustomData>,
+}
+
+impl<E: HasCustomData> From<E> for CustomData {
+    fn from(e: E) -> Self {
+        Self { inner: Box::new(e) }
+    }
+}
+
+impl std::fmt::Debug for CustomData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomData")
+            .field("detail", &self.detail_json())
+            .finish()
+    }
+}
+
+impl PartialEq for CustomData {
+    fn eq(&self, other: &Self) -> bool {
+        self.detail_json() == other.detail_json()
+    }
+}
+
+impl CustomData {
+    /// Create a new CustomData
+    pub fn new(inner: impl HasCustomData + 'static) -> Self {
+        Self {
+            inner: Box::new(inner),
+        }
+    }
+
+    /// The `detail` of the event as JSON, or `None` if the event was dispatched without a detail
+    pub fn detail_json(&self) -> Option<String> {
+        self.inner.detail_json()
+    }
+
+    /// Deserialize the `detail` of the event
+    #[cfg(feature = "serialize")]
+    pub fn detail<T: serde::de::DeserializeOwned>(&self) -> Result<Option<T>, serde_json::Error> {
+        self.detail_json()
+            .map(|detail| serde_json::from_str(&detail))
+            .transpose()
+    }
+
+    /// Downcast this event to a concrete event type
+    pub fn downcast<T: 'static>(&self) -> Option<&T> {
+        self.inner.as_any().downcast_ref::<T>()
+    }
+}
+
+#[cfg(feature = "serialize")]
+/// A serialized version of CustomData
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct SerializedCustomData {
+    #[serde(default)]
+    pub detail: Option<serde_json::Value>,
+}
+
+#[cfg(feature = "serialize")]
+impl SerializedCustomData {
+    /// Create a new SerializedCustomData
+    pub fn new(detail: Option<serde_json::Value>) -> Self {
+        Self { detail }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl From<&CustomData> for SerializedCustomData {
+    fn from(data: &CustomData) -> Self {
+        Self {
+            detail: data
+                .detail_json()
+                .and_then(|detail| serde_json::from_str(&detail).ok()),
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl HasCustomData for SerializedCustomData {
+    fn detail_json(&self) -> Option<String> {
+        self.detail
+            .as_ref()
+            .filter(|detail| !detail.is_null())
+            .map(|detail| detail.to_string())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for CustomData {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedCustomData::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserialize<'de> for CustomData {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = SerializedCustomData::deserialize(deserializer)?;
+        Ok(Self {
+            inner: Box::new(data),
+        })
+    }
+}
+
+pub trait HasCustomData: std::any::Any {
+    /// The `detail` of the event as JSON, or `None` if the event was dispatched without a detail
+    fn detail_json(&self) -> Option<String>;
+
+    /// return self as Any
+    fn as_any(&self) -> &dyn std::any::Any;
+}
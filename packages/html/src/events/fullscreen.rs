@@ -0,0 +1,123 @@
+use dioxus_core::Event;
+
+pub type FullscreenEvent = Event<FullscreenData>;
+
+/// The data of a [`fullscreenchange`](https://developer.mozilla.org/en-US/docs/Web/API/Element/fullscreenchange_event)
+/// or [`fullscreenerror`](https://developer.mozilla.org/en-US/docs/Web/API/Element/fullscreenerror_event) event
+pub struct FullscreenData {
+    inner: Box<dyn HasFullscreenData>,
+}
+
+impl std::fmt::Debug for FullscreenData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FullscreenData")
+            .field("is_fullscreen", &self.is_fullscreen())
+            .finish()
+    }
+}
+
+impl<E: HasFullscreenData> From<E> for FullscreenData {
+    fn from(e: E) -> Self {
+        Self { inner: Box::new(e) }
+    }
+}
+
+impl PartialEq for FullscreenData {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_fullscreen() == other.is_fullscreen()
+    }
+}
+
+impl FullscreenData {
+    /// Create a new FullscreenData
+    pub fn new(inner: impl HasFullscreenData + 'static) -> Self {
+        Self {
+            inner: Box::new(inner),
+        }
+    }
+
+    /// Whether an element of the document is in fullscreen mode after the event
+    pub fn is_fullscreen(&self) -> bool {
+        self.inner.is_fullscreen()
+    }
+
+    /// Downcast this event to a concrete event type
+    pub fn downcast<T: 'static>(&self) -> Option<&T> {
+        self.inner.as_any().downcast_ref::<T>()
+    }
+}
+
+#[cfg(feature = "serialize")]
+/// A serialized version of FullscreenData
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct SerializedFullscreenData {
+    is_fullscreen: bool,
+}
+
+#[cfg(feature = "serialize")]
+impl SerializedFullscreenData {
+    /// Create a new SerializedFullscreenData
+    pub fn new(is_fullscreen: bool) -> Self {
+        Self { is_fullscreen }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl From<&FullscreenData> for SerializedFullscreenData {
+    fn from(data: &FullscreenData) -> Self {
+        Self::new(data.is_fullscreen())
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl HasFullscreenData for SerializedFullscreenData {
+    fn is_fullscreen(&self) -> bool {
+        self.is_fullscreen
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for FullscreenData {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedFullscreenData::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserialize<'de> for FullscreenData {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = SerializedFullscreenData::deserialize(deserializer)?;
+        Ok(Self {
+            inner: Box::new(data),
+        })
+    }
+}
+
+/// A trait for any object that has the data for a fullscreen event
+pub trait HasFullscreenData: std::any::Any {
+    /// Whether an element of the document is in fullscreen mode after the event
+    fn is_fullscreen(&self) -> bool;
+
+    /// return self as Any
+    fn as_any(&self) -> &dyn std::any::Any;
+}
+
+impl_event! [
+    FullscreenData;
+
+    /// Execute a callback when the element enters or leaves fullscreen mode.
+    ///
+    /// - Bubbles: Yes
+    /// - Cancelable: No
+    onfullscreenchange
+
+    /// Execute a callback when the element could not enter or leave fullscreen mode.
+    ///
+    /// - Bubbles: Yes
+    /// - Cancelable: No
+    onfullscreenerror
+];
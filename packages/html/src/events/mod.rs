@@ -112,16 +112,22 @@ impl PlatformEventData {
 pub trait HtmlEventConverter: Send + Sync {
     /// Convert a general event to an animation data event
     fn convert_animation_data(&self, event: &PlatformEventData) -> AnimationData;
+    /// Convert a general event to a beforeinput data event
+    fn convert_before_input_data(&self, event: &PlatformEventData) -> BeforeInputData;
     /// Convert a general event to a clipboard data event
     fn convert_clipboard_data(&self, event: &PlatformEventData) -> ClipboardData;
     /// Convert a general event to a composition data event
     fn convert_composition_data(&self, event: &PlatformEventData) -> CompositionData;
+    /// Convert a general event to a custom data event
+    fn convert_custom_data(&self, event: &PlatformEventData) -> CustomData;
     /// Convert a general event to a drag data event
     fn convert_drag_data(&self, event: &PlatformEventData) -> DragData;
     /// Convert a general event to a focus data event
     fn convert_focus_data(&self, event: &PlatformEventData) -> FocusData;
     /// Convert a general event to a form data event
     fn convert_form_data(&self, event: &PlatformEventData) -> FormData;
+    /// Convert a general event to a fullscreen data event
+    fn convert_fullscreen_data(&self, event: &PlatformEventData) -> FullscreenData;
     /// Convert a general event to an image data event
     fn convert_image_data(&self, event: &PlatformEventData) -> ImageData;
     /// Convert a general event to a keyboard data event
@@ -134,6 +140,8 @@ pub trait HtmlEventConverter: Send + Sync {
     fn convert_mouse_data(&self, event: &PlatformEventData) -> MouseData;
     /// Convert a general event to a pointer data event
     fn convert_pointer_data(&self, event: &PlatformEventData) -> PointerData;
+    /// Convert a general event to a resize data event
+    fn convert_resize_data(&self, event: &PlatformEventData) -> ResizeData;
     /// Convert a general event to a scroll data event
     fn convert_scroll_data(&self, event: &PlatformEventData) -> ScrollData;
     /// Convert a general event to a selection data event
@@ -144,6 +152,8 @@ pub trait HtmlEventConverter: Send + Sync {
     fn convert_touch_data(&self, event: &PlatformEventData) -> TouchData;
     /// Convert a general event to a transition data event
     fn convert_transition_data(&self, event: &PlatformEventData) -> TransitionData;
+    /// Convert a general event to a visibility data event
+    fn convert_visibility_data(&self, event: &PlatformEventData) -> VisibilityData;
     /// Convert a general event to a wheel data event
     fn convert_wheel_data(&self, event: &PlatformEventData) -> WheelData;
 }
@@ -154,6 +164,12 @@ impl From<&PlatformEventData> for AnimationData {
     }
 }
 
+impl From<&PlatformEventData> for BeforeInputData {
+    fn from(val: &PlatformEventData) -> Self {
+        with_event_converter(|c| c.convert_before_input_data(val))
+    }
+}
+
 impl From<&PlatformEventData> for ClipboardData {
     fn from(val: &PlatformEventData) -> Self {
         with_event_converter(|c| c.convert_clipboard_data(val))
@@ -166,6 +182,12 @@ impl From<&PlatformEventData> for CompositionData {
     }
 }
 
+impl From<&PlatformEventData> for CustomData {
+    fn from(val: &PlatformEventData) -> Self {
+        with_event_converter(|c| c.convert_custom_data(val))
+    }
+}
+
 impl From<&PlatformEventData> for DragData {
     fn from(val: &PlatformEventData) -> Self {
         with_event_converter(|c| c.convert_drag_data(val))
@@ -184,6 +206,12 @@ impl From<&PlatformEventData> for FormData {
     }
 }
 
+impl From<&PlatformEventData> for FullscreenData {
+    fn from(val: &PlatformEventData) -> Self {
+        with_event_converter(|c| c.convert_fullscreen_data(val))
+    }
+}
+
 impl From<&PlatformEventData> for ImageData {
     fn from(val: &PlatformEventData) -> Self {
         with_event_converter(|c| c.convert_image_data(val))
@@ -220,6 +248,12 @@ impl From<&PlatformEventData> for PointerData {
     }
 }
 
+impl From<&PlatformEventData> for ResizeData {
+    fn from(val: &PlatformEventData) -> Self {
+        with_event_converter(|c| c.convert_resize_data(val))
+    }
+}
+
 impl From<&PlatformEventData> for ScrollData {
     fn from(val: &PlatformEventData) -> Self {
         with_event_converter(|c| c.convert_scroll_data(val))
@@ -250,6 +284,12 @@ impl From<&PlatformEventData> for TransitionData {
     }
 }
 
+impl From<&PlatformEventData> for VisibilityData {
+    fn from(val: &PlatformEventData) -> Self {
+        with_event_converter(|c| c.convert_visibility_data(val))
+    }
+}
+
 impl From<&PlatformEventData> for WheelData {
     fn from(val: &PlatformEventData) -> Self {
         with_event_converter(|c| c.convert_wheel_data(val))
@@ -257,41 +297,51 @@ impl From<&PlatformEventData> for WheelData {
 }
 
 mod animation;
+mod before_input;
 mod clipboard;
 mod composition;
+mod custom;
 mod drag;
 mod focus;
 mod form;
+mod fullscreen;
 mod image;
 mod keyboard;
 mod media;
 mod mounted;
 mod mouse;
 mod pointer;
+mod resize;
 mod scroll;
 mod selection;
 mod toggle;
 mod touch;
 mod transition;
+mod visibility;
 mod wheel;
 
 pub use animation::*;
+pub use before_input::*;
 pub use clipboard::*;
 pub use composition::*;
+pub use custom::*;
 pub use drag::*;
 pub use focus::*;
 pub use form::*;
+pub use fullscreen::*;
 pub use image::*;
 pub use keyboard::*;
 pub use media::*;
 pub use mounted::*;
 pub use mouse::*;
 pub use pointer::*;
+pub use resize::*;
 pub use scroll::*;
 pub use selection::*;
 pub use toggle::*;
 pub use touch::*;
 pub use transition::*;
+pub use visibility::*;
 pub use wheel::*;
 
 pub fn event_bubbles(evt: &str) -> bool {
@@ -310,6 +360,7 @@ pub fn event_bubbles(evt: &str) -> bool {
         "focusin" => true,
         "blur" => false,
         "change" => true,
+        "beforeinput" => true,
         "input" => true,
         "invalid" => true,
         "reset" => true,
@@ -380,6 +431,12 @@ pub fn event_bubbles(evt: &str) -> bool {
         "transitionend" => true,
         "toggle" => true,
         "mounted" => false,
+        "fullscreenchange" => true,
+        "fullscreenerror" => true,
+        "visibilitychange" => false,
+        "resize" => false,
+        // Custom events from web components don't always bubble, so they are listened to on the element itself
+        custom if custom.contains(['-', ':']) => false,
         _ => {
             tracing::warn!("Unknown event name: {evt}");
             true
@@ -43,6 +43,23 @@ pub trait RenderedElementBacking: std::any::Any {
     fn set_focus(&self, _focus: bool) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
         Box::pin(async { Err(MountedError::NotSupported) })
     }
+
+    /// Start observing changes to the size of the element
+    #[allow(clippy::type_complexity)]
+    fn observe_resize(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<Observer<ResizeObservation>>>>> {
+        Box::pin(async { Err(MountedError::NotSupported) })
+    }
+
+    /// Start observing how much of the element is visible in the viewport
+    #[allow(clippy::type_complexity)]
+    fn observe_intersection(
+        &self,
+        _options: IntersectionOptions,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<Observer<IntersectionObservation>>>>> {
+        Box::pin(async { Err(MountedError::NotSupported) })
+    }
 }
 
 impl RenderedElementBacking for () {
@@ -120,6 +137,29 @@ impl MountedData {
         self.inner.set_focus(focus)
     }
 
+    /// Start observing changes to the size of the element. The renderer stops observing the element when the
+    /// [`Observer`] is dropped.
+    ///
+    /// Use `use_resize_observer` from `dioxus-hooks` to keep the latest size in a signal.
+    #[doc(alias = "ResizeObserver")]
+    pub fn observe_resize(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<Observer<ResizeObservation>>>>> {
+        self.inner.observe_resize()
+    }
+
+    /// Start observing how much of the element is visible in the viewport. The renderer stops observing the element
+    /// when the [`Observer`] is dropped.
+    ///
+    /// Use `use_intersection_observer` from `dioxus-hooks` to keep the latest observation in a signal.
+    #[doc(alias = "IntersectionObserver")]
+    pub fn observe_intersection(
+        &self,
+        options: IntersectionOptions,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<Observer<IntersectionObservation>>>>> {
+        self.inner.observe_intersection(options)
+    }
+
     /// Downcast this event to a concrete event type
     pub fn downcast<T: 'static>(&self) -> Option<&T> {
         self.inner.as_any().downcast_ref::<T>()
@@ -129,6 +169,7 @@ impl MountedData {
 use dioxus_core::Event;
 
 use crate::geometry::{PixelsRect, PixelsSize, PixelsVector2D};
+use crate::{IntersectionObservation, IntersectionOptions, Observer, ResizeObservation};
 
 pub type MountedEvent = Event<MountedData>;
 
@@ -72,7 +72,22 @@ impl_event! {
     /// - <https://developer.mozilla.org/en-US/docs/Web/API/Element/click_event>
     onclick
 
-    /// oncontextmenu
+    /// Execute a callback when the user tries to open a context menu, usually with a right click or the context menu key.
+    ///
+    /// - Bubbles: Yes
+    /// - Cancelable: Yes
+    /// - Interface: [`MouseEvent`]
+    ///
+    /// The event carries the pointer position and modifiers of the right click. Call [`Event::prevent_default`] to
+    /// replace the browser's context menu with your own.
+    ///
+    /// ## Example
+    /// ```rust, ignore
+    /// rsx!( div { oncontextmenu: move |evt| evt.prevent_default(), "no menu here" } )
+    /// ```
+    ///
+    /// ## Reference
+    /// - <https://developer.mozilla.org/en-US/docs/Web/API/Element/contextmenu_event>
     oncontextmenu
 
     #[deprecated(since = "0.5.0", note = "use ondoubleclick instead")]
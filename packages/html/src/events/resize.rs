@@ -0,0 +1,123 @@
+use dioxus_core::Event;
+
+pub type ResizeEvent = Event<ResizeData>;
+
+/// The data of a window [`resize`](https://developer.mozilla.org/en-US/docs/Web/API/Window/resize_event) event
+pub struct ResizeData {
+    inner: Box<dyn HasResizeData>,
+}
+
+impl std::fmt::Debug for ResizeData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResizeData")
+            .field("inner_width", &self.inner_width())
+            .field("inner_height", &self.inner_height())
+            .finish()
+    }
+}
+
+impl<E: HasResizeData> From<E> for ResizeData {
+    fn from(e: E) -> Self {
+        Self { inner: Box::new(e) }
+    }
+}
+
+impl PartialEq for ResizeData {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner_width() == other.inner_width() && self.inner_height() == other.inner_height()
+    }
+}
+
+impl ResizeData {
+    /// Create a new ResizeData
+    pub fn new(inner: impl HasResizeData + 'static) -> Self {
+        Self {
+            inner: Box::new(inner),
+        }
+    }
+
+    /// The width of the window's layout viewport in CSS pixels after the resize
+    pub fn inner_width(&self) -> f64 {
+        self.inner.inner_width()
+    }
+
+    /// The height of the window's layout viewport in CSS pixels after the resize
+    pub fn inner_height(&self) -> f64 {
+        self.inner.inner_height()
+    }
+
+    /// Downcast this event to a concrete event type
+    pub fn downcast<T: 'static>(&self) -> Option<&T> {
+        self.inner.as_any().downcast_ref::<T>()
+    }
+}
+
+#[cfg(feature = "serialize")]
+/// A serialized version of ResizeData
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct SerializedResizeData {
+    inner_width: f64,
+    inner_height: f64,
+}
+
+#[cfg(feature = "serialize")]
+impl SerializedResizeData {
+    /// Create a new SerializedResizeData
+    pub fn new(inner_width: f64, inner_height: f64) -> Self {
+        Self {
+            inner_width,
+            inner_height,
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl From<&ResizeData> for SerializedResizeData {
+    fn from(data: &ResizeData) -> Self {
+        Self::new(data.inner_width(), data.inner_height())
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl HasResizeData for SerializedResizeData {
+    fn inner_width(&self) -> f64 {
+        self.inner_width
+    }
+
+    fn inner_height(&self) -> f64 {
+        self.inner_height
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for ResizeData {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedResizeData::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserialize<'de> for ResizeData {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = SerializedResizeData::deserialize(deserializer)?;
+        Ok(Self {
+            inner: Box::new(data),
+        })
+    }
+}
+
+/// A trait for any object that has the data for a window resize event
+pub trait HasResizeData: std::any::Any {
+    /// The width of the window's layout viewport in CSS pixels
+    fn inner_width(&self) -> f64;
+
+    /// The height of the window's layout viewport in CSS pixels
+    fn inner_height(&self) -> f64;
+
+    /// return self as Any
+    fn as_any(&self) -> &dyn std::any::Any;
+}
@@ -0,0 +1,119 @@
+use dioxus_core::Event;
+
+pub type VisibilityEvent = Event<VisibilityData>;
+
+/// The data of a [`visibilitychange`](https://developer.mozilla.org/en-US/docs/Web/API/Document/visibilitychange_event)
+/// event, which fires on the document when the page is shown or hidden.
+pub struct VisibilityData {
+    inner: Box<dyn HasVisibilityData>,
+}
+
+impl std::fmt::Debug for VisibilityData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VisibilityData")
+            .field("visibility_state", &self.visibility_state())
+            .finish()
+    }
+}
+
+impl<E: HasVisibilityData> From<E> for VisibilityData {
+    fn from(e: E) -> Self {
+        Self { inner: Box::new(e) }
+    }
+}
+
+impl PartialEq for VisibilityData {
+    fn eq(&self, other: &Self) -> bool {
+        self.visibility_state() == other.visibility_state()
+    }
+}
+
+impl VisibilityData {
+    /// Create a new VisibilityData
+    pub fn new(inner: impl HasVisibilityData + 'static) -> Self {
+        Self {
+            inner: Box::new(inner),
+        }
+    }
+
+    /// Whether the page is visible after the event
+    pub fn visibility_state(&self) -> VisibilityState {
+        self.inner.visibility_state()
+    }
+
+    /// Downcast this event to a concrete event type
+    pub fn downcast<T: 'static>(&self) -> Option<&T> {
+        self.inner.as_any().downcast_ref::<T>()
+    }
+}
+
+/// The [visibility state](https://developer.mozilla.org/en-US/docs/Web/API/Document/visibilityState) of a document
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum VisibilityState {
+    /// The page content may be at least partially visible
+    #[default]
+    Visible,
+    /// The page content is not visible to the user, for example because the tab is in the background
+    Hidden,
+}
+
+#[cfg(feature = "serialize")]
+/// A serialized version of VisibilityData
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct SerializedVisibilityData {
+    visibility_state: VisibilityState,
+}
+
+#[cfg(feature = "serialize")]
+impl SerializedVisibilityData {
+    /// Create a new SerializedVisibilityData
+    pub fn new(visibility_state: VisibilityState) -> Self {
+        Self { visibility_state }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl From<&VisibilityData> for SerializedVisibilityData {
+    fn from(data: &VisibilityData) -> Self {
+        Self::new(data.visibility_state())
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl HasVisibilityData for SerializedVisibilityData {
+    fn visibility_state(&self) -> VisibilityState {
+        self.visibility_state
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for VisibilityData {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedVisibilityData::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserialize<'de> for VisibilityData {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = SerializedVisibilityData::deserialize(deserializer)?;
+        Ok(Self {
+            inner: Box::new(data),
+        })
+    }
+}
+
+/// A trait for any object that has the data for a visibilitychange event
+pub trait HasVisibilityData: std::any::Any {
+    /// Whether the page is visible after the event
+    fn visibility_state(&self) -> VisibilityState;
+
+    /// return self as Any
+    fn as_any(&self) -> &dyn std::any::Any;
+}
@@ -0,0 +1,178 @@
+//! Listeners on the window and document
+//!
+//! Events like global keyboard shortcuts, `resize`, `visibilitychange`, `online` or `storage` fire on the window or
+//! document instead of an element in the rsx tree. The [`use_window_event`] and [`use_document_event`] hooks attach a
+//! listener through the renderer's [`eval`](crate::eval::eval) channel and detach it when the component is dropped.
+//!
+//! Every hook runs its own script, and the script copies the primitive fields of each event to send it to Rust. Prefer
+//! one hook per event over many hooks listening to the same event.
+//!
+//! The handler runs after the browser finished dispatching the event, so [`Event::stop_propagation`] has no effect and
+//! the default action of the event can't be prevented. Shortcuts the browser handles itself, like ctrl+f, can't be
+//! overridden from these hooks. Listen on a focused element with the `prevent_default` attribute instead.
+
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus_core::prelude::{spawn, try_consume_context, use_drop, use_hook};
+use dioxus_core::Event;
+use serde::de::DeserializeOwned;
+
+use crate::eval::{EvalProvider, UseEval};
+
+/// Listen to an event on the window. The listener is removed when the component is dropped, and moved to the new event
+/// if `name` changes.
+///
+/// The event data is deserialized from the fields of the JavaScript event in snake case, along with the `inner_width`,
+/// `inner_height`, `visibility_state` and `is_fullscreen` of the page. Any data type that can be deserialized from
+/// those fields works, including the event data types of this crate.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let mut size = use_signal(|| (0.0, 0.0));
+///     use_window_event("resize", move |event: ResizeEvent| {
+///         size.set((event.inner_width(), event.inner_height()));
+///     });
+///
+///     // Close the dialog with escape anywhere on the page
+///     let mut dialog_open = use_signal(|| true);
+///     use_window_event("keydown", move |event: KeyboardEvent| {
+///         if event.key() == Key::Escape {
+///             dialog_open.set(false);
+///         }
+///     });
+///
+///     // Events without a data type in this crate can use their own
+///     #[derive(serde::Deserialize)]
+///     struct StorageChange {
+///         key: Option<String>,
+///         new_value: Option<String>,
+///     }
+///     use_window_event("storage", move |event: Event<StorageChange>| {
+///         tracing::info!("{:?} changed to {:?}", event.key, event.new_value);
+///     });
+///
+///     rsx! { "The window is {size().0}x{size().1}" }
+/// }
+/// ```
+pub fn use_window_event<T: DeserializeOwned + 'static>(
+    name: impl ToString,
+    handler: impl FnMut(Event<T>) + 'static,
+) {
+    use_global_event("window", name, handler)
+}
+
+/// Listen to an event on the document. The listener is removed when the component is dropped.
+///
+/// The event data is deserialized the same way as [`use_window_event`].
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let mut hidden = use_signal(|| false);
+///     use_document_event("visibilitychange", move |event: VisibilityEvent| {
+///         hidden.set(event.visibility_state() == VisibilityState::Hidden);
+///     });
+///
+///     rsx! {
+///         if hidden() {
+///             "Paused while the page is hidden"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_document_event<T: DeserializeOwned + 'static>(
+    name: impl ToString,
+    handler: impl FnMut(Event<T>) + 'static,
+) {
+    use_global_event("document", name, handler)
+}
+
+type Handler<T> = Box<dyn FnMut(Event<T>)>;
+
+fn use_global_event<T: DeserializeOwned + 'static>(
+    target: &'static str,
+    name: impl ToString,
+    handler: impl FnMut(Event<T>) + 'static,
+) {
+    // Always call the handler from the latest render
+    let current_handler = use_hook(|| Rc::new(RefCell::new(None::<Handler<T>>)));
+    *current_handler.borrow_mut() = Some(Box::new(handler));
+
+    // The event name the script listens to and the script, if there is a page to listen on
+    let listener = use_hook(|| Rc::new(RefCell::new(None::<(String, Option<UseEval>)>)));
+    let name = name.to_string();
+    let listening = matches!(&*listener.borrow(), Some((current, _)) if *current == name);
+    if !listening {
+        if let Some((_, Some(old))) = listener.borrow_mut().take() {
+            detach(old);
+        }
+        let script = listen(target, &name, current_handler.clone());
+        *listener.borrow_mut() = Some((name, script));
+    }
+
+    use_drop(move || {
+        if let Some((_, Some(script))) = listener.borrow_mut().take() {
+            detach(script);
+        }
+    });
+}
+
+/// Start a script that sends the events to the handler. Renderers without a page, like the server, never fire these
+/// events, so nothing is started.
+fn listen<T: DeserializeOwned + 'static>(
+    target: &'static str,
+    name: &str,
+    handler: Rc<RefCell<Option<Handler<T>>>>,
+) -> Option<UseEval> {
+    let provider = try_consume_context::<Rc<dyn EvalProvider>>()?;
+    let mut script = UseEval::new(provider.new_evaluator(listener_script(target, name)));
+
+    let name = name.to_string();
+    spawn(async move {
+        while let Ok(message) = script.recv().await {
+            match T::deserialize(message) {
+                Ok(data) => {
+                    if let Some(handler) = handler.borrow_mut().as_mut() {
+                        handler(Event::detached(Rc::new(data)));
+                    }
+                }
+                Err(err) => tracing::error!("Failed to deserialize the {name} event: {err}"),
+            }
+        }
+    });
+
+    Some(script)
+}
+
+/// Remove the listener of a script. Any message tells the script to remove it.
+fn detach(script: UseEval) {
+    _ = script.send(serde_json::Value::Null);
+}
+
+fn listener_script(target: &str, name: &str) -> String {
+    let name = serde_json::to_string(name).expect("Failed to serialize event name");
+    format!(
+        r#"const target = {target};
+        const listener = (event) => {{
+            const data = {{}};
+            for (const key in event) {{
+                const value = event[key];
+                const primitive = ["string", "number", "boolean"].includes(typeof value);
+                // Skip constants like Event.AT_TARGET
+                if (primitive && key !== key.toUpperCase()) {{
+                    data[key.replace(/[A-Z]/g, (c) => "_" + c.toLowerCase())] = value;
+                }}
+            }}
+            if (event instanceof CustomEvent) data.detail = event.detail;
+            data.inner_width = window.innerWidth;
+            data.inner_height = window.innerHeight;
+            data.visibility_state = document.visibilityState;
+            data.is_fullscreen = !!document.fullscreenElement;
+            dioxus.send(data);
+        }};
+        target.addEventListener({name}, listener);
+        await dioxus.recv();
+        target.removeEventListener({name}, listener);"#
+    )
+}
@@ -0,0 +1,550 @@
+//! A typed builder for inline styles
+//!
+//! The `style` attribute and the individual CSS properties on elements accept any string. [`Style`] builds the same
+//! properties from typed values instead:
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus::html::inline_style::*;
+//!
+//! fn app() -> Element {
+//!     let mut wide = use_signal(|| false);
+//!
+//!     let style = Style::new()
+//!         .display(Display::Flex)
+//!         .justify_content(JustifyContent::SpaceBetween)
+//!         .width(if wide() { percent(100) } else { px(320) })
+//!         .padding(rem(1.5))
+//!         .background_color(Color::hex(0x1e293b))
+//!         .property("--accent", "hotpink");
+//!
+//!     rsx! {
+//!         // Spreading the style sets each property on its own, so toggling `wide` only updates the width
+//!         div { onclick: move |_| wide.toggle(), ..style.into() }
+//!     }
+//! }
+//! ```
+//!
+//! Spreading a [`Style`] into an element turns every property into its own attribute in the `style` namespace. Web and
+//! desktop set and remove those properties one at a time, so changing one property doesn't rewrite the whole `style`
+//! attribute. SSR merges them into a single `style` attribute with the properties sorted by name.
+//!
+//! A [`Style`] can also be passed to the `style` attribute directly, which sets the whole attribute as a string.
+
+use crate::attribute_values::attribute_value;
+use dioxus_core::prelude::IntoAttributeValue;
+use dioxus_core::{Attribute, AttributeValue};
+use std::collections::BTreeMap;
+use std::fmt::{Display as FmtDisplay, Formatter};
+
+/// A set of inline CSS properties
+///
+/// Properties are kept sorted by name so the rendered style is the same no matter what order they were set in. Setting a
+/// property that was already set replaces its value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    properties: BTreeMap<&'static str, String>,
+}
+
+impl Style {
+    /// Create an empty style
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set any CSS property, including custom properties like `--accent`
+    pub fn property(mut self, name: &'static str, value: impl FmtDisplay) -> Self {
+        self.properties.insert(name, value.to_string());
+        self
+    }
+
+    /// Get the value of a property if it is set
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.properties.get(name).map(String::as_str)
+    }
+
+    /// Iterate over the properties sorted by name
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.properties
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+    }
+
+    /// Check if no properties are set
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+
+    /// Turn every property into an attribute in the `style` namespace
+    pub fn into_attributes(self) -> Vec<Attribute> {
+        self.properties
+            .into_iter()
+            .map(|(name, value)| Attribute::new(name, value, Some("style"), false))
+            .collect()
+    }
+}
+
+impl From<Style> for Vec<Attribute> {
+    fn from(style: Style) -> Self {
+        style.into_attributes()
+    }
+}
+
+impl FmtDisplay for Style {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (name, value) in self.iter() {
+            write!(f, "{name}:{value};")?;
+        }
+        Ok(())
+    }
+}
+
+impl IntoAttributeValue for Style {
+    fn into_value(self) -> AttributeValue {
+        AttributeValue::Text(self.to_string())
+    }
+}
+
+macro_rules! style_properties {
+    (
+        $(
+            $(#[$attr:meta])*
+            $method:ident: $name:literal => $ty:ty;
+        )*
+    ) => {
+        impl Style {
+            $(
+                $(#[$attr])*
+                #[doc = ""]
+                #[doc = concat!("<https://developer.mozilla.org/en-US/docs/Web/CSS/", $name, ">")]
+                pub fn $method(self, value: impl Into<$ty>) -> Self {
+                    self.property($name, value.into())
+                }
+            )*
+        }
+    };
+}
+
+style_properties! {
+    /// Set the `display` property
+    display: "display" => Display;
+    /// Set the `position` property
+    position: "position" => Position;
+    /// Set the `box-sizing` property
+    box_sizing: "box-sizing" => BoxSizing;
+    /// Set the `visibility` property
+    visibility: "visibility" => Visibility;
+    /// Set the `overflow` property
+    overflow: "overflow" => Overflow;
+    /// Set the `overflow-x` property
+    overflow_x: "overflow-x" => Overflow;
+    /// Set the `overflow-y` property
+    overflow_y: "overflow-y" => Overflow;
+    /// Set the `cursor` property
+    cursor: "cursor" => Cursor;
+    /// Set the `pointer-events` property
+    pointer_events: "pointer-events" => PointerEvents;
+    /// Set the `opacity` property
+    opacity: "opacity" => f64;
+    /// Set the `z-index` property
+    z_index: "z-index" => i32;
+
+    /// Set the `width` property
+    width: "width" => Length;
+    /// Set the `height` property
+    height: "height" => Length;
+    /// Set the `min-width` property
+    min_width: "min-width" => Length;
+    /// Set the `min-height` property
+    min_height: "min-height" => Length;
+    /// Set the `max-width` property
+    max_width: "max-width" => Length;
+    /// Set the `max-height` property
+    max_height: "max-height" => Length;
+    /// Set the `top` property
+    top: "top" => Length;
+    /// Set the `right` property
+    right: "right" => Length;
+    /// Set the `bottom` property
+    bottom: "bottom" => Length;
+    /// Set the `left` property
+    left: "left" => Length;
+
+    /// Set the `margin` property on every side
+    margin: "margin" => Length;
+    /// Set the `margin-top` property
+    margin_top: "margin-top" => Length;
+    /// Set the `margin-right` property
+    margin_right: "margin-right" => Length;
+    /// Set the `margin-bottom` property
+    margin_bottom: "margin-bottom" => Length;
+    /// Set the `margin-left` property
+    margin_left: "margin-left" => Length;
+    /// Set the `padding` property on every side
+    padding: "padding" => Length;
+    /// Set the `padding-top` property
+    padding_top: "padding-top" => Length;
+    /// Set the `padding-right` property
+    padding_right: "padding-right" => Length;
+    /// Set the `padding-bottom` property
+    padding_bottom: "padding-bottom" => Length;
+    /// Set the `padding-left` property
+    padding_left: "padding-left" => Length;
+
+    /// Set the `flex-direction` property
+    flex_direction: "flex-direction" => FlexDirection;
+    /// Set the `flex-wrap` property
+    flex_wrap: "flex-wrap" => FlexWrap;
+    /// Set the `flex-grow` property
+    flex_grow: "flex-grow" => f64;
+    /// Set the `flex-shrink` property
+    flex_shrink: "flex-shrink" => f64;
+    /// Set the `flex-basis` property
+    flex_basis: "flex-basis" => Length;
+    /// Set the `order` property
+    order: "order" => i32;
+    /// Set the `justify-content` property
+    justify_content: "justify-content" => JustifyContent;
+    /// Set the `align-items` property
+    align_items: "align-items" => AlignItems;
+    /// Set the `align-self` property
+    align_self: "align-self" => AlignItems;
+    /// Set the `gap` property
+    gap: "gap" => Length;
+    /// Set the `row-gap` property
+    row_gap: "row-gap" => Length;
+    /// Set the `column-gap` property
+    column_gap: "column-gap" => Length;
+
+    /// Set the `color` property
+    color: "color" => Color;
+    /// Set the `background-color` property
+    background_color: "background-color" => Color;
+    /// Set the `border-color` property
+    border_color: "border-color" => Color;
+    /// Set the `border-width` property
+    border_width: "border-width" => Length;
+    /// Set the `border-radius` property
+    border_radius: "border-radius" => Length;
+
+    /// Set the `font-size` property
+    font_size: "font-size" => Length;
+    /// Set the `font-weight` property
+    font_weight: "font-weight" => FontWeight;
+    /// Set the `text-align` property
+    text_align: "text-align" => TextAlign;
+    /// Set the `white-space` property
+    white_space: "white-space" => WhiteSpace;
+}
+
+/// A CSS length
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Length {
+    /// `0`
+    Zero,
+    /// `auto`
+    Auto,
+    /// Pixels, e.g. `10px`
+    Px(f64),
+    /// Relative to the font size of the element, e.g. `1.5em`
+    Em(f64),
+    /// Relative to the font size of the root element, e.g. `1.5rem`
+    Rem(f64),
+    /// Relative to the size of the parent, e.g. `50%`
+    Percent(f64),
+    /// Relative to the width of the viewport, e.g. `100vw`
+    Vw(f64),
+    /// Relative to the height of the viewport, e.g. `100vh`
+    Vh(f64),
+}
+
+impl FmtDisplay for Length {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Length::Zero => f.write_str("0"),
+            Length::Auto => f.write_str("auto"),
+            Length::Px(value) => write!(f, "{value}px"),
+            Length::Em(value) => write!(f, "{value}em"),
+            Length::Rem(value) => write!(f, "{value}rem"),
+            Length::Percent(value) => write!(f, "{value}%"),
+            Length::Vw(value) => write!(f, "{value}vw"),
+            Length::Vh(value) => write!(f, "{value}vh"),
+        }
+    }
+}
+
+impl IntoAttributeValue for Length {
+    fn into_value(self) -> AttributeValue {
+        AttributeValue::Text(self.to_string())
+    }
+}
+
+/// A length in pixels
+pub fn px(value: impl Into<f64>) -> Length {
+    Length::Px(value.into())
+}
+
+/// A length relative to the font size of the element
+pub fn em(value: impl Into<f64>) -> Length {
+    Length::Em(value.into())
+}
+
+/// A length relative to the font size of the root element
+pub fn rem(value: impl Into<f64>) -> Length {
+    Length::Rem(value.into())
+}
+
+/// A length relative to the size of the parent
+pub fn percent(value: impl Into<f64>) -> Length {
+    Length::Percent(value.into())
+}
+
+/// A length relative to the width of the viewport
+pub fn vw(value: impl Into<f64>) -> Length {
+    Length::Vw(value.into())
+}
+
+/// A length relative to the height of the viewport
+pub fn vh(value: impl Into<f64>) -> Length {
+    Length::Vh(value.into())
+}
+
+/// A CSS color
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Color {
+    /// A color keyword like `red`, `transparent` or `currentcolor`
+    Keyword(&'static str),
+    /// An opaque color from its red, green and blue channels, written as `#rrggbb`
+    Rgb(u8, u8, u8),
+    /// A color from its red, green and blue channels and an alpha between `0.0` and `1.0`
+    Rgba(u8, u8, u8, f64),
+    /// A color from its hue in degrees and its saturation and lightness in percent
+    Hsl(f64, f64, f64),
+    /// A color from its hue in degrees, its saturation and lightness in percent and an alpha between `0.0` and `1.0`
+    Hsla(f64, f64, f64, f64),
+}
+
+impl Color {
+    /// `transparent`
+    pub const TRANSPARENT: Self = Self::Keyword("transparent");
+    /// `currentcolor`
+    pub const CURRENT_COLOR: Self = Self::Keyword("currentcolor");
+    /// `black`
+    pub const BLACK: Self = Self::Keyword("black");
+    /// `white`
+    pub const WHITE: Self = Self::Keyword("white");
+
+    /// An opaque color from its red, green and blue channels
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::Rgb(red, green, blue)
+    }
+
+    /// A color from its red, green and blue channels and an alpha between `0.0` and `1.0`
+    pub const fn rgba(red: u8, green: u8, blue: u8, alpha: f64) -> Self {
+        Self::Rgba(red, green, blue, alpha)
+    }
+
+    /// An opaque color from a hex code like `0x1e293b`
+    pub const fn hex(hex: u32) -> Self {
+        Self::Rgb((hex >> 16) as u8, (hex >> 8) as u8, hex as u8)
+    }
+}
+
+impl FmtDisplay for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Color::Keyword(keyword) => f.write_str(keyword),
+            Color::Rgb(red, green, blue) => write!(f, "#{red:02x}{green:02x}{blue:02x}"),
+            Color::Rgba(red, green, blue, alpha) => {
+                write!(f, "rgba({red}, {green}, {blue}, {alpha})")
+            }
+            Color::Hsl(hue, saturation, lightness) => {
+                write!(f, "hsl({hue}, {saturation}%, {lightness}%)")
+            }
+            Color::Hsla(hue, saturation, lightness, alpha) => {
+                write!(f, "hsla({hue}, {saturation}%, {lightness}%, {alpha})")
+            }
+        }
+    }
+}
+
+impl IntoAttributeValue for Color {
+    fn into_value(self) -> AttributeValue {
+        AttributeValue::Text(self.to_string())
+    }
+}
+
+attribute_value! {
+    /// The value of the `display` property
+    Display("display value") {
+        Block = "block",
+        Inline = "inline",
+        InlineBlock = "inline-block",
+        Flex = "flex",
+        InlineFlex = "inline-flex",
+        Grid = "grid",
+        InlineGrid = "inline-grid",
+        Contents = "contents",
+        Table = "table",
+        ListItem = "list-item",
+        None = "none",
+    }
+}
+
+attribute_value! {
+    /// The value of the `position` property
+    Position("position value") {
+        Static = "static",
+        Relative = "relative",
+        Absolute = "absolute",
+        Fixed = "fixed",
+        Sticky = "sticky",
+    }
+}
+
+attribute_value! {
+    /// The value of the `box-sizing` property
+    BoxSizing("box-sizing value") {
+        ContentBox = "content-box",
+        BorderBox = "border-box",
+    }
+}
+
+attribute_value! {
+    /// The value of the `visibility` property
+    Visibility("visibility value") {
+        Visible = "visible",
+        Hidden = "hidden",
+        Collapse = "collapse",
+    }
+}
+
+attribute_value! {
+    /// The value of the `overflow`, `overflow-x` and `overflow-y` properties
+    Overflow("overflow value") {
+        Visible = "visible",
+        Hidden = "hidden",
+        Clip = "clip",
+        Scroll = "scroll",
+        Auto = "auto",
+    }
+}
+
+attribute_value! {
+    /// The value of the `cursor` property
+    Cursor("cursor value") {
+        Auto = "auto",
+        Default = "default",
+        None = "none",
+        Pointer = "pointer",
+        Text = "text",
+        Wait = "wait",
+        Progress = "progress",
+        Help = "help",
+        Move = "move",
+        Grab = "grab",
+        Grabbing = "grabbing",
+        NotAllowed = "not-allowed",
+        Crosshair = "crosshair",
+        ColResize = "col-resize",
+        RowResize = "row-resize",
+    }
+}
+
+attribute_value! {
+    /// The value of the `pointer-events` property
+    PointerEvents("pointer-events value") {
+        Auto = "auto",
+        None = "none",
+    }
+}
+
+attribute_value! {
+    /// The value of the `flex-direction` property
+    FlexDirection("flex-direction value") {
+        Row = "row",
+        RowReverse = "row-reverse",
+        Column = "column",
+        ColumnReverse = "column-reverse",
+    }
+}
+
+attribute_value! {
+    /// The value of the `flex-wrap` property
+    FlexWrap("flex-wrap value") {
+        Nowrap = "nowrap",
+        Wrap = "wrap",
+        WrapReverse = "wrap-reverse",
+    }
+}
+
+attribute_value! {
+    /// The value of the `justify-content` property
+    JustifyContent("justify-content value") {
+        Start = "start",
+        End = "end",
+        FlexStart = "flex-start",
+        FlexEnd = "flex-end",
+        Center = "center",
+        SpaceBetween = "space-between",
+        SpaceAround = "space-around",
+        SpaceEvenly = "space-evenly",
+        Stretch = "stretch",
+    }
+}
+
+attribute_value! {
+    /// The value of the `align-items` and `align-self` properties
+    AlignItems("align-items value") {
+        Normal = "normal",
+        Start = "start",
+        End = "end",
+        FlexStart = "flex-start",
+        FlexEnd = "flex-end",
+        Center = "center",
+        Baseline = "baseline",
+        Stretch = "stretch",
+    }
+}
+
+attribute_value! {
+    /// The value of the `font-weight` property
+    FontWeight("font-weight value") {
+        Normal = "normal",
+        Bold = "bold",
+        Bolder = "bolder",
+        Lighter = "lighter",
+        Thin = "100",
+        ExtraLight = "200",
+        Light = "300",
+        Regular = "400",
+        Medium = "500",
+        SemiBold = "600",
+        ExtraBold = "800",
+        Black = "900",
+    }
+}
+
+attribute_value! {
+    /// The value of the `text-align` property
+    TextAlign("text-align value") {
+        Start = "start",
+        End = "end",
+        Left = "left",
+        Right = "right",
+        Center = "center",
+        Justify = "justify",
+    }
+}
+
+attribute_value! {
+    /// The value of the `white-space` property
+    WhiteSpace("white-space value") {
+        Normal = "normal",
+        Nowrap = "nowrap",
+        Pre = "pre",
+        PreWrap = "pre-wrap",
+        PreLine = "pre-line",
+        BreakSpaces = "break-spaces",
+    }
+}
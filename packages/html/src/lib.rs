@@ -25,8 +25,13 @@ pub mod events;
 pub(crate) mod file_data;
 pub use file_data::*;
 mod attribute_groups;
+mod attribute_values;
+pub mod css_module;
 pub mod geometry;
 pub mod input_data;
+mod observer;
+pub use observer::*;
+pub mod inline_style;
 #[cfg(feature = "native-bind")]
 pub mod native_bind;
 pub mod point_interaction;
@@ -43,6 +48,7 @@ mod transit;
 pub use transit::*;
 
 pub use attribute_groups::*;
+pub use attribute_values::*;
 pub use elements::*;
 pub use events::*;
 pub use render_template::*;
@@ -50,17 +56,44 @@ pub use render_template::*;
 #[cfg(feature = "eval")]
 pub mod eval;
 
+#[cfg(feature = "eval")]
+pub mod global_events;
+
+/// Items used by the bindings `dioxus-custom-elements` generates for web components
+#[doc(hidden)]
+pub mod __private {
+    pub use dioxus_core::{
+        prelude::{Attribute, Event, EventHandler, SuperInto},
+        AttributeValue, SpawnIfAsync,
+    };
+}
+
 pub mod extensions {
-    pub use crate::attribute_groups::{GlobalAttributesExtension, SvgAttributesExtension};
+    pub use crate::attribute_groups::{
+        GlobalAttributesExtension, MathmlAttributesExtension, SvgAttributesExtension,
+    };
     pub use crate::elements::extensions::*;
 }
 
 pub mod prelude {
-    pub use crate::attribute_groups::{GlobalAttributesExtension, SvgAttributesExtension};
+    pub use crate::attribute_groups::{
+        GlobalAttributesExtension, MathmlAttributesExtension, SvgAttributesExtension,
+    };
+    pub use crate::attribute_values::{
+        AriaAutocomplete, AriaChecked, AriaCurrent, AriaHasPopup, AriaInvalid, AriaLive,
+        AriaOrientation, AriaRole, AriaSort, Autocomplete, ButtonType, CrossOrigin, EnterKeyHint,
+        FormDialogMethod, FormEncodingType, FormMethod, ImageDecoding, InputMode, InputType,
+        LinkType, Loading, Preload, ReferrerPolicy, TableHeaderScope, Target, Wrap,
+    };
+    pub use crate::css_module::{use_css_module, CssModule};
     pub use crate::elements::extensions::*;
     #[cfg(feature = "eval")]
     pub use crate::eval::*;
     pub use crate::events::*;
+    #[cfg(feature = "eval")]
+    pub use crate::global_events::{use_document_event, use_window_event};
+    pub use crate::inline_style::Style;
+    pub use crate::observer::{IntersectionObservation, IntersectionOptions, ResizeObservation};
     pub use crate::point_interaction::*;
     pub use keyboard_types::{self, Code, Key, Location, Modifiers};
 }
@@ -0,0 +1,230 @@
+//! Streaming observers for mounted elements
+//!
+//! Renderers report changes to the size of an element or how much of it is visible through an [`Observer`]. The
+//! `use_resize_observer` and `use_intersection_observer` hooks in `dioxus-hooks` keep the latest observation in a
+//! signal.
+
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll},
+};
+
+use futures_channel::mpsc::UnboundedSender;
+use futures_core::Stream;
+
+use crate::{
+    geometry::{PixelsRect, PixelsSize},
+    MountedResult, RenderedElementBacking,
+};
+
+/// A stream of observations about a mounted element.
+///
+/// The renderer stops observing the element when the observer is dropped.
+pub struct Observer<T> {
+    observations: Pin<Box<dyn Stream<Item = T>>>,
+    disconnect: Option<Box<dyn FnOnce()>>,
+}
+
+impl<T> Observer<T> {
+    /// Create a new observer from a stream of observations. `disconnect` is called when the observer is dropped.
+    pub fn new(
+        observations: impl Stream<Item = T> + 'static,
+        disconnect: impl FnOnce() + 'static,
+    ) -> Self {
+        Self {
+            observations: Box::pin(observations),
+            disconnect: Some(Box::new(disconnect)),
+        }
+    }
+
+    /// Wait for the next observation. Returns `None` if the renderer stopped observing the element.
+    pub async fn next(&mut self) -> Option<T> {
+        std::future::poll_fn(|cx| self.observations.as_mut().poll_next(cx)).await
+    }
+}
+
+impl<T> Stream for Observer<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.observations.as_mut().poll_next(cx)
+    }
+}
+
+impl<T> Drop for Observer<T> {
+    fn drop(&mut self) {
+        if let Some(disconnect) = self.disconnect.take() {
+            disconnect();
+        }
+    }
+}
+
+/// The size of an element reported by a resize observer
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[doc(alias = "ResizeObserverEntry")]
+pub struct ResizeObservation {
+    /// The size of the content of the element, without padding or borders
+    pub content_box: PixelsSize,
+    /// The size of the element including padding and borders
+    pub border_box: PixelsSize,
+}
+
+/// How much of an element is visible, reported by an intersection observer
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[doc(alias = "IntersectionObserverEntry")]
+pub struct IntersectionObservation {
+    /// If any part of the element is visible
+    pub is_intersecting: bool,
+    /// The ratio of the element that is visible between 0 and 1
+    pub intersection_ratio: f64,
+    /// The bounding rectangle of the element relative to the viewport
+    pub bounding_client_rect: PixelsRect,
+    /// The part of the element that is visible relative to the viewport
+    pub intersection_rect: PixelsRect,
+}
+
+/// Options for observing how much of an element is visible in the viewport
+#[derive(Debug, Clone, PartialEq)]
+#[doc(alias = "IntersectionObserverInit")]
+pub struct IntersectionOptions {
+    /// The visible ratios between 0 and 1 that trigger a new observation when the element crosses them
+    pub thresholds: Vec<f64>,
+    /// A margin around the viewport in the same format as the CSS `margin` property, e.g. `"10px 0px"`
+    pub root_margin: String,
+}
+
+impl Default for IntersectionOptions {
+    fn default() -> Self {
+        Self {
+            thresholds: vec![0.0],
+            root_margin: "0px".to_string(),
+        }
+    }
+}
+
+impl IntersectionOptions {
+    /// Create the default options: an observation is reported whenever the element enters or leaves the viewport
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the visible ratios between 0 and 1 that trigger a new observation
+    pub fn with_thresholds(mut self, thresholds: impl IntoIterator<Item = f64>) -> Self {
+        self.thresholds = thresholds.into_iter().collect();
+        self
+    }
+
+    /// Set the margin around the viewport in the same format as the CSS `margin` property
+    pub fn with_root_margin(mut self, root_margin: impl ToString) -> Self {
+        self.root_margin = root_margin.to_string();
+        self
+    }
+}
+
+/// A fake mounted element that only reports the observations it is given.
+///
+/// This is useful for testing components that observe elements without a renderer. Every observer that is currently
+/// watching the element receives the observations passed to [`FakeElement::resize`] and [`FakeElement::intersect`] in
+/// order.
+///
+/// ```rust
+/// use dioxus_html::{geometry::PixelsSize, FakeElement, MountedData, ResizeObservation};
+/// use futures_util::FutureExt;
+///
+/// let element = FakeElement::new();
+/// let mounted = MountedData::new(element.clone());
+/// let mut observer = mounted.observe_resize().now_or_never().unwrap().unwrap();
+///
+/// let size = PixelsSize::new(100.0, 50.0);
+/// element.resize(ResizeObservation { content_box: size, border_box: size });
+/// let observation = observer.next().now_or_never().flatten().unwrap();
+/// assert_eq!(observation.content_box, size);
+/// ```
+#[derive(Clone, Default)]
+pub struct FakeElement {
+    observers: Rc<RefCell<FakeObservers>>,
+}
+
+#[derive(Default)]
+struct FakeObservers {
+    resize: Vec<UnboundedSender<ResizeObservation>>,
+    intersection: Vec<(
+        IntersectionOptions,
+        UnboundedSender<IntersectionObservation>,
+    )>,
+}
+
+impl FakeElement {
+    /// Create a new fake element
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report a new size to every resize observer that is watching the element
+    pub fn resize(&self, observation: ResizeObservation) {
+        let mut observers = self.observers.borrow_mut();
+        observers.resize.retain(|tx| !tx.is_closed());
+        for tx in &observers.resize {
+            _ = tx.unbounded_send(observation);
+        }
+    }
+
+    /// Report a new intersection to every intersection observer that is watching the element
+    pub fn intersect(&self, observation: IntersectionObservation) {
+        let mut observers = self.observers.borrow_mut();
+        observers.intersection.retain(|(_, tx)| !tx.is_closed());
+        for (_, tx) in &observers.intersection {
+            _ = tx.unbounded_send(observation);
+        }
+    }
+
+    /// Get the number of observers that are watching the element
+    pub fn observer_count(&self) -> usize {
+        let observers = self.observers.borrow();
+        observers.resize.iter().filter(|tx| !tx.is_closed()).count()
+            + observers
+                .intersection
+                .iter()
+                .filter(|(_, tx)| !tx.is_closed())
+                .count()
+    }
+
+    /// Get the options of the intersection observers that are watching the element
+    pub fn intersection_options(&self) -> Vec<IntersectionOptions> {
+        self.observers
+            .borrow()
+            .intersection
+            .iter()
+            .filter(|(_, tx)| !tx.is_closed())
+            .map(|(options, _)| options.clone())
+            .collect()
+    }
+}
+
+impl RenderedElementBacking for FakeElement {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn observe_resize(
+        &self,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<Observer<ResizeObservation>>>>> {
+        let (tx, rx) = futures_channel::mpsc::unbounded();
+        self.observers.borrow_mut().resize.push(tx);
+        Box::pin(async { Ok(Observer::new(rx, || {})) })
+    }
+
+    fn observe_intersection(
+        &self,
+        options: IntersectionOptions,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<Observer<IntersectionObservation>>>>> {
+        let (tx, rx) = futures_channel::mpsc::unbounded();
+        self.observers.borrow_mut().intersection.push((options, tx));
+        Box::pin(async { Ok(Observer::new(rx, || {})) })
+    }
+}
@@ -87,6 +87,9 @@ fn deserialize_raw(
         // Focus
         "blur" | "focus" | "focusin" | "focusout" => Focus(de(data)?),
 
+        // BeforeInput
+        "beforeinput" => BeforeInput(de(data)?),
+
         // Form
         "change" | "input" | "invalid" | "reset" | "submit" => Form(de(data)?),
 
@@ -127,12 +130,23 @@ fn deserialize_raw(
         // Toggle
         "toggle" => Toggle(de(data)?),
 
+        // Fullscreen
+        "fullscreenchange" | "fullscreenerror" => Fullscreen(de(data)?),
+
+        // Visibility
+        "visibilitychange" => Visibility(de(data)?),
+
+        // Resize
+        "resize" => Resize(de(data)?),
+
         "load" | "error" => Image(de(data)?),
 
         // Mounted
         "mounted" => Mounted,
 
-        // OtherData => "abort" | "afterprint" | "beforeprint" | "beforeunload" | "hashchange" | "languagechange" | "message" | "offline" | "online" | "pagehide" | "pageshow" | "popstate" | "rejectionhandled" | "storage" | "unhandledrejection" | "unload" | "userproximity" | "vrdisplayactivate" | "vrdisplayblur" | "vrdisplayconnect" | "vrdisplaydeactivate" | "vrdisplaydisconnect" | "vrdisplayfocus" | "vrdisplaypointerrestricted" | "vrdisplaypointerunrestricted" | "vrdisplaypresentchange";
+        // Web components name their custom events with a `-` or a namespace, like `sl-change` or `my:event`
+        custom if custom.contains(['-', ':']) => Custom(de(data)?),
+
         other => {
             return Err(serde_value::DeserializerError::UnknownVariant(
                 other.to_string(),
@@ -171,7 +185,12 @@ pub enum EventData {
     Animation(SerializedAnimationData),
     Transition(SerializedTransitionData),
     Toggle(SerializedToggleData),
+    BeforeInput(SerializedBeforeInputData),
+    Fullscreen(SerializedFullscreenData),
+    Visibility(SerializedVisibilityData),
+    Resize(SerializedResizeData),
     Image(SerializedImageData),
+    Custom(SerializedCustomData),
     Mounted,
 }
 
@@ -222,9 +241,24 @@ impl EventData {
             EventData::Toggle(data) => {
                 Rc::new(PlatformEventData::new(Box::new(data))) as Rc<dyn Any>
             }
+            EventData::BeforeInput(data) => {
+                Rc::new(PlatformEventData::new(Box::new(data))) as Rc<dyn Any>
+            }
+            EventData::Fullscreen(data) => {
+                Rc::new(PlatformEventData::new(Box::new(data))) as Rc<dyn Any>
+            }
+            EventData::Visibility(data) => {
+                Rc::new(PlatformEventData::new(Box::new(data))) as Rc<dyn Any>
+            }
+            EventData::Resize(data) => {
+                Rc::new(PlatformEventData::new(Box::new(data))) as Rc<dyn Any>
+            }
             EventData::Image(data) => {
                 Rc::new(PlatformEventData::new(Box::new(data))) as Rc<dyn Any>
             }
+            EventData::Custom(data) => {
+                Rc::new(PlatformEventData::new(Box::new(data))) as Rc<dyn Any>
+            }
             EventData::Mounted => {
                 Rc::new(PlatformEventData::new(Box::new(MountedData::new(())))) as Rc<dyn Any>
             }
@@ -284,6 +318,14 @@ impl HtmlEventConverter for SerializedHtmlEventConverter {
             .into()
     }
 
+    fn convert_before_input_data(&self, event: &PlatformEventData) -> BeforeInputData {
+        event
+            .downcast::<SerializedBeforeInputData>()
+            .cloned()
+            .unwrap()
+            .into()
+    }
+
     fn convert_clipboard_data(&self, event: &PlatformEventData) -> ClipboardData {
         event
             .downcast::<SerializedClipboardData>()
@@ -300,6 +342,14 @@ impl HtmlEventConverter for SerializedHtmlEventConverter {
             .into()
     }
 
+    fn convert_custom_data(&self, event: &PlatformEventData) -> CustomData {
+        event
+            .downcast::<SerializedCustomData>()
+            .cloned()
+            .unwrap()
+            .into()
+    }
+
     fn convert_drag_data(&self, event: &PlatformEventData) -> DragData {
         event
             .downcast::<SerializedDragData>()
@@ -324,6 +374,14 @@ impl HtmlEventConverter for SerializedHtmlEventConverter {
             .into()
     }
 
+    fn convert_fullscreen_data(&self, event: &PlatformEventData) -> FullscreenData {
+        event
+            .downcast::<SerializedFullscreenData>()
+            .cloned()
+            .unwrap()
+            .into()
+    }
+
     fn convert_image_data(&self, event: &PlatformEventData) -> ImageData {
         event
             .downcast::<SerializedImageData>()
@@ -368,6 +426,14 @@ impl HtmlEventConverter for SerializedHtmlEventConverter {
             .into()
     }
 
+    fn convert_resize_data(&self, event: &PlatformEventData) -> ResizeData {
+        event
+            .downcast::<SerializedResizeData>()
+            .cloned()
+            .unwrap()
+            .into()
+    }
+
     fn convert_scroll_data(&self, event: &PlatformEventData) -> ScrollData {
         event
             .downcast::<SerializedScrollData>()
@@ -408,6 +474,14 @@ impl HtmlEventConverter for SerializedHtmlEventConverter {
             .into()
     }
 
+    fn convert_visibility_data(&self, event: &PlatformEventData) -> VisibilityData {
+        event
+            .downcast::<SerializedVisibilityData>()
+            .cloned()
+            .unwrap()
+            .into()
+    }
+
     fn convert_wheel_data(&self, event: &PlatformEventData) -> WheelData {
         event
             .downcast::<SerializedWheelData>()
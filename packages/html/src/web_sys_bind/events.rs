@@ -39,6 +39,7 @@ macro_rules! uncheck_convert {
 }
 
 uncheck_convert![
+    web_sys::InputEvent       => BeforeInputData,
     web_sys::CompositionEvent => CompositionData,
     web_sys::KeyboardEvent    => KeyboardData,
     web_sys::MouseEvent       => MouseData,
@@ -51,6 +52,24 @@ uncheck_convert![
     web_sys::FocusEvent       => FocusData,
 ];
 
+impl HasBeforeInputData for web_sys::InputEvent {
+    fn input_type(&self) -> String {
+        self.input_type()
+    }
+
+    fn data(&self) -> Option<String> {
+        self.data()
+    }
+
+    fn is_composing(&self) -> bool {
+        self.is_composing()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
 impl HasCompositionData for CompositionEvent {
     fn data(&self) -> std::string::String {
         self.data().unwrap_or_default()
@@ -510,8 +529,120 @@ impl crate::RenderedElementBacking for web_sys::Element {
             });
         Box::pin(async { result })
     }
+
+    fn observe_resize(
+        &self,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                Output = crate::MountedResult<crate::Observer<crate::ResizeObservation>>,
+            >,
+        >,
+    > {
+        use wasm_bindgen::closure::Closure;
+
+        let (tx, rx) = futures_channel::mpsc::unbounded();
+        let callback = Closure::<dyn FnMut(js_sys::Array)>::new(move |entries: js_sys::Array| {
+            for entry in entries.iter() {
+                let entry: web_sys::ResizeObserverEntry = entry.unchecked_into();
+                let content = entry.content_rect();
+                let content_box =
+                    crate::geometry::PixelsSize::new(content.width(), content.height());
+                // Older browsers don't report the border box size
+                let border_box = entry
+                    .border_box_size()
+                    .get(0)
+                    .dyn_into::<web_sys::ResizeObserverSize>()
+                    .map(|size| {
+                        crate::geometry::PixelsSize::new(size.inline_size(), size.block_size())
+                    })
+                    .unwrap_or(content_box);
+                _ = tx.unbounded_send(crate::ResizeObservation {
+                    content_box,
+                    border_box,
+                });
+            }
+        });
+        let result = web_sys::ResizeObserver::new(callback.as_ref().unchecked_ref())
+            .map_err(|err| crate::MountedError::OperationFailed(Box::new(ObserverError(err))))
+            .map(|observer| {
+                observer.observe(self);
+                crate::Observer::new(rx, move || {
+                    observer.disconnect();
+                    drop(callback);
+                })
+            });
+        Box::pin(async { result })
+    }
+
+    fn observe_intersection(
+        &self,
+        options: crate::IntersectionOptions,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                Output = crate::MountedResult<crate::Observer<crate::IntersectionObservation>>,
+            >,
+        >,
+    > {
+        use wasm_bindgen::closure::Closure;
+
+        fn rect(rect: web_sys::DomRectReadOnly) -> crate::geometry::PixelsRect {
+            crate::geometry::PixelsRect::new(
+                euclid::Point2D::new(rect.left(), rect.top()),
+                euclid::Size2D::new(rect.width(), rect.height()),
+            )
+        }
+
+        let (tx, rx) = futures_channel::mpsc::unbounded();
+        let callback = Closure::<dyn FnMut(js_sys::Array)>::new(move |entries: js_sys::Array| {
+            for entry in entries.iter() {
+                let entry: web_sys::IntersectionObserverEntry = entry.unchecked_into();
+                _ = tx.unbounded_send(crate::IntersectionObservation {
+                    is_intersecting: entry.is_intersecting(),
+                    intersection_ratio: entry.intersection_ratio(),
+                    bounding_client_rect: rect(entry.bounding_client_rect()),
+                    intersection_rect: rect(entry.intersection_rect()),
+                });
+            }
+        });
+        let thresholds: js_sys::Array = options
+            .thresholds
+            .iter()
+            .map(|threshold| wasm_bindgen::JsValue::from_f64(*threshold))
+            .collect();
+        let result = web_sys::IntersectionObserver::new_with_options(
+            callback.as_ref().unchecked_ref(),
+            web_sys::IntersectionObserverInit::new()
+                .root_margin(&options.root_margin)
+                .threshold(&thresholds),
+        )
+        .map_err(|err| crate::MountedError::OperationFailed(Box::new(ObserverError(err))))
+        .map(|observer| {
+            observer.observe(self);
+            crate::Observer::new(rx, move || {
+                observer.disconnect();
+                drop(callback);
+            })
+        });
+        Box::pin(async { result })
+    }
 }
 
+#[cfg(feature = "mounted")]
+#[derive(Debug)]
+struct ObserverError(wasm_bindgen::JsValue);
+
+#[cfg(feature = "mounted")]
+impl std::fmt::Display for ObserverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to observe element {:?}", self.0)
+    }
+}
+
+#[cfg(feature = "mounted")]
+impl std::error::Error for ObserverError {}
+
 impl HasScrollData for Event {
     fn as_any(&self) -> &dyn std::any::Any {
         self
@@ -542,6 +673,20 @@ impl HasToggleData for web_sys::Event {
     }
 }
 
+impl HasCustomData for web_sys::Event {
+    fn detail_json(&self) -> Option<String> {
+        let detail = self.dyn_ref::<web_sys::CustomEvent>()?.detail();
+        if detail.is_null() || detail.is_undefined() {
+            return None;
+        }
+        js_sys::JSON::stringify(&detail).ok()?.as_string()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
 impl HasSelectionData for web_sys::Event {
     fn as_any(&self) -> &dyn std::any::Any {
         self
@@ -559,14 +704,70 @@ impl HasFileData for web_sys::Event {
     fn files(&self) -> Option<std::sync::Arc<dyn crate::file_data::FileEngine>> {
         let files = self
             .dyn_ref()
-            .and_then(|input: &web_sys::HtmlInputElement| {
-                input.files().and_then(|files| {
-                    #[allow(clippy::arc_with_non_send_sync)]
-                    crate::web_sys_bind::file_engine::WebFileEngine::new(files)
-                        .map(|f| std::sync::Arc::new(f) as std::sync::Arc<dyn crate::FileEngine>)
-                })
+            .and_then(|input: &web_sys::HtmlInputElement| input.files())
+            .or_else(|| {
+                // Files pasted into the page are stored on the clipboard data of the event
+                self.dyn_ref::<web_sys::ClipboardEvent>()?
+                    .clipboard_data()?
+                    .files()
             });
 
-        files
+        files.and_then(|files| {
+            #[allow(clippy::arc_with_non_send_sync)]
+            crate::web_sys_bind::file_engine::WebFileEngine::new(files)
+                .map(|f| std::sync::Arc::new(f) as std::sync::Arc<dyn crate::FileEngine>)
+        })
+    }
+}
+
+impl HasFullscreenData for web_sys::Event {
+    fn is_fullscreen(&self) -> bool {
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.fullscreen_element())
+            .is_some()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl HasVisibilityData for web_sys::Event {
+    fn visibility_state(&self) -> VisibilityState {
+        let hidden = web_sys::window()
+            .and_then(|window| window.document())
+            .map(|document| document.visibility_state() == web_sys::VisibilityState::Hidden)
+            .unwrap_or_default();
+
+        if hidden {
+            VisibilityState::Hidden
+        } else {
+            VisibilityState::Visible
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl HasResizeData for web_sys::Event {
+    fn inner_width(&self) -> f64 {
+        web_sys::window()
+            .and_then(|window| window.inner_width().ok())
+            .and_then(|width| width.as_f64())
+            .unwrap_or_default()
+    }
+
+    fn inner_height(&self) -> f64 {
+        web_sys::window()
+            .and_then(|window| window.inner_height().ok())
+            .and_then(|height| height.as_f64())
+            .unwrap_or_default()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
     }
 }
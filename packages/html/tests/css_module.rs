@@ -0,0 +1,78 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use std::cell::Cell;
+
+css_module!(Styles = "tests/fixtures/button.module.css");
+
+thread_local! {
+    static FIRST: Cell<usize> = const { Cell::new(0) };
+}
+
+#[component]
+fn Button(index: usize) -> Element {
+    let stylesheet = use_css_module(&Styles::MODULE);
+
+    rsx! {
+        {stylesheet}
+        button { class: "{Styles::button} {Styles::button_primary}", "{index}" }
+    }
+}
+
+fn app() -> Element {
+    rsx! {
+        for index in FIRST.with(Cell::get)..2 {
+            Button { key: "{index}", index }
+        }
+    }
+}
+
+#[test]
+fn classes_are_scoped_to_the_module() {
+    let id = dioxus_css_module::module_id("dioxus-html", "tests/fixtures/button.module.css");
+    assert_eq!(Styles::MODULE.id(), id);
+    assert_eq!(Styles::button, format!("button_{id}"));
+    assert_eq!(Styles::button_primary, format!("button-primary_{id}"));
+    assert_eq!(Styles::icon, format!("icon_{id}"));
+    assert!(Styles::MODULE
+        .css()
+        .contains(&format!(".dark .button_{id} {{")));
+}
+
+#[test]
+fn ssr_inlines_the_stylesheet_once() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    let id = Styles::MODULE.id();
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        format!(
+            r#"<style data-css-module="{id}">{}</style><button class="button_{id} button-primary_{id}">0</button><button class="button_{id} button-primary_{id}">1</button>"#,
+            Styles::MODULE.css()
+        )
+    );
+}
+
+#[test]
+fn the_next_component_renders_the_stylesheet_when_the_first_is_removed() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+
+    // Remove the first button, which was rendering the stylesheet
+    FIRST.with(|first| first.set(1));
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut NoOpMutations);
+    dom.process_events();
+    dom.render_immediate(&mut NoOpMutations);
+
+    let id = Styles::MODULE.id();
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        format!(
+            r#"<style data-css-module="{id}">{}</style><button class="button_{id} button-primary_{id}">1</button>"#,
+            Styles::MODULE.css()
+        )
+    );
+}
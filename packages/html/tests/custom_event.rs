@@ -0,0 +1,47 @@
+use dioxus_html::{CustomData, EventData, HtmlEvent, SerializedCustomData};
+
+#[test]
+fn custom_element_events_deserialize_as_custom_events() {
+    let event: HtmlEvent = serde_json::from_str(
+        r#"{ "element": 1, "name": "sl-change", "bubbles": false, "data": { "detail": { "value": "red" } } }"#,
+    )
+    .unwrap();
+
+    let EventData::Custom(data) = event.data else {
+        panic!("expected a custom event, found {:?}", event.data);
+    };
+    let data = CustomData::new(data);
+    assert_eq!(data.detail_json().as_deref(), Some(r#"{"value":"red"}"#));
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Detail {
+        value: String,
+    }
+    assert_eq!(
+        data.detail::<Detail>().unwrap(),
+        Some(Detail {
+            value: "red".to_string()
+        })
+    );
+    assert_eq!(
+        CustomData::new(SerializedCustomData::default()).detail_json(),
+        None
+    );
+}
+
+#[test]
+fn namespaced_events_deserialize_as_custom_events() {
+    let event: HtmlEvent = serde_json::from_str(
+        r#"{ "element": 1, "name": "my:event", "bubbles": false, "data": {} }"#,
+    )
+    .unwrap();
+    assert!(matches!(event.data, EventData::Custom(_)));
+}
+
+#[test]
+#[should_panic(expected = "notanevent")]
+fn unknown_events_without_a_custom_name_are_rejected() {
+    _ = serde_json::from_str::<HtmlEvent>(
+        r#"{ "element": 1, "name": "notanevent", "bubbles": true, "data": {} }"#,
+    );
+}
@@ -0,0 +1,79 @@
+use dioxus_html::{
+    event_bubbles, BeforeInputData, ClipboardData, EventData, FullscreenData, HasFileData,
+    HtmlEvent, ResizeData, VisibilityData, VisibilityState,
+};
+
+fn deserialize(name: &str, data: &str) -> EventData {
+    let event: HtmlEvent = serde_json::from_str(&format!(
+        r#"{{ "element": 1, "name": "{name}", "bubbles": {}, "data": {data} }}"#,
+        event_bubbles(name)
+    ))
+    .unwrap();
+    event.data
+}
+
+#[test]
+fn beforeinput_carries_the_input_type() {
+    let EventData::BeforeInput(data) = deserialize(
+        "beforeinput",
+        r#"{ "input_type": "insertText", "data": "a", "is_composing": false, "value": "" }"#,
+    ) else {
+        panic!("expected a beforeinput event");
+    };
+
+    let data = BeforeInputData::new(data);
+    assert_eq!(data.input_type(), "insertText");
+    assert_eq!(data.data().as_deref(), Some("a"));
+    assert!(!data.is_composing());
+}
+
+#[test]
+fn document_and_window_events_deserialize() {
+    let EventData::Fullscreen(data) =
+        deserialize("fullscreenchange", r#"{ "is_fullscreen": true }"#)
+    else {
+        panic!("expected a fullscreen event");
+    };
+    assert!(FullscreenData::new(data).is_fullscreen());
+
+    let EventData::Visibility(data) =
+        deserialize("visibilitychange", r#"{ "visibility_state": "hidden" }"#)
+    else {
+        panic!("expected a visibility event");
+    };
+    assert_eq!(
+        VisibilityData::new(data).visibility_state(),
+        VisibilityState::Hidden
+    );
+
+    let EventData::Resize(data) = deserialize(
+        "resize",
+        r#"{ "inner_width": 1280.0, "inner_height": 720.5 }"#,
+    ) else {
+        panic!("expected a resize event");
+    };
+    let data = ResizeData::new(data);
+    assert_eq!((data.inner_width(), data.inner_height()), (1280.0, 720.5));
+
+    assert!(event_bubbles("beforeinput"));
+    assert!(event_bubbles("fullscreenchange"));
+    assert!(!event_bubbles("visibilitychange"));
+    assert!(!event_bubbles("resize"));
+}
+
+#[test]
+fn pasted_files_are_available() {
+    let EventData::Clipboard(data) = deserialize(
+        "paste",
+        r#"{ "files": { "files": { "notes.txt": [104, 105] } } }"#,
+    ) else {
+        panic!("expected a clipboard event");
+    };
+    let files = ClipboardData::new(data).files().unwrap();
+    assert_eq!(files.files(), vec!["notes.txt".to_string()]);
+
+    let EventData::Clipboard(data) = deserialize("copy", "{}") else {
+        panic!("expected a clipboard event");
+    };
+    assert!(ClipboardData::new(data).files().is_none());
+}
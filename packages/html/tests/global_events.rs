@@ -0,0 +1,199 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use futures_channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures_util::StreamExt;
+use generational_box::{GenerationalBox, Owner};
+use serde_json::{json, Value};
+
+/// A page that records the scripts it evaluates and lets the test fire events into them
+#[derive(Clone, Default)]
+struct FakePage {
+    scripts: Rc<RefCell<Vec<String>>>,
+    fire: Rc<RefCell<Vec<UnboundedSender<Value>>>>,
+    sent: Rc<RefCell<Vec<Value>>>,
+    /// Keeps the evaluators alive for the whole test
+    owner: Owner,
+}
+
+impl FakePage {
+    fn fire(&self, event: Value) {
+        for tx in self.fire.borrow().iter() {
+            _ = tx.unbounded_send(event.clone());
+        }
+    }
+}
+
+struct FakeEvaluator {
+    page: FakePage,
+    events: UnboundedReceiver<Value>,
+}
+
+impl EvalProvider for FakePage {
+    fn new_evaluator(&self, js: String) -> GenerationalBox<Box<dyn Evaluator>> {
+        self.scripts.borrow_mut().push(js);
+        let (tx, events) = unbounded();
+        self.fire.borrow_mut().push(tx);
+        let evaluator = FakeEvaluator {
+            page: self.clone(),
+            events,
+        };
+        self.owner.insert(Box::new(evaluator) as Box<dyn Evaluator>)
+    }
+}
+
+impl Evaluator for FakeEvaluator {
+    fn send(&self, data: Value) -> Result<(), EvalError> {
+        self.page.sent.borrow_mut().push(data);
+        Ok(())
+    }
+
+    fn poll_recv(&mut self, context: &mut Context<'_>) -> Poll<Result<Value, EvalError>> {
+        self.events
+            .poll_next_unpin(context)
+            .map(|event| event.ok_or(EvalError::Finished))
+    }
+
+    fn poll_join(&mut self, _: &mut Context<'_>) -> Poll<Result<Value, EvalError>> {
+        Poll::Pending
+    }
+}
+
+thread_local! {
+    static SHOW: Cell<bool> = const { Cell::new(true) };
+    static KEYS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn app() -> Element {
+    rsx! {
+        if SHOW.with(Cell::get) {
+            Shortcuts {}
+        }
+    }
+}
+
+#[component]
+fn Shortcuts() -> Element {
+    use_window_event("keydown", |event: KeyboardEvent| {
+        KEYS.with(|keys| keys.borrow_mut().push(event.key().to_string()));
+    });
+    use_document_event("visibilitychange", |_: VisibilityEvent| {});
+    rsx! {}
+}
+
+/// Run the virtual dom for the given number of milliseconds
+async fn run_for(dom: &mut VirtualDom, millis: u64) {
+    let work = async {
+        loop {
+            dom.wait_for_work().await;
+            dom.render_immediate(&mut NoOpMutations);
+        }
+    };
+    tokio::select! {
+        _ = work => {},
+        _ = tokio::time::sleep(Duration::from_millis(millis)) => {}
+    }
+}
+
+#[test]
+fn listeners_receive_events_and_detach_on_unmount() {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap()
+        .block_on(listen_and_detach());
+}
+
+async fn listen_and_detach() {
+    let page = FakePage::default();
+    let mut dom =
+        VirtualDom::new(app).with_root_context(Rc::new(page.clone()) as Rc<dyn EvalProvider>);
+    dom.rebuild_in_place();
+
+    let scripts = page.scripts.borrow().clone();
+    assert_eq!(scripts.len(), 2);
+    assert!(scripts[0].contains("const target = window;"));
+    assert!(scripts[0].contains(r#"addEventListener("keydown", listener)"#));
+    assert!(scripts[1].contains("const target = document;"));
+    assert!(scripts[1].contains(r#"addEventListener("visibilitychange", listener)"#));
+
+    page.fire(json!({
+        "type": "keydown",
+        "char_code": 0,
+        "is_composing": false,
+        "key": "k",
+        "key_code": 75,
+        "code": "KeyK",
+        "alt_key": false,
+        "ctrl_key": true,
+        "meta_key": false,
+        "shift_key": false,
+        "location": 0,
+        "repeat": false,
+        "which": 75,
+        "inner_width": 1280.0,
+        "inner_height": 720.0,
+        "visibility_state": "visible",
+        "is_fullscreen": false,
+    }));
+    run_for(&mut dom, 20).await;
+    assert_eq!(KEYS.with(|keys| keys.borrow().clone()), ["k"]);
+    assert!(page.sent.borrow().is_empty());
+
+    SHOW.with(|show| show.set(false));
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut NoOpMutations);
+
+    // Both scripts were told to remove their listener
+    assert_eq!(*page.sent.borrow(), [Value::Null, Value::Null]);
+}
+
+#[test]
+fn listeners_without_a_page_do_nothing() {
+    fn app() -> Element {
+        use_window_event("resize", |_: ResizeEvent| {});
+        rsx! { "no page" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&dom), "no page");
+}
+
+#[test]
+fn listeners_move_to_the_new_event_when_the_name_changes() {
+    thread_local! {
+        static EVENT: RefCell<&'static str> = const { RefCell::new("online") };
+    }
+
+    fn app() -> Element {
+        use_window_event(EVENT.with(|event| *event.borrow()), |_: Event<Value>| {});
+        rsx! {}
+    }
+
+    let page = FakePage::default();
+    let mut dom =
+        VirtualDom::new(app).with_root_context(Rc::new(page.clone()) as Rc<dyn EvalProvider>);
+    dom.rebuild_in_place();
+
+    // Rendering again with the same name keeps the listener
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(page.scripts.borrow().len(), 1);
+
+    EVENT.with(|event| *event.borrow_mut() = "offline");
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut NoOpMutations);
+
+    let scripts = page.scripts.borrow().clone();
+    assert_eq!(scripts.len(), 2);
+    assert!(scripts[1].contains(r#"addEventListener("offline", listener)"#));
+    // The listener for the old name was removed
+    assert_eq!(*page.sent.borrow(), [Value::Null]);
+}
@@ -0,0 +1,108 @@
+use dioxus::prelude::*;
+use dioxus_core::{AttributeValue, ElementId, Mutation};
+use dioxus_html::inline_style::*;
+use std::cell::Cell;
+
+thread_local! {
+    static WIDE: Cell<bool> = const { Cell::new(false) };
+}
+
+#[test]
+fn renders_sorted_properties() {
+    let style = Style::new()
+        .width(px(320))
+        .display(Display::Flex)
+        .background_color(Color::hex(0x1e293b))
+        .margin(Length::Auto)
+        .opacity(0.5)
+        .property("--accent", "hotpink")
+        .width(percent(100));
+
+    assert_eq!(
+        style.to_string(),
+        "--accent:hotpink;background-color:#1e293b;display:flex;margin:auto;opacity:0.5;width:100%;"
+    );
+    assert_eq!(style.get("width"), Some("100%"));
+}
+
+#[test]
+fn parse_suggests_closest_value() {
+    assert_eq!("inline-flex".parse(), Ok(Display::InlineFlex));
+
+    let error = "flx".parse::<Display>().unwrap_err();
+    assert_eq!(error.suggestion, Some("flex"));
+    assert_eq!(
+        error.to_string(),
+        "`flx` is not a valid display value, did you mean `flex`?"
+    );
+}
+
+#[test]
+fn changing_one_property_only_updates_that_property() {
+    fn app() -> Element {
+        let style = Style::new()
+            .display(Display::Flex)
+            .color(Color::rgb(255, 0, 0))
+            .width(if WIDE.with(Cell::get) {
+                percent(100)
+            } else {
+                px(320)
+            });
+
+        rsx! {
+            div { ..style.into() }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let edits = dom.rebuild_to_vec().edits;
+    let styles: Vec<_> = edits
+        .iter()
+        .filter_map(|edit| match edit {
+            Mutation::SetAttribute { name, ns, .. } => Some((*name, *ns)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        styles,
+        [
+            ("color", Some("style")),
+            ("display", Some("style")),
+            ("width", Some("style"))
+        ]
+    );
+
+    WIDE.with(|wide| wide.set(true));
+    dom.mark_dirty(ScopeId::APP);
+    assert_eq!(
+        dom.render_immediate_to_vec().edits,
+        [Mutation::SetAttribute {
+            name: "width",
+            ns: Some("style"),
+            value: AttributeValue::Text("100%".to_string()),
+            id: ElementId(1),
+        }]
+    );
+}
+
+#[test]
+fn renders_in_ssr() {
+    fn app() -> Element {
+        let style = Style::new()
+            .position(Position::Absolute)
+            .top(Length::Zero)
+            .z_index(10);
+
+        rsx! {
+            div { ..style.clone().into() }
+            span { style: style }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"<div style="position:absolute;top:0;z-index:10;"></div><span style="position:absolute;top:0;z-index:10;"></span>"#
+    );
+}
@@ -0,0 +1,84 @@
+use dioxus::prelude::*;
+use dioxus_core::TemplateNode;
+
+const MATHML: Option<&str> = Some("http://www.w3.org/1998/Math/MathML");
+
+#[test]
+fn elements_are_in_the_mathml_namespace() {
+    assert_eq!(dioxus_elements::math::NAME_SPACE, MATHML);
+    assert_eq!(dioxus_elements::mmultiscripts::NAME_SPACE, MATHML);
+    assert_eq!(dioxus_elements::maction::NAME_SPACE, MATHML);
+    assert_eq!(dioxus_elements::annotationXml::TAG_NAME, "annotation-xml");
+    assert_eq!(dioxus_elements::annotationXml::NAME_SPACE, MATHML);
+
+    // MathML elements share the MathML global attributes, not the SVG ones
+    assert_eq!(dioxus_elements::mi::mathcolor.0, "mathcolor");
+    assert_eq!(dioxus_elements::mo::form.0, "form");
+}
+
+#[test]
+fn templates_create_mathml_elements() {
+    fn app() -> Element {
+        rsx! {
+            math { mi { "x" } }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let mutations = dom.rebuild_to_vec();
+
+    let TemplateNode::Element {
+        tag,
+        namespace,
+        children,
+        ..
+    } = mutations.templates[0].roots[0]
+    else {
+        panic!("expected an element");
+    };
+    assert_eq!((tag, namespace), ("math", MATHML));
+    assert!(matches!(
+        children[0],
+        TemplateNode::Element { tag: "mi", namespace, .. } if namespace == MATHML
+    ));
+}
+
+#[test]
+fn ssr_renders_mathml() {
+    fn app() -> Element {
+        let denominator = 2;
+
+        rsx! {
+            math { display: "block",
+                mfrac {
+                    mi { mathcolor: "red", "x" }
+                    mn { "{denominator}" }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"<math display="block"><mfrac><mi mathcolor="red">x</mi><mn>2</mn></mfrac></math>"#
+    );
+}
+
+#[cfg(feature = "hot-reload-context")]
+#[test]
+fn hot_reloading_maps_mathml() {
+    use dioxus_html::HtmlCtx;
+    use dioxus_rsx::HotReloadingContext;
+
+    assert_eq!(
+        HtmlCtx::map_element("annotationXml"),
+        Some(("annotation-xml", MATHML))
+    );
+    assert_eq!(
+        HtmlCtx::map_attribute("mi", "mathcolor"),
+        Some(("mathcolor", None))
+    );
+}
@@ -19,6 +19,9 @@ export class BaseInterpreter {
 
   root: HTMLElement;
   handler: EventListener;
+  // portal targets outside of the root element need their own bubbling listeners
+  portals: HTMLElement[];
+  portalHandler: EventListener;
   nodes: Node[];
   stack: Node[];
   templates: {
@@ -34,6 +37,7 @@ export class BaseInterpreter {
     this.global = {};
     this.local = {};
     this.root = root;
+    this.portals = [];
 
     this.nodes = [root];
     this.stack = [root];
@@ -42,6 +46,13 @@ export class BaseInterpreter {
     if (handler) {
       this.handler = handler;
     }
+
+    // events inside the root already reach the root listener, even if the portal target is one of its ancestors
+    this.portalHandler = (event) => {
+      if (!this.root.contains(event.target as Node)) {
+        this.handler(event);
+      }
+    };
   }
 
   createListener(event_name: string, element: HTMLElement, bubbles: boolean) {
@@ -49,6 +60,9 @@ export class BaseInterpreter {
       if (this.global[event_name] === undefined) {
         this.global[event_name] = { active: 1, callback: this.handler };
         this.root.addEventListener(event_name, this.handler);
+        for (const portal of this.portals) {
+          portal.addEventListener(event_name, this.portalHandler);
+        }
       } else {
         this.global[event_name].active++;
       }
@@ -76,6 +90,9 @@ export class BaseInterpreter {
         event_name,
         this.global[event_name].callback
       );
+      for (const portal of this.portals) {
+        portal.removeEventListener(event_name, this.portalHandler);
+      }
       delete this.global[event_name];
     }
   }
@@ -110,6 +127,25 @@ export class BaseInterpreter {
     }
   }
 
+  appendToPortal(target: string, many: number) {
+    let portal = document.getElementById(target);
+    if (!portal) {
+      portal = document.createElement("div");
+      portal.id = target;
+      document.body.appendChild(portal);
+    }
+
+    // targets outside of the root don't see events bubble up to the root, so they listen for bubbling events themselves
+    if (!this.root.contains(portal) && !this.portals.includes(portal)) {
+      this.portals.push(portal);
+      for (const event_name in this.global) {
+        portal.addEventListener(event_name, this.portalHandler);
+      }
+    }
+
+    portal.append(...this.stack.splice(this.stack.length - many));
+  }
+
   loadChild(ptr: number, len: number): Node {
     // iterate through each number and get that child
     let node = this.stack[this.stack.length - 1] as Node;
@@ -23,6 +23,7 @@ export class NativeInterpreter extends JSChannel_ {
   editsPath: string;
   kickStylesheets: boolean;
   queuedBytes: ArrayBuffer[] = [];
+  observers: { [key: number]: ResizeObserver | IntersectionObserver } = {};
 
   // eventually we want to remove liveview and build it into the server-side-events of fullstack
   // however, for now we need to support it since SSE in fullstack doesn't exist yet
@@ -164,6 +165,72 @@ export class NativeInterpreter extends JSChannel_ {
     }
   }
 
+  // Start observing the size of an element. Every observation is passed to send until the observer is disconnected
+  observeResize(id: NodeId, key: number, send: (data: any) => void): boolean {
+    const node = this.nodes[id];
+    if (!(node instanceof Element)) {
+      return false;
+    }
+
+    const observer = new ResizeObserver((entries) => {
+      for (const entry of entries) {
+        const content = [entry.contentRect.width, entry.contentRect.height];
+        const border = entry.borderBoxSize && entry.borderBoxSize[0];
+        send({
+          content_box: content,
+          border_box: border ? [border.inlineSize, border.blockSize] : content,
+        });
+      }
+    });
+    observer.observe(node);
+    this.observers[key] = observer;
+    return true;
+  }
+
+  // Start observing how much of an element is visible in the viewport. Every observation is passed to send until the
+  // observer is disconnected
+  observeIntersection(
+    id: NodeId,
+    key: number,
+    rootMargin: string,
+    threshold: number[],
+    send: (data: any) => void
+  ): boolean {
+    const node = this.nodes[id];
+    if (!(node instanceof Element)) {
+      return false;
+    }
+
+    const rect = (rect: DOMRectReadOnly) => ({
+      origin: [rect.left, rect.top],
+      size: [rect.width, rect.height],
+    });
+    const observer = new IntersectionObserver(
+      (entries) => {
+        for (const entry of entries) {
+          send({
+            is_intersecting: entry.isIntersecting,
+            intersection_ratio: entry.intersectionRatio,
+            bounding_client_rect: rect(entry.boundingClientRect),
+            intersection_rect: rect(entry.intersectionRect),
+          });
+        }
+      },
+      { rootMargin, threshold }
+    );
+    observer.observe(node);
+    this.observers[key] = observer;
+    return true;
+  }
+
+  disconnectObserver(key: number) {
+    const observer = this.observers[key];
+    if (observer) {
+      observer.disconnect();
+      delete this.observers[key];
+    }
+  }
+
   // ignore the fact the base interpreter uses ptr + len but we use array...
   // @ts-ignore
   loadChild(array: number[]) {
@@ -217,9 +284,16 @@ export class NativeInterpreter extends JSChannel_ {
         (event.type === "change" || event.type === "input")
       ) {
         if (target.getAttribute("type") === "file") {
-          this.readFiles(target, contents, bubbles, realId, name);
+          this.readFiles(target.files!, contents, bubbles, realId, name);
         }
       }
+    } else if (
+      event instanceof ClipboardEvent &&
+      event.clipboardData &&
+      event.clipboardData.files.length > 0
+    ) {
+      // Pasted files need to be read before the event is sent to the host
+      this.readFiles(event.clipboardData.files, contents, bubbles, realId, name);
     } else {
       const message = this.serializeIpcMessage("user_event", body);
       this.ipc.postMessage(message);
@@ -366,16 +440,15 @@ export class NativeInterpreter extends JSChannel_ {
     }
   }
 
-  //  A liveview only function
-  // Desktop will intercept the event before it hits this
+  // Read the files of an event and send the event to the host once they are loaded
+  // Desktop intercepts file inputs before they hit this, so it is only used for pasted files and liveview
   async readFiles(
-    target: HTMLInputElement,
+    files: FileList,
     contents: SerializedEvent,
     bubbles: boolean,
     realId: NodeId,
     name: string
   ) {
-    let files = target.files!;
     let file_contents: { [name: string]: number[] } = {};
 
     for (let i = 0; i < files.length; i++) {
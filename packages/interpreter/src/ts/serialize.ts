@@ -28,6 +28,7 @@ export function serializeEvent(event: Event, target: EventTarget): SerializedEve
   if (event instanceof DragEvent) { extend(serializeDragEvent(event)) }
   if (event instanceof FocusEvent) { extend({}) }
   if (event instanceof ClipboardEvent) { extend({}) }
+  if (event instanceof CustomEvent) { extend({ detail: event.detail }) }
 
   // safari is quirky and doesn't have TouchEvent
   if (typeof TouchEvent !== 'undefined' && event instanceof TouchEvent) { extend(serializeTouchEvent(event)); }
@@ -36,6 +37,16 @@ export function serializeEvent(event: Event, target: EventTarget): SerializedEve
     extend(serializeInputEvent(event as InputEvent, target));
   }
 
+  if (event.type === "beforeinput") {
+    let input = event as InputEvent;
+    extend({ input_type: input.inputType, data: input.data, is_composing: input.isComposing });
+  }
+
+  // These events describe the state of the document or window rather than the event itself
+  if (event.type === "fullscreenchange" || event.type === "fullscreenerror") { extend({ is_fullscreen: document.fullscreenElement !== null }) }
+  if (event.type === "visibilitychange") { extend({ visibility_state: document.visibilityState }) }
+  if (event.type === "resize") { extend({ inner_width: window.innerWidth, inner_height: window.innerHeight }) }
+
   // If there's any files, we need to serialize them
   if (event instanceof DragEvent) {
     // let files: { [key: string]: Uint8Array } = {};
@@ -53,6 +53,9 @@ mod js {
     fn append_children(id: u32, many: u16) {
         "{this.appendChildren($id$, $many$);}"
     }
+    fn append_to_portal(target: &str, many: u16) {
+        "{this.appendToPortal($target$, $many$);}"
+    }
     fn pop_root() {
         "{this.stack.pop();}"
     }
@@ -146,7 +149,7 @@ mod js {
         "{let node = this.templates[$tmpl_id$][$index$].cloneNode(true); this.nodes[$id$] = node; this.stack.push(node);}"
     }
 
-    #[cfg(feature = "binary-protocol")]
+    #[cfg(any(feature = "binary-protocol", feature = "webonly"))]
     fn append_children_to_top(many: u16) {
         "{
         let root = this.stack[this.stack.length-many-1];
@@ -1,5 +1,5 @@
 use crate::unified_bindings::Interpreter as Channel;
-use dioxus_core::{TemplateAttribute, TemplateNode, WriteMutations};
+use dioxus_core::{TemplateAttribute, TemplateCache, TemplateNode, WriteMutations};
 use dioxus_html::event_bubbles;
 use sledgehammer_utils::rustc_hash::FxHashMap;
 
@@ -32,6 +32,17 @@ impl MutationState {
         self.channel.reset();
     }
 
+    /// Register every template in a template cache. The templates are registered in order, so the same cache always
+    /// produces the same edits. Pass the same cache to [`dioxus_core::VirtualDom::preload_templates`] so the virtual dom
+    /// doesn't send the templates again.
+    pub fn preload_templates(&mut self, cache: &TemplateCache) {
+        for template in cache {
+            if !self.templates.contains_key(template.name) {
+                self.register_template(*template);
+            }
+        }
+    }
+
     pub fn channel(&mut self) -> &mut Channel {
         &mut self.channel
     }
@@ -198,4 +209,8 @@ impl WriteMutations for MutationState {
     fn push_root(&mut self, id: dioxus_core::ElementId) {
         self.channel.push_root(id.0 as _);
     }
+
+    fn append_to_portal(&mut self, target: &str, m: usize) {
+        self.channel.append_to_portal(target, m as u16);
+    }
 }
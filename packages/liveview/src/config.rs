@@ -1,5 +1,7 @@
+use std::time::Duration;
+
 use dioxus_cli_config::CURRENT_CONFIG;
-use dioxus_core::VirtualDom;
+use dioxus_core::{TemplateCache, VirtualDom};
 
 use crate::LiveviewRouter;
 
@@ -61,3 +63,124 @@ impl<R: LiveviewRouter> Config<R> {
         self.router.start(self.address).await
     }
 }
+
+/// The limits applied to every session in a [`crate::LiveViewPool`]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SessionLimits {
+    pub(crate) idle_timeout: Option<Duration>,
+    pub(crate) max_events_per_second: Option<u32>,
+    pub(crate) max_renders_per_second: Option<u32>,
+    pub(crate) max_frame_size: Option<usize>,
+    pub(crate) max_session_memory: Option<usize>,
+}
+
+/// A configuration for a [`crate::LiveViewPool`].
+///
+/// ```rust
+/// use dioxus_liveview::{LiveViewPool, LiveViewPoolConfig};
+/// use std::time::Duration;
+///
+/// let pool = LiveViewPool::with_config(
+///     LiveViewPoolConfig::new()
+///         .threads(4)
+///         .max_sessions(1000)
+///         .idle_timeout(Duration::from_secs(60 * 10))
+///         .max_events_per_second(100),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiveViewPoolConfig {
+    pub(crate) threads: usize,
+    pub(crate) max_sessions: Option<usize>,
+    pub(crate) overflow_message: String,
+    pub(crate) limits: SessionLimits,
+    pub(crate) template_cache: Option<TemplateCache>,
+}
+
+impl Default for LiveViewPoolConfig {
+    fn default() -> Self {
+        Self {
+            threads: 16,
+            max_sessions: None,
+            overflow_message: "The server is too busy to accept new connections".to_string(),
+            limits: SessionLimits::default(),
+            template_cache: None,
+        }
+    }
+}
+
+impl LiveViewPoolConfig {
+    /// Create a new configuration with the default settings: 16 threads and no limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of threads VirtualDoms are spawned on. Defaults to 16.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is 0.
+    pub fn threads(mut self, threads: usize) -> Self {
+        assert!(threads > 0, "a LiveViewPool needs at least one thread");
+        self.threads = threads;
+        self
+    }
+
+    /// Set the maximum number of sessions that can run at the same time. Connections over the limit receive the
+    /// [overflow message](LiveViewPoolConfig::overflow_message) and are closed.
+    pub fn max_sessions(mut self, max_sessions: usize) -> Self {
+        self.max_sessions = Some(max_sessions);
+        self
+    }
+
+    /// Set the message shown to clients that are rejected because the pool is full.
+    pub fn overflow_message(mut self, message: impl Into<String>) -> Self {
+        self.overflow_message = message.into();
+        self
+    }
+
+    /// Close sessions that have not received any events from the client for this long.
+    pub fn idle_timeout(mut self, timeout: Duration) -> Self {
+        self.limits.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the maximum number of events each session handles per second. Events over the limit are dropped.
+    pub fn max_events_per_second(mut self, events: u32) -> Self {
+        self.limits.max_events_per_second = Some(events);
+        self
+    }
+
+    /// Set the maximum number of times each session sends edits to the client per second. Updates over the limit are
+    /// batched into the next render.
+    pub fn max_renders_per_second(mut self, renders: u32) -> Self {
+        self.limits.max_renders_per_second = Some(renders);
+        self
+    }
+
+    /// Set the maximum size in bytes of a single batch of edits. Sessions that produce larger batches are closed to
+    /// keep a single session from using too much memory.
+    pub fn max_frame_size(mut self, bytes: usize) -> Self {
+        self.limits.max_frame_size = Some(bytes);
+        self
+    }
+
+    /// Set the maximum memory in bytes each session's rendered tree can use. Sessions that grow past the limit are
+    /// closed. The rendered tree is measured at most four times per second, so a session can stay over the limit for
+    /// up to a quarter of a second.
+    ///
+    /// The memory is estimated from the nodes, text and attributes the session's VirtualDom has rendered. State
+    /// stored in hooks and signals is not included.
+    pub fn max_session_memory(mut self, bytes: usize) -> Self {
+        self.limits.max_session_memory = Some(bytes);
+        self
+    }
+
+    /// Preload the templates in a [`TemplateCache`] in every session. Sessions don't send cached templates to the
+    /// client, so the client must load [`LiveViewPool::templates`](crate::LiveViewPool::templates) before it connects.
+    /// See [`interpreter_glue_with_templates`](crate::interpreter_glue_with_templates).
+    pub fn template_cache(mut self, cache: TemplateCache) -> Self {
+        self.template_cache = Some(cache);
+        self
+    }
+}
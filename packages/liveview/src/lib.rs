@@ -9,13 +9,17 @@ pub use adapters::*;
 mod element;
 pub mod pool;
 mod query;
+mod session;
 use dioxus_interpreter_js::NATIVE_JS;
 use futures_util::{SinkExt, StreamExt};
 pub use pool::*;
+pub use session::*;
 mod config;
 mod eval;
 mod events;
+mod metrics;
 pub use config::*;
+pub use metrics::LiveViewMetrics;
 #[cfg(feature = "axum")]
 pub mod launch;
 
@@ -30,6 +34,16 @@ impl<T> WebsocketRx for T where T: StreamExt<Item = Result<String, LiveViewError
 pub enum LiveViewError {
     #[error("Sending to client error")]
     SendingFailed,
+    #[error("The session has already been closed")]
+    SessionClosed,
+    #[error("The session is already running")]
+    SessionAlreadyRunning,
+    #[error("The maximum number of sessions has been reached")]
+    TooManySessions,
+    #[error("The session exceeded the maximum frame size")]
+    FrameSizeExceeded,
+    #[error("The session exceeded the maximum memory")]
+    MemoryLimitExceeded,
 }
 
 fn handle_edits_code() -> String {
@@ -116,6 +130,24 @@ fn handle_edits_code() -> String {
 /// interpreter_glue("ws://localhost:8080/api/liveview");
 /// ```
 pub fn interpreter_glue(url_or_path: &str) -> String {
+    glue(url_or_path, None)
+}
+
+/// Like [`interpreter_glue`], but the page loads the templates from a
+/// [template cache](LiveViewPoolConfig::template_cache) before it connects to the websocket.
+///
+/// `templates_url` is the url that serves [`LiveViewPool::templates`].
+///
+/// ```rust
+/// use dioxus_liveview::interpreter_glue_with_templates;
+///
+/// interpreter_glue_with_templates("/api/liveview", "/api/liveview/templates");
+/// ```
+pub fn interpreter_glue_with_templates(url_or_path: &str, templates_url: &str) -> String {
+    glue(url_or_path, Some(templates_url))
+}
+
+fn glue(url_or_path: &str, templates_url: Option<&str>) -> String {
     // If the url starts with a `/`, generate glue which reuses current host
     let get_ws_url = if url_or_path.starts_with('/') {
         r#"
@@ -134,6 +166,9 @@ pub fn interpreter_glue(url_or_path: &str) -> String {
     };
 
     let handle_edits = handle_edits_code();
+    let templates_url = templates_url
+        .map(|url| format!("var TEMPLATES_URL = \"{url}\";"))
+        .unwrap_or_default();
 
     format!(
         r#"
@@ -143,6 +178,7 @@ pub fn interpreter_glue(url_or_path: &str) -> String {
     }}
 
     var WS_ADDR = __dioxusGetWsUrl("{url_or_path}");
+    {templates_url}
     {handle_edits}
 </script>
     "#
@@ -2,16 +2,29 @@ const intercept_link_redirects = false;
 
 function main() {
   let root = window.document.getElementById("main");
-  if (root != null) {
-    window.ipc = new IPC(root);
+  if (root == null) {
+    return;
+  }
+  // the server doesn't send cached templates, so they need to be loaded before we connect
+  if (typeof TEMPLATES_URL === "undefined") {
+    window.ipc = new IPC(root, null);
+  } else {
+    fetch(TEMPLATES_URL)
+      .then((response) => response.arrayBuffer())
+      .then((templates) => {
+        window.ipc = new IPC(root, templates);
+      });
   }
 }
 
 class IPC {
-  constructor(root) {
+  constructor(root, templates) {
     window.interpreter = new NativeInterpreter();
     window.interpreter.initialize(root);
     window.interpreter.ipc = this;
+    if (templates) {
+      window.interpreter.run_from_bytes(templates);
+    }
     const ws = new WebSocket(WS_ADDR);
     ws.binaryType = "arraybuffer";
 
@@ -52,6 +65,11 @@ class IPC {
             case "query":
               Function("Eval", `"use strict";${event.data};`)();
               break;
+            // The server closed the session, show the reason instead of the stale UI
+            case "closed":
+              root.textContent = event.data;
+              ws.close();
+              break;
           }
         }
       }
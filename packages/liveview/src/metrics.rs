@@ -0,0 +1,257 @@
+//! Metrics collected by a [`crate::LiveViewPool`]
+
+use std::{
+    fmt::Write,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, Weak,
+    },
+    time::Instant,
+};
+
+/// The counters shared between the pool and every session running in it
+#[derive(Clone)]
+pub(crate) struct PoolMetrics {
+    inner: Arc<PoolMetricsInner>,
+}
+
+struct PoolMetricsInner {
+    created: Instant,
+    sessions: AtomicU64,
+    rejected_sessions: AtomicU64,
+    closed_sessions: AtomicU64,
+    events: AtomicU64,
+    dropped_events: AtomicU64,
+    bytes_sent: AtomicU64,
+    // Only locked when a session starts and when a snapshot is taken. Events are recorded in the session's own counters
+    session_rates: Mutex<Vec<Weak<EventRate>>>,
+}
+
+/// Counts the events one session receives in one second windows to calculate its event rate. Only the session writes
+/// to the counters, so they don't need to be updated together.
+struct EventRate {
+    /// When the current window started in milliseconds since the pool was created
+    window_start: AtomicU64,
+    window_events: AtomicU64,
+    /// The rate over the last full window, stored as the bits of an `f64`
+    last_rate: AtomicU64,
+}
+
+impl EventRate {
+    fn new(now: u64) -> Self {
+        Self {
+            window_start: AtomicU64::new(now),
+            window_events: AtomicU64::new(0),
+            last_rate: AtomicU64::new(0.0f64.to_bits()),
+        }
+    }
+
+    fn record(&self, now: u64) {
+        let elapsed = now.saturating_sub(self.window_start.load(Ordering::Relaxed));
+        if elapsed >= 1000 {
+            let events = self.window_events.swap(0, Ordering::Relaxed);
+            let rate = events as f64 * 1000.0 / elapsed as f64;
+            self.last_rate.store(rate.to_bits(), Ordering::Relaxed);
+            self.window_start.store(now, Ordering::Relaxed);
+        }
+        self.window_events.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn rate(&self, now: u64) -> f64 {
+        // If no event has closed the current window yet, it already covers more than a second
+        let elapsed = now.saturating_sub(self.window_start.load(Ordering::Relaxed));
+        if elapsed >= 1000 {
+            self.window_events.load(Ordering::Relaxed) as f64 * 1000.0 / elapsed as f64
+        } else {
+            f64::from_bits(self.last_rate.load(Ordering::Relaxed))
+        }
+    }
+}
+
+impl Default for PoolMetrics {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(PoolMetricsInner {
+                created: Instant::now(),
+                sessions: AtomicU64::new(0),
+                rejected_sessions: AtomicU64::new(0),
+                closed_sessions: AtomicU64::new(0),
+                events: AtomicU64::new(0),
+                dropped_events: AtomicU64::new(0),
+                bytes_sent: AtomicU64::new(0),
+                session_rates: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+}
+
+impl PoolMetrics {
+    pub(crate) fn session_started(&self) {
+        self.inner.sessions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn session_rejected(&self) {
+        self.inner.rejected_sessions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn session_closed_by_limit(&self) {
+        self.inner.closed_sessions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Create the counters for a session that is starting
+    pub(crate) fn session(&self) -> SessionMetrics {
+        let rate = Arc::new(EventRate::new(self.now()));
+        let mut rates = self.inner.session_rates.lock().unwrap();
+        rates.retain(|rate| rate.strong_count() > 0);
+        rates.push(Arc::downgrade(&rate));
+        SessionMetrics {
+            pool: self.clone(),
+            rate,
+        }
+    }
+
+    pub(crate) fn event_dropped(&self) {
+        self.inner.dropped_events.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn bytes_sent(&self, bytes: usize) {
+        self.inner
+            .bytes_sent
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self, active_sessions: usize) -> LiveViewMetrics {
+        let events_total = self.inner.events.load(Ordering::Relaxed);
+        let now = self.now();
+        let events_per_second = self
+            .inner
+            .session_rates
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(Weak::upgrade)
+            .map(|rate| rate.rate(now))
+            .sum();
+
+        LiveViewMetrics {
+            active_sessions,
+            sessions_total: self.inner.sessions.load(Ordering::Relaxed),
+            rejected_sessions_total: self.inner.rejected_sessions.load(Ordering::Relaxed),
+            closed_sessions_total: self.inner.closed_sessions.load(Ordering::Relaxed),
+            events_total,
+            dropped_events_total: self.inner.dropped_events.load(Ordering::Relaxed),
+            events_per_second,
+            bytes_sent_total: self.inner.bytes_sent.load(Ordering::Relaxed),
+        }
+    }
+
+    /// The number of milliseconds since the pool was created
+    fn now(&self) -> u64 {
+        self.inner.created.elapsed().as_millis() as u64
+    }
+}
+
+/// The counters for a single session. Recording an event doesn't lock anything shared with the other sessions.
+pub(crate) struct SessionMetrics {
+    pool: PoolMetrics,
+    rate: Arc<EventRate>,
+}
+
+impl SessionMetrics {
+    pub(crate) fn event_received(&self) {
+        self.pool.inner.events.fetch_add(1, Ordering::Relaxed);
+        self.rate.record(self.pool.now());
+    }
+}
+
+/// A snapshot of the metrics for a [`crate::LiveViewPool`], created with [`crate::LiveViewPool::metrics`]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct LiveViewMetrics {
+    /// The number of sessions currently running
+    pub active_sessions: usize,
+    /// The number of sessions that have been started
+    pub sessions_total: u64,
+    /// The number of sessions that were rejected because the pool was full
+    pub rejected_sessions_total: u64,
+    /// The number of sessions that were closed because they exceeded a limit or timed out
+    pub closed_sessions_total: u64,
+    /// The number of events received from clients
+    pub events_total: u64,
+    /// The number of events that were dropped because a session exceeded its event rate limit
+    pub dropped_events_total: u64,
+    /// The average number of events the running sessions received per second over the last second
+    pub events_per_second: f64,
+    /// The number of bytes sent to clients
+    pub bytes_sent_total: u64,
+}
+
+impl LiveViewMetrics {
+    /// Format the metrics in the [Prometheus text exposition format](https://prometheus.io/docs/instrumenting/exposition_formats/)
+    ///
+    /// ```rust
+    /// # use dioxus_liveview::LiveViewPool;
+    /// let pool = LiveViewPool::new();
+    /// let text = pool.metrics().to_prometheus();
+    /// assert!(text.contains("dioxus_liveview_active_sessions 0"));
+    /// ```
+    pub fn to_prometheus(&self) -> String {
+        let mut output = String::new();
+        let mut metric = |name: &str, kind: &str, help: &str, value: &dyn std::fmt::Display| {
+            let _ = writeln!(output, "# HELP dioxus_liveview_{name} {help}");
+            let _ = writeln!(output, "# TYPE dioxus_liveview_{name} {kind}");
+            let _ = writeln!(output, "dioxus_liveview_{name} {value}");
+        };
+
+        metric(
+            "active_sessions",
+            "gauge",
+            "The number of sessions currently running",
+            &self.active_sessions,
+        );
+        metric(
+            "sessions_total",
+            "counter",
+            "The number of sessions that have been started",
+            &self.sessions_total,
+        );
+        metric(
+            "rejected_sessions_total",
+            "counter",
+            "The number of sessions rejected because the pool was full",
+            &self.rejected_sessions_total,
+        );
+        metric(
+            "closed_sessions_total",
+            "counter",
+            "The number of sessions closed because they exceeded a limit or timed out",
+            &self.closed_sessions_total,
+        );
+        metric(
+            "events_total",
+            "counter",
+            "The number of events received from clients",
+            &self.events_total,
+        );
+        metric(
+            "dropped_events_total",
+            "counter",
+            "The number of events dropped by the event rate limit",
+            &self.dropped_events_total,
+        );
+        metric(
+            "events_per_second",
+            "gauge",
+            "The average number of events received per second over the last second",
+            &self.events_per_second,
+        );
+        metric(
+            "bytes_sent_total",
+            "counter",
+            "The number of bytes sent to clients",
+            &self.bytes_sent_total,
+        );
+
+        output
+    }
+}
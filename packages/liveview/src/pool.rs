@@ -2,20 +2,33 @@ use crate::{
     element::LiveviewElement,
     eval::init_eval,
     events::SerializedHtmlEventConverter,
+    metrics::PoolMetrics,
     query::{QueryEngine, QueryResult},
-    LiveViewError,
+    session::{LiveViewSession, ServerMessage, ServerMessageHandlers, SessionId},
+    LiveViewError, LiveViewMetrics, LiveViewPoolConfig, SessionLimits,
 };
 use dioxus_core::prelude::*;
+use dioxus_core::{Attribute, AttributeValue, DynamicNode, VNodeInner};
 use dioxus_html::{EventData, HtmlEvent, PlatformEventData};
 use dioxus_interpreter_js::MutationState;
 use futures_util::{pin_mut, SinkExt, StreamExt};
+use rustc_hash::FxHashMap;
 use serde::Serialize;
-use std::{rc::Rc, time::Duration};
+use std::{
+    rc::Rc,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::Instant;
 use tokio_util::task::LocalPoolHandle;
 
 #[derive(Clone)]
 pub struct LiveViewPool {
     pub(crate) pool: LocalPoolHandle,
+    sessions: Arc<Mutex<FxHashMap<SessionId, LiveViewSession>>>,
+    config: Arc<LiveViewPoolConfig>,
+    metrics: PoolMetrics,
+    templates: Option<Arc<[u8]>>,
 }
 
 impl Default for LiveViewPool {
@@ -26,14 +39,41 @@ impl Default for LiveViewPool {
 
 impl LiveViewPool {
     pub fn new() -> Self {
+        Self::with_config(LiveViewPoolConfig::default())
+    }
+
+    /// Create a new pool with a custom thread count and limits
+    pub fn with_config(config: LiveViewPoolConfig) -> Self {
         // Set the event converter
         dioxus_html::set_event_converter(Box::new(SerializedHtmlEventConverter));
 
+        // The edits that load the cached templates are the same for every session, so they are only created once
+        let templates = config.template_cache.as_ref().map(|cache| {
+            let mut mutations = MutationState::default();
+            mutations.preload_templates(cache);
+            mutations.export_memory().into()
+        });
+
         LiveViewPool {
-            pool: LocalPoolHandle::new(16),
+            pool: LocalPoolHandle::new(config.threads),
+            sessions: Default::default(),
+            config: Arc::new(config),
+            metrics: Default::default(),
+            templates,
         }
     }
 
+    /// The edits that load the templates from the [template cache](LiveViewPoolConfig::template_cache) into the
+    /// client. These never change while the server is running, so they can be served with a long cache lifetime.
+    pub fn templates(&self) -> Option<&[u8]> {
+        self.templates.as_deref()
+    }
+
+    /// Take a snapshot of the metrics for this pool
+    pub fn metrics(&self) -> LiveViewMetrics {
+        self.metrics.snapshot(self.sessions.lock().unwrap().len())
+    }
+
     pub async fn launch(
         &self,
         ws: impl LiveViewSocket,
@@ -57,12 +97,102 @@ impl LiveViewPool {
         ws: impl LiveViewSocket,
         make_app: F,
     ) -> Result<(), LiveViewError> {
-        match self.pool.spawn_pinned(move || run(make_app(), ws)).await {
+        self.launch_session(ws, LiveViewSession::new(), make_app)
+            .await
+    }
+
+    /// Launch a VirtualDom in an existing [`LiveViewSession`].
+    ///
+    /// The session is registered with the pool while it is running so server-side code can find it with
+    /// [`LiveViewPool::session`] or [`LiveViewPool::broadcast`]. If the pool already has the maximum number
+    /// of sessions running, the client is sent the overflow message and [`LiveViewError::TooManySessions`] is returned.
+    pub async fn launch_session<F: FnOnce() -> VirtualDom + Send + 'static>(
+        &self,
+        ws: impl LiveViewSocket,
+        session: LiveViewSession,
+        make_app: F,
+    ) -> Result<(), LiveViewError> {
+        let receiver = session
+            .take_receiver()
+            .ok_or(LiveViewError::SessionAlreadyRunning)?;
+
+        // Remove the session from the pool when it finishes, even if this future is dropped
+        struct SessionGuard {
+            id: SessionId,
+            sessions: Arc<Mutex<FxHashMap<SessionId, LiveViewSession>>>,
+        }
+
+        impl Drop for SessionGuard {
+            fn drop(&mut self) {
+                self.sessions.lock().unwrap().remove(&self.id);
+            }
+        }
+
+        let id = session.id();
+        let registered = {
+            let mut sessions = self.sessions.lock().unwrap();
+            let full = self
+                .config
+                .max_sessions
+                .is_some_and(|max| sessions.len() >= max);
+            if !full {
+                sessions.insert(id, session.clone());
+            }
+            !full
+        };
+        if !registered {
+            self.metrics.session_rejected();
+            pin_mut!(ws);
+            close_session(&mut ws, &self.metrics, &self.config.overflow_message).await?;
+            return Err(LiveViewError::TooManySessions);
+        }
+        let _guard = SessionGuard {
+            id,
+            sessions: self.sessions.clone(),
+        };
+        self.metrics.session_started();
+
+        let config = self.config.clone();
+        let metrics = self.metrics.clone();
+        match self
+            .pool
+            .spawn_pinned(move || async move {
+                let mut vdom = make_app();
+                if let Some(cache) = &config.template_cache {
+                    vdom.preload_templates(cache);
+                }
+                run_session(vdom, ws, session, receiver, &config, metrics).await
+            })
+            .await
+        {
             Ok(Ok(_)) => Ok(()),
             Ok(Err(e)) => Err(e),
             Err(_) => Err(LiveViewError::SendingFailed),
         }
     }
+
+    /// Get the handle to a running session
+    pub fn session(&self, id: SessionId) -> Option<LiveViewSession> {
+        self.sessions.lock().unwrap().get(&id).cloned()
+    }
+
+    /// Get handles to all of the sessions currently running in this pool
+    pub fn sessions(&self) -> Vec<LiveViewSession> {
+        self.sessions.lock().unwrap().values().cloned().collect()
+    }
+
+    /// Send a message to every running session that matches the filter. Returns the number of sessions the message was sent to.
+    pub fn broadcast<T: Clone + Send + 'static>(
+        &self,
+        message: T,
+        mut filter: impl FnMut(&LiveViewSession) -> bool,
+    ) -> usize {
+        self.sessions()
+            .into_iter()
+            .filter(|session| filter(session))
+            .filter(|session| session.send(message.clone()).is_ok())
+            .count()
+    }
 }
 
 /// A LiveViewSocket is a Sink and Stream of Strings that Dioxus uses to communicate with the client
@@ -115,7 +245,33 @@ impl<S> LiveViewSocket for S where
 /// As long as your framework can provide a Sink and Stream of Bytes, you can use this function.
 ///
 /// You might need to transform the error types of the web backend into the LiveView error type.
-pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), LiveViewError> {
+pub async fn run(vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), LiveViewError> {
+    let session = LiveViewSession::new();
+    let receiver = session
+        .take_receiver()
+        .expect("a new session has not been launched");
+    run_session(
+        vdom,
+        ws,
+        session,
+        receiver,
+        &LiveViewPoolConfig::default(),
+        PoolMetrics::default(),
+    )
+    .await
+}
+
+async fn run_session(
+    mut vdom: VirtualDom,
+    ws: impl LiveViewSocket,
+    session: LiveViewSession,
+    mut server_messages: tokio::sync::mpsc::UnboundedReceiver<ServerMessage>,
+    config: &LiveViewPoolConfig,
+    metrics: PoolMetrics,
+) -> Result<(), LiveViewError> {
+    let limits = &config.limits;
+    let session_metrics = metrics.session();
+
     #[cfg(all(feature = "hot-reload", debug_assertions))]
     let mut hot_reload_rx = {
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
@@ -127,11 +283,20 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
 
     let mut mutations = MutationState::default();
 
+    // The client loaded the cached templates before connecting, so we only need to catch up with it
+    if let Some(cache) = &config.template_cache {
+        mutations.preload_templates(cache);
+        mutations.export_memory();
+    }
+
     // Create the a proxy for query engine
     let (query_tx, mut query_rx) = tokio::sync::mpsc::unbounded_channel();
     let query_engine = QueryEngine::new(query_tx);
+    let server_message_handlers = ServerMessageHandlers::default();
     vdom.in_runtime(|| {
         ScopeId::ROOT.provide_context(query_engine.clone());
+        ScopeId::ROOT.provide_context(session);
+        ScopeId::ROOT.provide_context(server_message_handlers.clone());
         init_eval();
     });
 
@@ -143,7 +308,7 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
         take_edits(&mut mutations)
     } {
         // send the initial render to the client
-        ws.send(edits).await?;
+        send_edits(&mut ws, &metrics, limits, edits).await?;
     }
 
     // desktop uses this wrapper struct thing around the actual event itself
@@ -157,28 +322,86 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
         Query(QueryResult),
     }
 
+    let mut last_activity = Instant::now();
+    let mut last_render = Instant::now();
+    let mut event_window = (Instant::now(), 0);
+    // Walking the rendered tree is expensive, so the memory limit is checked at most once per sample interval
+    let mut last_memory_sample: Option<Instant> = None;
+    // Set when the tree changed since the last sample
+    let mut memory_unsampled = false;
+    // Set when a render was held back by the render rate limit
+    let mut render_pending = false;
+
     loop {
+        let idle_timeout = async {
+            match limits.idle_timeout {
+                Some(timeout) => tokio::time::sleep_until(last_activity + timeout).await,
+                None => std::future::pending().await,
+            }
+        };
+
+        let render_timer = async {
+            match limits.max_renders_per_second {
+                Some(max) if render_pending => {
+                    tokio::time::sleep_until(last_render + Duration::from_secs(1) / max.max(1))
+                        .await
+                }
+                _ => std::future::pending().await,
+            }
+        };
+
+        let memory_timer = async {
+            match last_memory_sample {
+                Some(last) if memory_unsampled => {
+                    tokio::time::sleep_until(last + MEMORY_SAMPLE_INTERVAL).await
+                }
+                _ => std::future::pending().await,
+            }
+        };
+
+        // Only branches that can change the VirtualDom need to render afterwards
+        let mut needs_render = false;
+
         #[cfg(all(feature = "hot-reload", debug_assertions))]
         let hot_reload_wait = hot_reload_rx.recv();
         #[cfg(not(all(feature = "hot-reload", debug_assertions)))]
         let hot_reload_wait: std::future::Pending<Option<()>> = std::future::pending();
 
         tokio::select! {
-            // poll any futures or suspense
-            _ = vdom.wait_for_work() => {}
+            // poll any futures or suspense. While a render is held back the dirty scopes wait for the render timer
+            _ = vdom.wait_for_work(), if !render_pending => needs_render = true,
+
+            _ = render_timer => needs_render = true,
 
             evt = ws.next() => {
                 match evt.as_ref().map(|o| o.as_deref()) {
                     // respond with a pong every ping to keep the websocket alive
                     Some(Ok(b"__ping__")) => {
-                        ws.send(text_frame("__pong__")).await?;
+                        send_frame(&mut ws, &metrics, text_frame("__pong__")).await?;
                     }
                     Some(Ok(evt)) => {
                         if let Ok(message) = serde_json::from_str::<IpcMessage>(&String::from_utf8_lossy(evt)) {
                             match message {
                                 IpcMessage::Event(evt) => {
-                                    // Intercept the mounted event and insert a custom element type
-                                    if let EventData::Mounted = &evt.data {
+                                    needs_render = true;
+                                    session_metrics.event_received();
+                                    last_activity = Instant::now();
+
+                                    // Mounted events are sent by the renderer, so they are never rate limited
+                                    let is_mounted = matches!(evt.data, EventData::Mounted);
+                                    let rate_limited = !is_mounted && limits.max_events_per_second.is_some_and(|max| {
+                                        if event_window.0.elapsed() >= Duration::from_secs(1) {
+                                            event_window = (Instant::now(), 0);
+                                        }
+                                        event_window.1 += 1;
+                                        event_window.1 > max
+                                    });
+
+                                    if rate_limited {
+                                        metrics.event_dropped();
+                                        tracing::warn!("Dropping {} event because the session exceeded the event rate limit", evt.name);
+                                    } else if is_mounted {
+                                        // Intercept the mounted event and insert a custom element type
                                         let element = LiveviewElement::new(evt.element, query_engine.clone());
                                         vdom.handle_event(
                                             &evt.name,
@@ -207,12 +430,33 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
                 }
             }
 
+            // deliver any messages pushed from the server
+            Some(message) = server_messages.recv() => {
+                needs_render = true;
+                vdom.in_runtime(|| server_message_handlers.dispatch(message));
+            }
+
             // handle any new queries
             Some(query) = query_rx.recv() => {
-                ws.send(text_frame(&serde_json::to_string(&ClientUpdate::Query(query)).unwrap())).await?;
+                send_frame(&mut ws, &metrics, text_frame(&serde_json::to_string(&ClientUpdate::Query(query)).unwrap())).await?;
+            }
+
+            // check the memory of changes that were rendered since the last sample
+            _ = memory_timer => {
+                memory_unsampled = false;
+                last_memory_sample = Some(Instant::now());
+                check_memory(&vdom, &mut ws, &metrics, limits).await?;
+            }
+
+            // close the session if the client has been idle for too long
+            _ = idle_timeout => {
+                metrics.session_closed_by_limit();
+                close_session(&mut ws, &metrics, "The session was closed because it was idle for too long").await?;
+                return Ok(());
             }
 
             Some(msg) = hot_reload_wait => {
+                needs_render = true;
                 #[cfg(all(feature = "hot-reload", debug_assertions))]
                 match msg{
                     dioxus_hot_reload::HotReloadMsg::UpdateTemplate(new_template) => {
@@ -220,6 +464,7 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
                     }
                     // todo: enable hotreloading in liveview
                     dioxus_hot_reload::HotReloadMsg::UpdateAsset(_) => {}
+                    dioxus_hot_reload::HotReloadMsg::UpdateCssModule { .. } => {}
                     dioxus_hot_reload::HotReloadMsg::Shutdown => {
                         std::process::exit(0);
                     },
@@ -229,6 +474,19 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
             }
         }
 
+        if !needs_render {
+            continue;
+        }
+
+        // batch updates that happen faster than the render rate limit into the next render
+        if let Some(max) = limits.max_renders_per_second {
+            if last_render.elapsed() < Duration::from_secs(1) / max.max(1) {
+                render_pending = true;
+                continue;
+            }
+        }
+        render_pending = false;
+
         // wait for suspense to resolve in a 10ms window
         tokio::select! {
             _ = tokio::time::sleep(Duration::from_millis(10)) => {}
@@ -237,13 +495,132 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
 
         // render the vdom
         vdom.render_immediate(&mut mutations);
+        last_render = Instant::now();
 
         if let Some(edits) = take_edits(&mut mutations) {
-            ws.send(edits).await?;
+            send_edits(&mut ws, &metrics, limits, edits).await?;
+
+            if limits.max_session_memory.is_some() {
+                let sampled_recently = matches!(
+                    last_memory_sample,
+                    Some(last) if last.elapsed() < MEMORY_SAMPLE_INTERVAL
+                );
+                if !sampled_recently {
+                    memory_unsampled = false;
+                    last_memory_sample = Some(Instant::now());
+                    check_memory(&vdom, &mut ws, &metrics, limits).await?;
+                } else {
+                    memory_unsampled = true;
+                }
+            }
         }
     }
 }
 
+/// The shortest time between two walks of a session's rendered tree to check the memory limit
+const MEMORY_SAMPLE_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Close the session if the tree it rendered is over the memory limit
+async fn check_memory(
+    vdom: &VirtualDom,
+    ws: &mut (impl SinkExt<Vec<u8>, Error = LiveViewError> + Unpin),
+    metrics: &PoolMetrics,
+    limits: &SessionLimits,
+) -> Result<(), LiveViewError> {
+    if limits
+        .max_session_memory
+        .is_some_and(|max| rendered_memory(vdom) > max)
+    {
+        metrics.session_closed_by_limit();
+        close_session(
+            ws,
+            metrics,
+            "The session was closed because it exceeded the maximum memory",
+        )
+        .await?;
+        return Err(LiveViewError::MemoryLimitExceeded);
+    }
+    Ok(())
+}
+
+async fn send_frame(
+    ws: &mut (impl SinkExt<Vec<u8>, Error = LiveViewError> + Unpin),
+    metrics: &PoolMetrics,
+    frame: Vec<u8>,
+) -> Result<(), LiveViewError> {
+    metrics.bytes_sent(frame.len());
+    ws.send(frame).await
+}
+
+/// Send a batch of edits to the client, closing the session if it is over the frame size limit
+async fn send_edits(
+    ws: &mut (impl SinkExt<Vec<u8>, Error = LiveViewError> + Unpin),
+    metrics: &PoolMetrics,
+    limits: &SessionLimits,
+    edits: Vec<u8>,
+) -> Result<(), LiveViewError> {
+    if limits.max_frame_size.is_some_and(|max| edits.len() > max) {
+        metrics.session_closed_by_limit();
+        close_session(
+            ws,
+            metrics,
+            "The session was closed because it exceeded the maximum frame size",
+        )
+        .await?;
+        return Err(LiveViewError::FrameSizeExceeded);
+    }
+    send_frame(ws, metrics, edits).await
+}
+
+/// Tell the client why the session is closing
+async fn close_session(
+    ws: &mut (impl SinkExt<Vec<u8>, Error = LiveViewError> + Unpin),
+    metrics: &PoolMetrics,
+    reason: &str,
+) -> Result<(), LiveViewError> {
+    let update = serde_json::to_string(&ClientUpdate::Closed(reason.to_string())).unwrap();
+    send_frame(ws, metrics, text_frame(&update)).await?;
+    ws.close().await
+}
+
+/// Estimate the memory used by the nodes the VirtualDom has rendered
+fn rendered_memory(vdom: &VirtualDom) -> usize {
+    fn node_memory(node: &VNode, vdom: &VirtualDom) -> usize {
+        let mut bytes = std::mem::size_of::<VNodeInner>()
+            + node.key.as_ref().map_or(0, String::len)
+            + node.dynamic_nodes.len() * std::mem::size_of::<DynamicNode>();
+
+        for attribute in node.dynamic_attrs.iter().flat_map(|attrs| attrs.iter()) {
+            bytes += std::mem::size_of::<Attribute>();
+            if let AttributeValue::Text(text) = &attribute.value {
+                bytes += text.len();
+            }
+        }
+
+        for (idx, dynamic) in node.dynamic_nodes.iter().enumerate() {
+            bytes += match dynamic {
+                DynamicNode::Text(text) => text.value.len(),
+                DynamicNode::Fragment(children) => {
+                    children.iter().map(|child| node_memory(child, vdom)).sum()
+                }
+                DynamicNode::Component(component) => component
+                    .mounted_scope(idx, node, vdom)
+                    .and_then(|scope| scope.try_root_node())
+                    .map_or(0, |root| {
+                        std::mem::size_of::<ScopeState>() + node_memory(root, vdom)
+                    }),
+                DynamicNode::Placeholder(_) => 0,
+            };
+        }
+
+        bytes
+    }
+
+    vdom.base_scope()
+        .try_root_node()
+        .map_or(0, |root| node_memory(root, vdom))
+}
+
 fn text_frame(text: &str) -> Vec<u8> {
     let mut bytes = vec![0];
     bytes.extend(text.as_bytes());
@@ -262,4 +639,6 @@ fn take_edits(mutations: &mut MutationState) -> Option<Vec<u8>> {
 enum ClientUpdate {
     #[serde(rename = "query")]
     Query(String),
+    #[serde(rename = "closed")]
+    Closed(String),
 }
@@ -0,0 +1,219 @@
+//! Push messages from server-side code into running liveview sessions
+
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    fmt::Display,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, RwLock,
+    },
+};
+
+use dioxus_core::prelude::*;
+use dioxus_signals::{CopyValue, Writable};
+use slab::Slab;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+
+use crate::LiveViewError;
+
+pub(crate) type ServerMessage = Box<dyn Any + Send>;
+
+/// A unique identifier for a liveview session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SessionId(usize);
+
+impl SessionId {
+    fn next() -> Self {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl Display for SessionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A handle to a liveview session that can be used from any thread to push messages into the session's [`VirtualDom`].
+///
+/// Messages are delivered to every handler registered with [`use_server_message`] for the message type.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// use dioxus_liveview::{use_server_message, LiveViewPool};
+///
+/// #[derive(Clone)]
+/// struct Notification(String);
+///
+/// fn app() -> Element {
+///     let mut notifications = use_signal(Vec::new);
+///     use_server_message(move |Notification(text)| notifications.push(text));
+///
+///     rsx! {
+///         for notification in notifications.iter() {
+///             p { "{notification}" }
+///         }
+///     }
+/// }
+///
+/// fn notify_everyone(pool: &LiveViewPool) {
+///     pool.broadcast(Notification("The server is restarting".into()), |_| true);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct LiveViewSession {
+    id: SessionId,
+    sender: UnboundedSender<ServerMessage>,
+    receiver: Arc<Mutex<Option<UnboundedReceiver<ServerMessage>>>>,
+    metadata: Arc<RwLock<Option<Arc<dyn Any + Send + Sync>>>>,
+}
+
+impl Default for LiveViewSession {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LiveViewSession {
+    /// Create a new session that can be launched with [`crate::LiveViewPool::launch_session`].
+    ///
+    /// Messages sent before the session is launched are queued and delivered once the app starts.
+    pub fn new() -> Self {
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+        Self {
+            id: SessionId::next(),
+            sender,
+            receiver: Arc::new(Mutex::new(Some(receiver))),
+            metadata: Default::default(),
+        }
+    }
+
+    /// Get the unique id of this session
+    pub fn id(&self) -> SessionId {
+        self.id
+    }
+
+    /// Send a message to the session. The message will be delivered to any [`use_server_message`] handlers for the type `T`.
+    pub fn send<T: Send + 'static>(&self, message: T) -> Result<(), LiveViewError> {
+        self.sender
+            .send(Box::new(message))
+            .map_err(|_| LiveViewError::SessionClosed)
+    }
+
+    /// Check if the session has finished running
+    pub fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+
+    /// Attach some metadata to the session. This can be used to find sessions in [`crate::LiveViewPool::broadcast`].
+    ///
+    /// Setting new metadata replaces any existing metadata.
+    pub fn set_metadata<M: Send + Sync + 'static>(&self, metadata: M) {
+        *self.metadata.write().unwrap() = Some(Arc::new(metadata));
+    }
+
+    /// Get the metadata attached to the session if it exists and is of type `M`
+    pub fn metadata<M: Clone + Send + Sync + 'static>(&self) -> Option<M> {
+        self.metadata
+            .read()
+            .unwrap()
+            .as_ref()
+            .and_then(|metadata| metadata.downcast_ref::<M>())
+            .cloned()
+    }
+
+    pub(crate) fn take_receiver(&self) -> Option<UnboundedReceiver<ServerMessage>> {
+        self.receiver.lock().unwrap().take()
+    }
+}
+
+type ServerMessageCallback = Rc<RefCell<dyn FnMut(&dyn Any)>>;
+
+struct ServerMessageHandler {
+    type_id: TypeId,
+    scope: ScopeId,
+    callback: ServerMessageCallback,
+}
+
+/// The handlers for server messages registered in a session's [`VirtualDom`]
+#[derive(Clone, Default)]
+pub(crate) struct ServerMessageHandlers {
+    handlers: Rc<RefCell<Slab<ServerMessageHandler>>>,
+}
+
+impl ServerMessageHandlers {
+    fn insert<T: Clone + 'static>(
+        &self,
+        scope: ScopeId,
+        mut handler: impl FnMut(T) + 'static,
+    ) -> usize {
+        self.handlers.borrow_mut().insert(ServerMessageHandler {
+            type_id: TypeId::of::<T>(),
+            scope,
+            callback: Rc::new(RefCell::new(move |message: &dyn Any| {
+                if let Some(message) = message.downcast_ref::<T>() {
+                    handler(message.clone())
+                }
+            })),
+        })
+    }
+
+    fn remove(&self, id: usize) {
+        self.handlers.borrow_mut().try_remove(id);
+    }
+
+    /// Deliver a message to every handler registered for its type. This must be called inside of the runtime.
+    pub(crate) fn dispatch(&self, message: ServerMessage) {
+        let message: &(dyn Any + Send) = &*message;
+        let type_id = message.type_id();
+        // Collect the handlers first so handlers can register or remove other handlers
+        let handlers: Vec<_> = self
+            .handlers
+            .borrow()
+            .iter()
+            .filter(|(_, handler)| handler.type_id == type_id)
+            .map(|(_, handler)| (handler.scope, handler.callback.clone()))
+            .collect();
+
+        if handlers.is_empty() {
+            tracing::trace!("No handlers registered for server message {:?}", type_id);
+        }
+
+        for (scope, callback) in handlers {
+            scope.in_runtime(|| (callback.borrow_mut())(message));
+        }
+    }
+}
+
+/// Get the handle to the liveview session this component is running in.
+///
+/// The handle can be passed to server-side code to push messages into the session later.
+pub fn use_liveview_session() -> LiveViewSession {
+    use_hook(consume_context::<LiveViewSession>)
+}
+
+/// Register a handler for messages of type `T` sent to this session with [`LiveViewSession::send`] or [`crate::LiveViewPool::broadcast`].
+///
+/// The handler runs in the scope of the component that registered it, so it can write to signals or send messages to coroutines.
+/// It is removed when the component is dropped.
+pub fn use_server_message<T: Clone + 'static>(handler: impl FnMut(T) + 'static) {
+    // Always call the handler from the latest render so it sees the latest captured values
+    let mut current_handler = use_hook(|| CopyValue::new(None::<Box<dyn FnMut(T)>>));
+    current_handler.set(Some(Box::new(handler)));
+
+    let handlers = use_hook(consume_context::<ServerMessageHandlers>);
+    let id = use_hook(|| {
+        handlers.insert(
+            current_scope_id().expect("use_server_message must be called in a component"),
+            move |message: T| {
+                if let Some(handler) = current_handler.write().as_mut() {
+                    handler(message)
+                }
+            },
+        )
+    });
+    use_drop(move || handlers.remove(id));
+}
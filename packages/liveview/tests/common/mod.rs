@@ -0,0 +1,99 @@
+#![allow(dead_code)]
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use dioxus_liveview::LiveViewError;
+use futures_channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures_util::{Sink, Stream, StreamExt};
+
+/// An in-memory websocket for a liveview session
+pub struct FakeSocket {
+    incoming: UnboundedReceiver<Vec<u8>>,
+    outgoing: UnboundedSender<Vec<u8>>,
+}
+
+/// The client side of a [`FakeSocket`]
+pub struct FakeClient {
+    pub send: UnboundedSender<Vec<u8>>,
+    pub received: UnboundedReceiver<Vec<u8>>,
+}
+
+impl FakeClient {
+    /// Send a text frame to the session
+    pub fn send_text(&self, text: &str) {
+        self.send.unbounded_send(text.as_bytes().to_vec()).unwrap();
+    }
+
+    /// Wait for the next frame the session sends, if it sends one soon
+    pub async fn next_frame(&mut self) -> Option<Vec<u8>> {
+        tokio::time::timeout(Duration::from_secs(5), self.received.next())
+            .await
+            .ok()
+            .flatten()
+    }
+
+    /// Wait for the next text frame the session sends, skipping binary edit frames
+    pub async fn next_text(&mut self) -> Option<String> {
+        loop {
+            let frame = self.next_frame().await?;
+            if frame.first() == Some(&0) {
+                return Some(String::from_utf8_lossy(&frame[1..]).to_string());
+            }
+        }
+    }
+}
+
+pub fn fake_socket() -> (FakeSocket, FakeClient) {
+    let (send, incoming) = unbounded();
+    let (outgoing, received) = unbounded();
+    (
+        FakeSocket { incoming, outgoing },
+        FakeClient { send, received },
+    )
+}
+
+impl Stream for FakeSocket {
+    type Item = Result<Vec<u8>, LiveViewError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.incoming.poll_next_unpin(cx).map(|frame| frame.map(Ok))
+    }
+}
+
+impl Sink<Vec<u8>> for FakeSocket {
+    type Error = LiveViewError;
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Vec<u8>) -> Result<(), Self::Error> {
+        self.outgoing
+            .unbounded_send(item)
+            .map_err(|_| LiveViewError::SendingFailed)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.outgoing.close_channel();
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Wait until the condition is true, panicking if it takes too long
+pub async fn wait_until(mut condition: impl FnMut() -> bool) {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !condition() {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("timed out waiting for the condition");
+}
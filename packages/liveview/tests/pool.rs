@@ -0,0 +1,134 @@
+use std::{sync::Arc, time::Duration};
+
+use dioxus::prelude::*;
+use dioxus_liveview::{
+    use_server_message, LiveViewError, LiveViewPool, LiveViewPoolConfig, LiveViewSession,
+};
+
+mod common;
+use common::{fake_socket, wait_until, FakeClient};
+
+#[derive(Clone)]
+struct Grow;
+
+fn focus_event() -> String {
+    r#"{"method":"user_event","params":{"name":"focus","element":1,"bubbles":false,"data":{}}}"#
+        .to_string()
+}
+
+/// Launch a session on the pool, returning the client and the result of the session
+fn launch(
+    pool: &Arc<LiveViewPool>,
+    app: fn() -> Element,
+) -> (
+    FakeClient,
+    tokio::task::JoinHandle<Result<(), LiveViewError>>,
+) {
+    let (socket, client) = fake_socket();
+    let pool = pool.clone();
+    let result = tokio::spawn(async move {
+        pool.launch_session(socket, LiveViewSession::new(), move || VirtualDom::new(app))
+            .await
+    });
+    (client, result)
+}
+
+#[test]
+#[should_panic(expected = "at least one thread")]
+fn zero_threads_is_rejected() {
+    _ = LiveViewPoolConfig::new().threads(0);
+}
+
+#[tokio::test]
+async fn metrics_snapshots_do_not_change_the_metrics() {
+    fn app() -> Element {
+        let mut focused = use_signal(|| 0);
+        rsx! {
+            button { onfocus: move |_| focused += 1, "focused {focused}" }
+        }
+    }
+
+    let pool = Arc::new(LiveViewPool::new());
+    let (mut client, _) = launch(&pool, app);
+    assert!(client.next_frame().await.is_some());
+
+    for _ in 0..3 {
+        client.send_text(&focus_event());
+    }
+    wait_until(|| pool.metrics().events_total == 3).await;
+
+    let first = pool.metrics();
+    let second = pool.metrics();
+    assert_eq!(first, second);
+    assert_eq!(first.active_sessions, 1);
+    assert!(first.bytes_sent_total > 0);
+}
+
+#[tokio::test]
+async fn sessions_over_the_memory_limit_are_closed() {
+    fn app() -> Element {
+        let mut size = use_signal(|| 1);
+        use_server_message(move |_: Grow| size *= 100);
+        rsx! { "{\"x\".repeat(size())}" }
+    }
+
+    let pool = Arc::new(LiveViewPool::with_config(
+        LiveViewPoolConfig::new().max_session_memory(10_000),
+    ));
+    let (mut client, result) = launch(&pool, app);
+    assert!(client.next_frame().await.is_some());
+
+    // 100 bytes of text is under the limit
+    pool.broadcast(Grow, |_| true);
+    assert!(client.next_frame().await.is_some());
+    assert_eq!(pool.metrics().closed_sessions_total, 0);
+
+    // 10,000 bytes is not
+    pool.broadcast(Grow, |_| true);
+    let closed = client.next_text().await.unwrap();
+    assert!(closed.contains("maximum memory"), "{closed}");
+    assert!(matches!(
+        result.await.unwrap(),
+        Err(LiveViewError::MemoryLimitExceeded)
+    ));
+    assert_eq!(pool.metrics().closed_sessions_total, 1);
+}
+
+#[tokio::test]
+async fn render_rate_limit_only_delays_renders() {
+    fn app() -> Element {
+        let mut focused = use_signal(|| 0);
+        rsx! {
+            button { onfocus: move |_| focused += 1, "focused {focused}" }
+        }
+    }
+
+    let pool = Arc::new(LiveViewPool::with_config(
+        LiveViewPoolConfig::new().max_renders_per_second(1),
+    ));
+    let (mut client, _) = launch(&pool, app);
+    assert!(client.next_frame().await.is_some());
+
+    // Both events land inside the render window, so they are batched into one render
+    client.send_text(&focus_event());
+    client.send_text(&focus_event());
+
+    // Pings are answered while the render is held back
+    for _ in 0..2 {
+        client.send_text("__ping__");
+        let pong = tokio::time::timeout(Duration::from_millis(300), client.next_frame())
+            .await
+            .expect("the pong was delayed by the render rate limit")
+            .unwrap();
+        assert_eq!(pong[1..], *b"__pong__");
+    }
+
+    let edits = client.next_frame().await.unwrap();
+    assert_eq!(edits[0], 1);
+    assert!(
+        tokio::time::timeout(Duration::from_millis(300), client.next_frame())
+            .await
+            .is_err(),
+        "the batched events rendered more than once"
+    );
+}
@@ -0,0 +1,120 @@
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
+
+use dioxus::prelude::*;
+use dioxus_liveview::{use_server_message, LiveViewPool, LiveViewSession, SessionId};
+
+mod common;
+use common::{fake_socket, wait_until};
+
+#[derive(Clone)]
+struct Bump;
+
+#[derive(Clone)]
+struct Report;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Room(u32);
+
+#[tokio::test]
+async fn server_messages_reach_the_latest_handler() {
+    static REPORTED: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+    fn app() -> Element {
+        let mut count = use_signal(|| 0);
+        use_server_message(move |_: Bump| count += 1);
+        // Capture the value from this render. A stale handler would keep reporting the first value
+        let current = count();
+        use_server_message(move |_: Report| REPORTED.lock().unwrap().push(current));
+        rsx! { "count: {current}" }
+    }
+
+    let pool = Arc::new(LiveViewPool::new());
+    let (socket, mut client) = fake_socket();
+    tokio::spawn({
+        let pool = pool.clone();
+        async move {
+            _ = pool
+                .launch_session(socket, LiveViewSession::new(), || VirtualDom::new(app))
+                .await;
+        }
+    });
+
+    // The initial render
+    assert!(client.next_frame().await.is_some());
+    assert_eq!(pool.sessions().len(), 1);
+
+    // Wait for the rerender after the bump before asking for a report
+    assert_eq!(pool.broadcast(Bump, |_| true), 1);
+    assert!(client.next_frame().await.is_some());
+
+    assert_eq!(pool.broadcast(Report, |_| true), 1);
+    wait_until(|| !REPORTED.lock().unwrap().is_empty()).await;
+    assert_eq!(*REPORTED.lock().unwrap(), [1]);
+}
+
+#[tokio::test]
+async fn broadcast_only_reaches_matching_sessions() {
+    static RECEIVED: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+    fn app() -> Element {
+        let session = dioxus_liveview::use_liveview_session();
+        use_server_message(move |_: Bump| {
+            let Room(room) = session.metadata::<Room>().unwrap();
+            RECEIVED.lock().unwrap().push(room);
+        });
+        rsx! { "room" }
+    }
+
+    let pool = Arc::new(LiveViewPool::new());
+    let mut clients = Vec::new();
+    for room in [1, 2] {
+        let (socket, client) = fake_socket();
+        clients.push(client);
+        let session = LiveViewSession::new();
+        session.set_metadata(Room(room));
+        let pool = pool.clone();
+        tokio::spawn(async move {
+            _ = pool
+                .launch_session(socket, session, || VirtualDom::new(app))
+                .await;
+        });
+    }
+    for client in &mut clients {
+        assert!(client.next_frame().await.is_some());
+    }
+    assert_eq!(pool.sessions().len(), 2);
+
+    let sent = pool.broadcast(Bump, |session| session.metadata::<Room>() == Some(Room(2)));
+    assert_eq!(sent, 1);
+    wait_until(|| !RECEIVED.lock().unwrap().is_empty()).await;
+    assert_eq!(*RECEIVED.lock().unwrap(), [2]);
+
+    // Sessions are removed from the pool when their socket closes
+    drop(clients);
+    wait_until(|| pool.sessions().is_empty()).await;
+    assert_eq!(pool.broadcast(Bump, |_| true), 0);
+}
+
+#[test]
+fn session_ids_are_unique() {
+    let sessions: Vec<_> = (0..10).map(|_| LiveViewSession::new()).collect();
+    let ids: HashSet<SessionId> = sessions.iter().map(LiveViewSession::id).collect();
+    assert_eq!(ids.len(), sessions.len());
+
+    let session = &sessions[0];
+    assert_eq!(session.id(), session.clone().id());
+    assert!(session.id().to_string().parse::<usize>().is_ok());
+}
+
+#[test]
+fn sending_to_a_session_that_never_ran() {
+    let session = LiveViewSession::new();
+    assert!(!session.is_closed());
+    assert!(session.send(Bump).is_ok());
+    assert_eq!(session.metadata::<Room>(), None);
+    session.set_metadata(Room(3));
+    assert_eq!(session.metadata::<Room>(), Some(Room(3)));
+}
@@ -0,0 +1,12 @@
+#![doc = include_str!("../README.md")]
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![warn(missing_docs)]
+
+mod mutations;
+mod node;
+mod real_dom;
+pub mod test_renderer;
+
+pub use crate::node::*;
+pub use crate::real_dom::RealDom;
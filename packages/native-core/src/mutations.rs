@@ -0,0 +1,209 @@
+use dioxus_core::{
+    AttributeValue, ElementId, Template, TemplateAttribute, TemplateNode, WriteMutations,
+};
+
+use crate::{AttributeName, ElementNode, NodeId, NodeType, OwnedAttributeValue, RealDom};
+
+impl<T: Default> RealDom<T> {
+    /// Create a new copy of a node from a template
+    fn create_template_node(&mut self, node: &'static TemplateNode) -> NodeId {
+        match node {
+            TemplateNode::Element {
+                tag,
+                namespace,
+                attrs,
+                children,
+            } => {
+                let mut element = ElementNode::new(tag, *namespace);
+                for attr in attrs.iter() {
+                    if let TemplateAttribute::Static {
+                        name,
+                        value,
+                        namespace,
+                    } = attr
+                    {
+                        element.attributes.insert(
+                            AttributeName {
+                                name,
+                                namespace: *namespace,
+                            },
+                            OwnedAttributeValue::Text(value.to_string()),
+                        );
+                    }
+                }
+                let id = self.create_node(NodeType::Element(element));
+                let children = children
+                    .iter()
+                    .map(|child| self.create_template_node(child))
+                    .collect();
+                self.insert_children(id, 0, children);
+                id
+            }
+            TemplateNode::Text { text } => self.create_node(NodeType::Text(text.to_string())),
+            TemplateNode::Dynamic { .. } => self.create_node(NodeType::Placeholder),
+        }
+    }
+
+    fn portal_root(&mut self, target: &str) -> NodeId {
+        if let Some(root) = self.portals.get(target) {
+            return *root;
+        }
+        let root = self.create_node(NodeType::Root);
+        self.portals.insert(target.to_string(), root);
+        root
+    }
+}
+
+impl<T: Default> WriteMutations for RealDom<T> {
+    fn register_template(&mut self, template: Template) {
+        self.templates.insert(template.name, template);
+    }
+
+    fn append_children(&mut self, id: ElementId, m: usize) {
+        let children = self.pop_nodes(m);
+        let parent = self.element(id);
+        let index = self.nodes[parent.0].children.len();
+        self.insert_children(parent, index, children);
+    }
+
+    fn assign_node_id(&mut self, path: &'static [u8], id: ElementId) {
+        let node = self.node_at_path(path);
+        self.set_element_id(id, node);
+    }
+
+    fn create_placeholder(&mut self, id: ElementId) {
+        let node = self.create_node(NodeType::Placeholder);
+        self.set_element_id(id, node);
+        self.stack.push(node);
+    }
+
+    fn create_text_node(&mut self, value: &str, id: ElementId) {
+        let node = self.create_node(NodeType::Text(value.to_string()));
+        self.set_element_id(id, node);
+        self.stack.push(node);
+    }
+
+    fn hydrate_text_node(&mut self, path: &'static [u8], value: &str, id: ElementId) {
+        let node = self.node_at_path(path);
+        self.nodes[node.0].node_type = NodeType::Text(value.to_string());
+        self.set_element_id(id, node);
+        self.mark_dirty(node);
+    }
+
+    fn load_template(&mut self, name: &'static str, index: usize, id: ElementId) {
+        let template = self
+            .templates
+            .get(name)
+            .unwrap_or_else(|| panic!("the template {name} was never registered"));
+        let root = &template.roots[index];
+        let node = self.create_template_node(root);
+        self.set_element_id(id, node);
+        self.stack.push(node);
+    }
+
+    fn replace_node_with(&mut self, id: ElementId, m: usize) {
+        let new_nodes = self.pop_nodes(m);
+        let old = self.element(id);
+        if let (Some(parent), Some(index)) = (self.nodes[old.0].parent, self.index_in_parent(old)) {
+            self.insert_children(parent, index, new_nodes);
+        }
+        self.remove(old);
+    }
+
+    fn replace_placeholder_with_nodes(&mut self, path: &'static [u8], m: usize) {
+        let new_nodes = self.pop_nodes(m);
+        let old = self.node_at_path(path);
+        if let (Some(parent), Some(index)) = (self.nodes[old.0].parent, self.index_in_parent(old)) {
+            self.insert_children(parent, index, new_nodes);
+        }
+        self.remove(old);
+    }
+
+    fn insert_nodes_after(&mut self, id: ElementId, m: usize) {
+        let new_nodes = self.pop_nodes(m);
+        let sibling = self.element(id);
+        if let (Some(parent), Some(index)) =
+            (self.nodes[sibling.0].parent, self.index_in_parent(sibling))
+        {
+            self.insert_children(parent, index + 1, new_nodes);
+        }
+    }
+
+    fn insert_nodes_before(&mut self, id: ElementId, m: usize) {
+        let new_nodes = self.pop_nodes(m);
+        let sibling = self.element(id);
+        if let (Some(parent), Some(index)) =
+            (self.nodes[sibling.0].parent, self.index_in_parent(sibling))
+        {
+            self.insert_children(parent, index, new_nodes);
+        }
+    }
+
+    fn set_attribute(
+        &mut self,
+        name: &'static str,
+        ns: Option<&'static str>,
+        value: &AttributeValue,
+        id: ElementId,
+    ) {
+        let node = self.element(id);
+        let NodeType::Element(element) = &mut self.nodes[node.0].node_type else {
+            return;
+        };
+        let name = AttributeName {
+            name,
+            namespace: ns,
+        };
+        match OwnedAttributeValue::from_value(value) {
+            Some(value) => {
+                element.attributes.insert(name, value);
+            }
+            None if matches!(value, AttributeValue::None) => {
+                element.attributes.remove(&name);
+            }
+            None => return,
+        }
+        self.mark_dirty(node);
+    }
+
+    fn set_node_text(&mut self, value: &str, id: ElementId) {
+        let node = self.element(id);
+        if let NodeType::Text(text) = &mut self.nodes[node.0].node_type {
+            *text = value.to_string();
+            self.mark_dirty(node);
+        }
+    }
+
+    fn create_event_listener(&mut self, name: &'static str, id: ElementId) {
+        let node = self.element(id);
+        if let NodeType::Element(element) = &mut self.nodes[node.0].node_type {
+            element.listeners.insert(name.to_string());
+            self.mark_dirty(node);
+        }
+    }
+
+    fn remove_event_listener(&mut self, name: &'static str, id: ElementId) {
+        let node = self.element(id);
+        if let NodeType::Element(element) = &mut self.nodes[node.0].node_type {
+            element.listeners.remove(name);
+            self.mark_dirty(node);
+        }
+    }
+
+    fn remove_node(&mut self, id: ElementId) {
+        let node = self.element(id);
+        self.remove(node);
+    }
+
+    fn push_root(&mut self, id: ElementId) {
+        let node = self.element(id);
+        self.stack.push(node);
+    }
+
+    fn append_to_portal(&mut self, target: &str, m: usize) {
+        let children = self.pop_nodes(m);
+        let root = self.portal_root(target);
+        let index = self.nodes[root.0].children.len();
+        self.insert_children(root, index, children);
+    }
+}
@@ -0,0 +1,176 @@
+use dioxus_core::{AttributeValue, ElementId};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// The id of a node in a [`RealDom`](crate::RealDom).
+///
+/// Unlike [`ElementId`]s, every node in the tree has a [`NodeId`], including the static nodes that were created from a
+/// template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(pub(crate) usize);
+
+/// A node in a [`RealDom`](crate::RealDom).
+///
+/// The `data` field holds any extra state the renderer needs for the node, like a layout or a handle to a native widget.
+#[derive(Debug)]
+pub struct Node<T = ()> {
+    pub(crate) id: NodeId,
+    pub(crate) parent: Option<NodeId>,
+    pub(crate) children: Vec<NodeId>,
+    pub(crate) node_type: NodeType,
+    pub(crate) element_id: Option<ElementId>,
+    pub(crate) data: T,
+}
+
+impl<T> Node<T> {
+    /// The id of this node
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    /// The parent of this node. Nodes that are not attached to the tree and root nodes don't have a parent.
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    /// The children of this node in order
+    pub fn children(&self) -> &[NodeId] {
+        &self.children
+    }
+
+    /// What kind of node this is
+    pub fn node_type(&self) -> &NodeType {
+        &self.node_type
+    }
+
+    /// The [`ElementId`] the VirtualDom uses for this node if it has one
+    pub fn element_id(&self) -> Option<ElementId> {
+        self.element_id
+    }
+
+    /// Get the element data if this node is an element
+    pub fn element(&self) -> Option<&ElementNode> {
+        match &self.node_type {
+            NodeType::Element(element) => Some(element),
+            _ => None,
+        }
+    }
+
+    /// Get the text if this node is a text node
+    pub fn text(&self) -> Option<&str> {
+        match &self.node_type {
+            NodeType::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// The renderer specific data for this node
+    pub fn data(&self) -> &T {
+        &self.data
+    }
+
+    /// A mutable reference to the renderer specific data for this node
+    pub fn data_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+}
+
+/// The different kinds of nodes in a [`RealDom`](crate::RealDom)
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeType {
+    /// The root of the tree or the root of a portal target
+    Root,
+    /// An element with a tag, attributes and listeners
+    Element(ElementNode),
+    /// A text node
+    Text(String),
+    /// A placeholder that marks the position of a fragment or component that rendered nothing
+    Placeholder,
+}
+
+/// The data for an element node
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElementNode {
+    /// The tag of the element
+    pub tag: &'static str,
+    /// The namespace of the element
+    pub namespace: Option<&'static str>,
+    /// The attributes currently set on the element
+    pub attributes: FxHashMap<AttributeName, OwnedAttributeValue>,
+    /// The names of the events the VirtualDom listens for on this element
+    pub listeners: FxHashSet<String>,
+}
+
+impl ElementNode {
+    pub(crate) fn new(tag: &'static str, namespace: Option<&'static str>) -> Self {
+        Self {
+            tag,
+            namespace,
+            attributes: FxHashMap::default(),
+            listeners: FxHashSet::default(),
+        }
+    }
+
+    /// Get the value of an attribute without a namespace
+    pub fn attribute(&self, name: &str) -> Option<&OwnedAttributeValue> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key.name == name && key.namespace.is_none())
+            .map(|(_, value)| value)
+    }
+}
+
+/// The name and namespace of an attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AttributeName {
+    /// The name of the attribute
+    pub name: &'static str,
+    /// The namespace of the attribute. For instance, "style" for inline styles
+    pub namespace: Option<&'static str>,
+}
+
+/// An attribute value that the tree can store.
+///
+/// Listeners are tracked separately in [`ElementNode::listeners`] and [`AttributeValue::Any`] values are only
+/// meaningful to the renderer that created them, so neither is stored in the tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OwnedAttributeValue {
+    /// Text attribute
+    Text(String),
+    /// A float
+    Float(f64),
+    /// Signed integer
+    Int(i64),
+    /// Boolean
+    Bool(bool),
+}
+
+impl OwnedAttributeValue {
+    pub(crate) fn from_value(value: &AttributeValue) -> Option<Self> {
+        match value {
+            AttributeValue::Text(text) => Some(Self::Text(text.clone())),
+            AttributeValue::Float(float) => Some(Self::Float(*float)),
+            AttributeValue::Int(int) => Some(Self::Int(*int)),
+            AttributeValue::Bool(bool) => Some(Self::Bool(*bool)),
+            AttributeValue::Listener(_) | AttributeValue::Any(_) | AttributeValue::None => None,
+        }
+    }
+
+    /// Get the value as text if it is a text attribute
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for OwnedAttributeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Float(float) => write!(f, "{float}"),
+            Self::Int(int) => write!(f, "{int}"),
+            Self::Bool(bool) => write!(f, "{bool}"),
+        }
+    }
+}
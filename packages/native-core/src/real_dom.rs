@@ -0,0 +1,296 @@
+use std::{any::Any, rc::Rc};
+
+use dioxus_core::{ElementId, Template, VirtualDom};
+use rustc_hash::{FxHashMap, FxHashSet};
+use slab::Slab;
+
+use crate::{Node, NodeId, NodeType};
+
+/// A retained tree of nodes that mirrors the VirtualDom.
+///
+/// The tree implements [`WriteMutations`](dioxus_core::WriteMutations), so it can be passed directly to
+/// [`VirtualDom::rebuild`] and [`VirtualDom::render_immediate`]. It keeps track of the mapping between [`ElementId`]s and
+/// nodes, instantiates templates, and runs the stack machine the mutations are written for. Renderers only need to read
+/// the tree and update their own output for the nodes that changed.
+///
+/// Each node holds some renderer specific data `T` that is created with [`Default`] when the node is created.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// use dioxus_native_core::RealDom;
+///
+/// fn app() -> Element {
+///     rsx! { div { class: "container", "hello world" } }
+/// }
+///
+/// let mut vdom = VirtualDom::new(app);
+/// let mut tree = RealDom::<()>::new();
+/// vdom.rebuild(&mut tree);
+///
+/// assert_eq!(tree.to_string(), r#"<div class="container">hello world</div>"#);
+/// ```
+pub struct RealDom<T = ()> {
+    pub(crate) nodes: Slab<Node<T>>,
+    pub(crate) root: NodeId,
+    pub(crate) element_ids: Vec<Option<NodeId>>,
+    pub(crate) stack: Vec<NodeId>,
+    pub(crate) templates: FxHashMap<&'static str, Template>,
+    pub(crate) portals: FxHashMap<String, NodeId>,
+    pub(crate) dirty: FxHashSet<NodeId>,
+}
+
+impl<T: Default> Default for RealDom<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Default> RealDom<T> {
+    /// Create a new tree with a single root node. The root node has the [`ElementId`] the VirtualDom mounts the app to.
+    pub fn new() -> Self {
+        let mut tree = Self {
+            nodes: Slab::new(),
+            root: NodeId(0),
+            element_ids: Vec::new(),
+            stack: Vec::new(),
+            templates: FxHashMap::default(),
+            portals: FxHashMap::default(),
+            dirty: FxHashSet::default(),
+        };
+        tree.root = tree.create_node(NodeType::Root);
+        tree.set_element_id(ElementId(0), tree.root);
+        tree.stack.push(tree.root);
+        tree
+    }
+
+    pub(crate) fn create_node(&mut self, node_type: NodeType) -> NodeId {
+        let entry = self.nodes.vacant_entry();
+        let id = NodeId(entry.key());
+        entry.insert(Node {
+            id,
+            parent: None,
+            children: Vec::new(),
+            node_type,
+            element_id: None,
+            data: T::default(),
+        });
+        self.dirty.insert(id);
+        id
+    }
+}
+
+impl<T> RealDom<T> {
+    /// The root node of the tree
+    pub fn root(&self) -> NodeId {
+        self.root
+    }
+
+    /// Get a node by id
+    pub fn get(&self, id: NodeId) -> Option<&Node<T>> {
+        self.nodes.get(id.0)
+    }
+
+    /// Get a mutable reference to a node by id. Only the renderer specific data can be changed through this reference.
+    pub fn get_mut(&mut self, id: NodeId) -> Option<&mut Node<T>> {
+        self.nodes.get_mut(id.0)
+    }
+
+    /// Get the node the VirtualDom refers to with an [`ElementId`]
+    pub fn node_for_element(&self, id: ElementId) -> Option<NodeId> {
+        self.element_ids.get(id.0).copied().flatten()
+    }
+
+    /// Get the root node of the portal target with the given name, if anything has been rendered into it
+    pub fn portal(&self, target: &str) -> Option<NodeId> {
+        self.portals.get(target).copied()
+    }
+
+    /// Iterate over a node and all of its descendants in depth first order
+    pub fn descendants(&self, id: NodeId) -> impl Iterator<Item = &Node<T>> {
+        let mut stack = vec![id];
+        std::iter::from_fn(move || {
+            let node = self.get(stack.pop()?)?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    /// Find all elements in the tree with the given tag
+    pub fn elements_with_tag<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = NodeId> + 'a {
+        self.descendants(self.root)
+            .filter(move |node| node.element().is_some_and(|element| element.tag == tag))
+            .map(|node| node.id)
+    }
+
+    /// Take the nodes that were created or changed since the last call. A node is changed if its attributes, text,
+    /// listeners or children changed.
+    pub fn take_dirty(&mut self) -> Vec<NodeId> {
+        let mut dirty: Vec<_> = self
+            .dirty
+            .drain()
+            .filter(|id| self.nodes.contains(id.0))
+            .collect();
+        dirty.sort();
+        dirty
+    }
+
+    /// Send an event that happened on a node to the VirtualDom.
+    ///
+    /// Bubbling events are sent to the closest node (starting with the target) that listens for the event, and the
+    /// VirtualDom bubbles them up from there. Other events are only sent if the target listens for them. Returns `false`
+    /// if no listener was found.
+    ///
+    /// This does not render the VirtualDom. The renderer should render it after the event is handled.
+    pub fn handle_event(
+        &self,
+        dom: &mut VirtualDom,
+        target: NodeId,
+        name: &str,
+        data: Rc<dyn Any>,
+        bubbles: bool,
+    ) -> bool {
+        let mut current = Some(target);
+        while let Some(id) = current {
+            let Some(node) = self.get(id) else {
+                return false;
+            };
+            let listening = node
+                .element()
+                .is_some_and(|element| element.listeners.contains(name));
+            if let (true, Some(element_id)) = (listening, node.element_id) {
+                dom.handle_event(name, data, element_id, bubbles);
+                return true;
+            }
+            if !bubbles {
+                return false;
+            }
+            current = node.parent;
+        }
+        false
+    }
+
+    pub(crate) fn set_element_id(&mut self, element_id: ElementId, node: NodeId) {
+        if self.element_ids.len() <= element_id.0 {
+            self.element_ids.resize(element_id.0 + 1, None);
+        }
+        self.element_ids[element_id.0] = Some(node);
+        self.nodes[node.0].element_id = Some(element_id);
+    }
+
+    /// Get the node for an element id, panicking if the VirtualDom refers to a node that doesn't exist
+    pub(crate) fn element(&self, id: ElementId) -> NodeId {
+        self.node_for_element(id)
+            .unwrap_or_else(|| panic!("{id:?} does not refer to a node in the tree"))
+    }
+
+    pub(crate) fn mark_dirty(&mut self, id: NodeId) {
+        self.dirty.insert(id);
+    }
+
+    /// Remove a node from its parent without removing it from the tree
+    pub(crate) fn detach(&mut self, id: NodeId) {
+        if let Some(parent) = self.nodes[id.0].parent.take() {
+            self.nodes[parent.0].children.retain(|child| *child != id);
+            self.mark_dirty(parent);
+        }
+    }
+
+    /// Insert nodes into a parent at the given index, detaching them from their old parent first
+    pub(crate) fn insert_children(
+        &mut self,
+        parent: NodeId,
+        mut index: usize,
+        children: Vec<NodeId>,
+    ) {
+        for child in children {
+            // Moving a node earlier in the same parent shifts the nodes after it
+            if let Some(old_index) = self.index_in_parent(child) {
+                if self.nodes[child.0].parent == Some(parent) && old_index < index {
+                    index -= 1;
+                }
+            }
+            self.detach(child);
+            self.nodes[child.0].parent = Some(parent);
+            self.nodes[parent.0].children.insert(index, child);
+            index += 1;
+        }
+        self.mark_dirty(parent);
+    }
+
+    pub(crate) fn index_in_parent(&self, id: NodeId) -> Option<usize> {
+        let parent = self.nodes[id.0].parent?;
+        self.nodes[parent.0]
+            .children
+            .iter()
+            .position(|child| *child == id)
+    }
+
+    /// Remove a node and all of its descendants from the tree
+    pub(crate) fn remove(&mut self, id: NodeId) {
+        self.detach(id);
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            let node = self.nodes.remove(id.0);
+            if let Some(element_id) = node.element_id {
+                // The VirtualDom may have already given the id to a new node
+                if self.element_ids.get(element_id.0) == Some(&Some(id)) {
+                    self.element_ids[element_id.0] = None;
+                }
+            }
+            self.dirty.remove(&id);
+            stack.extend(node.children);
+        }
+    }
+
+    /// Get the node at a path relative to the top of the stack
+    pub(crate) fn node_at_path(&self, path: &[u8]) -> NodeId {
+        let mut current = *self.stack.last().expect("the stack is empty");
+        for index in path {
+            current = self.nodes[current.0].children[*index as usize];
+        }
+        current
+    }
+
+    pub(crate) fn pop_nodes(&mut self, m: usize) -> Vec<NodeId> {
+        self.stack.split_off(self.stack.len() - m)
+    }
+}
+
+impl<T> std::fmt::Display for RealDom<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn write_node<T>(
+            tree: &RealDom<T>,
+            id: NodeId,
+            f: &mut std::fmt::Formatter<'_>,
+        ) -> std::fmt::Result {
+            let node = &tree.nodes[id.0];
+            match &node.node_type {
+                NodeType::Root => {}
+                NodeType::Text(text) => return write!(f, "{text}"),
+                NodeType::Placeholder => return write!(f, "<!--placeholder-->"),
+                NodeType::Element(element) => {
+                    write!(f, "<{}", element.tag)?;
+                    let mut attributes: Vec<_> = element
+                        .attributes
+                        .iter()
+                        .filter(|(name, _)| name.namespace.is_none())
+                        .collect();
+                    attributes.sort_by_key(|(name, _)| name.name);
+                    for (name, value) in attributes {
+                        write!(f, " {}=\"{value}\"", name.name)?;
+                    }
+                    write!(f, ">")?;
+                }
+            }
+            for child in &node.children {
+                write_node(tree, *child, f)?;
+            }
+            if let NodeType::Element(element) = &node.node_type {
+                write!(f, "</{}>", element.tag)?;
+            }
+            Ok(())
+        }
+
+        write_node(self, self.root, f)
+    }
+}
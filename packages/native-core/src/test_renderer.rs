@@ -0,0 +1,95 @@
+//! A renderer that keeps the VirtualDom in a [`RealDom`] so tests can inspect the output and send events without a
+//! real platform.
+
+use std::{any::Any, rc::Rc};
+
+use dioxus_core::{Element, VirtualDom};
+
+use crate::{NodeId, RealDom};
+
+/// A renderer that keeps the VirtualDom in a [`RealDom`].
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus::html::{PlatformEventData, SerializedHtmlEventConverter, SerializedMouseData};
+/// use dioxus_native_core::test_renderer::TestRenderer;
+/// use std::rc::Rc;
+///
+/// fn app() -> Element {
+///     let mut count = use_signal(|| 0);
+///     rsx! {
+///         button { onclick: move |_| count += 1, "count: {count}" }
+///     }
+/// }
+///
+/// set_event_converter(Box::new(SerializedHtmlEventConverter));
+///
+/// let mut renderer = TestRenderer::new(app);
+/// assert_eq!(renderer.to_string(), "<button>count: 0</button>");
+///
+/// let button = renderer.tree().elements_with_tag("button").next().unwrap();
+/// let click = PlatformEventData::new(Box::<SerializedMouseData>::default());
+/// renderer.handle_event(button, "click", Rc::new(click), true);
+/// assert_eq!(renderer.to_string(), "<button>count: 1</button>");
+/// ```
+pub struct TestRenderer {
+    dom: VirtualDom,
+    tree: RealDom,
+}
+
+impl TestRenderer {
+    /// Create a renderer for a component and render it
+    pub fn new(app: fn() -> Element) -> Self {
+        Self::from_dom(VirtualDom::new(app))
+    }
+
+    /// Create a renderer for an existing VirtualDom and render it
+    pub fn from_dom(mut dom: VirtualDom) -> Self {
+        let mut tree = RealDom::new();
+        dom.rebuild(&mut tree);
+        Self { dom, tree }
+    }
+
+    /// The VirtualDom that is being rendered
+    pub fn dom(&mut self) -> &mut VirtualDom {
+        &mut self.dom
+    }
+
+    /// The tree the VirtualDom is rendered into
+    pub fn tree(&self) -> &RealDom {
+        &self.tree
+    }
+
+    /// Apply any changes that are ready to the tree
+    pub fn render(&mut self) {
+        self.dom.render_immediate(&mut self.tree);
+    }
+
+    /// Wait for the VirtualDom to have work, like a future that resolved or a signal that changed, and apply the
+    /// changes to the tree
+    pub async fn wait_for_work(&mut self) {
+        self.dom.wait_for_work().await;
+        self.render();
+    }
+
+    /// Send an event to a node and apply the changes to the tree. Returns `false` if nothing listened for the event.
+    pub fn handle_event(
+        &mut self,
+        target: NodeId,
+        name: &str,
+        data: Rc<dyn Any>,
+        bubbles: bool,
+    ) -> bool {
+        let handled = self
+            .tree
+            .handle_event(&mut self.dom, target, name, data, bubbles);
+        self.render();
+        handled
+    }
+}
+
+impl std::fmt::Display for TestRenderer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.tree.fmt(f)
+    }
+}
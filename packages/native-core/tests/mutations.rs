@@ -0,0 +1,161 @@
+//! Verify that the tree stays in sync with the VirtualDom as it renders
+
+use dioxus::prelude::*;
+use dioxus_native_core::{test_renderer::TestRenderer, NodeType, OwnedAttributeValue, RealDom};
+
+#[test]
+fn templates_are_instantiated() {
+    fn app() -> Element {
+        let name = "world";
+        rsx! {
+            div { class: "static", id: "{name}",
+                "hello {name}"
+                span { "static text" }
+            }
+        }
+    }
+
+    let mut vdom = VirtualDom::new(app);
+    let mut tree = RealDom::<()>::new();
+    vdom.rebuild(&mut tree);
+
+    assert_eq!(
+        tree.to_string(),
+        r#"<div class="static" id="world">hello world<span>static text</span></div>"#
+    );
+
+    let div = tree.elements_with_tag("div").next().unwrap();
+    let element = tree.get(div).unwrap().element().unwrap();
+    assert_eq!(
+        element.attribute("id"),
+        Some(&OwnedAttributeValue::Text("world".to_string()))
+    );
+}
+
+#[test]
+fn keyed_lists_are_reordered() {
+    fn app() -> Element {
+        let mut items = use_signal(|| vec![1, 2, 3]);
+        use_hook(move || {
+            spawn(async move {
+                items.write().reverse();
+            })
+        });
+        rsx! {
+            ul {
+                for item in items() {
+                    li { key: "{item}", "{item}" }
+                }
+            }
+        }
+    }
+
+    let mut renderer = TestRenderer::new(app);
+    assert_eq!(
+        renderer.to_string(),
+        "<ul><li>1</li><li>2</li><li>3</li></ul>"
+    );
+
+    let before: Vec<_> = renderer.tree().elements_with_tag("li").collect();
+
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(renderer.wait_for_work());
+
+    assert_eq!(
+        renderer.to_string(),
+        "<ul><li>3</li><li>2</li><li>1</li></ul>"
+    );
+
+    // Moved nodes keep their identity
+    let mut after: Vec<_> = renderer.tree().elements_with_tag("li").collect();
+    after.reverse();
+    assert_eq!(before, after);
+}
+
+#[test]
+fn removed_nodes_are_dropped() {
+    fn app() -> Element {
+        let mut show = use_signal(|| true);
+        use_hook(move || spawn(async move { show.set(false) }));
+        rsx! {
+            if show() {
+                div { "visible" }
+            }
+        }
+    }
+
+    let mut renderer = TestRenderer::new(app);
+    assert_eq!(renderer.to_string(), "<div>visible</div>");
+    let div = renderer.tree().elements_with_tag("div").next().unwrap();
+
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(renderer.wait_for_work());
+
+    assert_eq!(renderer.to_string(), "<!--placeholder-->");
+    assert!(renderer.tree().get(div).is_none());
+}
+
+#[test]
+fn dirty_nodes_are_tracked() {
+    fn app() -> Element {
+        let mut count = use_signal(|| 0);
+        use_hook(move || spawn(async move { count += 1 }));
+        rsx! {
+            div { "static" }
+            "count: {count}"
+        }
+    }
+
+    let mut vdom = VirtualDom::new(app);
+    let mut tree = RealDom::<()>::new();
+    vdom.rebuild(&mut tree);
+
+    // The root, the div, its text and the dynamic text node
+    assert_eq!(tree.take_dirty().len(), 4);
+
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(vdom.wait_for_work());
+    vdom.render_immediate(&mut tree);
+
+    let changed = tree.take_dirty();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(
+        tree.get(changed[0]).unwrap().node_type(),
+        &NodeType::Text("count: 1".to_string())
+    );
+}
+
+#[test]
+fn events_are_routed_to_the_closest_listener() {
+    use dioxus::html::{PlatformEventData, SerializedHtmlEventConverter, SerializedMouseData};
+    use std::rc::Rc;
+
+    fn app() -> Element {
+        let mut clicks = use_signal(|| 0);
+        rsx! {
+            div { onclick: move |_| clicks += 1,
+                span { "clicks: {clicks}" }
+            }
+        }
+    }
+
+    set_event_converter(Box::new(SerializedHtmlEventConverter));
+    let click = || Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default()));
+
+    let mut renderer = TestRenderer::new(app);
+    let span = renderer.tree().elements_with_tag("span").next().unwrap();
+
+    // Clicks on the span bubble up to the div
+    assert!(renderer.handle_event(span, "click", click(), true));
+    assert_eq!(renderer.to_string(), "<div><span>clicks: 1</span></div>");
+
+    // Events that don't bubble only reach the target
+    assert!(!renderer.handle_event(span, "click", click(), false));
+    assert_eq!(renderer.to_string(), "<div><span>clicks: 1</span></div>");
+}
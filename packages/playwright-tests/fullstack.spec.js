@@ -32,3 +32,19 @@ test('fullstack communication', async ({ page }) => {
   // Expect the page to contain the updated counter text.
   await expect(main).toContainText('Server said: Hello from the server!');
 });
+
+test('portals are mounted after hydration', async ({ page }) => {
+  await page.goto('http://localhost:3333');
+  await page.waitForTimeout(1000);
+
+  // The server copy of the portal is replaced with the client's portal children
+  const portal = page.locator('#portal-root');
+  await expect(portal).toHaveCount(1);
+  await expect(page.locator('button.portal-button')).toHaveCount(1);
+  await expect(portal).toContainText('Portal count: 12345');
+
+  // Events in the portal children update the app
+  await page.locator('button.portal-button').click();
+  await expect(portal).toContainText('Portal count: 12346');
+  await expect(page.locator('#main')).toContainText('hello axum! 12346');
+});
@@ -3,6 +3,7 @@
 // - Server functions
 // - SSR
 // - Hydration
+// - Portals
 
 #![allow(non_snake_case)]
 use dioxus::prelude::*;
@@ -30,6 +31,9 @@ fn app() -> Element {
             "Run a server function!"
         }
         "Server said: {text}"
+        Portal { target: "portal-root",
+            button { class: "portal-button", onclick: move |_| count += 1, "Portal count: {count}" }
+        }
     }
 }
 
@@ -3,7 +3,9 @@
 #![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
 
 use convert_case::{Case, Casing};
-use dioxus_html::{map_html_attribute_to_rsx, map_html_element_to_rsx};
+use dioxus_html::{
+    map_html_attribute_to_rsx, map_html_attribute_value_to_rsx, map_html_element_to_rsx,
+};
 use dioxus_rsx::{
     AttributeType, BodyNode, CallBody, Component, Element, ElementAttr, ElementAttrNamed,
     ElementName, IfmtInput,
@@ -27,7 +29,12 @@ pub fn rsx_node_from_html(node: &Node) -> Option<BodyNode> {
         Node::Text(text) => Some(BodyNode::Text(ifmt_from_text(text))),
         Node::Element(el) => {
             let el_name = if let Some(name) = map_html_element_to_rsx(&el.name) {
-                ElementName::Ident(Ident::new(name, Span::call_site()))
+                let ident = if let Some(name) = name.strip_prefix("r#") {
+                    Ident::new_raw(name, Span::call_site())
+                } else {
+                    Ident::new(name, Span::call_site())
+                };
+                ElementName::Ident(ident)
             } else {
                 // if we don't recognize it and it has a dash, we assume it's a web component
                 if el.name.contains('-') {
@@ -42,7 +49,14 @@ pub fn rsx_node_from_html(node: &Node) -> Option<BodyNode> {
                 .attributes
                 .iter()
                 .map(|(name, value)| {
-                    let value = ifmt_from_text(value.as_deref().unwrap_or("false"));
+                    let text = value.as_deref().unwrap_or("false");
+                    // Enumerated attributes are written with their typed values, e.g. `InputType::Checkbox`
+                    let value = match map_html_attribute_value_to_rsx(&el.name, name, text)
+                        .and_then(|typed| syn::parse_str(&typed).ok())
+                    {
+                        Some(typed) => dioxus_rsx::ElementAttrValue::AttrExpr(typed),
+                        None => dioxus_rsx::ElementAttrValue::AttrLiteral(ifmt_from_text(text)),
+                    };
                     let attr = if let Some(name) = map_html_attribute_to_rsx(name) {
                         let ident = if let Some(name) = name.strip_prefix("r#") {
                             Ident::new_raw(name, Span::call_site())
@@ -50,13 +64,13 @@ pub fn rsx_node_from_html(node: &Node) -> Option<BodyNode> {
                             Ident::new(name, Span::call_site())
                         };
                         ElementAttr {
-                            value: dioxus_rsx::ElementAttrValue::AttrLiteral(value),
+                            value,
                             name: dioxus_rsx::ElementAttrName::BuiltIn(ident),
                         }
                     } else {
                         // If we don't recognize the attribute, we assume it's a custom attribute
                         ElementAttr {
-                            value: dioxus_rsx::ElementAttrValue::AttrLiteral(value),
+                            value,
                             name: dioxus_rsx::ElementAttrName::Custom(LitStr::new(
                                 name,
                                 Span::call_site(),
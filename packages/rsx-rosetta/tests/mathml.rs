@@ -0,0 +1,31 @@
+use html_parser::Dom;
+
+#[test]
+fn mathml_elements_translate() {
+    let html = r#"
+    <math display="block">
+        <mfrac>
+            <mi mathcolor="red">x</mi>
+            <mn>2</mn>
+        </mfrac>
+        <annotation-xml encoding="MathML-Content"></annotation-xml>
+    </math>
+    "#
+    .trim();
+
+    let dom = Dom::parse(html).unwrap();
+
+    let body = rsx_rosetta::rsx_from_html(&dom);
+
+    let out = dioxus_autofmt::write_block_out(&body).unwrap();
+
+    let expected = r#"
+    math { display: "block",
+        mfrac {
+            mi { mathcolor: "red", "x" }
+            mn { "2" }
+        }
+        annotationXml { encoding: "MathML-Content" }
+    }"#;
+    pretty_assertions::assert_eq!(&out, &expected);
+}
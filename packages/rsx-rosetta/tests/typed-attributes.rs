@@ -0,0 +1,31 @@
+use html_parser::Dom;
+
+#[test]
+fn enumerated_attributes_are_typed() {
+    let html = r#"
+    <form method="post">
+        <input type="checkbox">
+        <input type="chekbox">
+        <input autocomplete="off">
+        <a target="_blank">Home</a>
+        <div role="button"></div>
+    </form>
+    "#
+    .trim();
+
+    let dom = Dom::parse(html).unwrap();
+
+    let body = rsx_rosetta::rsx_from_html(&dom);
+
+    let out = dioxus_autofmt::write_block_out(&body).unwrap();
+
+    let expected = r#"
+    form { method: FormDialogMethod::Post,
+        input { r#type: InputType::Checkbox }
+        input { r#type: "chekbox" }
+        input { autocomplete: Autocomplete::Off }
+        a { target: Target::Blank, "Home" }
+        div { role: AriaRole::Button }
+    }"#;
+    pretty_assertions::assert_eq!(&out, &expected);
+}
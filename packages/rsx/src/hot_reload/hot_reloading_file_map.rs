@@ -5,7 +5,7 @@ use super::{
 use crate::{CallBody, HotReloadingContext};
 use dioxus_core::{
     prelude::{TemplateAttribute, TemplateNode},
-    Template,
+    Template, TemplateCache,
 };
 use krates::cm::MetadataCommand;
 use krates::Cmd;
@@ -274,6 +274,53 @@ impl<Ctx: HotReloadingContext> FileMap<Ctx> {
             .collect()
     }
 
+    /// Extract the templates of every `rsx!` call in the crate into a [`TemplateCache`] that renderers can preload
+    ///
+    /// Only the outermost template of each call is extracted, which are the same templates hot reloading can update.
+    /// Templates are named by the location of their call, so the renderer can match them with the compiled templates.
+    /// The templates are sorted by name so the same source code always produces the same cache
+    pub fn template_cache(&mut self, crate_dir: &Path) -> io::Result<TemplateCache> {
+        // if the file!() macro is invoked in a workspace, the path is relative to the workspace root, otherwise it's relative to the crate root
+        let prefix = self
+            .child_in_workspace(crate_dir)?
+            .unwrap_or_else(|| crate_dir.to_path_buf());
+
+        let mut templates = Vec::new();
+        for cached in self.map.values() {
+            let Ok(syntax) = syn::parse_file(&cached.raw) else {
+                continue;
+            };
+            let Ok(file) = cached.path.strip_prefix(&prefix) else {
+                continue;
+            };
+
+            // Diffing a file against itself finds every rsx call in the file
+            let DiffResult::RsxChanged { rsx_calls } = diff_rsx(&syntax, &syntax) else {
+                continue;
+            };
+            for ChangedRsx { old, .. } in rsx_calls {
+                let start = old.span().start();
+                let (Ok(old_call_body), Ok(call_body)) = (
+                    syn::parse2::<CallBody>(old.tokens.clone()),
+                    syn::parse2::<CallBody>(old.tokens),
+                ) else {
+                    continue;
+                };
+                let location = Box::leak(template_location(start, file).into_boxed_str());
+                if let Some(template) =
+                    call_body.update_template::<Ctx>(Some(old_call_body), location)
+                {
+                    if !template.roots.is_empty() {
+                        templates.push(template);
+                    }
+                }
+            }
+        }
+
+        templates.sort_by_key(|template| template.name);
+        Ok(templates.into_iter().collect())
+    }
+
     pub fn is_tracking_asset(&self, path: &PathBuf) -> Option<&CachedSynFile> {
         self.map
             .values()
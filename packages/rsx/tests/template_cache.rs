@@ -0,0 +1,54 @@
+use dioxus_core::prelude::{TemplateAttribute, TemplateNode};
+use dioxus_rsx::hot_reload::{Empty, FileMap, FileMapBuildResult};
+
+const MAIN: &str = r#"fn app() -> Element {
+    rsx! {
+        div { class: "static", "hello {name}" }
+    }
+}
+
+fn other() -> Element {
+    rsx! { p { "world" } }
+}
+"#;
+
+#[test]
+fn template_cache_extracts_every_rsx_call() {
+    // Create a small crate to extract the templates from
+    let crate_dir =
+        std::env::temp_dir().join(format!("dioxus-template-cache-{}", std::process::id()));
+    std::fs::create_dir_all(crate_dir.join("src")).unwrap();
+    std::fs::write(
+        crate_dir.join("Cargo.toml"),
+        "[package]\nname = \"fixture\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[workspace]\n",
+    )
+    .unwrap();
+    std::fs::write(crate_dir.join("src/main.rs"), MAIN).unwrap();
+    let crate_dir = crate_dir.canonicalize().unwrap();
+
+    let FileMapBuildResult { mut map, errors } =
+        FileMap::<Empty>::create(crate_dir.clone()).unwrap();
+    assert!(errors.is_empty());
+    let cache = map.template_cache(&crate_dir).unwrap();
+    std::fs::remove_dir_all(&crate_dir).unwrap();
+
+    // Templates are named after the location of the rsx call, just like the templates rsx! compiles
+    let names: Vec<_> = cache.iter().map(|template| template.name).collect();
+    assert_eq!(names, ["src/main.rs:2:5:0", "src/main.rs:8:5:0"]);
+
+    let app = cache.get("src/main.rs:2:5:0").unwrap();
+    assert_eq!(
+        app.roots,
+        [TemplateNode::Element {
+            tag: "div",
+            namespace: None,
+            attrs: &[TemplateAttribute::Static {
+                name: "class",
+                value: "static",
+                namespace: None,
+            }],
+            children: &[TemplateNode::Dynamic { id: 0 }],
+        }]
+    );
+    assert_eq!(app.node_paths, [&[0, 0][..]]);
+}
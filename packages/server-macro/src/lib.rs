@@ -6,8 +6,13 @@
 //! See the [server_fn_macro] crate for more information.
 
 use proc_macro::TokenStream;
+use quote::quote;
 use server_fn_macro::server_macro_impl;
-use syn::__private::ToTokens;
+use syn::{
+    __private::ToTokens, parse::Parser, punctuated::Punctuated, spanned::Spanned, Expr, Token,
+};
+
+mod websocket;
 
 /// Declares that a function is a [server function](https://docs.rs/server_fn/).
 /// This means that its body will only run on the server, i.e., when the `ssr`
@@ -44,13 +49,18 @@ use syn::__private::ToTokens;
 ///     - `"GetCbor"`: `GET` request with URL-encoded arguments and CBOR response
 /// - `req` and `res` specify the HTTP request and response types to be used on the server (these
 ///   should usually only be necessary if you are integrating with a server other than Actix/Axum)
+/// - `middleware`: a `ServerFnMiddleware` that runs before and after the server function. This argument
+///   can be repeated to add multiple middleware, which run in the order they are declared. Middleware
+///   requires the `axum` feature
+/// - `protocol`: set to `websocket` to create a websocket server function (see below)
 /// ```rust,ignore
 /// #[server(
 ///   name = SomeStructName,
 ///   prefix = "/my_api",
 ///   endpoint = "my_fn",
 ///   input = Cbor,
-///   output = Json
+///   output = Json,
+///   middleware = RequireUser,
 /// )]
 /// pub async fn my_wacky_server_fn(input: Vec<String>) -> Result<usize, ServerFnError> {
 ///   unimplemented!()
@@ -68,11 +78,56 @@ use syn::__private::ToTokens;
 ///   // etc.
 /// }
 /// ```
+///
+/// ## Websocket server functions
+///
+/// Setting `protocol = websocket` creates a server function that opens a bidirectional channel between the
+/// client and server. The last argument of the function must be a `Websocket<Out, In>` that the server uses
+/// to send `Out` messages to the client and receive `In` messages from the client. Calling the function on
+/// the client connects to the server and returns a `Websocket<In, Out>`. The server function context is
+/// created from the handshake request, so extractors work the same way they do in other server functions.
+/// Websocket server functions accept the `prefix`, `endpoint` and `middleware` arguments. Middleware runs on the
+/// handshake request, so a rejection is returned before the connection is upgraded.
+///
+/// ```rust,ignore
+/// #[server(protocol = websocket)]
+/// pub async fn echo(mut socket: Websocket<String, String>) -> Result<(), ServerFnError> {
+///     while let Some(message) = socket.recv().await {
+///         socket.send(message?).await?;
+///     }
+///     Ok(())
+/// }
+///
+/// // on the client
+/// let mut socket: Websocket<String, String> = echo().await?;
+/// socket.send("hello".to_string()).await?;
+/// ```
 #[proc_macro_attribute]
 pub fn server(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
+    if let Ok(parsed) = Punctuated::<Expr, Token![,]>::parse_terminated.parse(args.clone()) {
+        if websocket::is_websocket(&parsed) {
+            return match websocket::websocket_server_fn(parsed, s.into()) {
+                Err(e) => e.to_compile_error().into(),
+                Ok(s) => s.into(),
+            };
+        }
+    }
+
+    let (args, middleware) = split_middleware_args(args.into());
+    // Combine the middleware into a single `#[middleware]` attribute that the server_fn macro understands
+    let body: proc_macro2::TokenStream = s.into();
+    let middleware = match middleware_layer(&middleware) {
+        Ok(layer) => layer.map(|layer| quote! { #[middleware(#layer)] }),
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let body = quote! {
+        #middleware
+        #body
+    };
+
     match server_macro_impl(
-        args.into(),
-        s.into(),
+        args,
+        body,
         Some(syn::parse_quote!(server_fn)),
         "/api",
         None,
@@ -82,3 +137,48 @@ pub fn server(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
         Ok(s) => s.to_token_stream().into(),
     }
 }
+
+/// Split the `middleware = ...` arguments out of the arguments passed to the server macro
+fn split_middleware_args(args: proc_macro2::TokenStream) -> (proc_macro2::TokenStream, Vec<Expr>) {
+    let Ok(parsed) = Punctuated::<Expr, Token![,]>::parse_terminated.parse2(args.clone()) else {
+        // Let the server_fn macro report any errors in the arguments
+        return (args, Vec::new());
+    };
+
+    let mut middleware = Vec::new();
+    let mut remaining = Punctuated::<Expr, Token![,]>::new();
+    for arg in parsed {
+        match arg {
+            Expr::Assign(assign) if is_middleware_arg(&assign.left) => {
+                middleware.push(*assign.right);
+            }
+            arg => remaining.push(arg),
+        }
+    }
+
+    (remaining.into_token_stream(), middleware)
+}
+
+/// Create a `ServerFnMiddlewareLayer` that runs all of the middleware in order. Middleware only runs on the server, so
+/// this returns `None` in client builds.
+fn middleware_layer(middleware: &[Expr]) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    let Some((first, rest)) = middleware.split_first() else {
+        return Ok(None);
+    };
+    if !cfg!(feature = "server") {
+        return Ok(None);
+    }
+    if !cfg!(feature = "axum") {
+        return Err(syn::Error::new(
+            first.span(),
+            "Server function middleware requires the `axum` feature of dioxus-fullstack",
+        ));
+    }
+    Ok(Some(quote! {
+        dioxus_fullstack::prelude::ServerFnMiddlewareLayer::new(#first)#(.and(#rest))*
+    }))
+}
+
+fn is_middleware_arg(expr: &Expr) -> bool {
+    matches!(expr, Expr::Path(path) if path.path.is_ident("middleware"))
+}
@@ -0,0 +1,239 @@
+//! The implementation of `#[server(protocol = websocket)]`
+
+use convert_case::{Case, Converter};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    punctuated::Punctuated, spanned::Spanned, Expr, ExprLit, FnArg, GenericArgument, ItemFn, Lit,
+    Pat, PathArguments, ReturnType, Token, Type,
+};
+
+/// Check if the arguments passed to the server macro select the websocket protocol
+pub(crate) fn is_websocket(args: &Punctuated<Expr, Token![,]>) -> bool {
+    args.iter()
+        .any(|arg| matches!(arg, Expr::Assign(assign) if is_arg(&assign.left, "protocol")))
+}
+
+pub(crate) fn websocket_server_fn(
+    args: Punctuated<Expr, Token![,]>,
+    body: TokenStream,
+) -> syn::Result<TokenStream> {
+    let item: ItemFn = syn::parse2(body)?;
+
+    let mut prefix = "/api".to_string();
+    let mut endpoint = item.sig.ident.to_string();
+    let mut middleware = Vec::new();
+    for arg in args {
+        let Expr::Assign(assign) = &arg else {
+            return Err(syn::Error::new(arg.span(), "Expected a named argument"));
+        };
+        if is_arg(&assign.left, "protocol") {
+            match &*assign.right {
+                Expr::Path(path) if path.path.is_ident("websocket") => {}
+                other => {
+                    return Err(syn::Error::new(
+                        other.span(),
+                        "The only supported protocol is `websocket`",
+                    ))
+                }
+            }
+        } else if is_arg(&assign.left, "prefix") {
+            prefix = string_arg(&assign.right)?;
+        } else if is_arg(&assign.left, "endpoint") {
+            endpoint = string_arg(&assign.right)?;
+        } else if is_arg(&assign.left, "middleware") {
+            middleware.push((*assign.right).clone());
+        } else {
+            return Err(syn::Error::new(
+                assign.left.span(),
+                "Websocket server functions only support the `prefix`, `endpoint` and `middleware` arguments",
+            ));
+        }
+    }
+    let path = format!(
+        "{}/{}",
+        prefix.trim_end_matches('/'),
+        endpoint.trim_start_matches('/')
+    );
+
+    if item.sig.asyncness.is_none() {
+        return Err(syn::Error::new(
+            item.sig.fn_token.span(),
+            "Websocket server functions must be async",
+        ));
+    }
+    if !item.sig.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            item.sig.generics.span(),
+            "Websocket server functions cannot be generic",
+        ));
+    }
+    if let ReturnType::Default = item.sig.output {
+        return Err(syn::Error::new(
+            item.sig.span(),
+            "Websocket server functions must return `Result<(), ServerFnError>`",
+        ));
+    }
+
+    // The last argument is the socket the server uses to talk to the client
+    let mut inputs: Vec<_> = item.sig.inputs.iter().cloned().collect();
+    let socket = inputs.pop().ok_or_else(|| {
+        syn::Error::new(
+            item.sig.inputs.span(),
+            "Websocket server functions must take a `Websocket<Out, In>` as their last argument",
+        )
+    })?;
+    let (server_send, server_receive) = socket_types(&socket)?;
+
+    let mut names = Vec::new();
+    let mut types = Vec::new();
+    for input in &inputs {
+        let FnArg::Typed(input) = input else {
+            return Err(syn::Error::new(
+                input.span(),
+                "Websocket server functions cannot take self",
+            ));
+        };
+        let Pat::Ident(pat) = &*input.pat else {
+            return Err(syn::Error::new(
+                input.pat.span(),
+                "Websocket server function arguments must be identifiers",
+            ));
+        };
+        names.push(pat.ident.clone());
+        types.push((*input.ty).clone());
+    }
+
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = item;
+    let fn_name = &sig.ident;
+    let server_inputs = &sig.inputs;
+    let server_output = &sig.output;
+    let args_struct = format_ident!(
+        "{}WebsocketArgs",
+        Converter::new()
+            .to_case(Case::Pascal)
+            .convert(fn_name.to_string())
+    );
+
+    let server = if cfg!(feature = "server") {
+        // The middleware runs on the handshake request before the connection is upgraded
+        let (middleware, register) = match crate::middleware_layer(&middleware)? {
+            Some(layer) => (
+                quote! {
+                    fn __middleware() -> dioxus_fullstack::prelude::ServerFnMiddlewareLayer {
+                        #layer
+                    }
+                },
+                quote! {
+                    dioxus_fullstack::prelude::__websocket::WebsocketServerFn::new(#path, __handler)
+                        .with_middleware(__middleware)
+                },
+            ),
+            None => (
+                quote! {},
+                quote! {
+                    dioxus_fullstack::prelude::__websocket::WebsocketServerFn::new(#path, __handler)
+                },
+            ),
+        };
+
+        quote! {
+            const _: () = {
+                async fn __server_body(#server_inputs) #server_output #block
+
+                fn __handler(
+                    args: String,
+                    socket: dioxus_fullstack::prelude::__websocket::RawWebsocket,
+                ) -> dioxus_fullstack::prelude::__websocket::WebsocketFuture {
+                    Box::pin(async move {
+                        let #args_struct { #(#names),* } =
+                            dioxus_fullstack::prelude::__websocket::decode_args(&args)?;
+                        __server_body(#(#names,)* socket.into_typed()).await
+                    })
+                }
+
+                #middleware
+
+                dioxus_fullstack::prelude::server_fn::inventory::submit! {
+                    #register
+                }
+            };
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        #[doc(hidden)]
+        #[derive(
+            dioxus_fullstack::prelude::server_fn::serde::Serialize,
+            dioxus_fullstack::prelude::server_fn::serde::Deserialize
+        )]
+        #[serde(crate = "dioxus_fullstack::prelude::server_fn::serde")]
+        #vis struct #args_struct {
+            #(#names: #types,)*
+        }
+
+        #(#attrs)*
+        #vis async fn #fn_name(#(#names: #types),*) -> Result<
+            dioxus_fullstack::prelude::Websocket<#server_receive, #server_send>,
+            dioxus_fullstack::prelude::ServerFnError,
+        > {
+            dioxus_fullstack::prelude::__websocket::connect(#path, &#args_struct { #(#names),* }).await
+        }
+
+        #server
+    })
+}
+
+fn is_arg(expr: &Expr, name: &str) -> bool {
+    matches!(expr, Expr::Path(path) if path.path.is_ident(name))
+}
+
+fn string_arg(expr: &Expr) -> syn::Result<String> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Ok(lit.value()),
+        _ => Err(syn::Error::new(expr.span(), "Expected a string literal")),
+    }
+}
+
+/// Get the type of messages the server sends and receives from the `Websocket<Out, In>` argument
+fn socket_types(socket: &FnArg) -> syn::Result<(Type, Type)> {
+    let error = || {
+        syn::Error::new(
+            socket.span(),
+            "The last argument of a websocket server function must be a `Websocket<Out, In>`",
+        )
+    };
+    let FnArg::Typed(socket) = socket else {
+        return Err(error());
+    };
+    let Type::Path(path) = &*socket.ty else {
+        return Err(error());
+    };
+    let segment = path.path.segments.last().ok_or_else(error)?;
+    if segment.ident != "Websocket" {
+        return Err(error());
+    }
+    let PathArguments::AngleBracketed(generics) = &segment.arguments else {
+        return Err(error());
+    };
+    let mut types = generics.args.iter().filter_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty.clone()),
+        _ => None,
+    });
+    match (types.next(), types.next(), types.next()) {
+        (Some(send), Some(receive), None) => Ok((send, receive)),
+        _ => Err(syn::Error::new(
+            generics.span(),
+            "Expected `Websocket<Out, In>`",
+        )),
+    }
+}
@@ -635,6 +635,12 @@ impl<T: 'static, S: Storage<SignalData<T>>> Drop for SignalSubscriberDrop<T, S>
                 }
             }
         }
+        // Write locations are only tracked in debug builds so they can show up in the profiler
+        #[cfg(debug_assertions)]
+        dioxus_core::prelude::with_update_location(self.origin, || {
+            self.signal.update_subscribers()
+        });
+        #[cfg(not(debug_assertions))]
         self.signal.update_subscribers();
     }
 }
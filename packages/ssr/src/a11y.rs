@@ -0,0 +1,758 @@
+//! Accessibility checks for rendered output
+//!
+//! The auditor renders a VirtualDom with the [`Renderer`](crate::Renderer), reads the HTML back into a tree and flags
+//! common WCAG failures without a browser. Because it checks the rendered HTML, it sees exactly what server side
+//! rendering sends to the browser:
+//! - images without alternative text
+//! - form controls and buttons without an accessible name
+//! - unknown ARIA roles and attributes
+//! - headings that skip a level
+//! - click handlers on elements that can't be focused with the keyboard
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_ssr::a11y::{audit_element, Rule};
+//!
+//! let report = audit_element(rsx! {
+//!     img { src: "cat.png" }
+//! });
+//! assert_eq!(report.issues()[0].rule, Rule::MissingAlt);
+//! ```
+
+use crate::Renderer;
+use dioxus_core::prelude::*;
+use dioxus_html::AriaRole;
+use std::fmt::{Display, Formatter};
+
+/// A node in the tree that is audited
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuditNode {
+    /// An element with attributes and children
+    Element(AuditElement),
+    /// A text node
+    Text(String),
+}
+
+/// An element in the tree that is audited
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditElement {
+    /// The tag name of the element
+    pub tag: String,
+    /// The attributes of the element in the order they were written
+    pub attributes: Vec<(String, String)>,
+    /// The events the element listens to, e.g. `"onclick"`
+    pub listeners: Vec<String>,
+    /// The children of the element
+    pub children: Vec<AuditNode>,
+}
+
+impl AuditElement {
+    /// Create a new element with no attributes or children
+    pub fn new(tag: impl Into<String>) -> Self {
+        Self {
+            tag: tag.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Add an attribute to the element
+    pub fn with_attribute(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.push((name.into(), value.into()));
+        self
+    }
+
+    /// Add an event listener to the element
+    pub fn with_listener(mut self, name: impl Into<String>) -> Self {
+        self.listeners.push(name.into());
+        self
+    }
+
+    /// Add a child to the element
+    pub fn with_child(mut self, child: impl Into<AuditNode>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Get the value of an attribute
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| attribute.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn has_non_empty(&self, name: &str) -> bool {
+        self.attribute(name)
+            .is_some_and(|value| !value.trim().is_empty())
+    }
+
+    fn listens_to(&self, event: &str) -> bool {
+        self.listeners.iter().any(|listener| listener == event)
+    }
+
+    /// The text content of the element and its descendants
+    fn text(&self) -> String {
+        let mut text = String::new();
+        for child in &self.children {
+            match child {
+                AuditNode::Text(contents) => text.push_str(contents),
+                AuditNode::Element(element) => {
+                    // Images contribute their alternative text to the name of their parent
+                    if let Some(alt) = element.attribute("alt") {
+                        text.push_str(alt);
+                    }
+                    text.push_str(&element.text());
+                }
+            }
+        }
+        text
+    }
+
+    /// The opening tag of the element, used to point at the element in reports
+    fn opening_tag(&self) -> String {
+        let mut tag = format!("<{}", self.tag);
+        for (name, value) in &self.attributes {
+            tag.push_str(&format!(" {name}=\"{value}\""));
+        }
+        tag.push('>');
+        tag
+    }
+}
+
+impl From<AuditElement> for AuditNode {
+    fn from(element: AuditElement) -> Self {
+        AuditNode::Element(element)
+    }
+}
+
+impl From<&str> for AuditNode {
+    fn from(text: &str) -> Self {
+        AuditNode::Text(text.to_string())
+    }
+}
+
+impl From<String> for AuditNode {
+    fn from(text: String) -> Self {
+        AuditNode::Text(text)
+    }
+}
+
+/// An accessibility rule the auditor checks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// Images must have an `alt` attribute
+    MissingAlt,
+    /// Form controls and buttons must have an accessible name
+    UnlabeledControl,
+    /// The `role` attribute must be a known ARIA role
+    InvalidRole,
+    /// `aria-*` attributes must be known ARIA attributes with valid values
+    InvalidAria,
+    /// Headings must not skip a level
+    HeadingOrder,
+    /// Elements with click handlers must be focusable
+    ClickNotFocusable,
+}
+
+impl Rule {
+    /// Get the name of the rule, e.g. `missing-alt`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rule::MissingAlt => "missing-alt",
+            Rule::UnlabeledControl => "unlabeled-control",
+            Rule::InvalidRole => "invalid-role",
+            Rule::InvalidAria => "invalid-aria",
+            Rule::HeadingOrder => "heading-order",
+            Rule::ClickNotFocusable => "click-not-focusable",
+        }
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// An accessibility issue found in the rendered output
+#[derive(Debug, Clone, PartialEq)]
+pub struct Issue {
+    /// The rule that failed
+    pub rule: Rule,
+    /// The opening tag of the element that failed the rule
+    pub element: String,
+    /// The tags of the ancestors of the element, e.g. `div > form > input`
+    pub path: String,
+    /// A description of the problem
+    pub message: String,
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} at `{}`\n  {}",
+            self.rule, self.element, self.path, self.message
+        )
+    }
+}
+
+/// The issues found by an audit
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    issues: Vec<Issue>,
+}
+
+impl Report {
+    /// Get the issues that were found
+    pub fn issues(&self) -> &[Issue] {
+        &self.issues
+    }
+
+    /// Check if no issues were found
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Get the issues that failed a specific rule
+    pub fn issues_for(&self, rule: Rule) -> impl Iterator<Item = &Issue> {
+        self.issues.iter().filter(move |issue| issue.rule == rule)
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for issue in &self.issues {
+            writeln!(f, "{issue}")?;
+        }
+        match self.issues.len() {
+            0 => write!(f, "No accessibility issues found."),
+            1 => write!(f, "1 accessibility issue found."),
+            count => write!(f, "{count} accessibility issues found."),
+        }
+    }
+}
+
+/// Audit a VirtualDom with every rule enabled
+pub fn audit(dom: &VirtualDom) -> Report {
+    Auditor::new().audit(dom)
+}
+
+/// Audit an `rsx!` call with every rule enabled
+pub fn audit_element(element: Element) -> Report {
+    fn lazy_app(props: Element) -> Element {
+        props
+    }
+
+    let mut dom = VirtualDom::new_with_props(lazy_app, element);
+    dom.rebuild_in_place();
+
+    audit(&dom)
+}
+
+/// Audit a VirtualDom and panic with the report if any issues are found. This is useful as a test assertion.
+///
+/// ```rust, should_panic
+/// use dioxus::prelude::*;
+///
+/// fn app() -> Element {
+///     rsx! { div { onclick: |_| {}, "Open the menu" } }
+/// }
+///
+/// let mut dom = VirtualDom::new(app);
+/// dom.rebuild_in_place();
+/// dioxus_ssr::a11y::assert_accessible(&dom);
+/// ```
+#[track_caller]
+pub fn assert_accessible(dom: &VirtualDom) {
+    let report = audit(dom);
+    if !report.is_empty() {
+        panic!("{report}");
+    }
+}
+
+/// Checks rendered output for accessibility issues
+#[derive(Debug, Clone, Default)]
+pub struct Auditor {
+    ignored: Vec<Rule>,
+}
+
+impl Auditor {
+    /// Create an auditor that checks every rule
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skip a rule
+    pub fn ignore(mut self, rule: Rule) -> Self {
+        self.ignored.push(rule);
+        self
+    }
+
+    /// Render the current contents of a VirtualDom and audit the HTML
+    pub fn audit(&self, dom: &VirtualDom) -> Report {
+        // Pre-rendering writes the events each element listens to, which the click rule needs
+        let mut renderer = Renderer::new();
+        renderer.pre_render = true;
+        let mut html = renderer.render(dom);
+        for (_, contents) in renderer.take_portals() {
+            html.push_str(&contents);
+        }
+        self.audit_html(&html)
+    }
+
+    /// Audit HTML that was rendered ahead of time, like the pages written by static generation
+    pub fn audit_html(&self, html: &str) -> Report {
+        self.audit_nodes(&nodes_from_html(html))
+    }
+
+    /// Audit a tree of nodes. Use this to audit output that was not rendered by a VirtualDom, like pre-rendered HTML.
+    pub fn audit_nodes(&self, nodes: &[AuditNode]) -> Report {
+        let mut ids = Vec::new();
+        collect_label_targets(nodes, &mut ids);
+
+        let mut audit = Audit {
+            auditor: self,
+            labelled_ids: ids,
+            path: Vec::new(),
+            last_heading: None,
+            issues: Vec::new(),
+        };
+        audit.nodes(nodes, Context::default());
+
+        Report {
+            issues: audit.issues,
+        }
+    }
+}
+
+/// Elements that never have children or a closing tag
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Read HTML into the tree the auditor checks. Comments, doctypes and the contents of scripts and styles are skipped.
+/// Elements the renderer marked for hydration list the events they listen to, which are read back as listeners.
+pub fn nodes_from_html(html: &str) -> Vec<AuditNode> {
+    let mut open: Vec<AuditElement> = Vec::new();
+    let mut roots = Vec::new();
+    let mut rest = html;
+
+    fn push(open: &mut [AuditElement], roots: &mut Vec<AuditNode>, node: AuditNode) {
+        match open.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => roots.push(node),
+        }
+    }
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.split_once("-->").map_or("", |(_, rest)| rest);
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.split_once('>').map_or("", |(_, rest)| rest);
+        } else if let Some(closing) = rest.strip_prefix("</") {
+            let (tag, after) = closing.split_once('>').unwrap_or((closing, ""));
+            let tag = tag.trim().to_ascii_lowercase();
+            // Close every element that was left open inside the element that is closed
+            if open.iter().any(|element| element.tag == tag) {
+                while let Some(element) = open.pop() {
+                    let done = element.tag == tag;
+                    push(&mut open, &mut roots, element.into());
+                    if done {
+                        break;
+                    }
+                }
+            }
+            rest = after;
+        } else if rest.starts_with('<') && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+        {
+            let (element, self_closing, after) = read_start_tag(&rest[1..]);
+            rest = after;
+            if matches!(element.tag.as_str(), "script" | "style") {
+                // Scripts and styles are raw text that may contain `<`
+                let end = format!("</{}", element.tag);
+                let lowercase = rest.to_ascii_lowercase();
+                let content_end = lowercase.find(&end).unwrap_or(rest.len());
+                rest = &rest[content_end..];
+                push(&mut open, &mut roots, element.into());
+                rest = rest.split_once('>').map_or("", |(_, rest)| rest);
+            } else if self_closing || VOID_ELEMENTS.contains(&element.tag.as_str()) {
+                push(&mut open, &mut roots, element.into());
+            } else {
+                open.push(element);
+            }
+        } else {
+            let end = rest[1..].find('<').map_or(rest.len(), |end| end + 1);
+            push(
+                &mut open,
+                &mut roots,
+                AuditNode::Text(rest[..end].to_string()),
+            );
+            rest = &rest[end..];
+        }
+    }
+    while let Some(element) = open.pop() {
+        push(&mut open, &mut roots, element.into());
+    }
+    roots
+}
+
+/// Read a start tag without the leading `<`. Returns the element, if the tag closes itself and the rest of the input.
+fn read_start_tag(html: &str) -> (AuditElement, bool, &str) {
+    let name_end = html
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .unwrap_or(html.len());
+    let mut element = AuditElement::new(html[..name_end].to_ascii_lowercase());
+    let mut rest = &html[name_end..];
+
+    loop {
+        rest = rest.trim_start();
+        if let Some(after) = rest.strip_prefix("/>") {
+            return (element, true, after);
+        }
+        if let Some(after) = rest.strip_prefix('>') {
+            return (element, false, after);
+        }
+        if rest.is_empty() {
+            return (element, false, rest);
+        }
+        if let Some(after) = rest.strip_prefix('/') {
+            rest = after;
+            continue;
+        }
+
+        let name_end = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/'))
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_string();
+        rest = rest[name_end..].trim_start();
+
+        let mut value = String::new();
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            let (raw, remaining) = match after.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let quoted = &after[1..];
+                    let end = quoted.find(quote).unwrap_or(quoted.len());
+                    (&quoted[..end], quoted.get(end + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let end = after
+                        .find(|c: char| c.is_whitespace() || c == '>')
+                        .unwrap_or(after.len());
+                    after.split_at(end)
+                }
+            };
+            value = raw.to_string();
+            rest = remaining;
+        }
+
+        if name == "data-node-hydration" {
+            // Hydration ids list the listeners of the element after the node id, e.g. `3,click:1`
+            for listener in value.split(',').skip(1) {
+                let event = listener.split(':').next().unwrap_or_default();
+                element.listeners.push(format!("on{event}"));
+            }
+        } else {
+            element.attributes.push((name, value));
+        }
+    }
+}
+
+/// Collect the ids that `label` elements point to with the `for` attribute
+fn collect_label_targets(nodes: &[AuditNode], ids: &mut Vec<String>) {
+    for node in nodes {
+        if let AuditNode::Element(element) = node {
+            if element.tag == "label" {
+                if let Some(id) = element.attribute("for") {
+                    ids.push(id.to_string());
+                }
+            }
+            collect_label_targets(&element.children, ids);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct Context {
+    /// If the element is inside a `label`
+    in_label: bool,
+    /// If the element is hidden from assistive technology
+    hidden: bool,
+}
+
+struct Audit<'a> {
+    auditor: &'a Auditor,
+    labelled_ids: Vec<String>,
+    path: Vec<String>,
+    last_heading: Option<u8>,
+    issues: Vec<Issue>,
+}
+
+impl Audit<'_> {
+    fn nodes(&mut self, nodes: &[AuditNode], context: Context) {
+        for node in nodes {
+            if let AuditNode::Element(element) = node {
+                self.element(element, context);
+            }
+        }
+    }
+
+    fn element(&mut self, element: &AuditElement, mut context: Context) {
+        self.path.push(element.tag.clone());
+
+        context.hidden |= element.attribute("aria-hidden") == Some("true")
+            || element
+                .attribute("hidden")
+                .is_some_and(|value| value != "false");
+
+        self.check_aria(element);
+        if !context.hidden {
+            self.check_alt(element);
+            self.check_label(element, context);
+            self.check_heading(element);
+            self.check_click(element);
+        }
+
+        context.in_label |= element.tag == "label";
+        self.nodes(&element.children, context);
+
+        self.path.pop();
+    }
+
+    fn report(&mut self, rule: Rule, element: &AuditElement, message: impl Into<String>) {
+        if self.auditor.ignored.contains(&rule) {
+            return;
+        }
+        self.issues.push(Issue {
+            rule,
+            element: element.opening_tag(),
+            path: self.path.join(" > "),
+            message: message.into(),
+        });
+    }
+
+    fn check_alt(&mut self, element: &AuditElement) {
+        let needs_alt = match element.tag.as_str() {
+            "img" => !matches!(element.attribute("role"), Some("presentation" | "none")),
+            "area" => element.attribute("href").is_some(),
+            "input" => element.attribute("type") == Some("image"),
+            _ => false,
+        };
+        let named = element.attribute("alt").is_some()
+            || element.has_non_empty("aria-label")
+            || element.has_non_empty("aria-labelledby");
+        if needs_alt && !named {
+            self.report(
+                Rule::MissingAlt,
+                element,
+                "Add an `alt` attribute describing the image, or `alt: \"\"` if it is decorative",
+            );
+        }
+    }
+
+    fn check_label(&mut self, element: &AuditElement, context: Context) {
+        let has_name = element.has_non_empty("aria-label")
+            || element.has_non_empty("aria-labelledby")
+            || element.has_non_empty("title");
+
+        match element.tag.as_str() {
+            "input" | "select" | "textarea" => {
+                let input_type = element.attribute("type").unwrap_or("text");
+                if element.tag == "input" {
+                    match input_type {
+                        // Hidden inputs are not shown and image inputs are checked by the alt rule
+                        "hidden" | "image" => return,
+                        // Buttons are named by their value or a default label from the browser
+                        "submit" | "reset" => return,
+                        "button" => {
+                            if !has_name && !element.has_non_empty("value") {
+                                self.report(
+                                    Rule::UnlabeledControl,
+                                    element,
+                                    "Add a `value` or `aria_label` to name the button",
+                                );
+                            }
+                            return;
+                        }
+                        _ => {}
+                    }
+                }
+                let labelled_by_id = element
+                    .attribute("id")
+                    .is_some_and(|id| self.labelled_ids.iter().any(|target| target == id));
+                if !has_name && !context.in_label && !labelled_by_id {
+                    self.report(
+                        Rule::UnlabeledControl,
+                        element,
+                        "Wrap the control in a `label`, point a `label` at its `id`, or add an `aria_label`",
+                    );
+                }
+            }
+            "button" if !has_name && element.text().trim().is_empty() => {
+                self.report(
+                    Rule::UnlabeledControl,
+                    element,
+                    "Add text or an `aria_label` to name the button",
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn check_aria(&mut self, element: &AuditElement) {
+        if let Some(role) = element.attribute("role") {
+            // Multiple roles can be listed as fallbacks, so every one of them must be valid
+            for role in role.split_whitespace() {
+                if let Err(err) = role.parse::<AriaRole>() {
+                    self.report(Rule::InvalidRole, element, err.to_string());
+                }
+            }
+        }
+
+        for (name, value) in &element.attributes {
+            let Some(aria) = name.strip_prefix("aria-") else {
+                continue;
+            };
+            if !ARIA_ATTRIBUTES.contains(&aria) {
+                self.report(
+                    Rule::InvalidAria,
+                    element,
+                    format!("`{name}` is not a valid ARIA attribute"),
+                );
+                continue;
+            }
+            let allowed: &[&str] = match aria {
+                "atomic" | "busy" | "disabled" | "modal" | "multiline" | "multiselectable"
+                | "readonly" | "required" => &["true", "false"],
+                "expanded" | "grabbed" | "hidden" | "selected" => &["true", "false", "undefined"],
+                "checked" | "pressed" => &["true", "false", "mixed", "undefined"],
+                _ => continue,
+            };
+            if !allowed.contains(&value.as_str()) {
+                self.report(
+                    Rule::InvalidAria,
+                    element,
+                    format!(
+                        "`{name}` must be one of {}, found `{value}`",
+                        allowed.join(", ")
+                    ),
+                );
+            }
+        }
+    }
+
+    fn check_heading(&mut self, element: &AuditElement) {
+        let level = match element.attribute("role") {
+            Some("heading") => element
+                .attribute("aria-level")
+                .and_then(|level| level.parse().ok())
+                .unwrap_or(2),
+            _ => match element.tag.as_str() {
+                "h1" => 1,
+                "h2" => 2,
+                "h3" => 3,
+                "h4" => 4,
+                "h5" => 5,
+                "h6" => 6,
+                _ => return,
+            },
+        };
+        if let Some(last) = self.last_heading {
+            if level > last + 1 {
+                self.report(
+                    Rule::HeadingOrder,
+                    element,
+                    format!(
+                        "Heading level {level} follows level {last}. Headings should only increase by one level"
+                    ),
+                );
+            }
+        }
+        self.last_heading = Some(level);
+    }
+
+    fn check_click(&mut self, element: &AuditElement) {
+        if !element.listens_to("onclick") {
+            return;
+        }
+        let natively_focusable = match element.tag.as_str() {
+            "button" | "select" | "textarea" | "summary" | "iframe" => true,
+            "input" => element.attribute("type") != Some("hidden"),
+            "a" | "area" => element.attribute("href").is_some(),
+            _ => false,
+        };
+        let focusable = natively_focusable
+            || element
+                .attribute("tabindex")
+                .is_some_and(|index| !index.trim_start().starts_with('-'))
+            || element
+                .attribute("contenteditable")
+                .is_some_and(|editable| editable != "false");
+        if !focusable {
+            self.report(
+                Rule::ClickNotFocusable,
+                element,
+                "Use a `button`, or add a `tabindex` and `role` so the element can be used with a keyboard",
+            );
+        }
+    }
+}
+
+/// Every `aria-*` attribute in WAI-ARIA 1.2 without the prefix
+const ARIA_ATTRIBUTES: &[&str] = &[
+    "activedescendant",
+    "atomic",
+    "autocomplete",
+    "braillelabel",
+    "brailleroledescription",
+    "busy",
+    "checked",
+    "colcount",
+    "colindex",
+    "colindextext",
+    "colspan",
+    "controls",
+    "current",
+    "describedby",
+    "description",
+    "details",
+    "disabled",
+    "dropeffect",
+    "errormessage",
+    "expanded",
+    "flowto",
+    "grabbed",
+    "haspopup",
+    "hidden",
+    "invalid",
+    "keyshortcuts",
+    "label",
+    "labelledby",
+    "level",
+    "live",
+    "modal",
+    "multiline",
+    "multiselectable",
+    "orientation",
+    "owns",
+    "placeholder",
+    "posinset",
+    "pressed",
+    "readonly",
+    "relevant",
+    "required",
+    "roledescription",
+    "rowcount",
+    "rowindex",
+    "rowindextext",
+    "rowspan",
+    "selected",
+    "setsize",
+    "sort",
+    "valuemax",
+    "valuemin",
+    "valuenow",
+    "valuetext",
+];
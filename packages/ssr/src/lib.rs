@@ -2,6 +2,7 @@
 #![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
 #![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
 
+pub mod a11y;
 mod cache;
 pub mod config;
 #[cfg(feature = "incremental")]
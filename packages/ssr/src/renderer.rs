@@ -24,6 +24,9 @@ pub struct Renderer {
 
     /// The current dynamic node id for hydration
     dynamic_node_id: usize,
+
+    /// The contents of the portals rendered in the last render, keyed by the portal target
+    portals: FxHashMap<String, String>,
 }
 
 impl Renderer {
@@ -59,9 +62,23 @@ impl Renderer {
         dom: &VirtualDom,
     ) -> std::fmt::Result {
         self.reset_hydration();
+        self.portals.clear();
         self.render_scope(buf, dom, ScopeId::ROOT)
     }
 
+    /// Take the contents rendered into the portal with the given target during the last render
+    ///
+    /// Portals render their children outside of the element they are placed in. The children are not included in the main
+    /// output of the renderer, you need to insert them into the target yourself.
+    pub fn take_portal(&mut self, target: &str) -> Option<String> {
+        self.portals.remove(target)
+    }
+
+    /// Take the contents of every portal rendered during the last render along with the target they render into
+    pub fn take_portals(&mut self) -> impl Iterator<Item = (String, String)> + '_ {
+        self.portals.drain()
+    }
+
     /// Reset the renderer hydration state
     pub fn reset_hydration(&mut self) {
         self.dynamic_node_id = 0;
@@ -73,12 +90,30 @@ impl Renderer {
         dom: &VirtualDom,
         scope: ScopeId,
     ) -> std::fmt::Result {
-        let node = dom.get_scope(scope).unwrap().root_node();
-        self.render_template(buf, dom, node)?;
+        let scope = dom.get_scope(scope).unwrap();
+        if let Some(portal) = PortalProps::downcast_from_scope(scope) {
+            self.render_portal(dom, portal)?;
+        }
+        self.render_template(buf, dom, scope.root_node())?;
 
         Ok(())
     }
 
+    fn render_portal(&mut self, dom: &VirtualDom, portal: &PortalProps) -> std::fmt::Result {
+        let Some(children) = portal.rendered_children() else {
+            return Ok(());
+        };
+
+        // The portal children are not part of the hydrated tree, so we never write hydration ids for them
+        let pre_render = std::mem::replace(&mut self.pre_render, false);
+        let mut contents = self.portals.remove(portal.target()).unwrap_or_default();
+        let result = self.render_template(&mut contents, dom, children);
+        self.pre_render = pre_render;
+        self.portals.insert(portal.target().to_string(), contents);
+
+        result
+    }
+
     fn render_template<W: Write + ?Sized>(
         &mut self,
         mut buf: &mut W,
@@ -144,9 +179,8 @@ impl Renderer {
 
                             render_components(self, &mut buf, dom, scope_id)?;
                         } else {
-                            let scope = node.mounted_scope(*idx, template, dom).unwrap();
-                            let node = scope.root_node();
-                            self.render_template(buf, dom, node)?
+                            let scope_id = node.mounted_scope_id(*idx, template, dom).unwrap();
+                            self.render_scope(buf, dom, scope_id)?
                         }
                     }
                     DynamicNode::Text(text) => {
@@ -0,0 +1,164 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_ssr::a11y::{audit, audit_element, AuditElement, Auditor, Rule};
+
+fn rules(element: Element) -> Vec<Rule> {
+    audit_element(element)
+        .issues()
+        .iter()
+        .map(|issue| issue.rule)
+        .collect()
+}
+
+/// Audit a component. Event handlers can only be created inside a running VirtualDom.
+fn component_rules(app: fn() -> Element) -> Vec<Rule> {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    audit(&dom)
+        .issues()
+        .iter()
+        .map(|issue| issue.rule)
+        .collect()
+}
+
+#[test]
+fn accessible_markup_passes() {
+    fn app() -> Element {
+        rsx! {
+            h1 { "Sign up" }
+            img { src: "logo.png", alt: "" }
+            form {
+                label { "Email" input { r#type: "email" } }
+                label { r#for: "age", "Age" }
+                input { id: "age", r#type: "number" }
+                input { r#type: "hidden", name: "token" }
+                h2 { "Preferences" }
+                select { aria_label: "Theme" }
+                button { onclick: |_| {}, "Submit" }
+            }
+            div { role: "button", tabindex: 0, onclick: |_| {}, "Open" }
+        }
+    }
+
+    assert!(component_rules(app).is_empty());
+}
+
+#[test]
+fn images_need_alt_text() {
+    assert_eq!(rules(rsx! { img { src: "cat.png" } }), [Rule::MissingAlt]);
+    assert_eq!(
+        rules(rsx! { input { r#type: "image", src: "go.png" } }),
+        [Rule::MissingAlt]
+    );
+    // Decorative and hidden images don't need alt text
+    assert!(rules(rsx! { img { src: "cat.png", role: "presentation" } }).is_empty());
+    assert!(rules(rsx! { div { aria_hidden: "true", img { src: "cat.png" } } }).is_empty());
+}
+
+#[test]
+fn form_controls_need_labels() {
+    let name = "email";
+    let report = audit_element(rsx! {
+        form {
+            input { name: "{name}" }
+            textarea {}
+            button { "" }
+            input { r#type: "submit" }
+        }
+    });
+    let issues = report.issues_for(Rule::UnlabeledControl).count();
+    assert_eq!(issues, 3, "{report}");
+    assert_eq!(report.issues()[0].path, "form > input");
+    assert_eq!(report.issues()[0].element, r#"<input name="email">"#);
+}
+
+#[test]
+fn aria_roles_and_attributes_are_checked() {
+    assert_eq!(rules(rsx! { div { role: "buton" } }), [Rule::InvalidRole]);
+    assert_eq!(
+        rules(rsx! { div { "aria-lable": "Menu" } }),
+        [Rule::InvalidAria]
+    );
+    assert_eq!(
+        rules(rsx! { button { aria_pressed: "yes", "Bold" } }),
+        [Rule::InvalidAria]
+    );
+    assert!(rules(rsx! { button { aria_pressed: "mixed", "Bold" } }).is_empty());
+}
+
+#[test]
+fn headings_must_not_skip_levels() {
+    fn Section() -> Element {
+        rsx! { h4 { "Details" } }
+    }
+
+    assert_eq!(
+        rules(rsx! {
+            h1 { "Title" }
+            h2 { "Summary" }
+            Section {}
+        }),
+        [Rule::HeadingOrder]
+    );
+    // Going back up any number of levels is fine
+    assert!(rules(rsx! {
+        h1 { "Title" }
+        h2 { "Summary" }
+        h3 { "Details" }
+        h2 { "More" }
+    })
+    .is_empty());
+}
+
+#[test]
+fn click_handlers_need_focusable_elements() {
+    fn Menu() -> Element {
+        rsx! { div { onclick: |_| {}, "Menu" } }
+    }
+    fn LinkWithoutHref() -> Element {
+        rsx! { a { onclick: |_| {}, "Menu" } }
+    }
+    fn Link() -> Element {
+        rsx! { a { href: "#", onclick: |_| {}, "Menu" } }
+    }
+
+    assert_eq!(component_rules(Menu), [Rule::ClickNotFocusable]);
+    assert_eq!(component_rules(LinkWithoutHref), [Rule::ClickNotFocusable]);
+    assert!(component_rules(Link).is_empty());
+}
+
+#[test]
+fn rules_can_be_ignored() {
+    fn app() -> Element {
+        rsx! { img { src: "cat.png" } }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert!(Auditor::new()
+        .ignore(Rule::MissingAlt)
+        .audit(&dom)
+        .is_empty());
+}
+
+#[test]
+fn nodes_can_be_audited_without_a_virtual_dom() {
+    let page = AuditElement::new("main")
+        .with_child(AuditElement::new("span").with_listener("onclick"))
+        .with_child(AuditElement::new("img").with_attribute("src", "cat.png"));
+    let report = Auditor::new().audit_nodes(&[page.into()]);
+    let rules: Vec<_> = report.issues().iter().map(|issue| issue.rule).collect();
+    assert_eq!(rules, [Rule::ClickNotFocusable, Rule::MissingAlt]);
+}
+
+#[test]
+fn pre_rendered_pages_can_be_audited() {
+    let page = r#"<!DOCTYPE html><html><head><script>if (1 < 2) { document.write("<img>") }</script></head>
+        <body><div id="main"><div data-node-hydration="0,click:1">Open</div><img src=cat.png/><input type="checkbox" hidden=true></div></body></html>"#;
+    let report = Auditor::new().audit_html(page);
+    let rules: Vec<_> = report.issues().iter().map(|issue| issue.rule).collect();
+    // The image in the script is not part of the page and the hidden input doesn't need a label
+    assert_eq!(rules, [Rule::ClickNotFocusable, Rule::MissingAlt]);
+    assert_eq!(report.issues()[0].element, "<div>");
+}
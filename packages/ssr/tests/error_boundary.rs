@@ -0,0 +1,66 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_ssr::Renderer;
+
+fn ThrowChild() -> Element {
+    Err(std::io::Error::other("failed"))?;
+    rsx! { "unreachable" }
+}
+
+#[test]
+fn error_handlers_are_rendered() {
+    fn App() -> Element {
+        rsx! {
+            div {
+                ErrorBoundary {
+                    handle_error: |_| rsx! { p { "something went wrong" } },
+                    ThrowChild {}
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+
+    let mut renderer = Renderer::new();
+    assert_eq!(
+        renderer.render(&dom),
+        "<div><p>something went wrong</p></div>"
+    );
+
+    // The error handler is hydrated like any other element
+    renderer.pre_render = true;
+    let html = renderer.render(&dom);
+    assert!(html.contains("something went wrong"));
+    assert!(!html.contains("unreachable"));
+}
+
+#[test]
+fn rethrown_errors_are_rendered_by_the_outer_boundary() {
+    fn App() -> Element {
+        rsx! {
+            ErrorBoundary {
+                handle_error: |_| rsx! { "outer" },
+                div {
+                    ErrorBoundary {
+                        handle_error: |errors: ErrorContext| {
+                            errors.rethrow();
+                            rsx! { "inner" }
+                        },
+                        ThrowChild {}
+                    }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+
+    assert_eq!(dioxus_ssr::render(&dom), "outer");
+}
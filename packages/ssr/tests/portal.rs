@@ -0,0 +1,37 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_ssr::Renderer;
+
+#[test]
+fn portals_render_into_their_target() {
+    fn App() -> Element {
+        rsx! {
+            div {
+                "page"
+                Portal { target: "modal", p { "first" } }
+                Portal { target: "modal", p { "second" } }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    let mut renderer = Renderer::new();
+    assert_eq!(renderer.render(&dom), "<div>page</div>");
+    assert_eq!(
+        renderer.take_portal("modal").as_deref(),
+        Some("<p>first</p><p>second</p>")
+    );
+    assert_eq!(renderer.take_portal("modal"), None);
+
+    // Portal contents are never hydrated
+    renderer.pre_render = true;
+    let html = renderer.render(&dom);
+    assert!(html.contains("<!--placeholder"));
+    assert_eq!(
+        renderer.take_portals().collect::<Vec<_>>(),
+        [("modal".to_string(), "<p>first</p><p>second</p>".to_string())]
+    );
+}
@@ -1,3 +1,5 @@
+use dioxus_core::TemplateCache;
+
 ///  Configuration for the WebSys renderer for the Dioxus VirtualDOM.
 ///
 /// This struct helps configure the specifics of hydration and render destination for WebSys.
@@ -11,6 +13,7 @@ pub struct Config {
     pub(crate) hydrate: bool,
     pub(crate) root: ConfigRoot,
     pub(crate) default_panic_hook: bool,
+    pub(crate) template_cache: Option<TemplateCache>,
 }
 
 pub(crate) enum ConfigRoot {
@@ -63,6 +66,16 @@ impl Config {
         self.default_panic_hook = f;
         self
     }
+
+    /// Load the templates in a [`TemplateCache`] before the first render.
+    ///
+    /// The templates are created once when the app starts, and the first render only sends the dynamic parts of each
+    /// template to the interpreter. `dx build` writes the templates of the crate to `templates.json` in the output
+    /// directory, which can be fetched and loaded with `TemplateCache::load` before the app is launched.
+    pub fn with_template_cache(mut self, cache: TemplateCache) -> Self {
+        self.template_cache = Some(cache);
+        self
+    }
 }
 
 impl Default for Config {
@@ -71,6 +84,7 @@ impl Default for Config {
             hydrate: false,
             root: ConfigRoot::RootName("main".to_string()),
             default_panic_hook: true,
+            template_cache: None,
         }
     }
 }
@@ -29,6 +29,14 @@ impl HtmlEventConverter for WebEventConverter {
         downcast_event(event).raw.clone().into()
     }
 
+    #[inline(always)]
+    fn convert_before_input_data(
+        &self,
+        event: &dioxus_html::PlatformEventData,
+    ) -> dioxus_html::BeforeInputData {
+        downcast_event(event).raw.clone().into()
+    }
+
     #[inline(always)]
     fn convert_clipboard_data(
         &self,
@@ -45,6 +53,14 @@ impl HtmlEventConverter for WebEventConverter {
         downcast_event(event).raw.clone().into()
     }
 
+    #[inline(always)]
+    fn convert_custom_data(
+        &self,
+        event: &dioxus_html::PlatformEventData,
+    ) -> dioxus_html::CustomData {
+        downcast_event(event).raw.clone().into()
+    }
+
     #[inline(always)]
     fn convert_drag_data(&self, event: &dioxus_html::PlatformEventData) -> dioxus_html::DragData {
         let event = downcast_event(event);
@@ -62,6 +78,14 @@ impl HtmlEventConverter for WebEventConverter {
         FormData::new(WebFormData::new(event.element.clone(), event.raw.clone()))
     }
 
+    #[inline(always)]
+    fn convert_fullscreen_data(
+        &self,
+        event: &dioxus_html::PlatformEventData,
+    ) -> dioxus_html::FullscreenData {
+        downcast_event(event).raw.clone().into()
+    }
+
     #[inline(always)]
     fn convert_image_data(&self, event: &dioxus_html::PlatformEventData) -> dioxus_html::ImageData {
         let event = downcast_event(event);
@@ -112,6 +136,14 @@ impl HtmlEventConverter for WebEventConverter {
         downcast_event(event).raw.clone().into()
     }
 
+    #[inline(always)]
+    fn convert_resize_data(
+        &self,
+        event: &dioxus_html::PlatformEventData,
+    ) -> dioxus_html::ResizeData {
+        downcast_event(event).raw.clone().into()
+    }
+
     #[inline(always)]
     fn convert_scroll_data(
         &self,
@@ -149,6 +181,14 @@ impl HtmlEventConverter for WebEventConverter {
         downcast_event(event).raw.clone().into()
     }
 
+    #[inline(always)]
+    fn convert_visibility_data(
+        &self,
+        event: &dioxus_html::PlatformEventData,
+    ) -> dioxus_html::VisibilityData {
+        downcast_event(event).raw.clone().into()
+    }
+
     #[inline(always)]
     fn convert_wheel_data(&self, event: &dioxus_html::PlatformEventData) -> dioxus_html::WheelData {
         downcast_event(event).raw.clone().into()
@@ -168,6 +208,13 @@ impl WebEventExt<web_sys::AnimationEvent> for dioxus_html::AnimationData {
     }
 }
 
+impl WebEventExt<web_sys::InputEvent> for dioxus_html::BeforeInputData {
+    fn web_event(&self) -> &web_sys::InputEvent {
+        self.downcast::<web_sys::InputEvent>()
+            .expect("event should be a WebBeforeInputEvent")
+    }
+}
+
 impl WebEventExt<web_sys::Event> for dioxus_html::ClipboardData {
     fn web_event(&self) -> &web_sys::Event {
         self.downcast::<web_sys::Event>()
@@ -205,6 +252,13 @@ impl WebEventExt<web_sys::Event> for dioxus_html::FormData {
     }
 }
 
+impl WebEventExt<web_sys::Event> for dioxus_html::FullscreenData {
+    fn web_event(&self) -> &web_sys::Event {
+        self.downcast::<web_sys::Event>()
+            .expect("event should be a WebFullscreenEvent")
+    }
+}
+
 impl WebEventExt<WebImageEvent> for dioxus_html::ImageData {
     fn web_event(&self) -> &WebImageEvent {
         self.downcast::<WebImageEvent>()
@@ -247,6 +301,13 @@ impl WebEventExt<web_sys::PointerEvent> for dioxus_html::PointerData {
     }
 }
 
+impl WebEventExt<web_sys::Event> for dioxus_html::ResizeData {
+    fn web_event(&self) -> &web_sys::Event {
+        self.downcast::<web_sys::Event>()
+            .expect("event should be a WebResizeEvent")
+    }
+}
+
 impl WebEventExt<web_sys::Event> for ScrollData {
     fn web_event(&self) -> &web_sys::Event {
         self.downcast::<web_sys::Event>()
@@ -282,6 +343,13 @@ impl WebEventExt<web_sys::TransitionEvent> for dioxus_html::TransitionData {
     }
 }
 
+impl WebEventExt<web_sys::Event> for dioxus_html::VisibilityData {
+    fn web_event(&self) -> &web_sys::Event {
+        self.downcast::<web_sys::Event>()
+            .expect("event should be a WebVisibilityEvent")
+    }
+}
+
 impl WebEventExt<web_sys::WheelEvent> for dioxus_html::WheelData {
     fn web_event(&self) -> &web_sys::WheelEvent {
         self.downcast::<web_sys::WheelEvent>()
@@ -38,6 +38,19 @@ pub(crate) fn init() -> UnboundedReceiver<Template> {
                 let val: &'static serde_json::Value = Box::leak(Box::new(val));
                 let template: Template = Template::deserialize(val).unwrap();
                 tx.unbounded_send(template).unwrap();
+            } else if let Some(module) = string.strip_prefix("reload-css-module: ") {
+                // replace the contents of every style element rendered for the module
+                let (id, css) = module.split_once('\n').unwrap_or((module, ""));
+                let styles = web_sys::window()
+                    .unwrap()
+                    .document()
+                    .unwrap()
+                    .query_selector_all(&format!("style[data-css-module=\"{id}\"]"))
+                    .unwrap();
+
+                for x in 0..styles.length() {
+                    styles.get(x).unwrap().set_text_content(Some(css));
+                }
             } else {
                 // it might be triggering a reload of assets
                 // invalidate all the stylesheets on the page
@@ -12,9 +12,10 @@ use dioxus_core::prelude::*;
 use dioxus_core::AttributeValue;
 use dioxus_core::{DynamicNode, ElementId};
 use futures_channel::mpsc::UnboundedReceiver;
+use wasm_bindgen::JsCast;
 use RehydrationError::*;
 
-use super::SuspenseMessage;
+use super::{HydrationMismatch, SuspenseMessage};
 
 #[derive(Debug)]
 #[non_exhaustive]
@@ -159,45 +160,137 @@ impl WebsysDom {
         // Remove the streaming div
         resolved_suspense_element.remove();
 
-        let Some(root_scope) = dom.get_scope(id) else {
+        if dom.get_scope(id).is_none() {
             // If the scope was removed on the client, we may not be able to rehydrate it, but this shouldn't cause an error
             return Ok(());
-        };
+        }
 
         // As we hydrate the suspense boundary, set the current path to the path of the suspense boundary
         self.suspense_hydration_ids
             .current_path
             .clone_from(&suspense_path);
-        self.start_hydration_at_scope(root_scope, dom, children)?;
+        self.start_hydration_at_scope(id, dom, children)?;
 
         Ok(())
     }
 
     fn start_hydration_at_scope(
         &mut self,
-        scope: &ScopeState,
-        dom: &VirtualDom,
+        scope_id: ScopeId,
+        dom: &mut VirtualDom,
         under: Vec<web_sys::Node>,
     ) -> Result<(), RehydrationError> {
         let mut ids = Vec::new();
         let mut to_mount = Vec::new();
 
         // Recursively rehydrate the nodes under the scope
+        let scope = dom.get_scope(scope_id).ok_or(VNodeNotInitialized)?;
         self.rehydrate_scope(scope, dom, &mut ids, &mut to_mount)?;
 
-        self.interpreter.base().hydrate(ids, under);
+        self.interpreter.base().hydrate(ids.clone(), under.clone());
 
-        #[cfg(feature = "mounted")]
-        for id in to_mount {
-            self.send_mount_event(id);
+        // The comments around text nodes are removed while hydrating, so we collect the hydrated nodes afterwards.
+        // If we hydrated the root element, the nodes of the scope are its children
+        let nodes: Vec<web_sys::Node> = match under.as_slice() {
+            [root] if root.is_same_node(Some(&self.root)) => {
+                let children = root.child_nodes();
+                (0..children.length())
+                    .filter_map(|i| children.get(i))
+                    .collect()
+            }
+            _ => under
+                .into_iter()
+                .filter(|node| node.is_connected())
+                .collect(),
+        };
+
+        match self.verify_hydration(scope, dom, &ids, &nodes) {
+            Ok(()) => {
+                // Portal children aren't part of the nodes the scope hydrates, so we create them on the client instead
+                self.mount_portals(scope_id, dom);
+
+                #[cfg(feature = "mounted")]
+                for id in to_mount {
+                    self.send_mount_event(id);
+                }
+            }
+            // Remounting the scope creates new nodes with new ids. The new nodes queue their own mounted events
+            Err(mismatch) => {
+                tracing::error!("{mismatch}. Rendering the nodes on the client instead.");
+                self.recover_from_hydration_mismatch(scope_id, dom, nodes);
+            }
         }
 
+        // Every node is in the document now, so the mounted handlers can measure them
+        #[cfg(feature = "mounted")]
+        self.flush_queued_mounted_events();
+
         Ok(())
     }
 
+    /// Check that the nodes we just hydrated match the nodes the client rendered.
+    ///
+    /// In debug mode, this walks the whole tree so we can report exactly where the server and client disagree. In release
+    /// mode, we only check that every node the client rendered was found in the server html.
+    #[allow(unused_variables)]
+    fn verify_hydration(
+        &self,
+        scope: &ScopeState,
+        dom: &VirtualDom,
+        ids: &[u32],
+        nodes: &[web_sys::Node],
+    ) -> Result<(), HydrationMismatch> {
+        #[cfg(debug_assertions)]
+        {
+            super::HydrationVerifier::new(dom, self.interpreter.base()).verify_scope(scope, nodes)
+        }
+        #[cfg(not(debug_assertions))]
+        {
+            let base = self.interpreter.base();
+            match ids.iter().find(|id| base.get_node(**id).is_undefined()) {
+                Some(id) => Err(HydrationMismatch::missing_node(ElementId(*id as usize))),
+                None => Ok(()),
+            }
+        }
+    }
+
+    /// Throw away the nodes the server rendered for a scope and render them on the client instead
+    fn recover_from_hydration_mismatch(
+        &mut self,
+        scope_id: ScopeId,
+        dom: &mut VirtualDom,
+        server_nodes: Vec<web_sys::Node>,
+    ) {
+        // Create the new nodes in a fragment so we can swap them in for the server nodes all at once
+        let fragment = self.document.create_document_fragment();
+        self.interpreter.base().push_root(fragment.clone().into());
+        let m = dom.remount_scope(scope_id, self);
+        self.interpreter.append_children_to_top(m as u16);
+        self.interpreter.pop_root();
+        // Only apply the edits here. The mounted events are sent once the nodes are swapped into the document
+        self.interpreter.flush();
+
+        let first = server_nodes.first();
+        match first.and_then(|node| node.parent_node()) {
+            Some(parent) => _ = parent.insert_before(&fragment, first),
+            None => _ = self.root.append_child(&fragment),
+        }
+        for node in server_nodes {
+            if let Some(parent) = node.parent_node() {
+                _ = parent.remove_child(&node);
+            }
+        }
+    }
+
+    /// Create the children of the portals in a scope that was just hydrated and move them into their targets
+    fn mount_portals(&mut self, scope_id: ScopeId, dom: &mut VirtualDom) {
+        dom.remount_portals(scope_id, self);
+        self.flush_edits();
+    }
+
     pub fn rehydrate(
         &mut self,
-        vdom: &VirtualDom,
+        vdom: &mut VirtualDom,
     ) -> Result<UnboundedReceiver<SuspenseMessage>, RehydrationError> {
         let (mut tx, rx) = futures_channel::mpsc::unbounded();
         let closure = move |path: Vec<u32>, data: js_sys::Uint8Array| {
@@ -211,9 +304,21 @@ impl WebsysDom {
         dioxus_interpreter_js::minimal_bindings::register_rehydrate_chunk_for_streaming(&closure);
         closure.forget();
 
+        // The server renders a copy of the portals after the main element. Remove it before the client mounts its own portal children
+        if let Ok(server_portals) = self.document.query_selector_all("[data-dioxus-ssr-portal]") {
+            for i in 0..server_portals.length() {
+                if let Some(element) = server_portals
+                    .get(i)
+                    .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+                {
+                    element.remove();
+                }
+            }
+        }
+
         // Rehydrate the root scope that was rendered on the server. We will likely run into suspense boundaries.
         // Any suspense boundaries we run into are stored for hydration later.
-        self.start_hydration_at_scope(vdom.base_scope(), vdom, vec![self.root.clone().into()])?;
+        self.start_hydration_at_scope(ScopeId::ROOT, vdom, vec![self.root.clone().into()])?;
 
         Ok(rx)
     }
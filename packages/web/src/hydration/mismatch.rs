@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+/// A difference between the nodes the client rendered and the nodes the server sent
+#[derive(Debug)]
+pub(crate) struct HydrationMismatch {
+    /// The components and elements the mismatched node is rendered in
+    pub(crate) path: Vec<&'static str>,
+    /// A description of the node the client rendered
+    pub(crate) expected: String,
+    /// A description of the node the server rendered
+    pub(crate) found: String,
+}
+
+impl HydrationMismatch {
+    /// Create a mismatch for an element id that was never found in the server rendered html
+    #[cfg(not(debug_assertions))]
+    pub(crate) fn missing_node(id: dioxus_core::ElementId) -> Self {
+        Self {
+            path: Vec::new(),
+            expected: format!("a node for {id:?}"),
+            found: "nothing".to_string(),
+        }
+    }
+}
+
+impl Display for HydrationMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Hydration mismatch")?;
+        if !self.path.is_empty() {
+            write!(f, " in {}", self.path.join(" > "))?;
+        }
+        write!(
+            f,
+            ": the client rendered {} but the server rendered {}",
+            self.expected, self.found
+        )
+    }
+}
@@ -2,12 +2,20 @@
 mod deserialize;
 #[cfg(feature = "hydrate")]
 mod hydrate;
+#[cfg(feature = "hydrate")]
+mod mismatch;
+#[cfg(all(feature = "hydrate", debug_assertions))]
+mod verify;
 
 #[cfg(feature = "hydrate")]
 pub use deserialize::*;
 #[cfg(feature = "hydrate")]
 #[allow(unused)]
 pub use hydrate::*;
+#[cfg(feature = "hydrate")]
+pub(crate) use mismatch::*;
+#[cfg(all(feature = "hydrate", debug_assertions))]
+pub(crate) use verify::*;
 
 /// The message sent from the server to the client to hydrate a suspense boundary
 #[derive(Debug)]
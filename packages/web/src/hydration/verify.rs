@@ -0,0 +1,316 @@
+//! Verify that the nodes the server rendered match the nodes the client rendered during hydration.
+//!
+//! The first render on the client must produce the same tree as the server. If it doesn't (for example because a
+//! component reads the current time or local storage while rendering), hydration attaches the wrong nodes to the
+//! virtual dom and later edits corrupt the page. In debug mode we walk the virtual dom alongside the real dom after
+//! hydrating and report the first difference along with the components it is rendered in.
+
+use dioxus_core::prelude::*;
+use dioxus_core::{AttributeValue, DynamicNode, ElementId, TemplateAttribute};
+use dioxus_interpreter_js::unified_bindings::BaseInterpreter;
+use wasm_bindgen::JsCast;
+use web_sys::{Comment, Element, Node, Text};
+
+use super::HydrationMismatch;
+
+/// Walks the virtual dom and the hydrated real dom side by side
+pub(crate) struct HydrationVerifier<'a> {
+    dom: &'a VirtualDom,
+    interpreter: &'a BaseInterpreter,
+    path: Vec<&'static str>,
+}
+
+impl<'a> HydrationVerifier<'a> {
+    pub(crate) fn new(dom: &'a VirtualDom, interpreter: &'a BaseInterpreter) -> Self {
+        Self {
+            dom,
+            interpreter,
+            path: Vec::new(),
+        }
+    }
+
+    /// Verify that the nodes a scope rendered match the real nodes that were hydrated for it
+    pub(crate) fn verify_scope(
+        &mut self,
+        scope: &ScopeState,
+        nodes: &[Node],
+    ) -> Result<(), HydrationMismatch> {
+        let mut cursor = 0;
+        self.verify_vnode(scope.root_node(), nodes, &mut cursor)?;
+        self.verify_end(nodes, cursor)
+    }
+
+    fn verify_vnode(
+        &mut self,
+        vnode: &VNode,
+        nodes: &[Node],
+        cursor: &mut usize,
+    ) -> Result<(), HydrationMismatch> {
+        for (i, root) in vnode.template.get().roots.iter().enumerate() {
+            let root_id = vnode.mounted_root(i, self.dom);
+            self.verify_template_node(vnode, root, root_id, nodes, cursor)?;
+        }
+        Ok(())
+    }
+
+    fn verify_template_node(
+        &mut self,
+        vnode: &VNode,
+        node: &TemplateNode,
+        root_id: Option<ElementId>,
+        nodes: &[Node],
+        cursor: &mut usize,
+    ) -> Result<(), HydrationMismatch> {
+        match node {
+            TemplateNode::Element {
+                tag,
+                attrs,
+                children,
+                ..
+            } => {
+                let expected = || format!("<{tag}>");
+                let real = self.next_node(nodes, cursor, expected)?;
+                let Some(element) = real
+                    .dyn_ref::<Element>()
+                    .filter(|element| element.local_name().eq_ignore_ascii_case(tag))
+                else {
+                    return Err(self.mismatch(expected(), describe(&real)));
+                };
+
+                self.path.push(tag);
+                let mut mounted_id = root_id;
+                let mut inner_html = false;
+                for attr in *attrs {
+                    match attr {
+                        TemplateAttribute::Static {
+                            name,
+                            value,
+                            namespace: None,
+                        } => {
+                            if *name == "dangerous_inner_html" {
+                                inner_html = true;
+                            } else if !is_merged(vnode, attrs, name) {
+                                self.verify_attribute(element, name, value)?;
+                            }
+                        }
+                        TemplateAttribute::Dynamic { id } => {
+                            mounted_id = vnode.mounted_dynamic_attribute(*id, self.dom);
+                            for attribute in &*vnode.dynamic_attrs[*id] {
+                                if attribute.name == "dangerous_inner_html" {
+                                    inner_html = true;
+                                } else if let (None, AttributeValue::Text(value)) =
+                                    (attribute.namespace, &attribute.value)
+                                {
+                                    if !is_merged(vnode, attrs, attribute.name) {
+                                        self.verify_attribute(element, attribute.name, value)?;
+                                    }
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                self.verify_id(mounted_id, &real)?;
+
+                // The children of elements with inner html are not rendered by dioxus
+                if !inner_html {
+                    let real_children = child_nodes(&real);
+                    let mut child_cursor = 0;
+                    let mut children = children.iter().peekable();
+                    while let Some(child) = children.next() {
+                        match child {
+                            // Static text next to each other is merged into one text node by the browser
+                            TemplateNode::Text { text } => {
+                                let mut text = text.to_string();
+                                while let Some(TemplateNode::Text { text: next }) = children.peek()
+                                {
+                                    text.push_str(next);
+                                    children.next();
+                                }
+                                if !text.is_empty() {
+                                    self.verify_text(
+                                        &text,
+                                        None,
+                                        &real_children,
+                                        &mut child_cursor,
+                                    )?;
+                                }
+                            }
+                            _ => self.verify_template_node(
+                                vnode,
+                                child,
+                                None,
+                                &real_children,
+                                &mut child_cursor,
+                            )?,
+                        }
+                    }
+                    self.verify_end(&real_children, child_cursor)?;
+                }
+                self.path.pop();
+            }
+            TemplateNode::Text { text } => self.verify_text(text, root_id, nodes, cursor)?,
+            TemplateNode::Dynamic { id } => self.verify_dynamic_node(vnode, *id, nodes, cursor)?,
+        }
+        Ok(())
+    }
+
+    fn verify_dynamic_node(
+        &mut self,
+        vnode: &VNode,
+        idx: usize,
+        nodes: &[Node],
+        cursor: &mut usize,
+    ) -> Result<(), HydrationMismatch> {
+        match &vnode.dynamic_nodes[idx] {
+            DynamicNode::Text(text) => {
+                let id = vnode.mounted_dynamic_node(idx, self.dom);
+                self.verify_text(&text.value, id, nodes, cursor)?;
+            }
+            DynamicNode::Placeholder(_) => {
+                let expected = || "a placeholder".to_string();
+                let real = self.next_node(nodes, cursor, expected)?;
+                if !real.has_type::<Comment>() {
+                    return Err(self.mismatch(expected(), describe(&real)));
+                }
+                self.verify_id(vnode.mounted_dynamic_node(idx, self.dom), &real)?;
+            }
+            DynamicNode::Component(component) => {
+                let Some(scope) = component.mounted_scope(idx, vnode, self.dom) else {
+                    return Ok(());
+                };
+                // Portals render their children somewhere else, so the server doesn't render anything in their place
+                if PortalProps::downcast_from_scope(scope).is_some() {
+                    return Ok(());
+                }
+                self.path.push(component.name);
+                self.verify_vnode(scope.root_node(), nodes, cursor)?;
+                self.path.pop();
+            }
+            DynamicNode::Fragment(fragment) => {
+                for vnode in fragment {
+                    self.verify_vnode(vnode, nodes, cursor)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn verify_text(
+        &mut self,
+        text: &str,
+        id: Option<ElementId>,
+        nodes: &[Node],
+        cursor: &mut usize,
+    ) -> Result<(), HydrationMismatch> {
+        let expected = || format!("text {text:?}");
+        let real = self.next_node(nodes, cursor, expected)?;
+        match real.dyn_ref::<Text>() {
+            Some(real_text) if real_text.data() == text => self.verify_id(id, &real),
+            _ => Err(self.mismatch(expected(), describe(&real))),
+        }
+    }
+
+    fn verify_attribute(
+        &self,
+        element: &Element,
+        name: &str,
+        value: &str,
+    ) -> Result<(), HydrationMismatch> {
+        let real = element.get_attribute(name);
+        if real.as_deref() == Some(value) {
+            return Ok(());
+        }
+        let found = match real {
+            Some(real) => format!("{name}={real:?}"),
+            None => format!("no {name} attribute"),
+        };
+        Err(self.mismatch(format!("{name}={value:?}"), found))
+    }
+
+    /// Make sure the node hydrated for an id is the node we are looking at. If the structure of the server html is
+    /// different, the hydration ids will point to the wrong nodes
+    fn verify_id(&self, id: Option<ElementId>, real: &Node) -> Result<(), HydrationMismatch> {
+        let Some(id) = id else {
+            return Ok(());
+        };
+        let hydrated = self.interpreter.get_node(id.0 as u32);
+        if real.is_same_node(Some(&hydrated)) {
+            return Ok(());
+        }
+        let found = if hydrated.is_undefined() {
+            format!("no node for {id:?}")
+        } else {
+            format!("{id:?} on {}", describe(&hydrated))
+        };
+        Err(self.mismatch(format!("{id:?} on {}", describe(real)), found))
+    }
+
+    fn verify_end(&self, nodes: &[Node], cursor: usize) -> Result<(), HydrationMismatch> {
+        match nodes.get(cursor) {
+            Some(extra) => Err(self.mismatch("nothing".to_string(), describe(extra))),
+            None => Ok(()),
+        }
+    }
+
+    fn next_node(
+        &self,
+        nodes: &[Node],
+        cursor: &mut usize,
+        expected: impl FnOnce() -> String,
+    ) -> Result<Node, HydrationMismatch> {
+        let node = nodes
+            .get(*cursor)
+            .cloned()
+            .ok_or_else(|| self.mismatch(expected(), "nothing".to_string()))?;
+        *cursor += 1;
+        Ok(node)
+    }
+
+    fn mismatch(&self, expected: String, found: String) -> HydrationMismatch {
+        HydrationMismatch {
+            path: self.path.clone(),
+            expected,
+            found,
+        }
+    }
+}
+
+/// Attributes with the same name are merged into one attribute when rendered, so we can't compare them one by one
+fn is_merged(vnode: &VNode, attrs: &[TemplateAttribute], name: &str) -> bool {
+    let count = attrs
+        .iter()
+        .map(|attr| match attr {
+            TemplateAttribute::Static {
+                name: static_name,
+                namespace: None,
+                ..
+            } => (*static_name == name) as usize,
+            TemplateAttribute::Dynamic { id } => vnode.dynamic_attrs[*id]
+                .iter()
+                .filter(|attr| attr.namespace.is_none() && attr.name == name)
+                .count(),
+            _ => 0,
+        })
+        .sum::<usize>();
+    count > 1
+}
+
+fn child_nodes(node: &Node) -> Vec<Node> {
+    let children = node.child_nodes();
+    (0..children.length())
+        .filter_map(|i| children.get(i))
+        .collect()
+}
+
+fn describe(node: &Node) -> String {
+    if let Some(element) = node.dyn_ref::<Element>() {
+        format!("<{}>", element.local_name())
+    } else if let Some(text) = node.dyn_ref::<Text>() {
+        format!("text {:?}", text.data())
+    } else if let Some(comment) = node.dyn_ref::<Comment>() {
+        format!("comment {:?}", comment.data())
+    } else {
+        node.node_name()
+    }
+}
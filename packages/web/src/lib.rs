@@ -55,7 +55,7 @@ pub use hydration::*;
 /// let app_fut = dioxus_web::run_with_props(App, RootProps { name: String::from("foo") });
 /// wasm_bindgen_futures::spawn_local(app_fut);
 /// ```
-pub async fn run(virtual_dom: VirtualDom, web_config: Config) -> ! {
+pub async fn run(virtual_dom: VirtualDom, mut web_config: Config) -> ! {
     tracing::info!("Starting up");
 
     let mut dom = virtual_dom;
@@ -74,9 +74,15 @@ pub async fn run(virtual_dom: VirtualDom, web_config: Config) -> ! {
     let (tx, mut rx) = futures_channel::mpsc::unbounded();
 
     let should_hydrate = web_config.hydrate;
+    let template_cache = web_config.template_cache.take();
 
     let mut websys_dom = dom::WebsysDom::new(web_config, tx);
 
+    if let Some(cache) = template_cache {
+        websys_dom.preload_templates(&cache);
+        dom.preload_templates(&cache);
+    }
+
     tracing::info!("rebuilding app");
 
     let mut hydration_receiver: Option<futures_channel::mpsc::UnboundedReceiver<SuspenseMessage>> =
@@ -103,7 +109,7 @@ pub async fn run(virtual_dom: VirtualDom, web_config: Config) -> ! {
             });
             websys_dom.only_write_templates = false;
 
-            let rx = websys_dom.rehydrate(&dom).unwrap();
+            let rx = websys_dom.rehydrate(&mut dom).unwrap();
             hydration_receiver = Some(rx);
         }
         #[cfg(not(feature = "hydrate"))]
@@ -1,7 +1,7 @@
 use crate::dom::WebsysDom;
 use dioxus_core::prelude::*;
 use dioxus_core::WriteMutations;
-use dioxus_core::{AttributeValue, ElementId};
+use dioxus_core::{AttributeValue, ElementId, TemplateCache};
 use dioxus_html::event_bubbles;
 use dioxus_interpreter_js::minimal_bindings;
 use wasm_bindgen::JsCast;
@@ -58,8 +58,17 @@ impl WebsysDom {
         self.flush_queued_mounted_events();
     }
 
+    /// Create every template in a template cache before the first render
+    pub(crate) fn preload_templates(&mut self, cache: &TemplateCache) {
+        for template in cache {
+            if !self.templates.contains_key(template.name) {
+                self.register_template(*template);
+            }
+        }
+    }
+
     #[cfg(feature = "mounted")]
-    fn flush_queued_mounted_events(&mut self) {
+    pub(crate) fn flush_queued_mounted_events(&mut self) {
         for id in self.queued_mounted_events.drain(..) {
             let node = self.interpreter.base().get_node(id.0 as u32);
             if let Some(element) = node.dyn_ref::<web_sys::Element>() {
@@ -269,4 +278,11 @@ impl WriteMutations for WebsysDom {
         }
         self.interpreter.push_root(id.0 as u32)
     }
+
+    fn append_to_portal(&mut self, target: &str, m: usize) {
+        if self.only_write_templates() {
+            return;
+        }
+        self.interpreter.append_to_portal(target, m as u16)
+    }
 }
@@ -1,5 +1,6 @@
 use dioxus::prelude::*;
 use dioxus_web::Config;
+use wasm_bindgen::JsCast;
 use wasm_bindgen_test::wasm_bindgen_test;
 use web_sys::window;
 
@@ -54,3 +55,149 @@ fn rehydrates() {
 
     dioxus_web::launch::launch_cfg(app, Config::new().hydrate(true));
 }
+
+thread_local! {
+    static MOUNTED: std::cell::RefCell<Vec<bool>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/// Record whether the element was in the document when its mounted event fired
+fn record_mounted(event: MountedEvent) {
+    let element = event.data().downcast::<web_sys::Element>().unwrap();
+    MOUNTED.with(|mounted| mounted.borrow_mut().push(element.is_connected()));
+}
+
+/// Render the server html into a new root element and hydrate it with the app
+fn hydrate_server_html(root: &str, server_html: &str, app: fn() -> Element) -> web_sys::Element {
+    MOUNTED.with(|mounted| mounted.borrow_mut().clear());
+    let window = window().unwrap();
+    // This is normally written by the server. It is an empty list of server future results
+    js_sys::Reflect::set(
+        &window,
+        &"initial_dioxus_hydration_data".into(),
+        &"gA==".into(),
+    )
+    .unwrap();
+    let body = window.document().unwrap().body().unwrap();
+    body.set_inner_html(&format!("<div id='{root}'>{server_html}</div>"));
+
+    dioxus_web::launch::launch_cfg(app, Config::new().rootname(root).hydrate(true));
+    window.document().unwrap().get_element_by_id(root).unwrap()
+}
+
+async fn next_frame() {
+    gloo_timers::future::TimeoutFuture::new(50).await;
+}
+
+fn pre_render(app: fn() -> Element) -> String {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    dioxus_ssr::pre_render(&dom)
+}
+
+fn counter(name: &'static str) -> Element {
+    let mut count = use_signal(|| 0);
+    rsx! {
+        div {
+            p { "{name}" }
+            button { onmounted: record_mounted, onclick: move |_| count += 1, "{count}" }
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+async fn hydrates_matching_server_html() {
+    fn app() -> Element {
+        counter("shared")
+    }
+
+    let root = hydrate_server_html("hydrate-match", &pre_render(app), app);
+    let server_button = root.query_selector("button").unwrap().unwrap();
+    next_frame().await;
+
+    // The server nodes are reused and the mounted events fire for them
+    let button = root.query_selector("button").unwrap().unwrap();
+    assert!(button.is_same_node(Some(&server_button)));
+    assert_eq!(MOUNTED.with(|mounted| mounted.borrow().clone()), [true]);
+
+    button.unchecked_ref::<web_sys::HtmlElement>().click();
+    next_frame().await;
+    assert_eq!(button.text_content().unwrap(), "1");
+}
+
+#[wasm_bindgen_test]
+async fn recovers_from_mismatched_text() {
+    fn server() -> Element {
+        counter("server")
+    }
+    fn client() -> Element {
+        counter("client")
+    }
+
+    // The structure matches, but the text doesn't. Only the debug verifier can catch this
+    let root = hydrate_server_html("hydrate-text-mismatch", &pre_render(server), client);
+    let server_button = root.query_selector("button").unwrap().unwrap();
+    next_frame().await;
+
+    let paragraph = root.query_selector("p").unwrap().unwrap();
+    assert_eq!(paragraph.text_content().unwrap(), "client");
+    let button = root.query_selector("button").unwrap().unwrap();
+    assert!(!button.is_same_node(Some(&server_button)));
+    assert!(!server_button.is_connected());
+    assert_eq!(MOUNTED.with(|mounted| mounted.borrow().clone()), [true]);
+
+    button.unchecked_ref::<web_sys::HtmlElement>().click();
+    next_frame().await;
+    assert_eq!(button.text_content().unwrap(), "1");
+}
+
+#[wasm_bindgen_test]
+async fn recovers_from_mismatched_structure() {
+    fn app() -> Element {
+        counter("client")
+    }
+
+    let root = hydrate_server_html(
+        "hydrate-structure-mismatch",
+        "<span id='server-only'>server</span>",
+        app,
+    );
+    next_frame().await;
+
+    // The server nodes are replaced with the nodes the client rendered, and the new nodes are mounted in the document
+    assert!(root.query_selector("#server-only").unwrap().is_none());
+    assert_eq!(root.child_element_count(), 1);
+    let button = root.query_selector("button").unwrap().unwrap();
+    assert_eq!(button.text_content().unwrap(), "0");
+    assert_eq!(MOUNTED.with(|mounted| mounted.borrow().clone()), [true]);
+
+    button.unchecked_ref::<web_sys::HtmlElement>().click();
+    next_frame().await;
+    assert_eq!(button.text_content().unwrap(), "1");
+}
+
+#[wasm_bindgen_test]
+async fn creates_mathml_elements_in_the_mathml_namespace() {
+    fn app() -> Element {
+        let variable = "x";
+        rsx! {
+            math { display: "block",
+                mi { "{variable}" }
+            }
+        }
+    }
+
+    // The server html doesn't match, so the client creates every element itself
+    let root = hydrate_server_html("hydrate-mathml", "<span>server</span>", app);
+    next_frame().await;
+
+    let math = root.query_selector("math").unwrap().unwrap();
+    let mi = root.query_selector("mi").unwrap().unwrap();
+    for element in [&math, &mi] {
+        assert_eq!(
+            element.namespace_uri().as_deref(),
+            Some("http://www.w3.org/1998/Math/MathML")
+        );
+    }
+    assert_eq!(math.get_attribute("display").as_deref(), Some("block"));
+    assert_eq!(mi.text_content().unwrap(), "x");
+}